@@ -0,0 +1,44 @@
+// Debug HUD - текст поверх кадру (debug_hud.rs)
+//
+// Вершини в пікселях екрану (0,0 - лівий верхній кут), projection -
+// ортографічна матриця під розмір вікна. Атлас шрифту - R8 coverage.
+
+struct HudUniform {
+    projection: mat4x4<f32>,
+};
+
+@group(0) @binding(0)
+var<uniform> hud: HudUniform;
+
+@group(0) @binding(1)
+var font_texture: texture_2d<f32>;
+
+@group(0) @binding(2)
+var font_sampler: sampler;
+
+struct VertexInput {
+    @location(0) position: vec2<f32>,
+    @location(1) uv: vec2<f32>,
+    @location(2) color: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+    @location(1) color: vec4<f32>,
+};
+
+@vertex
+fn vs_main(in: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = hud.projection * vec4<f32>(in.position, 0.0, 1.0);
+    out.uv = in.uv;
+    out.color = in.color;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let coverage = textureSample(font_texture, font_sampler, in.uv).r;
+    return vec4<f32>(in.color.rgb, in.color.a * coverage);
+}
@@ -0,0 +1,50 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// ФАЙЛ: assets/shaders/debug_lines.wgsl
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// 📋 ПРИЗНАЧЕННЯ:
+//    Shader для debug ліній (осі кісток, вектори швидкостей).
+//    Лінії в world space, суцільний колір без освітлення.
+//
+// 🎯 ВІДПОВІДАЛЬНІСТЬ:
+//    - Vertex shader: world space → clip space
+//    - Fragment shader: колір вершини
+//
+// 🔗 ЗВ'ЯЗКИ:
+//    Використовується в: src/rendering/debug_lines.rs
+//    Uniform buffer: CameraUniform (view-projection матриця)
+//
+// 🕐 ІСТОРІЯ:
+//    2026-10-16: Створено - debug лінії для ragdoll
+//
+// ═══════════════════════════════════════════════════════════════════════════
+
+struct CameraUniform {
+    view_proj: mat4x4<f32>,
+};
+
+@group(0) @binding(0)
+var<uniform> camera: CameraUniform;
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) color: vec3<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec3<f32>,
+};
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var output: VertexOutput;
+    output.clip_position = camera.view_proj * vec4<f32>(input.position, 1.0);
+    output.color = input.color;
+    return output;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(input.color, 1.0);
+}
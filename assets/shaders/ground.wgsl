@@ -0,0 +1,154 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: assets/shaders/ground.wgsl
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   Shader підлоги арени: процедурна шахівниця у world space поверх кольору
+   вершин, з тим самим освітленням і тінями, що й mesh.wgsl.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Vertex shader: як у mesh.wgsl (+ world_position для шахівниці)
+   - Fragment shader: клітинки CHECKER_SIZE м, світлі / темні
+     (CHECKER_DARK); Lambert diffuse + ambient + тінь (PCF 3×3)
+
+🔗 ЗВ'ЯЗКИ:
+   Використовується в: src/rendering/mesh.rs (MeshShader::Ground)
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+   - Bind groups та vertex layout - як у mesh.wgsl (один pipeline layout)
+   - Шахівниця фільтрована (fwidth): вдалині клітинки зливаються в середній
+     тон, а не мерехтять муаром
+   - lighting = ambient + (1 - ambient) × color × N·L × shadow - як у mesh.wgsl
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - процедурна підлога замість однотонної
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+// Camera uniform (View-Projection matrix)
+struct CameraUniform {
+    view_proj: mat4x4<f32>,
+};
+@group(0) @binding(0)
+var<uniform> camera: CameraUniform;
+
+// Transform uniform (Model matrix + Normal matrix)
+struct TransformUniform {
+    model: mat4x4<f32>,
+    normal_matrix_0: vec4<f32>,
+    normal_matrix_1: vec4<f32>,
+    normal_matrix_2: vec4<f32>,
+    tint: vec4<f32>,
+};
+@group(1) @binding(0)
+var<uniform> transform: TransformUniform;
+
+// Directional light (src/rendering/light.rs - LightUniform)
+struct LightUniform {
+    view_proj: mat4x4<f32>, // world → shadow map
+    to_light: vec3<f32>,    // нормалізований напрямок ДО світла
+    ambient: f32,
+    color: vec3<f32>,
+    shadow_texel: f32,      // 1 / розмір shadow map
+};
+@group(2) @binding(0)
+var<uniform> light: LightUniform;
+@group(2) @binding(1)
+var shadow_map: texture_depth_2d;
+@group(2) @binding(2)
+var shadow_sampler: sampler_comparison;
+
+// Vertex input
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+    @location(2) color: vec3<f32>,
+};
+
+// Vertex output / Fragment input
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) world_normal: vec3<f32>,
+    @location(1) color: vec3<f32>,
+    @location(2) world_position: vec3<f32>,
+};
+
+// ============================================================================
+// VERTEX SHADER
+// ============================================================================
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var output: VertexOutput;
+
+    // Transform position: local → world → clip
+    let world_position = transform.model * vec4<f32>(input.position, 1.0);
+    output.clip_position = camera.view_proj * world_position;
+    output.world_position = world_position.xyz;
+
+    // Transform normal using normal matrix (3x3 upper-left of inverse transpose)
+    let normal_matrix = mat3x3<f32>(
+        transform.normal_matrix_0.xyz,
+        transform.normal_matrix_1.xyz,
+        transform.normal_matrix_2.xyz
+    );
+    output.world_normal = normal_matrix * input.normal;
+
+    // Pass color
+    output.color = input.color;
+
+    return output;
+}
+
+// ============================================================================
+// FRAGMENT SHADER
+// ============================================================================
+
+// Частка світла (0 - тінь, 1 - освітлено): shadow map, PCF 3×3
+fn shadow_factor(world_position: vec3<f32>) -> f32 {
+    let light_clip = light.view_proj * vec4<f32>(world_position, 1.0);
+    let ndc = light_clip.xyz / light_clip.w;
+    let uv = ndc.xy * vec2<f32>(0.5, -0.5) + vec2<f32>(0.5, 0.5);
+
+    var lit = 0.0;
+    for (var x = -1; x <= 1; x++) {
+        for (var y = -1; y <= 1; y++) {
+            let offset = vec2<f32>(f32(x), f32(y)) * light.shadow_texel;
+            lit += textureSampleCompareLevel(shadow_map, shadow_sampler, uv + offset, ndc.z);
+        }
+    }
+
+    // За межами frustum'а світла - без тіні
+    let outside = any(uv < vec2<f32>(0.0)) || any(uv > vec2<f32>(1.0)) || ndc.z > 1.0;
+    return select(lit / 9.0, 1.0, outside);
+}
+
+// Розмір клітинки шахівниці (метри)
+const CHECKER_SIZE: f32 = 1.0;
+
+// Яскравість темних клітинок відносно світлих
+const CHECKER_DARK: f32 = 0.75;
+
+// Шахівниця 0 (світла) .. 1 (темна), box-фільтр по розміру пікселя
+fn checker(p: vec2<f32>) -> f32 {
+    let w = max(fwidth(p), vec2<f32>(1e-4));
+    let i = 2.0 * (abs(fract((p - 0.5 * w) * 0.5) - 0.5) - abs(fract((p + 0.5 * w) * 0.5) - 0.5)) / w;
+    return 0.5 - 0.5 * i.x * i.y;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    let normal = normalize(input.world_normal);
+
+    // Lambert diffuse з тінню
+    let diffuse = max(dot(normal, light.to_light), 0.0) * shadow_factor(input.world_position);
+    let lighting = light.ambient + (1.0 - light.ambient) * diffuse * light.color;
+
+    // Шахівниця - до освітлення (тінь на обох тонах однакова)
+    let tone = mix(1.0, CHECKER_DARK, checker(input.world_position.xz / CHECKER_SIZE));
+    let final_color = input.color * tone * lighting * transform.tint.rgb;
+
+    return vec4<f32>(final_color, transform.tint.a);
+}
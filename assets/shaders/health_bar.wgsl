@@ -0,0 +1,86 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// ФАЙЛ: assets/shaders/health_bar.wgsl
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// 📋 ПРИЗНАЧЕННЯ:
+//    Shader смуг здоров'я над ворогами (billboard quads).
+//    Геометрії немає - 12 вершин на instance з vertex_index:
+//    0..5 - фон на всю ширину, 6..11 - заповнення на fill ширини.
+//
+// 🎯 ВІДПОВІДАЛЬНІСТЬ:
+//    - Vertex shader: кут quad'а → center + right * x + up * y → clip space
+//    - Fragment shader: суцільний колір (фон темний, заповнення - instance)
+//
+// 🔗 ЗВ'ЯЗКИ:
+//    Використовується в: src/rendering/health_bar.rs
+//    Uniform buffer: CameraUniform (view-projection матриця)
+//
+// 🕐 ІСТОРІЯ:
+//    2026-10-16: Створено - смуги здоров'я ворогів
+//
+// ═══════════════════════════════════════════════════════════════════════════
+
+struct CameraUniform {
+    view_proj: mat4x4<f32>,
+};
+
+@group(0) @binding(0)
+var<uniform> camera: CameraUniform;
+
+struct InstanceInput {
+    @location(0) center: vec3<f32>,
+    @location(1) fill: f32,
+    @location(2) right: vec3<f32>, // половина ширини смуги
+    @location(3) up: vec3<f32>,    // половина висоти смуги
+    @location(4) color: vec3<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec3<f32>,
+};
+
+// Фон смуги
+const BACKGROUND_COLOR: vec3<f32> = vec3<f32>(0.08, 0.08, 0.08);
+
+// Зсув заповнення до камери (проти z-fighting з фоном)
+const FILL_OFFSET: f32 = 0.005;
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32, instance: InstanceInput) -> VertexOutput {
+    // Два трикутники quad'а: u - 0..1 вздовж смуги, v - -1..1 по висоті
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(0.0, -1.0),
+        vec2<f32>(1.0, -1.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(0.0, -1.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(0.0, 1.0),
+    );
+    let corner = corners[vertex_index % 6u];
+    let is_fill = vertex_index >= 6u;
+
+    var width = 1.0;
+    var position = instance.center;
+    var color = BACKGROUND_COLOR;
+    if is_fill {
+        width = clamp(instance.fill, 0.0, 1.0);
+        // right × up - до камери
+        position += normalize(cross(instance.right, instance.up)) * FILL_OFFSET;
+        color = instance.color;
+    }
+
+    // Заповнення росте від лівого краю
+    let x = -1.0 + 2.0 * corner.x * width;
+    position += instance.right * x + instance.up * corner.y;
+
+    var output: VertexOutput;
+    output.clip_position = camera.view_proj * vec4<f32>(position, 1.0);
+    output.color = color;
+    return output;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(input.color, 1.0);
+}
@@ -0,0 +1,106 @@
+// Instanced mesh shader - одна геометрія, багато екземплярів (вороги)
+//
+// Як mesh.wgsl, але model / normal matrix та tint - з instance buffer
+// (MeshInstance, src/rendering/instanced_mesh.rs) замість transform uniform.
+// Освітлення - LightUniform (group(1)), shadow_factor - копія з mesh.wgsl
+
+struct CameraUniform {
+    view_proj: mat4x4<f32>,
+}
+
+@group(0) @binding(0)
+var<uniform> camera: CameraUniform;
+
+// Directional light (src/rendering/light.rs - LightUniform)
+struct LightUniform {
+    view_proj: mat4x4<f32>, // world → shadow map
+    to_light: vec3<f32>,    // нормалізований напрямок ДО світла
+    ambient: f32,
+    color: vec3<f32>,
+    shadow_texel: f32,      // 1 / розмір shadow map
+}
+
+@group(1) @binding(0)
+var<uniform> light: LightUniform;
+@group(1) @binding(1)
+var shadow_map: texture_depth_2d;
+@group(1) @binding(2)
+var shadow_sampler: sampler_comparison;
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+    @location(2) color: vec3<f32>,
+}
+
+struct InstanceInput {
+    @location(3) model_matrix_0: vec4<f32>,
+    @location(4) model_matrix_1: vec4<f32>,
+    @location(5) model_matrix_2: vec4<f32>,
+    @location(6) model_matrix_3: vec4<f32>,
+    @location(7) normal_matrix_0: vec4<f32>,
+    @location(8) normal_matrix_1: vec4<f32>,
+    @location(9) normal_matrix_2: vec4<f32>,
+    @location(10) tint: vec4<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) world_normal: vec3<f32>,
+    @location(1) color: vec3<f32>,
+    @location(2) world_position: vec3<f32>,
+    @location(3) tint: vec4<f32>,
+}
+
+@vertex
+fn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {
+    let model_matrix = mat4x4<f32>(
+        instance.model_matrix_0,
+        instance.model_matrix_1,
+        instance.model_matrix_2,
+        instance.model_matrix_3,
+    );
+    let normal_matrix = mat3x3<f32>(
+        instance.normal_matrix_0.xyz,
+        instance.normal_matrix_1.xyz,
+        instance.normal_matrix_2.xyz,
+    );
+
+    var out: VertexOutput;
+    let world_position = model_matrix * vec4<f32>(vertex.position, 1.0);
+    out.clip_position = camera.view_proj * world_position;
+    out.world_position = world_position.xyz;
+    out.world_normal = normal_matrix * vertex.normal;
+    out.color = vertex.color;
+    out.tint = instance.tint;
+    return out;
+}
+
+// Частка світла (0 - тінь, 1 - освітлено): shadow map, PCF 3×3
+fn shadow_factor(world_position: vec3<f32>) -> f32 {
+    let light_clip = light.view_proj * vec4<f32>(world_position, 1.0);
+    let ndc = light_clip.xyz / light_clip.w;
+    let uv = ndc.xy * vec2<f32>(0.5, -0.5) + vec2<f32>(0.5, 0.5);
+
+    var lit = 0.0;
+    for (var x = -1; x <= 1; x++) {
+        for (var y = -1; y <= 1; y++) {
+            let offset = vec2<f32>(f32(x), f32(y)) * light.shadow_texel;
+            lit += textureSampleCompareLevel(shadow_map, shadow_sampler, uv + offset, ndc.z);
+        }
+    }
+
+    // За межами frustum'а світла - без тіні
+    let outside = any(uv < vec2<f32>(0.0)) || any(uv > vec2<f32>(1.0)) || ndc.z > 1.0;
+    return select(lit / 9.0, 1.0, outside);
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let normal = normalize(in.world_normal);
+    let diffuse = max(dot(normal, light.to_light), 0.0) * shadow_factor(in.world_position);
+    let lighting = light.ambient + (1.0 - light.ambient) * diffuse * light.color;
+
+    // tint екземпляра: rgb - замах, alpha - fade-out мертвого ворога
+    return vec4<f32>(in.color * lighting * in.tint.rgb, in.tint.a);
+}
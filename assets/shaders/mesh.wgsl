@@ -8,7 +8,8 @@
 
 🎯 ВІДПОВІДАЛЬНІСТЬ:
    - Vertex shader: transform position через Model matrix, pass normal та color
-   - Fragment shader: базове diffuse освітлення (directional light)
+   - Fragment shader: Lambert diffuse від directional light (LightUniform)
+     з тінню з shadow map (PCF 3×3)
 
 🔗 ЗВ'ЯЗКИ:
    Використовується в: src/rendering/mesh.rs
@@ -16,12 +17,15 @@
 ⚠️  ВАЖЛИВІ ДЕТАЛІ:
    - Model matrix: local space → world space
    - Normal matrix: для коректної трансформації нормалей (inverse transpose)
-   - Directional light: фіксований напрямок (зверху-спереду)
-   - Ambient light: 0.3 (щоб тіні не були повністю чорними)
+   - Directional light: LightUniform в group(2) (src/rendering/light.rs)
+   - lighting = ambient + (1 - ambient) × color × N·L × shadow - як у skeleton.wgsl
 
 🕐 ІСТОРІЯ:
    2025-12-14: Створено - базовий mesh shader з diffuse lighting
    2025-12-14: Додано Model matrix та Normal matrix
+   2026-10-16: Додано tint (RGBA) - прозорість для fade-out
+   2026-10-16: Світло з uniform (group(2)) замість захардкодженого
+   2026-10-16: Тіні - shadow map у group(2) (src/rendering/shadow.rs)
 
 ═══════════════════════════════════════════════════════════════════════════════
 */
@@ -39,11 +43,26 @@ struct TransformUniform {
     normal_matrix_0: vec4<f32>,
     normal_matrix_1: vec4<f32>,
     normal_matrix_2: vec4<f32>,
-    _padding: vec4<f32>,
+    tint: vec4<f32>,
 };
 @group(1) @binding(0)
 var<uniform> transform: TransformUniform;
 
+// Directional light (src/rendering/light.rs - LightUniform)
+struct LightUniform {
+    view_proj: mat4x4<f32>, // world → shadow map
+    to_light: vec3<f32>,    // нормалізований напрямок ДО світла
+    ambient: f32,
+    color: vec3<f32>,
+    shadow_texel: f32,      // 1 / розмір shadow map
+};
+@group(2) @binding(0)
+var<uniform> light: LightUniform;
+@group(2) @binding(1)
+var shadow_map: texture_depth_2d;
+@group(2) @binding(2)
+var shadow_sampler: sampler_comparison;
+
 // Vertex input
 struct VertexInput {
     @location(0) position: vec3<f32>,
@@ -56,6 +75,7 @@ struct VertexOutput {
     @builtin(position) clip_position: vec4<f32>,
     @location(0) world_normal: vec3<f32>,
     @location(1) color: vec3<f32>,
+    @location(2) world_position: vec3<f32>,
 };
 
 // ============================================================================
@@ -69,6 +89,7 @@ fn vs_main(input: VertexInput) -> VertexOutput {
     // Transform position: local → world → clip
     let world_position = transform.model * vec4<f32>(input.position, 1.0);
     output.clip_position = camera.view_proj * world_position;
+    output.world_position = world_position.xyz;
 
     // Transform normal using normal matrix (3x3 upper-left of inverse transpose)
     let normal_matrix = mat3x3<f32>(
@@ -88,29 +109,40 @@ fn vs_main(input: VertexInput) -> VertexOutput {
 // FRAGMENT SHADER
 // ============================================================================
 
+// Частка світла (0 - тінь, 1 - освітлено): shadow map, PCF 3×3
+fn shadow_factor(world_position: vec3<f32>) -> f32 {
+    let light_clip = light.view_proj * vec4<f32>(world_position, 1.0);
+    let ndc = light_clip.xyz / light_clip.w;
+    let uv = ndc.xy * vec2<f32>(0.5, -0.5) + vec2<f32>(0.5, 0.5);
+
+    var lit = 0.0;
+    for (var x = -1; x <= 1; x++) {
+        for (var y = -1; y <= 1; y++) {
+            let offset = vec2<f32>(f32(x), f32(y)) * light.shadow_texel;
+            lit += textureSampleCompareLevel(shadow_map, shadow_sampler, uv + offset, ndc.z);
+        }
+    }
+
+    // За межами frustum'а світла - без тіні
+    let outside = any(uv < vec2<f32>(0.0)) || any(uv > vec2<f32>(1.0)) || ndc.z > 1.0;
+    return select(lit / 9.0, 1.0, outside);
+}
+
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
-    // Directional light (normalized direction FROM light TO surface)
-    // Light comes from top-front-right (typical 3-point lighting key light position)
-    let light_dir = normalize(vec3<f32>(0.5, 1.0, 0.3));
-
     // Normalize the interpolated normal
     let normal = normalize(input.world_normal);
 
-    // Ambient light (base illumination so shadows aren't pitch black)
-    let ambient = 0.3;
-
     // Diffuse lighting (Lambert)
     // dot(N, L) gives cosine of angle between normal and light
     // max(0, ...) clamps negative values (surfaces facing away from light)
-    let diffuse = max(dot(normal, light_dir), 0.0);
+    let diffuse = max(dot(normal, light.to_light), 0.0) * shadow_factor(input.world_position);
 
-    // Final lighting = ambient + diffuse
-    // Clamped to 1.0 to prevent over-brightening
-    let lighting = min(ambient + diffuse, 1.0);
+    // Ambient + diffuse (з білим світлом не більше 1.0; в тіні - лише ambient)
+    let lighting = light.ambient + (1.0 - light.ambient) * diffuse * light.color;
 
-    // Apply lighting to color
-    let final_color = input.color * lighting;
+    // Apply lighting to color + runtime tint (alpha для fade-out)
+    let final_color = input.color * lighting * transform.tint.rgb;
 
-    return vec4<f32>(final_color, 1.0);
+    return vec4<f32>(final_color, transform.tint.a);
 }
@@ -0,0 +1,144 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// ФАЙЛ: assets/shaders/outline.wgsl
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// 📋 ПРИЗНАЧЕННЯ:
+//    Outline (силует) для гравця та цільового ворога - техніка "inverted hull".
+//    Геометрія роздувається вздовж нормалей і малюється тільки back-faces
+//    суцільним кольором. Основний mesh перекриває центр, залишається контур.
+//
+// 🎯 ВІДПОВІДАЛЬНІСТЬ:
+//    - vs_mesh: для Mesh (MeshVertex + TransformUniform)
+//    - vs_skeleton: для капсул скелета (CapsuleVertex + BoneInstance)
+//    - fs_main: суцільний колір контуру
+//
+// 🔗 ЗВ'ЯЗКИ:
+//    Використовується в: src/rendering/outline.rs
+//
+// ⚠️  ВАЖЛИВІ ДЕТАЛІ:
+//    - Роздування в WORLD space (товщина не залежить від scale mesh)
+//    - group(1) = OutlineUniform, group(2) = TransformUniform (тільки vs_mesh)
+//
+// 🕐 ІСТОРІЯ:
+//    2026-10-16: Створено - inverted hull outline
+//
+// ═══════════════════════════════════════════════════════════════════════════
+
+struct CameraUniform {
+    view_proj: mat4x4<f32>,
+};
+@group(0) @binding(0)
+var<uniform> camera: CameraUniform;
+
+// Параметри контуру
+struct OutlineUniform {
+    color: vec4<f32>,
+    // x = товщина (world units), yzw = unused
+    params: vec4<f32>,
+};
+@group(1) @binding(0)
+var<uniform> outline: OutlineUniform;
+
+// Transform для Mesh (той самий layout що в mesh.wgsl)
+struct TransformUniform {
+    model: mat4x4<f32>,
+    normal_matrix_0: vec4<f32>,
+    normal_matrix_1: vec4<f32>,
+    normal_matrix_2: vec4<f32>,
+    tint: vec4<f32>,
+};
+@group(2) @binding(0)
+var<uniform> transform: TransformUniform;
+
+struct MeshVertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+    @location(2) color: vec3<f32>,
+};
+
+struct CapsuleVertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+};
+
+struct InstanceInput {
+    @location(2) model_matrix_0: vec4<f32>,
+    @location(3) model_matrix_1: vec4<f32>,
+    @location(4) model_matrix_2: vec4<f32>,
+    @location(5) model_matrix_3: vec4<f32>,
+    @location(6) color: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+};
+
+// Зсуває world position вздовж нормалі на товщину контуру
+fn inflate(world_position: vec3<f32>, world_normal: vec3<f32>) -> VertexOutput {
+    var output: VertexOutput;
+    let n = normalize(world_normal);
+    let inflated = world_position + n * outline.params.x;
+    output.clip_position = camera.view_proj * vec4<f32>(inflated, 1.0);
+    return output;
+}
+
+@vertex
+fn vs_mesh(input: MeshVertexInput) -> VertexOutput {
+    let world_position = transform.model * vec4<f32>(input.position, 1.0);
+    let normal_matrix = mat3x3<f32>(
+        transform.normal_matrix_0.xyz,
+        transform.normal_matrix_1.xyz,
+        transform.normal_matrix_2.xyz
+    );
+    return inflate(world_position.xyz, normal_matrix * input.normal);
+}
+
+// Екземпляр InstancedMesh (MeshInstance, без tint)
+struct MeshInstanceInput {
+    @location(3) model_matrix_0: vec4<f32>,
+    @location(4) model_matrix_1: vec4<f32>,
+    @location(5) model_matrix_2: vec4<f32>,
+    @location(6) model_matrix_3: vec4<f32>,
+    @location(7) normal_matrix_0: vec4<f32>,
+    @location(8) normal_matrix_1: vec4<f32>,
+    @location(9) normal_matrix_2: vec4<f32>,
+};
+
+@vertex
+fn vs_mesh_instanced(input: MeshVertexInput, instance: MeshInstanceInput) -> VertexOutput {
+    let model_matrix = mat4x4<f32>(
+        instance.model_matrix_0,
+        instance.model_matrix_1,
+        instance.model_matrix_2,
+        instance.model_matrix_3,
+    );
+    let normal_matrix = mat3x3<f32>(
+        instance.normal_matrix_0.xyz,
+        instance.normal_matrix_1.xyz,
+        instance.normal_matrix_2.xyz
+    );
+    let world_position = model_matrix * vec4<f32>(input.position, 1.0);
+    return inflate(world_position.xyz, normal_matrix * input.normal);
+}
+
+@vertex
+fn vs_skeleton(vertex: CapsuleVertexInput, instance: InstanceInput) -> VertexOutput {
+    let model_matrix = mat4x4<f32>(
+        instance.model_matrix_0,
+        instance.model_matrix_1,
+        instance.model_matrix_2,
+        instance.model_matrix_3,
+    );
+    let world_position = model_matrix * vec4<f32>(vertex.position, 1.0);
+    let normal_matrix = mat3x3<f32>(
+        model_matrix[0].xyz,
+        model_matrix[1].xyz,
+        model_matrix[2].xyz,
+    );
+    return inflate(world_position.xyz, normal_matrix * vertex.normal);
+}
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    return outline.color;
+}
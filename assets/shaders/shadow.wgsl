@@ -0,0 +1,83 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// ФАЙЛ: assets/shaders/shadow.wgsl
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// 📋 ПРИЗНАЧЕННЯ:
+//    Shadow map pre-pass - глибина сцени з точки зору directional light.
+//    Тільки vertex shaders: fragment stage не потрібен (пишеться лише depth).
+//
+// 🎯 ВІДПОВІДАЛЬНІСТЬ:
+//    - vs_mesh: Mesh (MeshVertex + TransformUniform)
+//    - vs_skeleton: капсули скелета (CapsuleVertex + BoneInstance)
+//
+// 🔗 ЗВ'ЯЗКИ:
+//    Використовується в: src/rendering/shadow.rs
+//    Результат читають mesh.wgsl та skeleton.wgsl (LightUniform.view_proj)
+//
+// ⚠️  ВАЖЛИВІ ДЕТАЛІ:
+//    - group(0) = view-projection світла (layout як у CameraUniform)
+//    - group(1) = TransformUniform (тільки vs_mesh)
+//
+// 🕐 ІСТОРІЯ:
+//    2026-10-16: Створено - shadow map для mesh та скелетів
+//
+// ═══════════════════════════════════════════════════════════════════════════
+
+struct CameraUniform {
+    view_proj: mat4x4<f32>,
+};
+@group(0) @binding(0)
+var<uniform> light_camera: CameraUniform;
+
+// Transform для Mesh (той самий layout що в mesh.wgsl)
+struct TransformUniform {
+    model: mat4x4<f32>,
+    normal_matrix_0: vec4<f32>,
+    normal_matrix_1: vec4<f32>,
+    normal_matrix_2: vec4<f32>,
+    tint: vec4<f32>,
+};
+@group(1) @binding(0)
+var<uniform> transform: TransformUniform;
+
+struct InstanceInput {
+    @location(2) model_matrix_0: vec4<f32>,
+    @location(3) model_matrix_1: vec4<f32>,
+    @location(4) model_matrix_2: vec4<f32>,
+    @location(5) model_matrix_3: vec4<f32>,
+};
+
+@vertex
+fn vs_mesh(@location(0) position: vec3<f32>) -> @builtin(position) vec4<f32> {
+    return light_camera.view_proj * transform.model * vec4<f32>(position, 1.0);
+}
+
+// Екземпляр InstancedMesh (MeshInstance) - лише model matrix
+struct MeshInstanceInput {
+    @location(3) model_matrix_0: vec4<f32>,
+    @location(4) model_matrix_1: vec4<f32>,
+    @location(5) model_matrix_2: vec4<f32>,
+    @location(6) model_matrix_3: vec4<f32>,
+};
+
+@vertex
+fn vs_mesh_instanced(@location(0) position: vec3<f32>, instance: MeshInstanceInput) -> @builtin(position) vec4<f32> {
+    let model_matrix = mat4x4<f32>(
+        instance.model_matrix_0,
+        instance.model_matrix_1,
+        instance.model_matrix_2,
+        instance.model_matrix_3,
+    );
+    return light_camera.view_proj * model_matrix * vec4<f32>(position, 1.0);
+}
+
+@vertex
+fn vs_skeleton(@location(0) position: vec3<f32>, instance: InstanceInput) -> @builtin(position) vec4<f32> {
+    let model_matrix = mat4x4<f32>(
+        instance.model_matrix_0,
+        instance.model_matrix_1,
+        instance.model_matrix_2,
+        instance.model_matrix_3,
+    );
+    return light_camera.view_proj * model_matrix * vec4<f32>(position, 1.0);
+}
@@ -4,6 +4,9 @@
 // - Кожен тип кістки має свій mesh з правильними розмірами
 // - Shader просто застосовує position/rotation (без scaling/taper)
 // - Це гарантує правильні пропорції капсул
+//
+// Освітлення - LightUniform (group(1)), та сама формула, що й у mesh.wgsl,
+// разом з тінню з shadow map (shadow_factor - копія з mesh.wgsl)
 
 struct CameraUniform {
     view_proj: mat4x4<f32>,
@@ -12,6 +15,22 @@ struct CameraUniform {
 @group(0) @binding(0)
 var<uniform> camera: CameraUniform;
 
+// Directional light (src/rendering/light.rs - LightUniform)
+struct LightUniform {
+    view_proj: mat4x4<f32>, // world → shadow map
+    to_light: vec3<f32>,    // нормалізований напрямок ДО світла
+    ambient: f32,
+    color: vec3<f32>,
+    shadow_texel: f32,      // 1 / розмір shadow map
+}
+
+@group(1) @binding(0)
+var<uniform> light: LightUniform;
+@group(1) @binding(1)
+var shadow_map: texture_depth_2d;
+@group(1) @binding(2)
+var shadow_sampler: sampler_comparison;
+
 struct VertexInput {
     @location(0) position: vec3<f32>,
     @location(1) normal: vec3<f32>,
@@ -29,6 +48,7 @@ struct VertexOutput {
     @builtin(position) clip_position: vec4<f32>,
     @location(0) world_normal: vec3<f32>,
     @location(1) color: vec3<f32>,
+    @location(2) world_position: vec3<f32>,
 }
 
 @vertex
@@ -55,22 +75,39 @@ fn vs_main(
 
     var output: VertexOutput;
     output.clip_position = camera.view_proj * world_position;
+    output.world_position = world_position.xyz;
     output.world_normal = normalize(normal_matrix * vertex.normal);
     output.color = instance.color.rgb;
 
     return output;
 }
 
+// Частка світла (0 - тінь, 1 - освітлено): shadow map, PCF 3×3
+fn shadow_factor(world_position: vec3<f32>) -> f32 {
+    let light_clip = light.view_proj * vec4<f32>(world_position, 1.0);
+    let ndc = light_clip.xyz / light_clip.w;
+    let uv = ndc.xy * vec2<f32>(0.5, -0.5) + vec2<f32>(0.5, 0.5);
+
+    var lit = 0.0;
+    for (var x = -1; x <= 1; x++) {
+        for (var y = -1; y <= 1; y++) {
+            let offset = vec2<f32>(f32(x), f32(y)) * light.shadow_texel;
+            lit += textureSampleCompareLevel(shadow_map, shadow_sampler, uv + offset, ndc.z);
+        }
+    }
+
+    // За межами frustum'а світла - без тіні
+    let outside = any(uv < vec2<f32>(0.0)) || any(uv > vec2<f32>(1.0)) || ndc.z > 1.0;
+    return select(lit / 9.0, 1.0, outside);
+}
+
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
-    // Simple directional lighting
-    let light_dir = normalize(vec3<f32>(0.5, 1.0, 0.3));
-    let ndotl = max(dot(input.world_normal, light_dir), 0.0);
-
-    let ambient = 0.3;
-    let diffuse = ndotl * 0.7;
+    // Directional light (Lambert) + ambient
+    let ndotl = max(dot(normalize(input.world_normal), light.to_light), 0.0) * shadow_factor(input.world_position);
+    let lighting = light.ambient + (1.0 - light.ambient) * ndotl * light.color;
 
-    let final_color = input.color * (ambient + diffuse);
+    let final_color = input.color * lighting;
 
     return vec4<f32>(final_color, 1.0);
 }
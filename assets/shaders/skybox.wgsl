@@ -0,0 +1,62 @@
+// ═══════════════════════════════════════════════════════════════════════════
+// ФАЙЛ: assets/shaders/skybox.wgsl
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// 📋 ПРИЗНАЧЕННЯ:
+//    Небо - вертикальний градієнт за напрямком променя камери.
+//
+// 🎯 ВІДПОВІДАЛЬНІСТЬ:
+//    - Vertex shader: fullscreen трикутник з vertex_index (без vertex buffer)
+//    - Fragment shader: промінь камери через inv_view_proj → колір
+//      від bottom_color (горизонт і нижче) до top_color (зеніт)
+//
+// 🔗 ЗВ'ЯЗКИ:
+//    Використовується в: src/rendering/skybox.rs
+//    Uniform buffer: SkyboxUniform (group 0)
+//
+// ⚠️  ВАЖЛИВІ ДЕТАЛІ:
+//    - Промінь - різниця точок на near (z = 0) та far (z = 1) площинах NDC,
+//      тож позиція камери в uniform не потрібна
+//    - Градієнт залежить лише від dir.y - небо не рухається при русі камери
+//
+// 🕐 ІСТОРІЯ:
+//    2026-10-16: Створено - градієнтне небо
+//
+// ═══════════════════════════════════════════════════════════════════════════
+
+struct SkyboxUniform {
+    inv_view_proj: mat4x4<f32>,
+    top_color: vec4<f32>,
+    bottom_color: vec4<f32>,
+};
+
+@group(0) @binding(0)
+var<uniform> sky: SkyboxUniform;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) ndc: vec2<f32>,
+};
+
+// Трикутник (-1,-1), (3,-1), (-1,3) покриває весь екран
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    let x = f32(i32(vertex_index & 1u) * 4 - 1);
+    let y = f32(i32(vertex_index >> 1u) * 4 - 1);
+
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(x, y, 0.0, 1.0);
+    out.ndc = vec2<f32>(x, y);
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let near = sky.inv_view_proj * vec4<f32>(in.ndc, 0.0, 1.0);
+    let far = sky.inv_view_proj * vec4<f32>(in.ndc, 1.0, 1.0);
+    let dir = normalize(far.xyz / far.w - near.xyz / near.w);
+
+    // Трохи нижче горизонту - вже bottom_color, зеніт - top_color
+    let t = smoothstep(-0.1, 0.6, dir.y);
+    return vec4<f32>(mix(sky.bottom_color.rgb, sky.top_color.rgb, t), 1.0);
+}
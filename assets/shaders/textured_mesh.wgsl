@@ -0,0 +1,151 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: assets/shaders/textured_mesh.wgsl
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   WGSL shader для mesh з текстурою (TexturedVertex з UV).
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Vertex shader: як у mesh.wgsl, але передає UV замість кольору вершини
+   - Fragment shader: колір з текстури × освітлення (як у mesh.wgsl) × tint
+
+🔗 ЗВ'ЯЗКИ:
+   Використовується в: src/rendering/textured_mesh.rs
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+   - group(0..2) - ті самі що в mesh.wgsl (camera, transform, light)
+   - group(3) = texture + sampler (src/rendering/texture.rs)
+   - Освітлення та тіні - копія mesh.wgsl (тримати синхронно)
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - textured mesh pipeline
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+// Camera uniform (View-Projection matrix)
+struct CameraUniform {
+    view_proj: mat4x4<f32>,
+};
+@group(0) @binding(0)
+var<uniform> camera: CameraUniform;
+
+// Transform uniform (Model matrix + Normal matrix)
+struct TransformUniform {
+    model: mat4x4<f32>,
+    normal_matrix_0: vec4<f32>,
+    normal_matrix_1: vec4<f32>,
+    normal_matrix_2: vec4<f32>,
+    tint: vec4<f32>,
+};
+@group(1) @binding(0)
+var<uniform> transform: TransformUniform;
+
+// Directional light (src/rendering/light.rs - LightUniform)
+struct LightUniform {
+    view_proj: mat4x4<f32>, // world → shadow map
+    to_light: vec3<f32>,    // нормалізований напрямок ДО світла
+    ambient: f32,
+    color: vec3<f32>,
+    shadow_texel: f32,      // 1 / розмір shadow map
+};
+@group(2) @binding(0)
+var<uniform> light: LightUniform;
+@group(2) @binding(1)
+var shadow_map: texture_depth_2d;
+@group(2) @binding(2)
+var shadow_sampler: sampler_comparison;
+
+// Текстура поверхні (src/rendering/texture.rs)
+@group(3) @binding(0)
+var surface_texture: texture_2d<f32>;
+@group(3) @binding(1)
+var surface_sampler: sampler;
+
+// Vertex input
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+};
+
+// Vertex output / Fragment input
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) world_normal: vec3<f32>,
+    @location(1) uv: vec2<f32>,
+    @location(2) world_position: vec3<f32>,
+};
+
+// ============================================================================
+// VERTEX SHADER
+// ============================================================================
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var output: VertexOutput;
+
+    // Transform position: local → world → clip
+    let world_position = transform.model * vec4<f32>(input.position, 1.0);
+    output.clip_position = camera.view_proj * world_position;
+    output.world_position = world_position.xyz;
+
+    // Transform normal using normal matrix (3x3 upper-left of inverse transpose)
+    let normal_matrix = mat3x3<f32>(
+        transform.normal_matrix_0.xyz,
+        transform.normal_matrix_1.xyz,
+        transform.normal_matrix_2.xyz
+    );
+    output.world_normal = normal_matrix * input.normal;
+
+    // Pass UV
+    output.uv = input.uv;
+
+    return output;
+}
+
+// ============================================================================
+// FRAGMENT SHADER
+// ============================================================================
+
+// Частка світла (0 - тінь, 1 - освітлено): shadow map, PCF 3×3
+fn shadow_factor(world_position: vec3<f32>) -> f32 {
+    let light_clip = light.view_proj * vec4<f32>(world_position, 1.0);
+    let ndc = light_clip.xyz / light_clip.w;
+    let uv = ndc.xy * vec2<f32>(0.5, -0.5) + vec2<f32>(0.5, 0.5);
+
+    var lit = 0.0;
+    for (var x = -1; x <= 1; x++) {
+        for (var y = -1; y <= 1; y++) {
+            let offset = vec2<f32>(f32(x), f32(y)) * light.shadow_texel;
+            lit += textureSampleCompareLevel(shadow_map, shadow_sampler, uv + offset, ndc.z);
+        }
+    }
+
+    // За межами frustum'а світла - без тіні
+    let outside = any(uv < vec2<f32>(0.0)) || any(uv > vec2<f32>(1.0)) || ndc.z > 1.0;
+    return select(lit / 9.0, 1.0, outside);
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    // Normalize the interpolated normal
+    let normal = normalize(input.world_normal);
+
+    // Diffuse lighting (Lambert)
+    // dot(N, L) gives cosine of angle between normal and light
+    // max(0, ...) clamps negative values (surfaces facing away from light)
+    let diffuse = max(dot(normal, light.to_light), 0.0) * shadow_factor(input.world_position);
+
+    // Ambient + diffuse (з білим світлом не більше 1.0; в тіні - лише ambient)
+    let lighting = light.ambient + (1.0 - light.ambient) * diffuse * light.color;
+
+    // Колір з текстури (sRGB → linear робить формат текстури)
+    let albedo = textureSample(surface_texture, surface_sampler, input.uv);
+
+    // Apply lighting to texture + runtime tint (alpha для fade-out)
+    let final_color = albedo.rgb * lighting * transform.tint.rgb;
+
+    return vec4<f32>(final_color, albedo.a * transform.tint.a);
+}
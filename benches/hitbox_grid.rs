@@ -0,0 +1,78 @@
+//! Broadphase HitboxManager::check_collisions: сітка по XZ проти перебору всіх пар
+//!
+//! `cargo bench --bench hitbox_grid`
+
+use std::hint::black_box;
+
+use arena_combat::combat::{Hitbox, HitboxManager};
+use arena_combat::enemy::Enemy;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use glam::Vec3;
+
+/// Ворогів на арені
+const ENEMY_COUNT: usize = 500;
+
+/// Hitbox'ів гравця за кадр (лезо + сфери)
+const HITBOX_COUNT: usize = 16;
+
+const ENEMY_RADIUS: f32 = 0.5;
+
+/// Вороги рівномірною сіткою на арені 40×40 м
+fn enemies() -> Vec<Enemy> {
+    let side = (ENEMY_COUNT as f32).sqrt().ceil() as usize;
+    let spacing = 40.0 / side as f32;
+    (0..ENEMY_COUNT)
+        .map(|i| {
+            let (x, z) = ((i % side) as f32, (i / side) as f32);
+            Enemy::new(Vec3::new(x * spacing - 20.0, 0.0, z * spacing - 20.0))
+        })
+        .collect()
+}
+
+/// Лезо меча в центрі та сфери, розкидані по арені
+fn hitboxes() -> HitboxManager {
+    let mut manager = HitboxManager::new();
+    manager.spawn(Hitbox::capsule(Vec3::new(0.3, 1.2, -0.6), Vec3::new(0.3, 1.2, -1.8), 0.15, 0.2, 10.0));
+    for i in 1..HITBOX_COUNT {
+        let angle = i as f32 * 0.7;
+        let position = Vec3::new(angle.cos() * i as f32, 1.0, angle.sin() * i as f32);
+        manager.spawn(Hitbox::new(position, 0.6, 0.2, 10.0));
+    }
+    manager
+}
+
+/// Перебір всіх пар (hitbox × живий ворог) - те, що замінила сітка
+fn brute_force(manager: &mut HitboxManager, enemies: &[Enemy]) -> usize {
+    let mut hits = 0;
+    for hitbox in manager.iter_mut() {
+        for enemy in enemies.iter().filter(|e| e.is_alive()) {
+            if hitbox.collides_swept(enemy.position + Vec3::Y, ENEMY_RADIUS) {
+                hits += 1;
+            }
+        }
+    }
+    hits
+}
+
+fn bench_broadphase(c: &mut Criterion) {
+    let enemies = enemies();
+    let mut group = c.benchmark_group("hitbox_500_enemies");
+    group.bench_function("grid", |b| {
+        b.iter_batched(
+            hitboxes,
+            |mut manager| black_box(manager.check_collisions(&enemies, ENEMY_RADIUS, None)),
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("brute_force", |b| {
+        b.iter_batched(
+            hitboxes,
+            |mut manager| black_box(brute_force(&mut manager, &enemies)),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_broadphase);
+criterion_main!(benches);
@@ -0,0 +1,339 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/arena.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   ArenaConfig - розмір арени з одного значення (half_extent).
+
+   Раніше земля (collider 50×50), grid (size = 20) та межі були незалежними
+   hard-coded числами - видимий grid був набагато меншим за фізичну землю.
+   Тепер земля, grid та стіни по периметру рахуються з одного half_extent.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Розмір арени (від -half_extent до +half_extent по X та Z)
+   - Форма: квадрат (4 стіни) або коло радіуса half_extent (кільце
+     сегментів, ring_wall_boxes)
+   - Висота та товщина стін по периметру, їхні boxes (wall_boxes) - спільні
+     для colliders та mesh
+   - Позиції, що керуються не фізикою (AI ворогів), - всередині стін
+   - Читання з settings.ini ([arena]) та CLI (--arena-size=N)
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Імпортує:
+   - settings.rs - секція [arena]
+
+   Експортує для:
+   - sim.rs - PhysicsWorld::create_ground / create_walls, create_arena (коло)
+   - renderer.rs - підлога та mesh стін (set_arena)
+   - main.rs - розмір grid, позиції ворогів (clamp_inside)
+
+⚠️  ВАЖЛИВІ ОБМЕЖЕННЯ:
+   1. CLI має пріоритет над settings.ini
+   2. Grid має цілий розмір - half_extent округлюється вгору
+   3. Стіни - фізичні colliders і mesh з тих самих wall_boxes
+   4. Кругла арена: товщина кільця - RING_WALL_THICKNESS (wall_thickness
+      лише для квадрата), земля та grid - квадрат, що вміщує коло
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - єдиний розмір для землі, grid та стін
+   2026-10-16: wall_boxes (стіни видно), clamp_inside для позицій ворогів
+   2026-10-17: ArenaShape::Circle - кругла стіна з сегментів ([arena] shape)
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use std::f32::consts::PI;
+
+use glam::{Quat, Vec3};
+
+use crate::settings::Settings;
+
+/// Товщина стіни круглої арени (метри)
+pub const RING_WALL_THICKNESS: f32 = 0.5;
+
+/// Найбільша довжина сегмента кільця по внутрішній грані (метри)
+const MAX_RING_SEGMENT_LENGTH: f32 = 2.0;
+
+/// Найменше число сегментів кільця
+const MIN_RING_SEGMENTS: usize = 12;
+
+/// Форма арени
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ArenaShape {
+    /// Квадрат від -half_extent до +half_extent, 4 стіни
+    #[default]
+    Square,
+    /// Коло радіуса half_extent, стіна з сегментів
+    Circle,
+}
+
+impl ArenaShape {
+    /// Парсить назву ("square" / "circle")
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "square" => Some(Self::Square),
+            "circle" => Some(Self::Circle),
+            _ => None,
+        }
+    }
+}
+
+/// Box стіни: центр, половини розмірів та поворот навколо Y
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WallBox {
+    pub center: Vec3,
+    pub half_extents: Vec3,
+    /// Поворот навколо Y (радіани)
+    pub yaw: f32,
+}
+
+impl WallBox {
+    /// Box без повороту
+    pub fn axis_aligned(center: Vec3, half_extents: Vec3) -> Self {
+        Self { center, half_extents, yaw: 0.0 }
+    }
+
+    /// Поворот box'а
+    pub fn rotation(&self) -> Quat {
+        Quat::from_rotation_y(self.yaw)
+    }
+}
+
+/// Кругла стіна: сегменти-box'и по колу, внутрішня грань на `radius`
+///
+/// Сегменти не довші за MAX_RING_SEGMENT_LENGTH і перекриваються по
+/// зовнішній грані - щілин між ними немає. wall_height = 0 - без стін.
+pub fn ring_wall_boxes(radius: f32, wall_height: f32, wall_thickness: f32) -> Vec<WallBox> {
+    if wall_height <= 0.0 {
+        return Vec::new();
+    }
+    let circumference = 2.0 * PI * radius;
+    let count = ((circumference / MAX_RING_SEGMENT_LENGTH).ceil() as usize).max(MIN_RING_SEGMENTS);
+    let step = 2.0 * PI / count as f32;
+    let half_thickness = wall_thickness * 0.5;
+    // Половина довжини - по зовнішній грані, щоб сусідні сегменти сходились
+    let half_length = (radius + wall_thickness) * (step * 0.5).tan();
+    let distance = radius + half_thickness;
+
+    (0..count)
+        .map(|i| {
+            let angle = i as f32 * step;
+            WallBox {
+                center: Vec3::new(angle.cos() * distance, wall_height * 0.5, angle.sin() * distance),
+                // Локальна X - по дотичній, Z - по радіусу
+                half_extents: Vec3::new(half_length, wall_height * 0.5, half_thickness),
+                yaw: -(angle + PI * 0.5),
+            }
+        })
+        .collect()
+}
+
+/// Конфігурація арени
+#[derive(Debug, Clone, Copy)]
+pub struct ArenaConfig {
+    /// Половина розміру арени (world units); для кола - радіус
+    pub half_extent: f32,
+
+    /// Квадрат або коло
+    pub shape: ArenaShape,
+
+    /// Висота стін по периметру
+    pub wall_height: f32,
+
+    /// Товщина стін
+    pub wall_thickness: f32,
+}
+
+impl ArenaConfig {
+    /// Читає секцію [arena] з налаштувань
+    ///
+    /// Ключі: `half_extent`, `shape`, `wall_height`, `wall_thickness`
+    pub fn from_settings(settings: &Settings) -> Self {
+        let default = Self::default();
+        let shape = match settings.get_str("arena.shape") {
+            Some(name) => ArenaShape::from_name(name).unwrap_or_else(|| {
+                log::warn!("settings: невідома arena.shape {:?}, використовуємо square", name);
+                ArenaShape::Square
+            }),
+            None => default.shape,
+        };
+        Self {
+            half_extent: settings.get_f32("arena.half_extent", default.half_extent),
+            shape,
+            wall_height: settings.get_f32("arena.wall_height", default.wall_height),
+            wall_thickness: settings.get_f32("arena.wall_thickness", default.wall_thickness),
+        }
+        .validated()
+    }
+
+    /// Застосовує аргументи командного рядка
+    ///
+    /// Підтримується `--arena-size=N` та `--arena-size N` (N = half_extent)
+    pub fn with_args(mut self, args: impl IntoIterator<Item = String>) -> Self {
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let value = if let Some(v) = arg.strip_prefix("--arena-size=") {
+                Some(v.to_string())
+            } else if arg == "--arena-size" {
+                args.next()
+            } else {
+                None
+            };
+
+            if let Some(value) = value {
+                match value.parse::<f32>() {
+                    Ok(half_extent) => self.half_extent = half_extent,
+                    Err(_) => log::warn!("--arena-size: некоректне значення {:?}", value),
+                }
+            }
+        }
+        self.validated()
+    }
+
+    /// Розмір grid (ціле число ліній від центру)
+    pub fn grid_size(&self) -> i32 {
+        self.half_extent.ceil() as i32
+    }
+
+    /// Стіни по периметру
+    ///
+    /// Квадрат: 4 box'и, внутрішня грань - на half_extent, стіни перекривають
+    /// кути. Коло: ring_wall_boxes радіуса half_extent.
+    /// wall_height = 0 - без стін.
+    pub fn wall_boxes(&self) -> Vec<WallBox> {
+        if self.shape == ArenaShape::Circle {
+            return ring_wall_boxes(self.half_extent, self.wall_height, RING_WALL_THICKNESS);
+        }
+        if self.wall_height <= 0.0 {
+            return Vec::new();
+        }
+        let half_height = self.wall_height * 0.5;
+        let half_thickness = self.wall_thickness * 0.5;
+        let offset = self.half_extent + half_thickness;
+        let half_length = self.half_extent + self.wall_thickness;
+
+        vec![
+            WallBox::axis_aligned(Vec3::new(offset, half_height, 0.0), Vec3::new(half_thickness, half_height, half_length)),
+            WallBox::axis_aligned(Vec3::new(-offset, half_height, 0.0), Vec3::new(half_thickness, half_height, half_length)),
+            WallBox::axis_aligned(Vec3::new(0.0, half_height, offset), Vec3::new(half_length, half_height, half_thickness)),
+            WallBox::axis_aligned(Vec3::new(0.0, half_height, -offset), Vec3::new(half_length, half_height, half_thickness)),
+        ]
+    }
+
+    /// Позиція всередині арени (XZ), не ближче `margin` до стін
+    pub fn clamp_inside(&self, position: Vec3, margin: f32) -> Vec3 {
+        let limit = (self.half_extent - margin).max(0.0);
+        match self.shape {
+            ArenaShape::Square => {
+                Vec3::new(position.x.clamp(-limit, limit), position.y, position.z.clamp(-limit, limit))
+            }
+            ArenaShape::Circle => {
+                let horizontal = Vec3::new(position.x, 0.0, position.z).clamp_length_max(limit);
+                Vec3::new(horizontal.x, position.y, horizontal.z)
+            }
+        }
+    }
+
+    /// Обмежує значення розумними межами
+    fn validated(mut self) -> Self {
+        if self.half_extent.is_nan() || self.half_extent < 2.0 {
+            log::warn!("arena.half_extent = {} замалий, використовуємо 2.0", self.half_extent);
+            self.half_extent = 2.0;
+        }
+        self.wall_height = self.wall_height.max(0.0);
+        self.wall_thickness = self.wall_thickness.max(0.05);
+        self
+    }
+}
+
+impl Default for ArenaConfig {
+    fn default() -> Self {
+        Self {
+            half_extent: 20.0,
+            shape: ArenaShape::Square,
+            wall_height: 3.0,
+            wall_thickness: 0.5,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn circle(radius: f32) -> ArenaConfig {
+        ArenaConfig {
+            half_extent: radius,
+            shape: ArenaShape::Circle,
+            ..ArenaConfig::default()
+        }
+    }
+
+    /// Кут центру сегмента по XZ
+    fn angle_of(wall: &WallBox) -> f32 {
+        wall.center.z.atan2(wall.center.x)
+    }
+
+    #[test]
+    fn shape_from_settings() {
+        let arena = ArenaConfig::from_settings(&Settings::parse("[arena]\nshape = Circle\nhalf_extent = 8\n"));
+        assert_eq!(arena.shape, ArenaShape::Circle);
+        assert_eq!(arena.half_extent, 8.0);
+        let arena = ArenaConfig::from_settings(&Settings::parse("[arena]\nshape = hexagon\n"));
+        assert_eq!(arena.shape, ArenaShape::Square);
+        assert_eq!(ArenaConfig::from_settings(&Settings::parse("")).shape, ArenaShape::Square);
+    }
+
+    #[test]
+    fn ring_inner_face_on_radius() {
+        let radius = 10.0;
+        let walls = ring_wall_boxes(radius, 3.0, RING_WALL_THICKNESS);
+        assert!(walls.len() >= MIN_RING_SEGMENTS);
+        for wall in &walls {
+            // Локальна Z сегмента - по радіусу, внутрішня грань - на radius
+            let radial = Vec3::new(wall.center.x, 0.0, wall.center.z).normalize();
+            assert!((wall.rotation() * Vec3::Z).dot(radial).abs() > 0.999, "сегмент не вздовж дотичної");
+            let inner_face = wall.center - radial * wall.half_extents.z;
+            assert!((Vec3::new(inner_face.x, 0.0, inner_face.z).length() - radius).abs() < 1e-3);
+            assert_eq!(wall.center.y, 1.5);
+        }
+    }
+
+    #[test]
+    fn ring_segments_leave_no_gaps() {
+        let walls = ring_wall_boxes(20.0, 3.0, RING_WALL_THICKNESS);
+        let step = 2.0 * PI / walls.len() as f32;
+        for wall in &walls {
+            // Кінці сегмента по дотичній досягають кутів сусідніх сегментів
+            let tangent = wall.rotation() * Vec3::X;
+            let end = wall.center + tangent * wall.half_extents.x;
+            let end_angle = end.z.atan2(end.x) - angle_of(wall);
+            let end_angle = (end_angle + PI).rem_euclid(2.0 * PI) - PI;
+            assert!(end_angle.abs() >= step * 0.5 - 1e-4);
+            assert!(wall.half_extents.x * 2.0 <= MAX_RING_SEGMENT_LENGTH * 1.2);
+        }
+        assert!(ring_wall_boxes(20.0, 0.0, RING_WALL_THICKNESS).is_empty());
+    }
+
+    #[test]
+    fn circle_arena_uses_ring_walls() {
+        let arena = circle(6.0);
+        assert_eq!(arena.wall_boxes(), ring_wall_boxes(6.0, arena.wall_height, RING_WALL_THICKNESS));
+        assert_eq!(ArenaConfig::default().wall_boxes().len(), 4);
+    }
+
+    #[test]
+    fn clamp_inside_circle() {
+        let arena = circle(10.0);
+        let clamped = arena.clamp_inside(Vec3::new(30.0, 2.0, 40.0), 1.0);
+        assert!((Vec3::new(clamped.x, 0.0, clamped.z).length() - 9.0).abs() < 1e-4);
+        assert_eq!(clamped.y, 2.0);
+        // Кут квадрата всередині квадрата, але поза колом
+        let corner = Vec3::new(8.0, 0.0, 8.0);
+        assert_eq!(ArenaConfig::default().clamp_inside(corner, 1.0), corner);
+        assert!(arena.clamp_inside(corner, 1.0).length() < 9.0 + 1e-4);
+        let inside = Vec3::new(1.0, 0.0, -2.0);
+        assert_eq!(arena.clamp_inside(inside, 1.0), inside);
+    }
+}
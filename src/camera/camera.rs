@@ -46,11 +46,31 @@
 
 🕐 ІСТОРІЯ:
    2025-12-14: Створено - базова 3D camera з perspective projection
+   2026-10-16: Налаштовувані межі pitch для third person (set_pitch_limits)
+   2026-10-16: set_clip_planes з валідацією znear/zfar, near_plane_for_far
+   2026-10-16: Колізія third person камери - max_distance в update_third_person,
+               миттєве наближення та плавне відновлення distance
+   2026-10-16: Lock-on - update_lock_on (гравець і ціль в кадрі), lock_on_distance
+   2026-10-16: Free-fly (debug spectator) - set_free_fly, rotate_free_fly,
+               update_free_fly; third person yaw / pitch при цьому не змінюються
+   2026-10-16: CameraMode (first / third person) - toggle_mode, update_first_person;
+               обидва режими дивляться за тими самими yaw / pitch
+   2026-10-16: collision_enabled та skin_width (camera.collision / skin_width)
+   2026-10-16: follow_stiffness - експоненційне згладжування target (не залежить від FPS)
+   2026-10-16: Тряска (add_shake / update_shake) - trauma², лише у view matrix;
+               position / target не змінюються
+   2026-10-16: ProjectionMode - perspective (за замовчуванням) або orthographic
+               (camera.projection, ortho_height)
+   2026-10-16: screen_ray - промінь з пікселя екрана (mouse picking)
+   2026-10-17: obstruction_distance - sphere-cast колізії з main.rs у Camera
 
 ═══════════════════════════════════════════════════════════════════════════════
 */
 
-use glam::{Mat4, Vec3};
+use glam::{Mat4, Quat, Vec3};
+
+use crate::physics::{Group, PhysicsWorld};
+use crate::settings::Settings;
 
 /// 3D Camera з perspective projection
 ///
@@ -70,6 +90,9 @@ pub struct Camera {
     /// Field of View (вертикальний кут огляду) в радіанах
     pub fovy: f32,
 
+    /// Perspective (fovy) або orthographic (видима висота)
+    pub projection: ProjectionMode,
+
     /// Aspect ratio (width / height)
     pub aspect: f32,
 
@@ -89,10 +112,183 @@ pub struct Camera {
     /// Відстань камери від target
     pub distance: f32,
 
+    /// Мінімальний pitch third person камери (радіани)
+    min_pitch: f32,
+
+    /// Максимальний pitch third person камери (радіани)
+    max_pitch: f32,
+
     /// Згладжена позиція target (для smooth follow)
     smoothed_target: Vec3,
+
+    /// Жорсткість слідування target за гравцем (1/с): більше - менше
+    /// відставання; f32::INFINITY - target миттєво на гравці
+    pub follow_stiffness: f32,
+
+    /// Фактична відстань third person камери (<= distance при перешкоді)
+    collision_distance: f32,
+
+    /// Чи main.rs обмежує відстань sphere-cast'ом (false - камера крізь стіни)
+    pub collision_enabled: bool,
+
+    /// Відступ камери перед перешкодою (м) - не торкається поверхні впритул
+    pub skin_width: f32,
+
+    /// Від першої чи третьої особи (update_third_person у FirstPerson - no-op)
+    mode: CameraMode,
+
+    // === Free-fly Camera ===
+    /// Орієнтація free-fly камери (None - third person)
+    free_fly: Option<FreeFly>,
+
+    /// Швидкість free-fly камери (м/с)
+    pub free_fly_speed: f32,
+
+    /// Множник швидкості free-fly з прискоренням (Shift)
+    pub free_fly_boost: f32,
+
+    // === Camera Shake ===
+    /// Trauma тряски (0..1): зсув та поворот ∝ trauma²
+    trauma: f32,
+
+    /// Час тряски (секунди) - фаза шуму
+    shake_time: f32,
+
+    /// Швидкість згасання trauma (1/с): trauma 1 згасає за 1 / shake_decay секунд
+    pub shake_decay: f32,
+
+    /// Зсув камери при trauma = 1 (м)
+    pub shake_max_offset: f32,
+
+    /// Поворот камери (yaw / pitch / roll) при trauma = 1 (радіани)
+    pub shake_max_angle: f32,
+}
+
+/// Проєкція камери
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ProjectionMode {
+    /// Перспектива з вертикальним кутом fovy
+    #[default]
+    Perspective,
+    /// Без перспективи: `height` - видима висота (world units),
+    /// ширина = height × aspect (вид зверху, мінікарта, debug overlay)
+    Orthographic { height: f32 },
+}
+
+impl ProjectionMode {
+    /// Читає секцію [camera] з налаштувань
+    ///
+    /// Ключі: `projection` (perspective / orthographic), `ortho_height`
+    pub fn from_settings(settings: &Settings) -> Self {
+        match settings.get_str("camera.projection").map(|name| name.trim().to_lowercase()) {
+            Some(name) if name == "orthographic" => Self::Orthographic {
+                height: settings.get_f32("camera.ortho_height", DEFAULT_ORTHO_HEIGHT).max(0.1),
+            },
+            Some(name) if name != "perspective" => {
+                log::warn!("settings: невідома camera.projection {:?}, використовуємо perspective", name);
+                Self::Perspective
+            }
+            _ => Self::Perspective,
+        }
+    }
 }
 
+/// Режим камери гравця
+///
+/// Обидва режими дивляться вздовж -spherical_direction(yaw, pitch), тож
+/// перемикання не змінює напрямок погляду (лише позицію камери).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CameraMode {
+    /// Камера в голові персонажа, погляд - yaw / pitch напряму
+    FirstPerson,
+    /// Камера на орбіті навколо гравця
+    #[default]
+    ThirdPerson,
+}
+
+/// Орієнтація free-fly камери - окремо від third person yaw / pitch,
+/// щоб після виходу камера повернулась за спину гравця
+///
+/// Конвенція та сама, що в third_person_direction: напрямок погляду =
+/// -spherical_direction(yaw, pitch), тож миша обертає однаково.
+#[derive(Debug, Clone, Copy)]
+struct FreeFly {
+    yaw: f32,
+    pitch: f32,
+}
+
+/// Дефолтна видима висота orthographic проєкції (world units)
+pub const DEFAULT_ORTHO_HEIGHT: f32 = 20.0;
+
+/// Дефолтний мінімальний pitch third person (не дивимось знизу вгору)
+pub const DEFAULT_MIN_PITCH_DEG: f32 = -30.0;
+
+/// Дефолтний максимальний pitch third person (не перевертаємось через верх)
+pub const DEFAULT_MAX_PITCH_DEG: f32 = 85.0;
+
+/// Мінімально допустимий znear - менше дає втрату точності depth (z-fighting)
+pub const MIN_ZNEAR: f32 = 0.01;
+
+/// Максимальне співвідношення zfar / znear для Depth32Float
+///
+/// build_projection_matrix додатково стискає depth в [0.5, 1] (opengl_to_wgpu),
+/// тому точності менше ніж зазвичай - тримаємо співвідношення скромним.
+pub const MAX_DEPTH_RATIO: f32 = 1000.0;
+
+/// Максимальна відстань third person камери від гравця
+pub const THIRD_PERSON_MAX_DISTANCE: f32 = 20.0;
+
+/// Радіус сфери камери для sphere-cast колізії (з запасом на znear)
+pub const CAMERA_COLLISION_RADIUS: f32 = 0.2;
+
+/// Відступ камери перед перешкодою за замовчуванням (м)
+pub const DEFAULT_CAMERA_SKIN_WIDTH: f32 = 0.1;
+
+/// Мінімальна відстань при перешкоді - ближче камера опиняється в голові
+const CAMERA_MIN_COLLISION_DISTANCE: f32 = 0.5;
+
+/// Дефолтна жорсткість слідування (≈ колишній lerp 0.15 за кадр при 60 FPS)
+pub const DEFAULT_FOLLOW_STIFFNESS: f32 = 10.0;
+
+/// Швидкість повернення камери на distance після зникнення перешкоди (1/с)
+const CAMERA_RECOVERY_SPEED: f32 = 4.0;
+
+/// Швидкість повороту yaw за спину гравця при lock-on (1/с)
+const LOCK_ON_TURN_SPEED: f32 = 8.0;
+
+/// Частка вертикального FOV, в яку вписуються гравець та ціль при lock-on
+const LOCK_ON_FOV_FILL: f32 = 0.8;
+
+/// Абсолютна межа pitch - при ±90° look-at вироджується (gimbal lock)
+const PITCH_HARD_LIMIT_DEG: f32 = 89.0;
+
+/// Дефолтна швидкість free-fly камери (м/с)
+pub const DEFAULT_FREE_FLY_SPEED: f32 = 6.0;
+
+/// Дефолтний множник швидкості free-fly з Shift
+pub const DEFAULT_FREE_FLY_BOOST: f32 = 4.0;
+
+/// Межі швидкості free-fly при зміні колесом миші (м/с)
+const FREE_FLY_SPEED_RANGE: (f32, f32) = (0.5, 50.0);
+
+/// Множник швидкості free-fly за одну одиницю прокрутки
+const FREE_FLY_SPEED_STEP: f32 = 1.25;
+
+/// Дефолтна швидкість згасання trauma (1/с)
+pub const DEFAULT_SHAKE_DECAY: f32 = 1.5;
+
+/// Дефолтний зсув камери при trauma = 1 (м)
+pub const DEFAULT_SHAKE_MAX_OFFSET: f32 = 0.15;
+
+/// Дефолтний поворот камери при trauma = 1 (градуси)
+pub const DEFAULT_SHAKE_MAX_ANGLE_DEG: f32 = 3.0;
+
+/// Частота шуму тряски (радіани фази за секунду)
+const SHAKE_FREQUENCY: f32 = 25.0;
+
+/// Залишок trauma, що вважається нулем (похибка f32 від суми delta)
+const SHAKE_TRAUMA_EPSILON: f32 = 1e-4;
+
 impl Camera {
     /// Створює нову камеру з заданими параметрами
     ///
@@ -106,6 +302,8 @@ impl Camera {
     ///
     /// # Приклад
     /// ```
+    /// # use arena_combat::camera::Camera;
+    /// # use glam::Vec3;
     /// let camera = Camera::new(
     ///     Vec3::new(0.0, 2.0, 5.0),
     ///     Vec3::new(0.0, 0.0, 0.0),
@@ -128,37 +326,75 @@ impl Camera {
             target,
             up: Vec3::Y, // Стандартний "вгору" = (0, 1, 0)
             fovy: 45.0_f32.to_radians(), // 45 градусів у радіанах
+            projection: ProjectionMode::Perspective,
             aspect,
             znear: 0.1,
             zfar: 100.0,
             yaw,
             pitch,
             distance: distance.max(1.0),
+            min_pitch: DEFAULT_MIN_PITCH_DEG.to_radians(),
+            max_pitch: DEFAULT_MAX_PITCH_DEG.to_radians(),
             smoothed_target: target,
+            follow_stiffness: DEFAULT_FOLLOW_STIFFNESS,
+            collision_distance: distance.max(1.0),
+            collision_enabled: true,
+            skin_width: DEFAULT_CAMERA_SKIN_WIDTH,
+            mode: CameraMode::ThirdPerson,
+            free_fly: None,
+            free_fly_speed: DEFAULT_FREE_FLY_SPEED,
+            free_fly_boost: DEFAULT_FREE_FLY_BOOST,
+            trauma: 0.0,
+            shake_time: 0.0,
+            shake_decay: DEFAULT_SHAKE_DECAY,
+            shake_max_offset: DEFAULT_SHAKE_MAX_OFFSET,
+            shake_max_angle: DEFAULT_SHAKE_MAX_ANGLE_DEG.to_radians(),
         }
     }
 
     /// Будує view matrix (world space → camera space)
     ///
     /// Використовує "look-at" матрицю для перетворення координат
-    /// з world space в camera space.
+    /// з world space в camera space. Тряска - тимчасовий зсув та поворот
+    /// поверх position / target (самі вони не змінюються).
     ///
     /// # Повертає
     /// Mat4 - view матриця
     pub fn build_view_matrix(&self) -> Mat4 {
-        Mat4::look_at_rh(self.position, self.target, self.up)
+        let Some((offset, angles)) = self.shake() else {
+            return Mat4::look_at_rh(self.position, self.target, self.up);
+        };
+        let forward = (self.target - self.position).normalize_or_zero();
+        let right = forward.cross(self.up).normalize_or_zero();
+        if right == Vec3::ZERO {
+            return Mat4::look_at_rh(self.position, self.target, self.up);
+        }
+        let up = right.cross(forward);
+
+        let rotation = Quat::from_axis_angle(up, angles.x)
+            * Quat::from_axis_angle(right, angles.y)
+            * Quat::from_axis_angle(forward, angles.z);
+        let eye = self.position + right * offset.x + up * offset.y;
+        Mat4::look_to_rh(eye, rotation * forward, rotation * up)
     }
 
     /// Будує projection matrix (camera space → clip space)
     ///
-    /// Використовує perspective projection з FOV.
+    /// Perspective з FOV або orthographic (за projection).
     /// ВАЖЛИВО: Для wgpu потрібна коригуюча матриця OpenGL → Vulkan/DX.
     ///
     /// # Повертає
     /// Mat4 - projection матриця
     pub fn build_projection_matrix(&self) -> Mat4 {
-        // Базова perspective projection (OpenGL style)
-        let proj = Mat4::perspective_rh(self.fovy, self.aspect, self.znear, self.zfar);
+        // Базова projection (OpenGL style)
+        let proj = match self.projection {
+            ProjectionMode::Perspective => Mat4::perspective_rh(self.fovy, self.aspect, self.znear, self.zfar),
+            ProjectionMode::Orthographic { height } => {
+                let half_height = height * 0.5;
+                let half_width = half_height * self.aspect;
+                Mat4::orthographic_rh(-half_width, half_width, -half_height, half_height, self.znear, self.zfar)
+            }
+        };
 
         // Коригуюча матриця для wgpu (OpenGL → Vulkan/DirectX)
         // Vulkan/DX мають NDC Z в діапазоні [0, 1], а OpenGL [-1, 1]
@@ -185,6 +421,92 @@ impl Camera {
         self.build_projection_matrix() * self.build_view_matrix()
     }
 
+    /// Промінь з пікселя екрана у world space (mouse picking)
+    ///
+    /// Піксель → NDC (Y екрана вниз, NDC - вгору) → точки на near та far
+    /// площинах через inverse view-projection. Глибина площин у NDC - з
+    /// самої projection (build_projection_matrix переводить Z в діапазон
+    /// wgpu), а не захардкоджені 0 / 1.
+    /// Orthographic - паралельні промені з різних точок near площини.
+    ///
+    /// # Аргументи
+    /// * `pixel` - позиція курсора (пікселі, від лівого верхнього кута)
+    /// * `viewport` - розмір вікна (пікселі)
+    ///
+    /// # Повертає
+    /// (origin на near площині, нормалізований напрямок)
+    #[allow(dead_code)]
+    pub fn screen_ray(&self, pixel: (f32, f32), viewport: (u32, u32)) -> (Vec3, Vec3) {
+        let width = viewport.0.max(1) as f32;
+        let height = viewport.1.max(1) as f32;
+        let ndc_x = pixel.0 / width * 2.0 - 1.0;
+        let ndc_y = 1.0 - pixel.1 / height * 2.0;
+
+        let projection = self.build_projection_matrix();
+        let near_depth = projection.project_point3(Vec3::new(0.0, 0.0, -self.znear)).z;
+        let far_depth = projection.project_point3(Vec3::new(0.0, 0.0, -self.zfar)).z;
+
+        let inverse = (projection * self.build_view_matrix()).inverse();
+        let near = inverse.project_point3(Vec3::new(ndc_x, ndc_y, near_depth));
+        let far = inverse.project_point3(Vec3::new(ndc_x, ndc_y, far_depth));
+        (near, (far - near).normalize_or(self.forward()))
+    }
+
+    /// Встановлює near/far clip planes з валідацією
+    ///
+    /// # Аргументи
+    /// * `znear` - Ближня площина (обмежується знизу MIN_ZNEAR)
+    /// * `zfar` - Дальня площина (має бути більша за znear)
+    ///
+    /// # Обмеження
+    /// Якщо zfar / znear > MAX_DEPTH_RATIO - znear піднімається
+    /// (з попередженням в лог), щоб далекі об'єкти не мерехтіли.
+    pub fn set_clip_planes(&mut self, znear: f32, zfar: f32) {
+        let mut znear = if znear.is_finite() { znear } else { self.znear };
+        let zfar = if zfar.is_finite() { zfar } else { self.zfar };
+
+        if znear < MIN_ZNEAR {
+            log::warn!("Camera: znear = {} замалий, використовуємо {}", znear, MIN_ZNEAR);
+            znear = MIN_ZNEAR;
+        }
+
+        let zfar = if zfar <= znear * 2.0 {
+            log::warn!("Camera: zfar = {} <= 2 * znear, використовуємо {}", zfar, znear * 2.0);
+            znear * 2.0
+        } else {
+            zfar
+        };
+
+        let recommended = Self::near_plane_for_far(zfar);
+        if znear < recommended {
+            log::warn!(
+                "Camera: zfar/znear = {:.0} > {} - піднімаємо znear до {:.3}",
+                zfar / znear, MAX_DEPTH_RATIO, recommended
+            );
+            znear = recommended;
+        }
+
+        self.znear = znear;
+        self.zfar = zfar;
+    }
+
+    /// Рекомендована ближня площина для заданої дальньої
+    ///
+    /// Тримає zfar / znear в межах MAX_DEPTH_RATIO, але не нижче MIN_ZNEAR.
+    pub fn near_plane_for_far(zfar: f32) -> f32 {
+        (zfar / MAX_DEPTH_RATIO).max(MIN_ZNEAR)
+    }
+
+    /// Дальня площина, що покриває арену з будь-якої позиції камери
+    ///
+    /// # Аргументи
+    /// * `half_extent` - Половина розміру арени
+    /// * `max_camera_distance` - Максимальна відстань камери від гравця
+    pub fn far_plane_for_scene(half_extent: f32, max_camera_distance: f32) -> f32 {
+        // Діагональ арени + відстань камери + запас
+        half_extent * 2.0 * std::f32::consts::SQRT_2 + max_camera_distance + 10.0
+    }
+
     /// Оновлює aspect ratio (при зміні розміру вікна)
     ///
     /// # Аргументи
@@ -342,28 +664,146 @@ impl Camera {
     /// # Аргументи
     /// * `target_pos` - Позиція гравця (target point)
     /// * `target_height` - Висота точки на яку дивиться камера (груди гравця)
-    pub fn update_third_person(&mut self, target_pos: Vec3, target_height: f32) {
+    /// * `max_distance` - Вільна відстань до перешкоди вздовж third_person_direction
+    ///   (None - перешкоди немає)
+    /// * `delta` - Час кадру (секунди)
+    ///
+    /// # Колізія
+    /// Перешкода ближче за distance - камера наближається одразу (без кадру
+    /// крізь стіну). Коли перешкода зникає - плавно віддаляється назад.
+    pub fn update_third_person(
+        &mut self,
+        target_pos: Vec3,
+        target_height: f32,
+        max_distance: Option<f32>,
+        delta: f32,
+    ) {
+        if self.mode == CameraMode::FirstPerson {
+            return;
+        }
+
         // Реальна цільова позиція (позиція гравця + height offset)
         let actual_target = target_pos + Vec3::new(0.0, target_height, 0.0);
+        self.follow(actual_target, self.distance, max_distance, delta);
+    }
+
+    /// Вільна відстань від `origin` вздовж third_person_direction (max_distance
+    /// для update_third_person / update_lock_on)
+    ///
+    /// Sphere-cast радіусом CAMERA_COLLISION_RADIUS на `distance`, мінус
+    /// skin_width. `as_group` - група ragdoll гравця (власні кістки не заважають).
+    ///
+    /// # Повертає
+    /// None - колізія вимкнена (collision_enabled) або перешкоди немає
+    pub fn obstruction_distance(
+        &self,
+        physics: &PhysicsWorld,
+        origin: Vec3,
+        distance: f32,
+        as_group: Option<Group>,
+    ) -> Option<f32> {
+        if !self.collision_enabled {
+            return None;
+        }
+        physics
+            .cast_sphere(origin, self.third_person_direction(), distance, CAMERA_COLLISION_RADIUS, as_group)
+            .map(|toi| (toi - self.skin_width).max(0.0))
+    }
+
+    /// Оновлює камеру при lock-on: в кадрі і гравець, і ціль
+    ///
+    /// Yaw плавно повертається за спину гравця (камера дивиться на ціль),
+    /// pitch лишається за мишею. Фокус - середина між гравцем та ціллю,
+    /// відстань - lock_on_distance.
+    ///
+    /// # Аргументи
+    /// * `player_focus` - Точка погляду на гравця (позиція + висота камери)
+    /// * `target_focus` - Точка погляду на ціль (на тій самій висоті)
+    /// * `max_distance` - Вільна відстань від середини вздовж third_person_direction
+    /// * `delta` - Час кадру (секунди)
+    pub fn update_lock_on(
+        &mut self,
+        player_focus: Vec3,
+        target_focus: Vec3,
+        max_distance: Option<f32>,
+        delta: f32,
+    ) {
+        if self.mode == CameraMode::FirstPerson {
+            return;
+        }
+
+        // Камера з протилежного від цілі боку гравця
+        self.turn_away_from(player_focus - target_focus, delta);
+
+        let distance = self.lock_on_distance(player_focus, target_focus);
+        self.follow(player_focus.lerp(target_focus, 0.5), distance, max_distance, delta);
+    }
+
+    /// Плавно повертає yaw так, щоб камера дивилась проти `away` (площина XZ)
+    fn turn_away_from(&mut self, away: Vec3, delta: f32) {
+        if away.x.abs() + away.z.abs() > 1e-3 {
+            let wanted_yaw = away.z.atan2(away.x);
+            let diff = (wanted_yaw - self.yaw + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU)
+                - std::f32::consts::PI;
+            self.yaw += diff * (1.0 - (-LOCK_ON_TURN_SPEED * delta.max(0.0)).exp());
+        }
+    }
+
+    /// Відстань камери від середини гравець-ціль при lock-on
+    ///
+    /// Не менше distance від гравця; гравець (ближчий до камери, нижче осі
+    /// погляду через pitch) вміщується в LOCK_ON_FOV_FILL вертикального FOV.
+    pub fn lock_on_distance(&self, player_focus: Vec3, target_focus: Vec3) -> f32 {
+        let half = Vec3::new(target_focus.x - player_focus.x, 0.0, target_focus.z - player_focus.z).length() * 0.5;
+        let half_fov = (self.fovy * 0.5 * LOCK_ON_FOV_FILL).max(0.01);
+        let pitch = self.pitch.abs();
+        let fov_fit = half * pitch.cos() + half * pitch.sin() / half_fov.tan();
+        (half + self.distance).max(fov_fit).min(THIRD_PERSON_MAX_DISTANCE)
+    }
 
-        // Згладжуємо позицію target (lerp)
-        // Smooth factor: менше = плавніше, більше = швидше реакція
-        let smooth_factor = 0.15;
-        self.smoothed_target = self.smoothed_target.lerp(actual_target, smooth_factor);
+    /// Згладжене слідування камери за `focus` на відстані `desired_distance`
+    ///
+    /// Перешкода ближче - камера наближається одразу; решта змін
+    /// відстані (перешкода зникла, zoom, lock-on) - плавно.
+    fn follow(&mut self, focus: Vec3, desired_distance: f32, max_distance: Option<f32>, delta: f32) {
+        // yaw/pitch - pub поля, тож обмежуємо і тут (не тільки в rotate_third_person)
+        self.normalize_angles();
+
+        // Згладжуємо позицію target: експоненційно, не залежить від FPS
+        // (нескінченна жорсткість - без згладжування, як snap)
+        let t = if self.follow_stiffness.is_finite() {
+            1.0 - (-self.follow_stiffness.max(0.0) * delta.max(0.0)).exp()
+        } else {
+            1.0
+        };
+        self.smoothed_target = self.smoothed_target.lerp(focus, t);
 
         // Target = згладжена позиція
         self.target = self.smoothed_target;
 
-        // Обчислюємо позицію камери на основі spherical coordinates
-        // yaw = горизонтальний кут (навколо Y)
-        // pitch = вертикальний кут (від горизонту)
-        let camera_offset = Vec3::new(
-            self.distance * self.pitch.cos() * self.yaw.cos(),
-            self.distance * self.pitch.sin(),
-            self.distance * self.pitch.cos() * self.yaw.sin(),
-        );
+        // Відстань з урахуванням перешкоди
+        let obstructed = max_distance.is_some_and(|max| max < desired_distance);
+        let allowed = match max_distance {
+            Some(max) => max.clamp(CAMERA_MIN_COLLISION_DISTANCE.min(desired_distance), desired_distance),
+            None => desired_distance,
+        };
+        if obstructed && allowed < self.collision_distance {
+            self.collision_distance = allowed;
+        } else {
+            // Експоненційне згладжування - не залежить від FPS
+            let t = 1.0 - (-CAMERA_RECOVERY_SPEED * delta.max(0.0)).exp();
+            self.collision_distance += (allowed - self.collision_distance) * t;
+        }
 
-        self.position = self.target + camera_offset;
+        self.position = self.target + self.third_person_direction() * self.collision_distance;
+    }
+
+    /// Одиничний напрямок від target до third person камери
+    ///
+    /// Spherical coordinates: yaw - горизонтальний кут (навколо Y),
+    /// pitch - вертикальний кут (від горизонту).
+    pub fn third_person_direction(&self) -> Vec3 {
+        spherical_direction(self.yaw, self.pitch)
     }
 
     /// Обертає third person камеру (mouse look)
@@ -374,19 +814,45 @@ impl Camera {
     pub fn rotate_third_person(&mut self, delta_yaw: f32, delta_pitch: f32) {
         self.yaw += delta_yaw;
         self.pitch += delta_pitch;
+        self.normalize_angles();
+    }
+
+    /// Встановлює межі pitch для third person камери
+    ///
+    /// # Аргументи
+    /// * `min_deg` - Мінімальний pitch (градуси, від'ємний = камера нижче target)
+    /// * `max_deg` - Максимальний pitch (градуси)
+    ///
+    /// # Обмеження
+    /// Обидва значення обмежуються до ±89° (при ±90° камера перевертається).
+    /// Якщо min > max - значення міняються місцями.
+    pub fn set_pitch_limits(&mut self, min_deg: f32, max_deg: f32) {
+        let (min_deg, max_deg) = if min_deg <= max_deg { (min_deg, max_deg) } else { (max_deg, min_deg) };
+        self.min_pitch = min_deg.clamp(-PITCH_HARD_LIMIT_DEG, PITCH_HARD_LIMIT_DEG).to_radians();
+        self.max_pitch = max_deg.clamp(-PITCH_HARD_LIMIT_DEG, PITCH_HARD_LIMIT_DEG).to_radians();
+        self.normalize_angles();
+    }
 
+    /// Обмежує pitch межами та нормалізує yaw до [-PI, PI]
+    ///
+    /// Від першої особи межі ширші (±PITCH_HARD_LIMIT_DEG) - вгору можна
+    /// дивитись вище, ніж дозволяє орбіта над підлогою.
+    fn normalize_angles(&mut self) {
         // Обмежуємо pitch щоб камера не перевернулась
-        let max_pitch = 85.0_f32.to_radians();
-        let min_pitch = -30.0_f32.to_radians(); // Не дозволяємо дивитись знизу вгору
+        if !self.pitch.is_finite() {
+            self.pitch = 0.0;
+        }
+        let (min_pitch, max_pitch) = match self.mode {
+            CameraMode::FirstPerson => (-PITCH_HARD_LIMIT_DEG.to_radians(), PITCH_HARD_LIMIT_DEG.to_radians()),
+            CameraMode::ThirdPerson => (self.min_pitch, self.max_pitch),
+        };
         self.pitch = self.pitch.clamp(min_pitch, max_pitch);
 
-        // Нормалізуємо yaw до [-PI, PI]
-        while self.yaw > std::f32::consts::PI {
-            self.yaw -= std::f32::consts::TAU;
-        }
-        while self.yaw < -std::f32::consts::PI {
-            self.yaw += std::f32::consts::TAU;
+        // Нормалізуємо yaw до [-PI, PI] (rem_euclid - без циклу при великих значеннях)
+        if !self.yaw.is_finite() {
+            self.yaw = 0.0;
         }
+        self.yaw = (self.yaw + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU) - std::f32::consts::PI;
     }
 
     /// Zoom для third person (змінює distance)
@@ -395,7 +861,154 @@ impl Camera {
     /// * `delta` - Зміна відстані (+ = ближче, - = далі)
     pub fn zoom_third_person(&mut self, delta: f32) {
         self.distance -= delta;
-        self.distance = self.distance.clamp(2.0, 20.0);
+        self.distance = self.distance.clamp(2.0, THIRD_PERSON_MAX_DISTANCE);
+    }
+
+    // ========================================================================
+    // FIRST PERSON
+    // ========================================================================
+
+    /// Поточний режим камери
+    pub fn mode(&self) -> CameraMode {
+        self.mode
+    }
+
+    /// Перемикає першу / третю особу
+    ///
+    /// Напрямок погляду зберігається (спільні yaw / pitch; pitch поза
+    /// межами third person обмежується). Третя особа стартує з голови і
+    /// плавно відходить на distance - без різкого стрибка камери.
+    ///
+    /// # Повертає
+    /// Новий режим
+    pub fn toggle_mode(&mut self) -> CameraMode {
+        self.mode = match self.mode {
+            CameraMode::FirstPerson => {
+                self.smoothed_target = self.position;
+                self.collision_distance = CAMERA_MIN_COLLISION_DISTANCE;
+                CameraMode::ThirdPerson
+            }
+            CameraMode::ThirdPerson => CameraMode::FirstPerson,
+        };
+        self.normalize_angles();
+        self.mode
+    }
+
+    /// Оновлює камеру від першої особи (у ThirdPerson - no-op)
+    ///
+    /// # Аргументи
+    /// * `head_position` - Позиція голови персонажа (world space)
+    /// * `look_at` - Lock-on: точка, до якої плавно повертається yaw
+    /// * `delta` - Час кадру (секунди)
+    pub fn update_first_person(&mut self, head_position: Vec3, look_at: Option<Vec3>, delta: f32) {
+        if self.mode != CameraMode::FirstPerson {
+            return;
+        }
+        if let Some(look_at) = look_at {
+            self.turn_away_from(head_position - look_at, delta);
+        }
+        self.normalize_angles();
+
+        self.position = head_position;
+        self.target = head_position - self.third_person_direction();
+    }
+
+    // ========================================================================
+    // FREE-FLY CAMERA (debug spectator)
+    // ========================================================================
+
+    /// Чи активна free-fly камера
+    pub fn is_free_fly(&self) -> bool {
+        self.free_fly.is_some()
+    }
+
+    /// Вмикає / вимикає free-fly камеру
+    ///
+    /// Вмикання - погляд лишається тим самим, що зараз. Вимикання - камера
+    /// повертається до third person (наступний update_third_person).
+    pub fn set_free_fly(&mut self, enabled: bool) {
+        self.free_fly = if enabled {
+            let back = -self.forward();
+            let limit = PITCH_HARD_LIMIT_DEG.to_radians();
+            Some(FreeFly {
+                yaw: back.z.atan2(back.x),
+                pitch: back.y.clamp(-1.0, 1.0).asin().clamp(-limit, limit),
+            })
+        } else {
+            None
+        };
+    }
+
+    /// Обертає free-fly камеру (mouse look, ті самі знаки що в rotate_third_person)
+    pub fn rotate_free_fly(&mut self, delta_yaw: f32, delta_pitch: f32) {
+        let Some(fly) = &mut self.free_fly else {
+            return;
+        };
+        let limit = PITCH_HARD_LIMIT_DEG.to_radians();
+        fly.yaw = (fly.yaw + delta_yaw + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU) - std::f32::consts::PI;
+        fly.pitch = (fly.pitch + delta_pitch).clamp(-limit, limit);
+    }
+
+    /// Рухає free-fly камеру вздовж її власних осей
+    ///
+    /// # Аргументи
+    /// * `input` - x: вправо, y: вгору (world Y), z: вперед; нормалізується
+    /// * `boost` - множити швидкість на free_fly_boost
+    /// * `delta` - Час кадру (секунди)
+    pub fn update_free_fly(&mut self, input: Vec3, boost: bool, delta: f32) {
+        let Some(fly) = self.free_fly else {
+            return;
+        };
+        let forward = -spherical_direction(fly.yaw, fly.pitch);
+        let right = Vec3::new(fly.yaw.sin(), 0.0, -fly.yaw.cos());
+        let direction = (right * input.x + Vec3::Y * input.y + forward * input.z).normalize_or_zero();
+        let speed = self.free_fly_speed * if boost { self.free_fly_boost } else { 1.0 };
+
+        self.position += direction * speed * delta.max(0.0);
+        self.target = self.position + forward;
+    }
+
+    /// Змінює швидкість free-fly (колесо миші; + = швидше)
+    pub fn adjust_free_fly_speed(&mut self, scroll: f32) {
+        let (min, max) = FREE_FLY_SPEED_RANGE;
+        self.free_fly_speed = (self.free_fly_speed * FREE_FLY_SPEED_STEP.powf(scroll)).clamp(min, max);
+    }
+
+    // ========================================================================
+    // CAMERA SHAKE
+    // ========================================================================
+
+    /// Додає тряску (влучання, удар по гравцю)
+    ///
+    /// # Аргументи
+    /// * `trauma` - Додається до поточної, сума обмежується до 1
+    pub fn add_shake(&mut self, trauma: f32) {
+        if trauma.is_finite() {
+            self.trauma = (self.trauma + trauma.max(0.0)).min(1.0);
+        }
+    }
+
+    /// Згасання тряски (раз на кадр, реальний час)
+    pub fn update_shake(&mut self, delta: f32) {
+        let delta = delta.max(0.0);
+        let trauma = self.trauma - self.shake_decay * delta;
+        self.trauma = if trauma > SHAKE_TRAUMA_EPSILON { trauma } else { 0.0 };
+        // Фаза шуму не росте безмежно (точність f32)
+        self.shake_time = if self.trauma > 0.0 { (self.shake_time + delta) % 1000.0 } else { 0.0 };
+    }
+
+    /// Зсув (x - вправо, y - вгору, м) та поворот (yaw, pitch, roll, радіани)
+    /// тряски; None - тряски немає або free-fly
+    fn shake(&self) -> Option<(Vec3, Vec3)> {
+        if self.trauma <= 0.0 || self.free_fly.is_some() {
+            return None;
+        }
+        let strength = self.trauma * self.trauma;
+        let phase = self.shake_time * SHAKE_FREQUENCY;
+        let offset = Vec3::new(shake_noise(phase, 0.0), shake_noise(phase, 1.0), 0.0) * self.shake_max_offset;
+        let angles = Vec3::new(shake_noise(phase, 2.0), shake_noise(phase, 3.0), shake_noise(phase, 4.0))
+            * self.shake_max_angle;
+        Some((offset * strength, angles * strength))
     }
 
     /// Повертає forward direction камери в XZ plane (для руху гравця)
@@ -415,6 +1028,17 @@ impl Camera {
     }
 }
 
+/// Плавний шум тряски в [-1, 1] - дві синусоїди з некратними частотами,
+/// `seed` розводить осі між собою
+fn shake_noise(phase: f32, seed: f32) -> f32 {
+    ((phase + seed * 1.3).sin() + 0.5 * (phase * 2.7 + seed * 2.1).sin()) / 1.5
+}
+
+/// Одиничний вектор за spherical coordinates (yaw навколо Y, pitch від горизонту)
+fn spherical_direction(yaw: f32, pitch: f32) -> Vec3 {
+    Vec3::new(pitch.cos() * yaw.cos(), pitch.sin(), pitch.cos() * yaw.sin())
+}
+
 /// Uniform buffer для передачі в shader
 ///
 /// Це структура яка буде передаватись в GPU через uniform buffer.
@@ -448,3 +1072,241 @@ impl Default for CameraUniform {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Кадр 60 FPS
+    const DT: f32 = 1.0 / 60.0;
+
+    /// Голова гравця (гравець в початку координат)
+    const HEAD: Vec3 = Vec3::new(0.0, 1.7, 0.0);
+
+    /// Third person камера за гравцем, що вже встигла доїхати
+    fn settled_camera() -> Camera {
+        let mut camera = Camera::new(Vec3::new(0.0, 3.0, 6.0), Vec3::ZERO, 16.0 / 9.0);
+        camera.rotate_third_person(0.7, 0.2);
+        for _ in 0..300 {
+            camera.update_third_person(Vec3::ZERO, 1.5, None, DT);
+        }
+        camera
+    }
+
+    #[test]
+    fn first_person_camera_sits_at_head() {
+        let mut camera = settled_camera();
+        assert_eq!(camera.toggle_mode(), CameraMode::FirstPerson);
+        camera.update_first_person(HEAD, None, DT);
+        assert_eq!(camera.position, HEAD);
+
+        // Third person update від першої особи нічого не робить
+        camera.update_third_person(Vec3::new(5.0, 0.0, 5.0), 1.5, None, DT);
+        assert_eq!(camera.position, HEAD);
+    }
+
+    #[test]
+    fn toggle_keeps_look_direction() {
+        let mut camera = settled_camera();
+        let forward = camera.forward();
+
+        camera.toggle_mode();
+        camera.update_first_person(HEAD, None, DT);
+        assert!(camera.forward().distance(forward) < 1e-4, "погляд стрибнув при вході в першу особу");
+
+        assert_eq!(camera.toggle_mode(), CameraMode::ThirdPerson);
+        camera.update_third_person(Vec3::ZERO, 1.5, None, DT);
+        assert!(camera.forward().distance(forward) < 1e-4, "погляд стрибнув при виході з першої особи");
+        // Камера відходить від голови плавно, а не стрибком на distance
+        assert!(camera.position.distance(HEAD) < 1.0);
+    }
+
+    #[test]
+    fn first_person_update_is_noop_in_third_person() {
+        let mut camera = settled_camera();
+        let position = camera.position;
+        camera.update_first_person(HEAD, None, DT);
+        assert_eq!(camera.position, position);
+    }
+
+    /// Верх підлоги create_ground(0.0, ..) - куб половинної висоти 0.1
+    const GROUND_TOP: f32 = 0.1;
+
+    /// Найнижча Y камери за `seconds`, коли pitch опущено під підлогу
+    fn lowest_camera_y(collision_enabled: bool, seconds: f32) -> f32 {
+        let mut physics = PhysicsWorld::new();
+        physics.create_ground(0.0, 20.0);
+        physics.step(physics.fixed_timestep);
+
+        let mut camera = settled_camera();
+        camera.collision_enabled = collision_enabled;
+        camera.set_pitch_limits(-PITCH_HARD_LIMIT_DEG, DEFAULT_MAX_PITCH_DEG);
+        let focus = Vec3::Y * 1.5;
+        let mut lowest = f32::INFINITY;
+        for _ in 0..(seconds / DT) as usize {
+            camera.rotate_third_person(0.0, -0.05);
+            let max_distance = camera.obstruction_distance(&physics, focus, camera.distance, None);
+            camera.update_third_person(Vec3::ZERO, 1.5, max_distance, DT);
+            lowest = lowest.min(camera.position.y);
+        }
+        lowest
+    }
+
+    #[test]
+    fn camera_stays_above_ground_with_pitch_down() {
+        let lowest = lowest_camera_y(true, 2.0);
+        assert!(lowest > GROUND_TOP, "камера під підлогою: y = {}", lowest);
+        // Без колізії - та сама орбіта йде під підлогу
+        assert!(lowest_camera_y(false, 2.0) < GROUND_TOP);
+    }
+
+    #[test]
+    fn skin_width_keeps_gap_from_surface() {
+        let mut physics = PhysicsWorld::new();
+        physics.create_ground(0.0, 20.0);
+        physics.step(physics.fixed_timestep);
+        let mut camera = settled_camera();
+        camera.set_pitch_limits(-PITCH_HARD_LIMIT_DEG, DEFAULT_MAX_PITCH_DEG);
+        camera.rotate_third_person(0.0, -std::f32::consts::FRAC_PI_2);
+
+        let origin = Vec3::Y * 1.5;
+        camera.skin_width = 0.0;
+        let hit = camera.obstruction_distance(&physics, origin, 10.0, None).expect("підлога не знайдена");
+        camera.skin_width = 0.3;
+        let with_skin = camera.obstruction_distance(&physics, origin, 10.0, None).unwrap();
+        assert!((hit - with_skin - 0.3).abs() < 1e-4);
+        // Перешкода далі за distance - не обмежує
+        assert_eq!(camera.obstruction_distance(&physics, origin, 0.5, None), None);
+    }
+
+    /// Камера в (0, 0, 5), дивиться в -Z, near 1 / far 11
+    fn axis_camera(projection: ProjectionMode) -> Camera {
+        let mut camera = Camera::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, 2.0);
+        camera.set_clip_planes(1.0, 11.0);
+        camera.projection = projection;
+        camera
+    }
+
+    /// Точка world → NDC (Z у діапазоні wgpu 0..1)
+    fn project(camera: &Camera, point: Vec3) -> Vec3 {
+        camera.build_view_projection_matrix().project_point3(point)
+    }
+
+    #[test]
+    fn orthographic_depth_is_linear_unlike_perspective() {
+        let perspective = axis_camera(ProjectionMode::Perspective);
+        let ortho = axis_camera(ProjectionMode::Orthographic { height: 4.0 });
+
+        // Глибина посередині між near та far як частка шляху near → far
+        // (обидва режими проходять через ту саму корекцію Z для wgpu)
+        let relative_depth = |camera: &Camera| {
+            let near = project(camera, Vec3::new(0.0, 0.0, 4.0)).z;
+            let far = project(camera, Vec3::new(0.0, 0.0, -6.0)).z;
+            let middle = project(camera, Vec3::new(0.0, 0.0, -1.0)).z;
+            assert!(near < middle && middle < far);
+            (middle - near) / (far - near)
+        };
+
+        // Ortho - лінійна (рівно 0.5), perspective - притиснута до far
+        let ortho_depth = relative_depth(&ortho);
+        let perspective_depth = relative_depth(&perspective);
+        assert!((ortho_depth - 0.5).abs() < 1e-4, "ortho depth {}", ortho_depth);
+        assert!(perspective_depth > ortho_depth + 0.3, "perspective depth {}", perspective_depth);
+    }
+
+    #[test]
+    fn orthographic_width_scales_with_aspect() {
+        // height 4 → половина висоти 2, ширини 2 × aspect = 4
+        let camera = axis_camera(ProjectionMode::Orthographic { height: 4.0 });
+        for z in [3.0, -5.0] {
+            let edge = project(&camera, Vec3::new(4.0, 2.0, z));
+            assert!((edge.x - 1.0).abs() < 1e-4 && (edge.y - 1.0).abs() < 1e-4, "{:?}", edge);
+        }
+        assert_eq!(Camera::new(Vec3::Z, Vec3::ZERO, 1.0).projection, ProjectionMode::Perspective);
+    }
+
+    #[test]
+    fn screen_center_ray_points_forward() {
+        for projection in [ProjectionMode::Perspective, ProjectionMode::Orthographic { height: 4.0 }] {
+            let mut camera = Camera::new(Vec3::new(3.0, 4.0, 6.0), Vec3::new(-1.0, 0.5, 0.0), 16.0 / 9.0);
+            camera.projection = projection;
+            let (origin, direction) = camera.screen_ray((640.0, 360.0), (1280, 720));
+            assert!(direction.distance(camera.forward()) < 1e-3, "{:?}: {:?}", projection, direction);
+            // Початок - на near площині на осі погляду
+            let expected = camera.position + camera.forward() * camera.znear;
+            assert!(origin.distance(expected) < 1e-3, "{:?}: {:?}", projection, origin);
+        }
+    }
+
+    #[test]
+    fn screen_ray_flips_y() {
+        let camera = Camera::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, 1.0);
+        // Верх екрана (піксель y = 0) - промінь вгору, лівий край - вліво
+        let (_, up) = camera.screen_ray((50.0, 0.0), (100, 100));
+        assert!(up.y > 0.0);
+        let (_, left) = camera.screen_ray((0.0, 50.0), (100, 100));
+        assert!(left.x < 0.0);
+    }
+
+    #[test]
+    fn third_person_pitch_clamps_to_limits() {
+        let mut camera = Camera::new(Vec3::new(0.0, 2.0, 5.0), Vec3::ZERO, 1.0);
+        for _ in 0..100 {
+            camera.rotate_third_person(0.0, 0.1);
+        }
+        assert!((camera.pitch - DEFAULT_MAX_PITCH_DEG.to_radians()).abs() < 1e-5);
+        for _ in 0..200 {
+            camera.rotate_third_person(0.0, -0.1);
+        }
+        assert!((camera.pitch - DEFAULT_MIN_PITCH_DEG.to_radians()).abs() < 1e-5);
+
+        // Межі поза ±89° обрізаються, переплутані - міняються місцями
+        camera.set_pitch_limits(100.0, -100.0);
+        camera.rotate_third_person(0.0, -10.0);
+        assert!((camera.pitch + PITCH_HARD_LIMIT_DEG.to_radians()).abs() < 1e-5);
+        camera.rotate_third_person(0.0, f32::NAN);
+        assert!(camera.pitch.is_finite());
+    }
+
+    #[test]
+    fn shake_decays_to_zero_over_decay_duration() {
+        let mut camera = settled_camera();
+        camera.add_shake(1.0);
+        assert_eq!(camera.trauma, 1.0);
+
+        // trauma 1 згасає за 1 / shake_decay секунд
+        let frames = (1.0 / camera.shake_decay / DT).ceil() as usize;
+        for frame in 0..frames {
+            assert!(camera.trauma > 0.0, "тряска згасла на кадрі {} з {}", frame, frames);
+            camera.update_shake(DT);
+        }
+        assert_eq!(camera.trauma, 0.0);
+        assert!(camera.shake().is_none());
+    }
+
+    #[test]
+    fn shake_leaves_position_and_target_unchanged() {
+        let mut camera = settled_camera();
+        let (position, target) = (camera.position, camera.target);
+        let still = Mat4::look_at_rh(position, target, camera.up);
+
+        camera.add_shake(1.0);
+        let mut shaken = false;
+        for _ in 0..30 {
+            camera.update_shake(DT);
+            shaken |= !camera.build_view_matrix().abs_diff_eq(still, 1e-4);
+            assert_eq!(camera.position, position);
+            assert_eq!(camera.target, target);
+        }
+        assert!(shaken, "тряска не змінила view matrix");
+    }
+
+    #[test]
+    fn third_person_yaw_is_normalized() {
+        let mut camera = Camera::new(Vec3::new(0.0, 2.0, 5.0), Vec3::ZERO, 1.0);
+        let direction = camera.third_person_direction();
+        camera.rotate_third_person(std::f32::consts::TAU * 50.0, 0.0);
+        assert!(camera.yaw.abs() <= std::f32::consts::PI);
+        assert!(camera.third_person_direction().distance(direction) < 1e-3);
+    }
+}
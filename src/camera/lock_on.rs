@@ -0,0 +1,208 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/camera/lock_on.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   LockOnTarget - захоплення цілі (soulslike lock-on): камера тримає в кадрі
+   гравця та ворога, гравець завжди повернутий до ворога.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Вибір найближчого живого ворога в конусі перед камерою
+   - Перемикання на сусідню ціль різким рухом миші вліво / вправо
+   - Відпускання: повторне натискання, смерть цілі, ціль далеко
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Використовується в:
+   - main.rs - Action::LockOn, Camera::update_lock_on, поворот гравця
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+   - Ціль - індекс у App::enemies (on_enemy_removed зсуває його, як
+     HitboxManager::on_enemy_removed)
+   - Все рахується в площині XZ (висота ігнорується)
+   - Кандидати - лише живі вороги: (індекс, позиція)
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - lock-on на ворога
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use glam::Vec3;
+
+use crate::settings::Settings;
+
+/// Ціль відпускається, якщо відійшла далі range × цей множник
+const RELEASE_RANGE_SCALE: f32 = 1.5;
+
+/// Пауза між перемиканнями цілі (секунди) - один ривок миші = одне перемикання
+const SWITCH_COOLDOWN: f32 = 0.3;
+
+/// Захоплення цілі
+#[derive(Debug, Clone)]
+pub struct LockOnTarget {
+    /// Максимальна відстань до цілі при захопленні
+    pub range: f32,
+
+    /// Половина кута конусу перед камерою (радіани)
+    pub cone_angle: f32,
+
+    /// Рух миші по X за кадр (пікселі), що вважається ривком - перемикання цілі
+    pub flick_threshold: f32,
+
+    /// Індекс ворога-цілі
+    target: Option<usize>,
+
+    /// Скільки ще чекати до наступного перемикання (секунди)
+    switch_cooldown: f32,
+}
+
+impl LockOnTarget {
+    /// Читає секцію [camera] з налаштувань
+    ///
+    /// Ключі: `lock_on_range`, `lock_on_angle` (градуси), `lock_on_flick` (пікселі)
+    pub fn from_settings(settings: &Settings) -> Self {
+        let default = Self::default();
+        Self {
+            range: settings.get_f32("camera.lock_on_range", default.range).max(0.0),
+            cone_angle: settings
+                .get_f32("camera.lock_on_angle", default.cone_angle.to_degrees())
+                .clamp(0.0, 180.0)
+                .to_radians(),
+            flick_threshold: settings.get_f32("camera.lock_on_flick", default.flick_threshold).max(1.0),
+            ..default
+        }
+    }
+
+    /// Відпускає ціль
+    pub fn release(&mut self) {
+        self.target = None;
+    }
+
+    /// Захоплює ціль або відпускає поточну (натискання Action::LockOn)
+    ///
+    /// # Аргументи
+    /// * `origin` - Позиція гравця
+    /// * `forward` - Напрямок камери в площині XZ
+    /// * `candidates` - (індекс, позиція) живих ворогів
+    ///
+    /// # Повертає
+    /// Нову ціль; None - відпущено або в конусі нікого немає
+    pub fn toggle(
+        &mut self,
+        origin: Vec3,
+        forward: Vec3,
+        candidates: impl IntoIterator<Item = (usize, Vec3)>,
+    ) -> Option<usize> {
+        if self.target.take().is_some() {
+            return None;
+        }
+
+        let forward = flat(forward).normalize_or_zero();
+        self.target = candidates
+            .into_iter()
+            .filter_map(|(index, position)| {
+                let to_target = flat(position - origin);
+                let distance = to_target.length();
+                if distance > self.range {
+                    return None;
+                }
+                // Впритул - ціль у будь-якому напрямку
+                if distance > 1e-3 && forward != Vec3::ZERO && forward.angle_between(to_target / distance) > self.cone_angle {
+                    return None;
+                }
+                Some((index, distance))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(index, _)| index);
+        self.target
+    }
+
+    /// Раз на кадр: перевіряє ціль та перемикає її ривком миші
+    ///
+    /// # Аргументи
+    /// * `origin` - Позиція гравця
+    /// * `mouse_dx` - Рух миші по X за кадр (пікселі)
+    /// * `delta` - Час кадру (секунди)
+    /// * `candidates` - (індекс, позиція) живих ворогів
+    ///
+    /// # Повертає
+    /// Позицію цілі; None - ціль відпущено (мертва, зникла, надто далеко)
+    pub fn update(
+        &mut self,
+        origin: Vec3,
+        mouse_dx: f32,
+        delta: f32,
+        candidates: impl IntoIterator<Item = (usize, Vec3)>,
+    ) -> Option<Vec3> {
+        let target = self.target?;
+        let candidates: Vec<(usize, Vec3)> = candidates.into_iter().collect();
+
+        // Мертва / видалена ціль - в candidates її немає
+        let Some(&(_, target_position)) = candidates.iter().find(|(index, _)| *index == target) else {
+            self.target = None;
+            return None;
+        };
+        if flat(target_position - origin).length() > self.range * RELEASE_RANGE_SCALE {
+            self.target = None;
+            return None;
+        }
+
+        self.switch_cooldown = (self.switch_cooldown - delta).max(0.0);
+        if self.switch_cooldown > 0.0 || mouse_dx.abs() < self.flick_threshold {
+            return Some(target_position);
+        }
+
+        // Ривок вправо (+X) - найближча за кутом ціль праворуч від поточної
+        let forward = flat(target_position - origin).normalize_or_zero();
+        let right = Vec3::new(-forward.z, 0.0, forward.x);
+        let side = mouse_dx.signum();
+        let next = candidates
+            .iter()
+            .filter(|(index, _)| *index != target)
+            .filter_map(|&(index, position)| {
+                let to_candidate = flat(position - origin);
+                if to_candidate.length() > self.range {
+                    return None;
+                }
+                let angle = to_candidate.dot(right).atan2(to_candidate.dot(forward));
+                (angle * side > 0.0).then_some((index, position, angle.abs()))
+            })
+            .min_by(|a, b| a.2.total_cmp(&b.2));
+
+        match next {
+            Some((index, position, _)) => {
+                self.target = Some(index);
+                self.switch_cooldown = SWITCH_COOLDOWN;
+                Some(position)
+            }
+            None => Some(target_position),
+        }
+    }
+
+    /// Ворога з індексом `index` видалено з App::enemies
+    pub fn on_enemy_removed(&mut self, index: usize) {
+        self.target = match self.target {
+            Some(target) if target == index => None,
+            Some(target) if target > index => Some(target - 1),
+            target => target,
+        };
+    }
+}
+
+impl Default for LockOnTarget {
+    fn default() -> Self {
+        Self {
+            range: 15.0,
+            cone_angle: 35.0_f32.to_radians(),
+            flick_threshold: 40.0,
+            target: None,
+            switch_cooldown: 0.0,
+        }
+    }
+}
+
+/// Проєкція на площину XZ
+fn flat(v: Vec3) -> Vec3 {
+    Vec3::new(v.x, 0.0, v.z)
+}
@@ -13,17 +13,27 @@
 🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
    Імпортує:
    - camera.rs - основна структура камери
+   - lock_on.rs - LockOnTarget (захоплення ворога)
 
    Експортує для:
    - main.rs, rendering/renderer.rs - Camera struct
 
 🕐 ІСТОРІЯ:
    2025-12-14: Створено модуль для camera підсистеми
+   2026-10-16: lock_on - захоплення цілі
+   2026-10-16: CameraMode - перша / третя особа
+   2026-10-16: Тряска камери - DEFAULT_SHAKE_* для settings.ini
+   2026-10-16: ProjectionMode - perspective / orthographic
 
 ═══════════════════════════════════════════════════════════════════════════════
 */
 
+#[allow(clippy::module_inception)]
 pub mod camera;
+pub mod lock_on;
 
 // Реєкспортуємо Camera та CameraUniform для зручності
-pub use camera::{Camera, CameraUniform};
+pub use camera::{Camera, CameraMode, CameraUniform, ProjectionMode, DEFAULT_MIN_PITCH_DEG, DEFAULT_MAX_PITCH_DEG, THIRD_PERSON_MAX_DISTANCE,
+    CAMERA_COLLISION_RADIUS, DEFAULT_CAMERA_SKIN_WIDTH, DEFAULT_FOLLOW_STIFFNESS, DEFAULT_FREE_FLY_SPEED, DEFAULT_FREE_FLY_BOOST,
+    DEFAULT_SHAKE_DECAY, DEFAULT_SHAKE_MAX_OFFSET, DEFAULT_SHAKE_MAX_ANGLE_DEG};
+pub use lock_on::LockOnTarget;
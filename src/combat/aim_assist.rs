@@ -0,0 +1,109 @@
+/*
+===============================================================================
+ ФАЙЛ: src/combat/aim_assist.rs
+===============================================================================
+
+📋 ПРИЗНАЧЕННЯ:
+  Aim assist - м'яке доведення напрямку атаки до найближчого ворога.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+  - Пошук найближчої цілі в конусі перед гравцем
+  - Поворот напрямку атаки до цілі з заданою силою (0..1)
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+  - Все рахується в площині XZ (висота ігнорується)
+  - Ціль поза конусом або далі max_range - напрямок без змін
+  - strength = 1.0 → точно на ціль, 0.0 → вимкнено
+  - Викликати ДО spawn_attack_hitbox (hitbox йде по новому yaw)
+
+🕐 ІСТОРІЯ:
+  2026-10-16: Створено - aim assist для melee атак
+
+===============================================================================
+*/
+
+use glam::Vec3;
+
+use crate::settings::Settings;
+
+/// Параметри aim assist
+#[derive(Debug, Clone, Copy)]
+pub struct AimAssist {
+    /// Половина кута конусу (радіани)
+    pub cone_angle: f32,
+
+    /// Сила доведення (0.0 = вимкнено, 1.0 = точно на ціль)
+    pub strength: f32,
+
+    /// Максимальна відстань до цілі
+    pub max_range: f32,
+}
+
+impl AimAssist {
+    /// Читає секцію [combat] з налаштувань
+    ///
+    /// Ключі: `aim_assist_angle` (градуси), `aim_assist_strength`, `aim_assist_range`
+    pub fn from_settings(settings: &Settings) -> Self {
+        let default = Self::default();
+        Self {
+            cone_angle: settings
+                .get_f32("combat.aim_assist_angle", default.cone_angle.to_degrees())
+                .to_radians(),
+            strength: settings
+                .get_f32("combat.aim_assist_strength", default.strength)
+                .clamp(0.0, 1.0),
+            max_range: settings.get_f32("combat.aim_assist_range", default.max_range),
+        }
+    }
+
+    /// Повертає скоригований напрямок атаки
+    ///
+    /// # Аргументи
+    /// * `origin` - Позиція гравця
+    /// * `forward` - Поточний напрямок атаки
+    /// * `targets` - Позиції живих ворогів
+    ///
+    /// # Повертає
+    /// Нормалізований напрямок в площині XZ
+    pub fn adjust(&self, origin: Vec3, forward: Vec3, targets: impl IntoIterator<Item = Vec3>) -> Vec3 {
+        let forward = Vec3::new(forward.x, 0.0, forward.z).normalize_or_zero();
+        if forward == Vec3::ZERO || self.strength <= 0.0 {
+            return forward;
+        }
+
+        // Найближча ціль всередині конусу
+        let mut best: Option<(f32, Vec3)> = None;
+        for target in targets {
+            let to_target = Vec3::new(target.x - origin.x, 0.0, target.z - origin.z);
+            let distance = to_target.length();
+            if distance < 1e-3 || distance > self.max_range {
+                continue;
+            }
+
+            let direction = to_target / distance;
+            if forward.angle_between(direction) > self.cone_angle {
+                continue;
+            }
+
+            if best.is_none_or(|(d, _)| distance < d) {
+                best = Some((distance, direction));
+            }
+        }
+
+        let Some((_, target_dir)) = best else {
+            return forward;
+        };
+
+        forward.lerp(target_dir, self.strength).normalize_or(forward)
+    }
+}
+
+impl Default for AimAssist {
+    fn default() -> Self {
+        Self {
+            cone_angle: 20.0_f32.to_radians(),
+            strength: 0.7,
+            max_range: 3.0,
+        }
+    }
+}
@@ -0,0 +1,254 @@
+/*
+===============================================================================
+ ФАЙЛ: src/combat/block.rs
+===============================================================================
+
+📋 ПРИЗНАЧЕННЯ:
+  Блок та парирування (утримання ПКМ).
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+  - BlockState: опущений / піднятий (скільки часу утримується)
+  - Defense: час підйому, множник шкоди блоку, вікно парирування, конус
+  - resolve_hit: Hit / Blocked / Parried з фінальною шкодою
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+  - Перші raise_time секунд блок ще піднімається - удар проходить повністю
+  - Наступні parry_window секунд - парирування: шкода 0, атакуючий
+    приголомшений на PARRY_STAGGER_DURATION
+  - Далі - звичайний блок: шкода × damage_factor
+  - Блок діє лише якщо атака спереду (конус cone_cos)
+  - Коли можна піднімати блок - вирішує Combat (не під час удару / Recovery)
+
+🕐 ІСТОРІЯ:
+  2026-10-16: Створено - блок та парирування
+  2026-10-16: is_parrying - чи триває вікно парирування
+
+===============================================================================
+*/
+
+use glam::Vec3;
+
+use super::WeaponPose;
+
+/// Скільки секунд приголомшений атакуючий після парирування
+pub const PARRY_STAGGER_DURATION: f32 = 1.0;
+
+/// Поза меча в блоці (поперек тіла, вістря трохи вгору)
+const BLOCK_POSE: WeaponPose = WeaponPose { yaw: 0.9, pitch: 0.4, thrust: 0.0 };
+
+/// Стан блоку
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum BlockState {
+    /// Блок не тримається
+    #[default]
+    Lowered,
+    /// Блок утримується (секунди з моменту натискання)
+    Raised { held: f32 },
+}
+
+/// Результат удару по гравцю
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HitOutcome {
+    /// Повний удар
+    Hit { damage: f32 },
+    /// Заблоковано - зменшена шкода
+    Blocked { damage: f32 },
+    /// Парирування - без шкоди, атакуючого приголомшено
+    Parried { stagger: f32 },
+}
+
+impl HitOutcome {
+    /// Шкода, яку отримує захисник
+    pub fn damage(&self) -> f32 {
+        match self {
+            Self::Hit { damage } | Self::Blocked { damage } => *damage,
+            Self::Parried { .. } => 0.0,
+        }
+    }
+}
+
+/// Блок та його параметри
+#[derive(Debug, Clone, Copy)]
+pub struct Defense {
+    /// Поточний стан
+    pub state: BlockState,
+
+    /// Час підйому блоку (секунди) - до цього удар проходить
+    pub raise_time: f32,
+
+    /// Множник шкоди в блоці (0 = повний захист, 1 = без захисту)
+    pub damage_factor: f32,
+
+    /// Вікно парирування після підйому (секунди)
+    pub parry_window: f32,
+
+    /// Мінімальний cos кута між поглядом та напрямком на атакуючого
+    pub cone_cos: f32,
+}
+
+impl Defense {
+    /// Чи блок утримується (включно з підйомом)
+    pub fn is_blocking(&self) -> bool {
+        matches!(self.state, BlockState::Raised { .. })
+    }
+
+    /// Чи триває вікно парирування (блок щойно піднявся)
+    pub fn is_parrying(&self) -> bool {
+        match self.state {
+            BlockState::Raised { held } => held >= self.raise_time && held < self.raise_time + self.parry_window,
+            BlockState::Lowered => false,
+        }
+    }
+
+    /// Піднімає блок (вже піднятий - без змін)
+    pub fn raise(&mut self) {
+        if !self.is_blocking() {
+            self.state = BlockState::Raised { held: 0.0 };
+        }
+    }
+
+    /// Опускає блок
+    pub fn lower(&mut self) {
+        self.state = BlockState::Lowered;
+    }
+
+    /// Оновлює час утримання
+    pub fn update(&mut self, delta: f32) {
+        if let BlockState::Raised { held } = &mut self.state {
+            *held += delta;
+        }
+    }
+
+    /// Поза меча з урахуванням блоку (підйом - плавний перехід до BLOCK_POSE)
+    pub fn weapon_pose(&self, pose: WeaponPose) -> WeaponPose {
+        match self.state {
+            BlockState::Lowered => pose,
+            BlockState::Raised { held } => {
+                let t = if self.raise_time > 0.0 { (held / self.raise_time).min(1.0) } else { 1.0 };
+                pose.lerp(BLOCK_POSE, t)
+            }
+        }
+    }
+
+    /// Результат удару по захиснику
+    ///
+    /// # Аргументи
+    /// * `damage` - Шкода удару
+    /// * `attack_dir` - Напрямок руху удару (від атакуючого до захисника)
+    /// * `facing_dir` - Куди дивиться захисник
+    pub fn resolve_hit(&self, damage: f32, attack_dir: Vec3, facing_dir: Vec3) -> HitOutcome {
+        let BlockState::Raised { held } = self.state else {
+            return HitOutcome::Hit { damage };
+        };
+
+        // Блок тримає лише удари спереду (площина XZ)
+        let to_attacker = Vec3::new(-attack_dir.x, 0.0, -attack_dir.z).normalize_or_zero();
+        let facing = Vec3::new(facing_dir.x, 0.0, facing_dir.z).normalize_or_zero();
+        if held < self.raise_time || to_attacker.dot(facing) < self.cone_cos {
+            return HitOutcome::Hit { damage };
+        }
+
+        if self.is_parrying() {
+            HitOutcome::Parried { stagger: PARRY_STAGGER_DURATION }
+        } else {
+            HitOutcome::Blocked { damage: damage * self.damage_factor }
+        }
+    }
+}
+
+impl Default for Defense {
+    fn default() -> Self {
+        Self {
+            state: BlockState::Lowered,
+            raise_time: 0.1,        // 100ms підйом
+            damage_factor: 0.3,     // блок пропускає 30% шкоди
+            parry_window: 0.12,     // 120ms парирування
+            cone_cos: 0.5,          // ±60° спереду
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat::{AttackDirection, AttackKind, AttackPhase, Combat};
+
+    /// Удар спереду по гравцю, що дивиться в -Z
+    const ATTACK_DIR: Vec3 = Vec3::Z;
+    const FACING: Vec3 = Vec3::NEG_Z;
+
+    /// Блок, утримуваний `held` секунд
+    fn raised(held: f32) -> Defense {
+        Defense { state: BlockState::Raised { held }, ..Defense::default() }
+    }
+
+    #[test]
+    fn hit_inside_parry_window_is_parried() {
+        let defense = Defense::default();
+        let held = defense.raise_time + defense.parry_window * 0.5;
+        assert!(raised(held).is_parrying());
+        assert_eq!(
+            raised(held).resolve_hit(10.0, ATTACK_DIR, FACING),
+            HitOutcome::Parried { stagger: PARRY_STAGGER_DURATION }
+        );
+    }
+
+    #[test]
+    fn hit_after_parry_window_is_blocked() {
+        let defense = Defense::default();
+        let held = defense.raise_time + defense.parry_window + 0.05;
+        assert!(!raised(held).is_parrying());
+        let outcome = raised(held).resolve_hit(10.0, ATTACK_DIR, FACING);
+        assert_eq!(outcome, HitOutcome::Blocked { damage: 10.0 * defense.damage_factor });
+        assert!(outcome.damage() < 10.0);
+    }
+
+    #[test]
+    fn hit_while_raising_or_lowered_goes_through() {
+        let defense = Defense::default();
+        assert_eq!(raised(defense.raise_time * 0.5).resolve_hit(10.0, ATTACK_DIR, FACING), HitOutcome::Hit { damage: 10.0 });
+        assert_eq!(defense.resolve_hit(10.0, ATTACK_DIR, FACING), HitOutcome::Hit { damage: 10.0 });
+    }
+
+    #[test]
+    fn block_only_covers_front_cone() {
+        let held = 1.0;
+        // Удар у спину
+        assert_eq!(raised(held).resolve_hit(10.0, Vec3::NEG_Z, FACING), HitOutcome::Hit { damage: 10.0 });
+        // Збоку (90°) - поза конусом ±60°
+        assert_eq!(raised(held).resolve_hit(10.0, Vec3::X, FACING), HitOutcome::Hit { damage: 10.0 });
+        // Під 45° - ще в конусі
+        let diagonal = Vec3::new(1.0, 0.0, 1.0).normalize();
+        assert!(matches!(raised(held).resolve_hit(10.0, diagonal, FACING), HitOutcome::Blocked { .. }));
+    }
+
+    #[test]
+    fn combat_update_ticks_parry_window() {
+        let mut combat = Combat::new();
+        combat.set_blocking(true);
+        assert!(combat.is_blocking());
+        let dt = 1.0 / 120.0;
+        let mut seen_parry = false;
+        for _ in 0..60 {
+            combat.update(dt, FACING);
+            seen_parry |= combat.defense.is_parrying();
+        }
+        assert!(seen_parry, "вікно парирування не відкрилось");
+        assert!(!combat.defense.is_parrying(), "вікно парирування не закрилось");
+        assert!(matches!(combat.resolve_incoming_hit(10.0, ATTACK_DIR, FACING), HitOutcome::Blocked { .. }));
+
+        combat.set_blocking(false);
+        assert!(!combat.is_blocking());
+    }
+
+    #[test]
+    fn block_not_raised_mid_swing() {
+        let mut combat = Combat::new();
+        combat.start_attack(FACING, AttackKind::Light, AttackDirection::default());
+        while combat.get_phase() != Some(AttackPhase::Action) {
+            combat.update(1.0 / 120.0, FACING);
+        }
+        combat.set_blocking(true);
+        assert!(!combat.is_blocking());
+    }
+}
@@ -8,29 +8,135 @@
 
 🎯 ВІДПОВІДАЛЬНІСТЬ:
   - Hitbox struct (position, size, lifetime)
-  - Collision detection (sphere vs sphere)
+  - HitboxShape: сфера або капсула (відрізок + радіус)
+  - Collision detection (sphere / capsule vs sphere)
+  - Swept collision: рух hitbox'а між кадрами (collides_swept)
+  - Влучання в кістки ragdoll ворогів (PhysicsWorld::bones_in_sphere)
   - Damage application
 
 ⚠️  ВАЖЛИВІ ДЕТАЛІ:
-  - Hitbox існує короткий час (~100ms)
-  - Використовуємо sphere collision для простоти
+  - Hitbox атак ворогів існує короткий час (~150ms)
+  - Hitbox меча гравця існує лише у фазі Action і рухається по дузі замаху
+  - Удари ворогів - сфери; меч гравця - капсула вздовж леза (від
+    руків'я до вістря), тож ворог посередині клинка теж вражається
+  - position - центр hitbox'а (для капсули - середина відрізка):
+    broadphase, напрямок відкидання
+  - Hitbox пам'ятає позицію та форму з попереднього кадру - швидкий
+    замах не "проскакує" ворога між кадрами: сфера перевіряється як
+    капсула prev → position, лезо - проміжними відрізками між кадрами
   - Один hitbox може вразити кожного ворога лише раз
+  - owner: hitbox гравця б'є ворогів, hitbox ворога - гравця
+  - check_collisions: вороги розкладаються в рівномірну сітку по XZ
+    (GRID_CELL_SIZE), hitbox перевіряється лише з ворогами сусідніх клітинок
+  - Ворог з ragdoll вражається по кістках: замах розкладається на сфери
+    (sweep_samples), перша сфера, що зачепила кістку ворога, дає влучання
+    в цю кістку. Ворог без ragdoll - одна сфера на висоті грудей
 
 🕐 ІСТОРІЯ:
   2025-12-14: Створено - базова hitbox система
+  2026-10-16: Висота hitbox атаки залежить від стійки (crouch)
+  2026-10-16: HitboxOwner - hitbox'и гравця та ворогів
+  2026-10-16: Hitbox меча рухається з мечем у фазі Action (замість спавну при кліку)
+  2026-10-16: Траєкторія hitbox'а меча за WeaponPose (напрямок удару)
+  2026-10-16: check_collisions - broadphase через сітку по XZ
+  2026-10-16: Швидкість hitbox'а меча відносно гравця (для шкоди від швидкості)
+  2026-10-16: HitboxShape - капсула вздовж леза для hitbox'а меча
+  2026-10-16: Swept collision (prev_position) проти тунелювання
+  2026-10-16: Влучання по кістках ragdoll ворогів (HitboxHit::bone)
+  2026-10-16: Довжина леза - з зброї (Weapon::reach)
+  2026-10-17: Тест сітки проти перебору, benches/hitbox_grid.rs (500 ворогів)
+  2026-10-17: register_blade_contact - контакт фізичного леза як влучання замаху
 
 ===============================================================================
 */
 
+use std::collections::HashMap;
+
 use glam::Vec3;
 
+use super::{Combat, WeaponPose};
+use crate::enemy::Enemy;
+use crate::physics::{BoneId, PhysicsWorld};
+
+/// Розмір клітинки сітки broadphase (м)
+///
+/// Більше за типовий діаметр hitbox + ворога - hitbox зачіпає 1-4 клітинки
+const GRID_CELL_SIZE: f32 = 2.0;
+
+/// Висота центру ворога над його позицією (ноги)
+const ENEMY_CENTER_HEIGHT: f32 = 1.0;
+
+/// Максимум проміжних відрізків леза в swept перевірці
+const MAX_SWEEP_STEPS: usize = 16;
+
+/// Радіус навколо центру ворога, що вміщує його ragdoll (broadphase)
+const RAGDOLL_REACH: f32 = 1.2;
+
+/// Влучання hitbox'а гравця у ворога (check_collisions)
+#[derive(Debug, Clone, Copy)]
+pub struct HitboxHit {
+    /// Індекс hitbox'а в порядку iter_mut (див. HitboxManager::get)
+    pub hitbox_index: usize,
+
+    /// Індекс ворога
+    pub enemy_index: usize,
+
+    /// Базова шкода hitbox'а
+    pub damage: f32,
+
+    /// Влучена кістка ragdoll ворога (None - ворог без ragdoll)
+    pub bone: Option<BoneId>,
+}
+
+/// Відстань від точки до відрізка start → end
+fn segment_distance(point: Vec3, start: Vec3, end: Vec3) -> f32 {
+    let axis = end - start;
+    let t = ((point - start).dot(axis) / axis.length_squared().max(1e-6)).clamp(0.0, 1.0);
+    (start + axis * t).distance(point)
+}
+
+/// Хто створив hitbox (кого він може вразити)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HitboxOwner {
+    /// Атака гравця - б'є ворогів
+    Player,
+    /// Атака ворога з індексом в `enemies` - б'є гравця
+    Enemy(usize),
+}
+
+/// Форма hitbox'а (world space)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HitboxShape {
+    /// Сфера навколо position
+    Sphere { radius: f32 },
+    /// Капсула: відрізок start → end з радіусом
+    Capsule { start: Vec3, end: Vec3, radius: f32 },
+}
+
+impl HitboxShape {
+    /// Відстань від точки до "кістяка" форми (центру сфери / відрізка капсули)
+    fn core_distance(&self, position: Vec3, point: Vec3) -> f32 {
+        match *self {
+            Self::Sphere { .. } => position.distance(point),
+            Self::Capsule { start, end, .. } => segment_distance(point, start, end),
+        }
+    }
+
+    /// Радіус форми
+    pub fn radius(&self) -> f32 {
+        match *self {
+            Self::Sphere { radius } | Self::Capsule { radius, .. } => radius,
+        }
+    }
+}
+
 /// Hitbox - зона ураження
 pub struct Hitbox {
-    /// Центр hitbox в world space
+    /// Центр hitbox в world space (для капсули - середина відрізка)
     pub position: Vec3,
 
-    /// Радіус hitbox (sphere collision)
-    pub radius: f32,
+    /// Форма hitbox'а
+    pub shape: HitboxShape,
 
     /// Час життя що залишився (секунди)
     pub lifetime: f32,
@@ -40,20 +146,90 @@ pub struct Hitbox {
 
     /// ID ворогів яких вже вразили (щоб не бити двічі)
     pub hit_enemies: Vec<usize>,
+
+    /// Власник hitbox
+    pub owner: HitboxOwner,
+
+    /// Швидкість вістря / центру відносно власника (м/с), нуль для нерухомих
+    pub velocity: Vec3,
+
+    /// Центр у попередньому кадрі (swept collision)
+    pub prev_position: Vec3,
+
+    /// Форма у попередньому кадрі (swept collision)
+    pub prev_shape: HitboxShape,
 }
 
 impl Hitbox {
-    /// Створює новий hitbox
+    /// Створює новий сферичний hitbox (власник - гравець)
     pub fn new(position: Vec3, radius: f32, lifetime: f32, damage: f32) -> Self {
+        let shape = HitboxShape::Sphere { radius };
         Self {
             position,
-            radius,
+            shape,
             lifetime,
             damage,
             hit_enemies: Vec::new(),
+            owner: HitboxOwner::Player,
+            velocity: Vec3::ZERO,
+            prev_position: position,
+            prev_shape: shape,
         }
     }
 
+    /// Створює капсульний hitbox вздовж відрізка start → end (власник - гравець)
+    pub fn capsule(start: Vec3, end: Vec3, radius: f32, lifetime: f32, damage: f32) -> Self {
+        let mut hitbox = Self::new(Vec3::ZERO, radius, lifetime, damage);
+        hitbox.set_segment(start, end);
+        // Перший кадр - без руху
+        hitbox.prev_position = hitbox.position;
+        hitbox.prev_shape = hitbox.shape;
+        hitbox
+    }
+
+    /// Переміщує hitbox (форма зсувається разом з центром)
+    ///
+    /// Поточна позиція та форма стають prev_position / prev_shape.
+    pub fn update_position(&mut self, new_pos: Vec3) {
+        let offset = new_pos - self.position;
+        self.prev_position = self.position;
+        self.prev_shape = self.shape;
+        self.position = new_pos;
+        if let HitboxShape::Capsule { start, end, .. } = &mut self.shape {
+            *start += offset;
+            *end += offset;
+        }
+    }
+
+    /// Переміщує hitbox на відрізок start → end (сфера стає капсулою)
+    ///
+    /// Поточна позиція та форма стають prev_position / prev_shape.
+    pub fn set_segment(&mut self, start: Vec3, end: Vec3) {
+        self.update_position((start + end) * 0.5);
+        self.shape = HitboxShape::Capsule { start, end, radius: self.shape.radius() };
+    }
+
+    /// Радіус сфери навколо position, що вміщує весь hitbox (broadphase)
+    pub fn bounding_radius(&self) -> f32 {
+        match self.shape {
+            HitboxShape::Sphere { radius } => radius,
+            HitboxShape::Capsule { start, end, radius } => start.distance(end) * 0.5 + radius,
+        }
+    }
+
+    /// Сфера, що вміщує hitbox в обох кадрах (broadphase swept): (центр, радіус)
+    pub fn swept_bounds(&self) -> (Vec3, f32) {
+        let center = (self.prev_position + self.position) * 0.5;
+        let radius = self.bounding_radius() + self.prev_position.distance(self.position) * 0.5;
+        (center, radius)
+    }
+
+    /// Встановлює власника hitbox
+    pub fn with_owner(mut self, owner: HitboxOwner) -> Self {
+        self.owner = owner;
+        self
+    }
+
     /// Перевіряє чи hitbox ще активний
     pub fn is_active(&self) -> bool {
         self.lifetime > 0.0
@@ -64,16 +240,66 @@ impl Hitbox {
         self.lifetime -= delta;
     }
 
-    /// Перевіряє колізію з точкою (sphere vs point)
+    /// Перевіряє колізію з точкою
     pub fn collides_with_point(&self, point: Vec3) -> bool {
-        let distance = (self.position - point).length();
-        distance < self.radius
+        self.shape.core_distance(self.position, point) < self.shape.radius()
     }
 
-    /// Перевіряє колізію зі сферою (sphere vs sphere)
+    /// Перевіряє колізію зі сферою (для капсули - відстань до відрізка)
     pub fn collides_with_sphere(&self, center: Vec3, radius: f32) -> bool {
-        let distance = (self.position - center).length();
-        distance < (self.radius + radius)
+        self.shape.core_distance(self.position, center) < (self.shape.radius() + radius)
+    }
+
+    /// Перевіряє колізію зі сферою з урахуванням руху з попереднього кадру
+    ///
+    /// Сфера: капсула prev_position → position. Капсула (лезо): поточний,
+    /// попередній та проміжні відрізки (крок не більше радіуса hitbox'а).
+    pub fn collides_swept(&self, center: Vec3, radius: f32) -> bool {
+        if self.collides_with_sphere(center, radius) {
+            return true;
+        }
+
+        let reach = self.shape.radius() + radius;
+        self.sweep_segments()
+            .into_iter()
+            .any(|(start, end)| segment_distance(center, start, end) < reach)
+    }
+
+    /// Відрізки, які замітає hitbox між кадрами (від попереднього до поточного)
+    ///
+    /// Сфера: один відрізок prev_position → position. Капсула (лезо):
+    /// попередній, проміжні та поточний відрізки (крок не більше радіуса).
+    fn sweep_segments(&self) -> Vec<(Vec3, Vec3)> {
+        match (self.prev_shape, self.shape) {
+            (
+                HitboxShape::Capsule { start: prev_start, end: prev_end, .. },
+                HitboxShape::Capsule { start, end, radius },
+            ) => {
+                let travel = prev_start.distance(start).max(prev_end.distance(end));
+                let steps = ((travel / radius.max(1e-3)).ceil() as usize).clamp(1, MAX_SWEEP_STEPS);
+                (0..=steps)
+                    .map(|i| {
+                        let t = i as f32 / steps as f32;
+                        (prev_start.lerp(start, t), prev_end.lerp(end, t))
+                    })
+                    .collect()
+            }
+            _ => vec![(self.prev_position, self.position)],
+        }
+    }
+
+    /// Центри сфер радіуса hitbox'а, що покривають замах між кадрами
+    ///
+    /// Порядок - від попереднього кадру до поточного (від руків'я до вістря),
+    /// тож перша сфера з влучанням - перший контакт.
+    pub fn sweep_samples(&self) -> Vec<Vec3> {
+        let spacing = self.shape.radius().max(1e-3);
+        let mut samples = Vec::new();
+        for (start, end) in self.sweep_segments() {
+            let count = ((start.distance(end) / spacing).ceil() as usize).clamp(1, MAX_SWEEP_STEPS);
+            samples.extend((0..=count).map(|i| start.lerp(end, i as f32 / count as f32)));
+        }
+        samples
     }
 
     /// Позначає ворога як враженого
@@ -85,61 +311,265 @@ impl Hitbox {
     pub fn has_hit(&self, enemy_index: usize) -> bool {
         self.hit_enemies.contains(&enemy_index)
     }
+
+    /// Коригує індекси після видалення ворога з `enemies`
+    pub fn on_enemy_removed(&mut self, enemy_index: usize) {
+        self.hit_enemies.retain(|&i| i != enemy_index);
+        for i in &mut self.hit_enemies {
+            if *i > enemy_index {
+                *i -= 1;
+            }
+        }
+        if let HitboxOwner::Enemy(i) = &mut self.owner {
+            if *i > enemy_index {
+                *i -= 1;
+            }
+        }
+    }
+}
+
+/// Лезо меча при заданій позі меча: (руків'я, вістря)
+///
+/// Геометрія як у generate_weapon_arm та WgpuRenderer::update_player:
+/// рука від плеча вздовж +X, меч довжиною `reach` з кінця руки вздовж -Z;
+/// поза - поворот на yaw + pose.yaw навколо Y, pose.pitch навколо X та
+/// висування на pose.thrust вздовж меча.
+fn weapon_blade_segment(
+    player_pos: Vec3,
+    player_yaw: f32,
+    pose: WeaponPose,
+    reach: f32,
+    height_scale: f32,
+) -> (Vec3, Vec3) {
+    // Weapon parameters (мають співпадати з generate_weapon_arm)
+    let body_radius = 0.3;
+    let arm_length = 0.6;
+    let shoulder_height = (0.75 + 0.45) * height_scale;
+
+    let right = Vec3::new(player_yaw.cos(), 0.0, -player_yaw.sin());
+    let shoulder = player_pos + Vec3::new(0.0, shoulder_height, 0.0) + right * body_radius;
+
+    let rotation = pose.rotation(player_yaw);
+    let hilt = Vec3::new(arm_length, 0.0, -pose.thrust);
+    let tip = Vec3::new(arm_length, 0.0, -(reach + pose.thrust));
+    (shoulder + rotation * hilt, shoulder + rotation * tip)
+}
+
+/// Рівномірна сітка по XZ: клітинка → індекси ворогів
+struct SpatialGrid {
+    cells: HashMap<(i32, i32), Vec<usize>>,
+}
+
+impl SpatialGrid {
+    /// Розкладає живих ворогів по клітинках (за центром)
+    fn build(enemies: &[Enemy]) -> Self {
+        let mut cells: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+        for (i, enemy) in enemies.iter().enumerate() {
+            if enemy.is_alive() {
+                cells.entry(Self::cell(enemy.position)).or_default().push(i);
+            }
+        }
+        Self { cells }
+    }
+
+    /// Клітинка точки
+    fn cell(point: Vec3) -> (i32, i32) {
+        (
+            (point.x / GRID_CELL_SIZE).floor() as i32,
+            (point.z / GRID_CELL_SIZE).floor() as i32,
+        )
+    }
+
+    /// Індекси ворогів у клітинках, які перекриває коло (center, radius) по XZ
+    fn query(&self, center: Vec3, radius: f32) -> impl Iterator<Item = usize> + '_ {
+        let (min_x, min_z) = Self::cell(center - Vec3::new(radius, 0.0, radius));
+        let (max_x, max_z) = Self::cell(center + Vec3::new(radius, 0.0, radius));
+        (min_x..=max_x)
+            .flat_map(move |x| (min_z..=max_z).map(move |z| (x, z)))
+            .filter_map(|key| self.cells.get(&key))
+            .flatten()
+            .copied()
+    }
 }
 
 /// Менеджер hitbox'ів
 pub struct HitboxManager {
-    /// Активні hitbox'и
+    /// Активні hitbox'и (з фіксованою позицією та lifetime)
     pub hitboxes: Vec<Hitbox>,
+
+    /// Hitbox меча гравця (лише у фазі Action, рухається з мечем)
+    swing: Option<Hitbox>,
+
+    /// Зміщення hitbox'а меча від гравця в попередньому кадрі атаки
+    last_swing_offset: Option<Vec3>,
 }
 
 impl HitboxManager {
     pub fn new() -> Self {
         Self {
             hitboxes: Vec::new(),
+            swing: None,
+            last_swing_offset: None,
         }
     }
 
+    /// Всі активні hitbox'и, включно з hitbox'ом меча
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Hitbox> {
+        self.hitboxes.iter_mut().chain(self.swing.iter_mut())
+    }
+
+    /// Hitbox за індексом (порядок як в iter_mut / check_collisions)
+    pub fn get(&self, index: usize) -> Option<&Hitbox> {
+        self.hitboxes.get(index).or_else(|| {
+            index
+                .checked_sub(self.hitboxes.len())
+                .and_then(|i| self.swing.iter().nth(i))
+        })
+    }
+
     /// Додає новий hitbox
     pub fn spawn(&mut self, hitbox: Hitbox) {
         self.hitboxes.push(hitbox);
     }
 
-    /// Створює hitbox атаки на кінці зброї
+    /// Веде hitbox меча за станом Combat (викликати кожен кадр ПІСЛЯ combat.update)
     ///
-    /// Зброя знаходиться на правій руці гравця, меч направлений вперед.
-    /// Hitbox з'являється на кінці меча.
-    pub fn spawn_attack_hitbox(&mut self, player_pos: Vec3, player_yaw: f32, damage: f32) {
-        // Weapon parameters (мають співпадати з generate_armed_mannequin)
-        let body_radius = 0.3;
-        let arm_length = 0.6;
-        let weapon_length = 1.0;
-        let shoulder_height = 1.2 / 2.0 - 0.15; // body_height/2 - offset
-
-        // Right direction (перпендикулярно до forward)
-        let right = Vec3::new(player_yaw.cos(), 0.0, -player_yaw.sin());
-
-        // Forward direction
-        let forward = Vec3::new(-player_yaw.sin(), 0.0, -player_yaw.cos());
-
-        // Позиція кінця зброї:
-        // - праворуч на відстані (body_radius + arm_length)
-        // - вперед на довжину меча
-        // - на висоті плеча
-        let weapon_tip_offset = right * (body_radius + arm_length)
-            + forward * (weapon_length * 0.8)  // 80% довжини меча вперед
-            + Vec3::new(0.0, shoulder_height, 0.0);
-
-        let hitbox_pos = player_pos + weapon_tip_offset;
-
-        let hitbox = Hitbox::new(
-            hitbox_pos,
-            0.5,    // radius (менший, точніший)
-            0.15,   // lifetime (150ms)
-            damage,
+    /// Hitbox - капсула вздовж леза, існує лише у фазі Action і щокадру
+    /// переміщується по дузі замаху (weapon_pose) - як меч у renderer. В Anticipation /
+    /// Recovery hitbox'а немає. Вороги, вражені раніше в цьому ж замаху,
+    /// вдруге не отримують шкоди. Шкода та радіус - з типу атаки та зброї,
+    /// довжина леза - combat.weapon.reach.
+    ///
+    /// Швидкість hitbox'а - різниця зміщення вістря від гравця між кадрами
+    /// атаки (рух меча відносно тіла, без руху самого гравця).
+    ///
+    /// `height_scale` - множник висоти плеча (1.0 стоячи, менше - присівши)
+    pub fn update_swing_hitbox(
+        &mut self,
+        combat: &Combat,
+        player_pos: Vec3,
+        player_yaw: f32,
+        height_scale: f32,
+        delta: f32,
+    ) {
+        if !combat.is_attacking() {
+            self.last_swing_offset = None;
+        }
+        let (hilt, tip) = weapon_blade_segment(
+            player_pos,
+            player_yaw,
+            combat.weapon_pose,
+            combat.weapon.reach,
+            height_scale,
         );
+        let offset = tip - player_pos;
+        let velocity = match self.last_swing_offset {
+            Some(last) if delta > 0.0 => (offset - last) / delta,
+            _ => Vec3::ZERO,
+        };
+        if combat.is_attacking() {
+            self.last_swing_offset = Some(offset);
+        }
+
+        if !combat.is_hitbox_active() {
+            self.swing = None;
+            return;
+        }
 
-        self.spawn(hitbox);
+        let hitbox = self.swing.get_or_insert_with(|| {
+            let attack = combat.definition();
+            Hitbox::capsule(hilt, tip, attack.hitbox_radius, attack.phases.action, attack.damage)
+        });
+        hitbox.set_segment(hilt, tip);
+        hitbox.velocity = velocity;
+    }
+
+    /// Колізії hitbox'ів гравця з ворогами
+    ///
+    /// Вороги розкладаються в сітку по XZ, кожен hitbox перевіряється лише
+    /// з ворогами сусідніх клітинок - замість перебору всіх пар. Кожен hitbox
+    /// вражає ворога лише раз (has_hit / mark_hit); мертві вороги пропускаються.
+    /// Hitbox'и ворогів (б'ють гравця) тут не перевіряються.
+    ///
+    /// Ворог з ragdoll (і є `physics`) - перевірка по кістках: перша сфера
+    /// sweep_samples, що зачепила кістку його скелета. Інакше - сфера
+    /// `enemy_radius` на висоті грудей.
+    ///
+    /// # Повертає
+    /// Влучання (HitboxHit); шкоду та множник кістки застосовує викликаючий.
+    pub fn check_collisions(
+        &mut self,
+        enemies: &[Enemy],
+        enemy_radius: f32,
+        physics: Option<&PhysicsWorld>,
+    ) -> Vec<HitboxHit> {
+        let grid = SpatialGrid::build(enemies);
+        let mut hits = Vec::new();
+
+        for (hitbox_index, hitbox) in self.iter_mut().enumerate() {
+            if hitbox.owner != HitboxOwner::Player {
+                continue;
+            }
+
+            // Кістки під замахом (в порядку sweep_samples) - лише якщо поруч ragdoll
+            let mut bone_hits = None;
+
+            let (bounds_center, bounds_radius) = hitbox.swept_bounds();
+            for enemy_index in grid.query(bounds_center, bounds_radius + enemy_radius.max(RAGDOLL_REACH)) {
+                if hitbox.has_hit(enemy_index) {
+                    continue;
+                }
+
+                let enemy = &enemies[enemy_index];
+                let bone = match (physics, &enemy.ragdoll) {
+                    (Some(physics), Some(ragdoll)) => {
+                        let bone_hits = bone_hits.get_or_insert_with(|| {
+                            let radius = hitbox.shape.radius();
+                            hitbox
+                                .sweep_samples()
+                                .into_iter()
+                                .flat_map(|sample| physics.bones_in_sphere(sample, radius))
+                                .collect::<Vec<_>>()
+                        });
+                        let group = ragdoll.skeleton.collision_group;
+                        match bone_hits.iter().find(|(bone_group, _)| *bone_group == group) {
+                            Some((_, bone_id)) => Some(*bone_id),
+                            None => continue,
+                        }
+                    }
+                    _ => {
+                        let enemy_center = enemy.position + Vec3::new(0.0, ENEMY_CENTER_HEIGHT, 0.0);
+                        if !hitbox.collides_swept(enemy_center, enemy_radius) {
+                            continue;
+                        }
+                        None
+                    }
+                };
+
+                hitbox.mark_hit(enemy_index);
+                hits.push(HitboxHit { hitbox_index, enemy_index, damage: hitbox.damage, bone });
+            }
+        }
+
+        hits
+    }
+
+    /// Контакт леза фізичного меча з кісткою ворога (collision event)
+    ///
+    /// Влучання зараховується hitbox'у замаху: лише у фазі Action і лише раз
+    /// на ворога за замах - разом з check_collisions, без подвійної шкоди.
+    ///
+    /// # Повертає
+    /// Влучання з індексом hitbox'а замаху (None - замаху немає або ворога
+    /// вже вражено)
+    pub fn register_blade_contact(&mut self, enemy_index: usize, bone: BoneId) -> Option<HitboxHit> {
+        let hitbox_index = self.hitboxes.len();
+        let swing = self.swing.as_mut()?;
+        if swing.has_hit(enemy_index) {
+            return None;
+        }
+        swing.mark_hit(enemy_index);
+        Some(HitboxHit { hitbox_index, enemy_index, damage: swing.damage, bone: Some(bone) })
     }
 
     /// Оновлює всі hitbox'и та видаляє неактивні
@@ -153,9 +583,23 @@ impl HitboxManager {
         self.hitboxes.retain(|h| h.is_active());
     }
 
+    /// Коригує індекси ворогів у всіх hitbox'ах після видалення ворога
+    pub fn on_enemy_removed(&mut self, enemy_index: usize) {
+        for hitbox in self.iter_mut() {
+            hitbox.on_enemy_removed(enemy_index);
+        }
+    }
+
+    /// Видаляє всі hitbox'и (рестарт)
+    pub fn clear(&mut self) {
+        self.hitboxes.clear();
+        self.swing = None;
+        self.last_swing_offset = None;
+    }
+
     /// Повертає кількість активних hitbox'ів
     pub fn active_count(&self) -> usize {
-        self.hitboxes.len()
+        self.hitboxes.len() + self.swing.iter().count()
     }
 }
 
@@ -164,3 +608,187 @@ impl Default for HitboxManager {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat::{AttackDirection, AttackKind};
+
+    const ENEMY_RADIUS: f32 = 0.5;
+
+    /// Детермінований псевдовипадковий генератор (LCG) для розкладки
+    struct Lcg(u64);
+
+    impl Lcg {
+        /// Число в [min, max)
+        fn range(&mut self, min: f32, max: f32) -> f32 {
+            self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            min + (self.0 >> 40) as f32 / (1u64 << 24) as f32 * (max - min)
+        }
+    }
+
+    /// Пари (hitbox, ворог) перебором усіх живих ворогів
+    fn brute_force_pairs(manager: &mut HitboxManager, enemies: &[Enemy]) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        for (hitbox_index, hitbox) in manager.iter_mut().enumerate() {
+            for (enemy_index, enemy) in enemies.iter().enumerate() {
+                let center = enemy.position + Vec3::new(0.0, ENEMY_CENTER_HEIGHT, 0.0);
+                if enemy.is_alive() && hitbox.collides_swept(center, ENEMY_RADIUS) {
+                    pairs.push((hitbox_index, enemy_index));
+                }
+            }
+        }
+        pairs
+    }
+
+    #[test]
+    fn grid_matches_brute_force() {
+        let mut rng = Lcg(7);
+        let mut enemies: Vec<Enemy> = (0..300)
+            .map(|_| Enemy::new(Vec3::new(rng.range(-15.0, 15.0), 0.0, rng.range(-15.0, 15.0))))
+            .collect();
+        for enemy in enemies.iter_mut().step_by(7) {
+            enemy.health = 0.0;
+        }
+
+        let mut manager = HitboxManager::new();
+        for _ in 0..40 {
+            let position = Vec3::new(rng.range(-16.0, 16.0), 1.0, rng.range(-16.0, 16.0));
+            let mut hitbox = Hitbox::new(position, rng.range(0.2, 1.5), 1.0, 10.0);
+            // Частина рухається між кадрами (swept bounds ширші за клітинку)
+            hitbox.update_position(position + Vec3::new(rng.range(-3.0, 3.0), 0.0, rng.range(-3.0, 3.0)));
+            manager.spawn(hitbox);
+        }
+        let start = Vec3::new(rng.range(-5.0, 5.0), 1.2, rng.range(-5.0, 5.0));
+        manager.spawn(Hitbox::capsule(start, start + Vec3::new(1.5, 0.0, -1.0), 0.15, 1.0, 10.0));
+
+        let mut expected = brute_force_pairs(&mut manager, &enemies);
+        let mut actual: Vec<_> = manager
+            .check_collisions(&enemies, ENEMY_RADIUS, None)
+            .iter()
+            .map(|hit| (hit.hitbox_index, hit.enemy_index))
+            .collect();
+        expected.sort_unstable();
+        actual.sort_unstable();
+        assert!(!expected.is_empty());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn grid_skips_enemy_hitboxes_and_repeat_hits() {
+        let enemies = vec![Enemy::new(Vec3::ZERO)];
+        let mut manager = HitboxManager::new();
+        manager.spawn(Hitbox::new(Vec3::Y, 0.5, 1.0, 10.0));
+        manager.spawn(Hitbox::new(Vec3::Y, 0.5, 1.0, 10.0).with_owner(HitboxOwner::Enemy(0)));
+
+        let hits = manager.check_collisions(&enemies, ENEMY_RADIUS, None);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].hitbox_index, 0);
+        // Той самий hitbox вдруге не б'є
+        assert!(manager.check_collisions(&enemies, ENEMY_RADIUS, None).is_empty());
+    }
+
+    #[test]
+    fn blade_contact_counts_once_per_swing() {
+        let mut manager = HitboxManager::new();
+        // Без замаху контакт леза не влучання
+        assert!(manager.register_blade_contact(0, BoneId::Spine).is_none());
+
+        manager.spawn(Hitbox::new(Vec3::Y, 0.5, 1.0, 5.0));
+        manager.swing = Some(Hitbox::capsule(Vec3::ZERO, Vec3::X, 0.1, 0.2, 20.0));
+        let hit = manager.register_blade_contact(2, BoneId::Head).expect("влучання замаху");
+        assert_eq!(hit.hitbox_index, 1);
+        assert_eq!(hit.enemy_index, 2);
+        assert_eq!(hit.damage, 20.0);
+        assert_eq!(hit.bone, Some(BoneId::Head));
+        assert!(manager.get(hit.hitbox_index).is_some_and(|h| h.damage == 20.0));
+
+        // Та сама ціль за той самий замах - вдруге ні (ні лезом, ні hitbox'ом)
+        assert!(manager.register_blade_contact(2, BoneId::Spine).is_none());
+        let enemies: Vec<Enemy> = (0..3).map(|_| Enemy::new(Vec3::new(0.5, 0.0, 0.0))).collect();
+        let hits = manager.check_collisions(&enemies, ENEMY_RADIUS, None);
+        assert!(hits.iter().all(|h| !(h.hitbox_index == 1 && h.enemy_index == 2)));
+    }
+
+    #[test]
+    fn capsule_hits_enemy_mid_blade() {
+        let (hilt, tip) = (Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.0, 1.0, -2.0));
+        let capsule = Hitbox::capsule(hilt, tip, 0.1, 1.0, 10.0);
+        let tip_only = Hitbox::new(tip, 0.1, 1.0, 10.0);
+
+        // Ворог посередині леза, збоку на 0.5 м
+        let enemy = Vec3::new(0.5, 1.0, -1.0);
+        assert!(capsule.collides_with_sphere(enemy, ENEMY_RADIUS));
+        assert!(!tip_only.collides_with_sphere(enemy, ENEMY_RADIUS));
+        // За кінцем леза - ні
+        assert!(!capsule.collides_with_sphere(Vec3::new(0.0, 1.0, -2.8), ENEMY_RADIUS));
+        assert!(capsule.collides_with_point(Vec3::new(0.05, 1.0, -1.5)));
+        assert_eq!(capsule.position, Vec3::new(0.0, 1.0, -1.0));
+    }
+
+    #[test]
+    fn sphere_api_unchanged() {
+        let sphere = Hitbox::new(Vec3::ZERO, 0.5, 1.0, 10.0);
+        assert_eq!(sphere.shape, HitboxShape::Sphere { radius: 0.5 });
+        assert!(sphere.collides_with_point(Vec3::new(0.4, 0.0, 0.0)));
+        assert!(!sphere.collides_with_point(Vec3::new(0.6, 0.0, 0.0)));
+        assert!(sphere.collides_with_sphere(Vec3::new(0.9, 0.0, 0.0), 0.5));
+        assert!(!sphere.collides_with_sphere(Vec3::new(1.1, 0.0, 0.0), 0.5));
+    }
+
+    #[test]
+    fn swing_hitbox_spans_blade() {
+        let mut combat = Combat::new();
+        combat.start_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default());
+        let mut manager = HitboxManager::new();
+        manager.update_swing_hitbox(&combat, Vec3::ZERO, 0.0, 1.0, 0.0);
+        assert_eq!(manager.active_count(), 0, "hitbox у замаху");
+
+        // Середина фази Action
+        let phases = combat.definition().phases;
+        combat.update(phases.anticipation + phases.action * 0.5, Vec3::NEG_Z);
+        assert!(combat.is_hitbox_active());
+        manager.update_swing_hitbox(&combat, Vec3::ZERO, 0.0, 1.0, 0.0);
+        let swing = manager.iter_mut().next().expect("hitbox меча");
+        match swing.shape {
+            HitboxShape::Capsule { start, end, radius } => {
+                assert!((start.distance(end) - combat.weapon.reach).abs() < 1e-4);
+                assert_eq!(radius, combat.definition().hitbox_radius);
+            }
+            shape => panic!("меч - не капсула: {:?}", shape),
+        }
+    }
+
+    #[test]
+    fn swept_sphere_catches_enemy_between_frames() {
+        let mut hitbox = Hitbox::new(Vec3::new(-3.0, 1.0, 0.0), 0.2, 1.0, 10.0);
+        hitbox.update_position(Vec3::new(3.0, 1.0, 0.0));
+        assert_eq!(hitbox.prev_position, Vec3::new(-3.0, 1.0, 0.0));
+
+        // Ворог посередині шляху: у жодному кадрі hitbox його не торкається
+        let enemy = Vec3::new(0.0, 1.0, 0.0);
+        assert!(!hitbox.collides_with_sphere(enemy, 0.3));
+        assert!(hitbox.collides_swept(enemy, 0.3));
+        // Осторонь шляху - ні
+        assert!(!hitbox.collides_swept(Vec3::new(0.0, 1.0, 1.0), 0.3));
+    }
+
+    #[test]
+    fn fast_blade_crosses_thin_target() {
+        // Лезо за кадр повертається на 90° навколо руків'я
+        let hilt = Vec3::new(0.0, 1.0, 0.0);
+        let mut blade = Hitbox::capsule(hilt, hilt + Vec3::new(-1.5, 0.0, 0.0), 0.05, 1.0, 10.0);
+        blade.set_segment(hilt, hilt + Vec3::new(0.0, 0.0, -1.5));
+
+        // Тонка ціль на діагоналі - між двома положеннями леза
+        // (всередині хорди: проміжні відрізки інтерполюються лінійно)
+        let target = hilt + Vec3::new(-0.6, 0.0, -0.6);
+        assert!(!blade.collides_with_sphere(target, 0.1));
+        assert!(blade.collides_swept(target, 0.1));
+        // check_collisions теж бачить влучання між кадрами
+        let enemies = vec![Enemy::new(target - Vec3::new(0.0, ENEMY_CENTER_HEIGHT, 0.0))];
+        let mut manager = HitboxManager::new();
+        manager.spawn(blade);
+        assert_eq!(manager.check_collisions(&enemies, 0.1, None).len(), 1);
+    }
+}
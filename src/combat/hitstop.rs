@@ -0,0 +1,96 @@
+/*
+===============================================================================
+ ФАЙЛ: src/combat/hitstop.rs
+===============================================================================
+
+📋 ПРИЗНАЧЕННЯ:
+  Hitstop - коротка зупинка світу (40-60 мс) при влучанні гравця: удар
+  відчувається важким.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+  - Таймер зупинки (trigger при влучанні)
+  - Delta світу на кадр: 0 під час зупинки, залишок кадру - після
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+  - Зупиняється бій, фізика, вороги та частинки; камера, миша та ввід
+    працюють у реальному часі (GameTime::real_delta)
+  - Кілька влучань підряд (один замах по кількох ворогах, кілька
+    hitbox'ів за кадр) не подовжують зупинку: наступна - лише після
+    REARM_DELAY від кінця попередньої
+  - duration = 0 - вимкнено
+
+🕐 ІСТОРІЯ:
+  2026-10-16: Створено - hitstop при влучанні
+
+===============================================================================
+*/
+
+use crate::settings::Settings;
+
+/// Максимальна тривалість зупинки (секунди) - довша виглядає як лаг
+const MAX_DURATION: f32 = 0.2;
+
+/// Пауза після зупинки, поки нові влучання її не запускають (секунди)
+const REARM_DELAY: f32 = 0.1;
+
+/// Зупинка світу при влучанні
+#[derive(Debug, Clone)]
+pub struct Hitstop {
+    /// Тривалість зупинки (секунди, 0 = вимкнено)
+    pub duration: f32,
+
+    /// Скільки ще триває зупинка (секунди ігрового часу)
+    remaining: f32,
+
+    /// Скільки ще влучання не запускають нову зупинку (секунди)
+    rearm_timer: f32,
+}
+
+impl Hitstop {
+    /// Читає секцію [combat] з налаштувань
+    ///
+    /// Ключ: `hitstop` (секунди, 0 = вимкнено)
+    pub fn from_settings(settings: &Settings) -> Self {
+        let default = Self::default();
+        Self {
+            duration: settings.get_f32("combat.hitstop", default.duration).clamp(0.0, MAX_DURATION),
+            ..default
+        }
+    }
+
+    /// Влучання: зупиняє світ на duration (якщо зупинка не щойно була)
+    pub fn trigger(&mut self) {
+        if self.duration <= 0.0 || self.rearm_timer > 0.0 {
+            return;
+        }
+        self.remaining = self.duration;
+        self.rearm_timer = self.duration + REARM_DELAY;
+    }
+
+    /// Раз на кадр: delta світу з реального delta кадру
+    ///
+    /// # Повертає
+    /// 0 - світ стоїть; кадр, в якому зупинка скінчилась, - лише залишок
+    pub fn update(&mut self, delta: f32) -> f32 {
+        self.rearm_timer = (self.rearm_timer - delta).max(0.0);
+        let frozen = self.remaining.min(delta);
+        self.remaining -= frozen;
+        delta - frozen
+    }
+
+    /// Скасовує зупинку (рестарт)
+    pub fn cancel(&mut self) {
+        self.remaining = 0.0;
+        self.rearm_timer = 0.0;
+    }
+}
+
+impl Default for Hitstop {
+    fn default() -> Self {
+        Self {
+            duration: 0.05,
+            remaining: 0.0,
+            rearm_timer: 0.0,
+        }
+    }
+}
@@ -17,24 +17,125 @@
   - Attack duration: час виконання атаки (анімація)
   - Cooldown: час між атаками
   - Attack можна виконати тільки в Ready стані
+  - Фази, cooldown, шкода та радіус - зі зброї (weapon.rs), важка атака
+    та кроки комбо їх масштабують; дуга замаху - з AttackKind::definition
+  - В блоці атакувати не можна; блок не піднімається під час удару (Action)
+    та Recovery, а замах (Anticipation), який можна скасувати, скасовується
+  - Клік поза Ready → буфер (queue_attack) на input_buffer секунд;
+    update() запускає атаку з буфера, щойно стан повертається в Ready
+  - Заряд важкої атаки: begin_charge → (меч тримається в замаху, заряд
+    росте до max_charge) → release_charge; рівень 0..1 масштабує шкоду
+    та радіус hitbox'а, заряджений удар починається одразу з Action
+  - Комбо легких атак: наступна легка атака в Recovery (або з буфера,
+    щойно настав Recovery) - наступний крок combo_steps зі своїми фазами
+    та множником шкоди. Recovery минув без продовження - combo_index 0
+  - Витривалість (stamina.rs): атака без вартості attack_cost /
+    heavy_attack_cost не починається; поза атакою - відновлення
 
 🕐 ІСТОРІЯ:
   2025-12-14: Створено - базова attack state machine
   2025-12-14: Додано hitbox система
+  2026-10-16: Додано aim assist (aim_assist.rs)
+  2026-10-16: Буфер атаки (queue_attack) - клік під час атаки/cooldown не губиться
+  2026-10-16: AttackKind - легка (ЛКМ) та важка (ПКМ) атаки з власними фазами
+  2026-10-16: AttackDirection з руху миші (swing.rs), поза меча - WeaponPose
+  2026-10-16: Блок та парирування (block.rs), resolve_incoming_hit
+  2026-10-16: Шкода залежить від швидкості вістря (tip_speed_damage_scale)
+  2026-10-16: Множник шкоди за кісткою ragdoll (BONE_DAMAGE_MULTIPLIERS)
+  2026-10-16: Комбо легких атак (combo_steps, combo_index)
+  2026-10-16: Заряд важкої атаки (begin_charge / release_charge)
+  2026-10-16: Витривалість (stamina.rs) - вартість атак та спринту
+  2026-10-16: Зброя (weapon.rs) - досяжність, шкода та темп ударів
+  2026-10-16: Hitstop (hitstop.rs) - зупинка світу при влучанні
 
 ===============================================================================
 */
 
 pub mod hitbox;
+pub mod aim_assist;
+pub mod swing;
+pub mod block;
+pub mod stamina;
+pub mod weapon;
+pub mod hitstop;
 
-pub use hitbox::{Hitbox, HitboxManager};
+pub use hitbox::{Hitbox, HitboxHit, HitboxManager, HitboxOwner};
+pub use aim_assist::AimAssist;
+pub use swing::{AttackDirection, SwingSampler, WeaponPose};
+pub use block::{Defense, HitOutcome};
+pub use stamina::Stamina;
+pub use weapon::Weapon;
+pub use hitstop::Hitstop;
 
 use glam::Vec3;
 
+use crate::physics::BoneId;
+use crate::settings::Settings;
+
+/// Швидкість вістря (м/с), нижче якої удар - легкий тичок
+const SLOW_TIP_SPEED: f32 = 2.0;
+
+/// Швидкість вістря (м/с) повного замаху
+const FULL_SWING_TIP_SPEED: f32 = 15.0;
+
+/// Множник шкоди тичка та повного замаху
+const MIN_TIP_DAMAGE_SCALE: f32 = 0.2;
+const MAX_TIP_DAMAGE_SCALE: f32 = 1.3;
+
+/// Множник шкоди від швидкості вістря меча
+///
+/// Лінійно від MIN_TIP_DAMAGE_SCALE (SLOW_TIP_SPEED і повільніше) до
+/// MAX_TIP_DAMAGE_SCALE (FULL_SWING_TIP_SPEED і швидше). Легка атака
+/// (50): тичок - 10, повний замах - 65.
+pub fn tip_speed_damage_scale(speed: f32) -> f32 {
+    let t = ((speed - SLOW_TIP_SPEED) / (FULL_SWING_TIP_SPEED - SLOW_TIP_SPEED)).clamp(0.0, 1.0);
+    MIN_TIP_DAMAGE_SCALE + (MAX_TIP_DAMAGE_SCALE - MIN_TIP_DAMAGE_SCALE) * t
+}
+
+/// Додаткова шкода важкої атаки при повному заряді (частка базової)
+const CHARGE_DAMAGE_BONUS: f32 = 1.0;
+
+/// Додатковий радіус hitbox'а важкої атаки при повному заряді (частка базового)
+const CHARGE_RADIUS_BONUS: f32 = 0.5;
+
+/// Масштаб фаз, cooldown, шкоди та радіусу важкої атаки відносно легкої
+const HEAVY_PHASE_SCALE: AttackPhases = AttackPhases {
+    anticipation: 3.0,
+    action: 4.0 / 3.0,
+    recovery: 3.0,
+};
+const HEAVY_COOLDOWN_SCALE: f32 = 8.0 / 3.0;
+const HEAVY_DAMAGE_SCALE: f32 = 2.0;
+const HEAVY_RADIUS_SCALE: f32 = 1.2;
+
+/// Множник шкоди за влученою кісткою ragdoll
+pub const BONE_DAMAGE_MULTIPLIERS: [(BoneId, f32); 11] = [
+    (BoneId::Head, 2.0),
+    (BoneId::Spine, 1.0),
+    (BoneId::Pelvis, 1.0),
+    (BoneId::LeftUpperArm, 0.6),
+    (BoneId::LeftLowerArm, 0.6),
+    (BoneId::RightUpperArm, 0.6),
+    (BoneId::RightLowerArm, 0.6),
+    (BoneId::LeftUpperLeg, 0.6),
+    (BoneId::LeftLowerLeg, 0.6),
+    (BoneId::RightUpperLeg, 0.6),
+    (BoneId::RightLowerLeg, 0.6),
+];
+
+/// Множник шкоди кістки з BONE_DAMAGE_MULTIPLIERS (1.0 якщо кістки немає в таблиці)
+pub fn bone_damage_multiplier(bone: BoneId) -> f32 {
+    BONE_DAMAGE_MULTIPLIERS
+        .iter()
+        .find(|(id, _)| *id == bone)
+        .map_or(1.0, |(_, multiplier)| *multiplier)
+}
+
 /// Стан атаки гравця
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum AttackState {
     /// Готовий атакувати
+    #[default]
     Ready,
     /// Виконує атаку (час в секундах що залишився)
     Attacking(f32),
@@ -54,8 +155,66 @@ pub enum AttackPhase {
     Recovery,
 }
 
-/// Тайминги фаз атаки
+/// Тип атаки
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AttackKind {
+    /// Легка - ЛКМ, швидка, можна скасувати в замаху
+    #[default]
+    Light,
+    /// Важка - утримання ЛКМ, повільна, більше шкоди, без скасування
+    Heavy,
+}
+
+impl AttackKind {
+    /// Параметри атаки цього типу зброєю `weapon`
+    ///
+    /// Легка - параметри зброї як є, важка - повільніша, сильніша і ширша
+    /// (HEAVY_*: мечем 300 / 200 / 300ms, 100 шкоди).
+    pub fn definition(self, weapon: &Weapon) -> AttackDefinition {
+        match self {
+            Self::Light => AttackDefinition {
+                phases: weapon.phases,
+                cooldown: weapon.cooldown,
+                damage: weapon.base_damage,
+                hitbox_radius: weapon.hitbox_radius,
+                swing_start: -0.8,    // -45° замах назад
+                swing_end: 1.6,       // +90° удар вперед
+                cancelable: true,
+            },
+            Self::Heavy => AttackDefinition {
+                phases: weapon.phases.scaled(&HEAVY_PHASE_SCALE),
+                cooldown: weapon.cooldown * HEAVY_COOLDOWN_SCALE,
+                damage: weapon.base_damage * HEAVY_DAMAGE_SCALE,
+                hitbox_radius: weapon.hitbox_radius * HEAVY_RADIUS_SCALE,
+                swing_start: -1.4,    // ~-80° далекий замах
+                swing_end: 2.0,       // ~+115° широкий удар
+                cancelable: false,
+            },
+        }
+    }
+}
+
+/// Параметри одного типу атаки
 #[derive(Debug, Clone, Copy)]
+pub struct AttackDefinition {
+    /// Тайминги фаз
+    pub phases: AttackPhases,
+    /// Cooldown після атаки (секунди)
+    pub cooldown: f32,
+    /// Шкода hitbox'а
+    pub damage: f32,
+    /// Радіус hitbox'а меча
+    pub hitbox_radius: f32,
+    /// Кут замаху назад (радіани, кінець Anticipation; дуга - AttackDirection::arc)
+    pub swing_start: f32,
+    /// Кут кінця удару (радіани, кінець Action)
+    pub swing_end: f32,
+    /// Чи можна скасувати в Anticipation
+    pub cancelable: bool,
+}
+
+/// Тайминги фаз атаки
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct AttackPhases {
     /// Час замаху (можна скасувати)
     pub anticipation: f32,
@@ -80,14 +239,57 @@ impl AttackPhases {
     pub fn total_duration(&self) -> f32 {
         self.anticipation + self.action + self.recovery
     }
-}
 
-impl Default for AttackState {
-    fn default() -> Self {
-        Self::Ready
+    /// Фази, помножені покомпонентно на `scale`
+    pub fn scaled(&self, scale: &AttackPhases) -> Self {
+        Self {
+            anticipation: self.anticipation * scale.anticipation,
+            action: self.action * scale.action,
+            recovery: self.recovery * scale.recovery,
+        }
     }
 }
 
+/// Крок комбо легких атак
+#[derive(Debug, Clone, Copy)]
+pub struct ComboStep {
+    /// Множники таймінгів фаз зброї для цього удару
+    pub phase_scale: AttackPhases,
+    /// Множник шкоди легкої атаки
+    pub damage_multiplier: f32,
+}
+
+/// Комбо з 3 ударів: два швидких та повільніший добиваючий
+/// (мечем 100/150/100, 80/150/120 та 150/200/250ms)
+fn default_combo_steps() -> Vec<ComboStep> {
+    vec![
+        ComboStep {
+            phase_scale: AttackPhases {
+                anticipation: 1.0,
+                action: 1.0,
+                recovery: 1.0,
+            },
+            damage_multiplier: 1.0,
+        },
+        ComboStep {
+            phase_scale: AttackPhases {
+                anticipation: 0.8,
+                action: 1.0,
+                recovery: 1.2,
+            },
+            damage_multiplier: 1.2,
+        },
+        ComboStep {
+            phase_scale: AttackPhases {
+                anticipation: 1.5,
+                action: 4.0 / 3.0,
+                recovery: 2.5,
+            },
+            damage_multiplier: 1.5,
+        },
+    ]
+}
+
 /// Combat компонент для entity
 ///
 /// Відстежує attack state, timing та напрямок атаки.
@@ -95,11 +297,11 @@ pub struct Combat {
     /// Поточний стан атаки
     pub state: AttackState,
 
-    /// Тайминги фаз атаки
-    pub phases: AttackPhases,
+    /// Тип поточної (останньої) атаки
+    pub kind: AttackKind,
 
-    /// Час cooldown між атаками (секунди)
-    pub attack_cooldown: f32,
+    /// Напрямок удару поточної (останньої) атаки
+    pub swing_direction: AttackDirection,
 
     /// Напрямок останньої атаки (normalized)
     pub attack_direction: Vec3,
@@ -108,60 +310,340 @@ pub struct Combat {
     /// Корисно для анімації
     pub attack_progress: f32,
 
-    /// Кут замаху зброї (радіани)
-    /// Swing: від -45° (замах назад) до +90° (удар вперед)
-    pub weapon_swing_angle: f32,
+    /// Поза меча (для renderer та hitbox)
+    /// Дуга залежить від swing_direction: горизонтальна, зверху або укол
+    pub weapon_pose: WeaponPose,
+
+    /// Скільки секунд пам'ятається атака, запитана поза Ready
+    pub input_buffer: f32,
+
+    /// true - атака з буфера йде туди, куди гравець дивиться в момент старту
+    /// (facing в update), false - в напрямку на момент кліку
+    pub resample_buffered_direction: bool,
+
+    /// Блок та парирування
+    pub defense: Defense,
+
+    /// Витривалість (вартість атак, спринт)
+    pub stamina: Stamina,
+
+    /// Зброя в руці (досяжність, шкода, темп) - змінювати через set_weapon
+    pub weapon: Weapon,
+
+    /// Ланцюжок комбо легких атак (крок 0 - звичайна легка атака)
+    pub combo_steps: Vec<ComboStep>,
+
+    /// Поточний крок комбо (індекс в combo_steps)
+    pub combo_index: usize,
+
+    /// Час заряду до повного (секунди)
+    pub max_charge: f32,
+
+    /// Рівень заряду поточної (останньої) важкої атаки (0..1)
+    pub charge_level: f32,
+
+    /// Заряд триває: скільки секунд утримується
+    charge: Option<f32>,
+
+    /// Рівень щойно відпущеного заряду - для наступного start_attack
+    released_charge: Option<f32>,
+
+    /// Атака в буфері: (тип, напрямок удару, напрямок на момент кліку, час що залишився)
+    buffered_attack: Option<(AttackKind, AttackDirection, Vec3, f32)>,
 }
 
 impl Combat {
     /// Створює новий Combat компонент
     pub fn new() -> Self {
-        let phases = AttackPhases::default();
         Self {
             state: AttackState::Ready,
-            phases,
-            attack_cooldown: 0.15,  // 150ms cooldown
+            kind: AttackKind::Light,
+            swing_direction: AttackDirection::default(),
             attack_direction: Vec3::NEG_Z,
             attack_progress: 0.0,
-            weapon_swing_angle: 0.0,
+            weapon_pose: WeaponPose::NEUTRAL,
+            input_buffer: 0.2,      // 200ms буфер
+            resample_buffered_direction: true,
+            defense: Defense::default(),
+            stamina: Stamina::default(),
+            weapon: Weapon::default(),
+            combo_steps: default_combo_steps(),
+            combo_index: 0,
+            max_charge: 1.0,
+            charge_level: 0.0,
+            charge: None,
+            released_charge: None,
+            buffered_attack: None,
+        }
+    }
+
+    /// Читає секцію [combat] з налаштувань
+    ///
+    /// Ключі: `input_buffer` (секунди), `resample_buffered_direction`,
+    /// `block_raise_time`, `block_damage_factor`, `parry_window` (секунди),
+    /// `block_angle` (градуси, половина конусу), `max_charge` (секунди),
+    /// `stamina_max`, `stamina_regen` (за секунду), `stamina_regen_delay`
+    /// (секунди), `light_attack_cost`, `heavy_attack_cost`, `sprint_drain`
+    /// (за секунду), `weapon` (dagger / sword / greatsword)
+    pub fn from_settings(settings: &Settings) -> Self {
+        let mut combat = Self::new();
+        combat.weapon = Weapon::from_settings(settings);
+        combat.input_buffer = settings.get_f32("combat.input_buffer", combat.input_buffer).max(0.0);
+        combat.max_charge = settings.get_f32("combat.max_charge", combat.max_charge).max(0.01);
+        combat.resample_buffered_direction = settings
+            .get_bool("combat.resample_buffered_direction", combat.resample_buffered_direction);
+
+        let defense = &mut combat.defense;
+        defense.raise_time = settings.get_f32("combat.block_raise_time", defense.raise_time).max(0.0);
+        defense.damage_factor = settings
+            .get_f32("combat.block_damage_factor", defense.damage_factor)
+            .clamp(0.0, 1.0);
+        defense.parry_window = settings.get_f32("combat.parry_window", defense.parry_window).max(0.0);
+        defense.cone_cos = settings
+            .get_f32("combat.block_angle", defense.cone_cos.acos().to_degrees())
+            .to_radians()
+            .cos();
+
+        let stamina = &mut combat.stamina;
+        stamina.max = settings.get_f32("combat.stamina_max", stamina.max).max(1.0);
+        stamina.current = stamina.max;
+        stamina.regen_rate = settings.get_f32("combat.stamina_regen", stamina.regen_rate).max(0.0);
+        stamina.regen_delay = settings
+            .get_f32("combat.stamina_regen_delay", stamina.regen_delay)
+            .max(0.0);
+        stamina.attack_cost = settings.get_f32("combat.light_attack_cost", stamina.attack_cost).max(0.0);
+        stamina.heavy_attack_cost = settings
+            .get_f32("combat.heavy_attack_cost", stamina.heavy_attack_cost)
+            .max(0.0);
+        stamina.sprint_drain = settings.get_f32("combat.sprint_drain", stamina.sprint_drain).max(0.0);
+        combat
+    }
+
+    /// Повертає в Ready без атаки та буфера (рестарт), налаштування лишаються
+    pub fn reset(&mut self) {
+        self.state = AttackState::Ready;
+        self.attack_progress = 0.0;
+        self.weapon_pose = WeaponPose::NEUTRAL;
+        self.defense.lower();
+        self.stamina.reset();
+        self.combo_index = 0;
+        self.charge_level = 0.0;
+        self.charge = None;
+        self.released_charge = None;
+        self.buffered_attack = None;
+    }
+
+    /// Змінює зброю
+    ///
+    /// Поточна атака, заряд та буфер скасовуються, комбо починається знову.
+    pub fn set_weapon(&mut self, weapon: Weapon) {
+        self.weapon = weapon;
+        self.state = AttackState::Ready;
+        self.attack_progress = 0.0;
+        self.weapon_pose = WeaponPose::NEUTRAL;
+        self.combo_index = 0;
+        self.charge = None;
+        self.released_charge = None;
+        self.buffered_attack = None;
+    }
+
+    /// Параметри поточної (останньої) атаки
+    ///
+    /// Базові - з типу атаки та зброї. Легка атака масштабує фази та
+    /// шкоду кроком комбо, важка - шкоду та радіус hitbox'а рівнем заряду.
+    pub fn definition(&self) -> AttackDefinition {
+        let mut definition = self.kind.definition(&self.weapon);
+        match self.kind {
+            AttackKind::Light => {
+                if let Some(step) = self.combo_steps.get(self.combo_index) {
+                    definition.phases = definition.phases.scaled(&step.phase_scale);
+                    definition.damage *= step.damage_multiplier;
+                }
+            }
+            AttackKind::Heavy => {
+                definition.damage *= 1.0 + CHARGE_DAMAGE_BONUS * self.charge_level;
+                definition.hitbox_radius *= 1.0 + CHARGE_RADIUS_BONUS * self.charge_level;
+            }
         }
+        definition
     }
 
     /// Загальна тривалість атаки
     pub fn attack_duration(&self) -> f32 {
-        self.phases.total_duration()
+        self.definition().phases.total_duration()
     }
 
     /// Перевіряє чи можна атакувати
     pub fn can_attack(&self) -> bool {
-        matches!(self.state, AttackState::Ready)
+        matches!(self.state, AttackState::Ready) && !self.defense.is_blocking() && self.charge.is_none()
+    }
+
+    /// Вартість атаки типу `kind` у витривалості
+    pub fn attack_cost(&self, kind: AttackKind) -> f32 {
+        match kind {
+            AttackKind::Light => self.stamina.attack_cost,
+            AttackKind::Heavy => self.stamina.heavy_attack_cost,
+        }
+    }
+
+    /// Чи заряджається важка атака
+    pub fn is_charging(&self) -> bool {
+        self.charge.is_some()
+    }
+
+    /// Починає заряд важкої атаки (меч іде в замах і тримається там)
+    ///
+    /// # Returns
+    /// `true` якщо заряд почався (лише коли можна атакувати і вистачає
+    /// витривалості на важку атаку)
+    pub fn begin_charge(&mut self, swing: AttackDirection) -> bool {
+        if !self.can_attack() || !self.stamina.can_spend(self.attack_cost(AttackKind::Heavy)) {
+            return false;
+        }
+        self.swing_direction = swing;
+        self.charge = Some(0.0);
+        self.buffered_attack = None;
+        true
+    }
+
+    /// Завершує заряд
+    ///
+    /// Рівень дістанеться наступній важкій атаці (start_attack / queue_attack).
+    ///
+    /// # Returns
+    /// Рівень заряду 0..1 (час утримання / max_charge), 0 якщо заряду не було
+    pub fn release_charge(&mut self) -> f32 {
+        let Some(held) = self.charge.take() else {
+            return 0.0;
+        };
+        let level = (held / self.max_charge).clamp(0.0, 1.0);
+        self.released_charge = Some(level);
+        level
+    }
+
+    /// Чи можна продовжити комбо атакою `kind` зараз
+    ///
+    /// Лише легка атака після легкої, у фазі Recovery, якщо в ланцюжку
+    /// є наступний крок.
+    pub fn can_chain(&self, kind: AttackKind) -> bool {
+        kind == AttackKind::Light
+            && self.kind == AttackKind::Light
+            && self.combo_index + 1 < self.combo_steps.len()
+            && matches!(self.get_phase(), Some(AttackPhase::Recovery))
+            && !self.defense.is_blocking()
+    }
+
+    /// Чи піднятий блок
+    pub fn is_blocking(&self) -> bool {
+        self.defense.is_blocking()
+    }
+
+    /// Утримання блоку (викликати кожен кадр зі станом кнопки)
+    ///
+    /// Блок не піднімається під час удару (Action) та Recovery; замах, який
+    /// можна скасувати, скасовується. Коли атака закінчиться, а кнопка
+    /// ще утримується - блок підніметься в наступному виклику.
+    pub fn set_blocking(&mut self, wanted: bool) {
+        if !wanted {
+            self.defense.lower();
+            return;
+        }
+        if self.defense.is_blocking() {
+            return;
+        }
+
+        if self.is_attacking() {
+            if !self.can_cancel() {
+                return;
+            }
+            self.state = AttackState::Ready;
+        }
+        self.charge = None;
+        self.buffered_attack = None;
+        self.defense.raise();
+    }
+
+    /// Результат удару по гравцю з урахуванням блоку
+    ///
+    /// # Аргументи
+    /// * `damage` - Шкода удару
+    /// * `attack_dir` - Напрямок руху удару (від атакуючого до гравця)
+    /// * `facing_dir` - Куди дивиться гравець
+    pub fn resolve_incoming_hit(&self, damage: f32, attack_dir: Vec3, facing_dir: Vec3) -> HitOutcome {
+        self.defense.resolve_hit(damage, attack_dir, facing_dir)
     }
 
     /// Починає атаку в заданому напрямку
     ///
+    /// Легка атака в Recovery легкої - наступний крок комбо (can_chain).
+    /// Важка атака одразу після release_charge - заряджена: рівень заряду
+    /// в charge_level, замах уже зроблено - старт з фази Action.
+    ///
+    /// Вартість атаки (attack_cost) списується з витривалості.
+    ///
     /// # Returns
-    /// `true` якщо атака почалася, `false` якщо не можна атакувати
-    pub fn start_attack(&mut self, direction: Vec3) -> bool {
-        if !self.can_attack() {
+    /// `true` якщо атака почалася, `false` якщо не можна атакувати або
+    /// не вистачає витривалості
+    pub fn start_attack(&mut self, direction: Vec3, kind: AttackKind, swing: AttackDirection) -> bool {
+        let chain = self.can_chain(kind);
+        if !chain && !self.can_attack() {
             return false;
         }
+        if !self.stamina.try_spend(self.attack_cost(kind)) {
+            return false;
+        }
+        self.combo_index = if chain { self.combo_index + 1 } else { 0 };
+
+        let charge = self.released_charge.take().filter(|_| kind == AttackKind::Heavy);
+        self.charge_level = charge.unwrap_or(0.0);
 
-        self.state = AttackState::Attacking(self.attack_duration());
+        self.kind = kind;
+        self.swing_direction = swing;
+        self.state = AttackState::Attacking(match charge {
+            Some(_) => self.attack_duration() - self.definition().phases.anticipation,
+            None => self.attack_duration(),
+        });
         self.attack_direction = direction.normalize_or_zero();
         self.attack_progress = 0.0;
 
         true
     }
 
+    /// Запам'ятовує атаку, яку зараз не можна почати
+    ///
+    /// Новий клік замінює попередній і знову відкриває вікно буфера.
+    /// Якщо атакувати можна вже зараз - просто start_attack. Атака іншого
+    /// типу в замаху, який можна скасувати, скасовує його (фінт).
+    ///
+    /// # Returns
+    /// `true` якщо атака почалася одразу
+    pub fn queue_attack(&mut self, direction: Vec3, kind: AttackKind, swing: AttackDirection) -> bool {
+        if kind != self.kind && self.can_cancel() {
+            self.state = AttackState::Ready;
+        }
+        if self.start_attack(direction, kind, swing) {
+            return true;
+        }
+        if self.input_buffer > 0.0 {
+            self.buffered_attack = Some((kind, swing, direction, self.input_buffer));
+        }
+        false
+    }
+
+    /// Чи є атака в буфері
+    pub fn has_buffered_attack(&self) -> bool {
+        self.buffered_attack.is_some()
+    }
+
     /// Повертає поточну фазу атаки (Anticipation/Action/Recovery)
     pub fn get_phase(&self) -> Option<AttackPhase> {
         if let AttackState::Attacking(remaining) = self.state {
-            let total = self.attack_duration();
-            let elapsed = total - remaining;
+            let phases = self.definition().phases;
+            let elapsed = phases.total_duration() - remaining;
 
-            if elapsed < self.phases.anticipation {
+            if elapsed < phases.anticipation {
                 Some(AttackPhase::Anticipation)
-            } else if elapsed < self.phases.anticipation + self.phases.action {
+            } else if elapsed < phases.anticipation + phases.action {
                 Some(AttackPhase::Action)
             } else {
                 Some(AttackPhase::Recovery)
@@ -176,27 +658,73 @@ impl Combat {
         matches!(self.get_phase(), Some(AttackPhase::Action))
     }
 
-    /// Перевіряє чи можна скасувати атаку (тільки в Anticipation)
+    /// Перевіряє чи можна скасувати атаку (тільки в Anticipation, не Heavy)
     pub fn can_cancel(&self) -> bool {
-        matches!(self.get_phase(), Some(AttackPhase::Anticipation))
+        self.definition().cancelable && matches!(self.get_phase(), Some(AttackPhase::Anticipation))
     }
 
     /// Оновлює combat state
     ///
+    /// Поза атакою та заряджанням відновлює витривалість.
+    ///
     /// # Аргументи
     /// * `delta` - Delta time в секундах
-    pub fn update(&mut self, delta: f32) {
-        // Swing animation constants
-        let swing_start = -0.8_f32;  // -45° замах назад
-        let swing_end = 1.6_f32;     // +90° удар вперед
-        let swing_range = swing_end - swing_start;
+    /// * `facing` - Куди гравець дивиться зараз (для атаки з буфера)
+    ///
+    /// # Returns
+    /// `true` якщо в цьому кадрі почалася атака з буфера
+    pub fn update(&mut self, delta: f32, facing: Vec3) -> bool {
+        self.update_state(delta);
+        self.update_charge(delta);
+        self.defense.update(delta);
+        self.weapon_pose = self.defense.weapon_pose(self.weapon_pose);
+        if !self.is_attacking() && !self.is_charging() {
+            self.stamina.regenerate(delta);
+        }
+
+        // Буфер: спершу старт (клік ще "свіжий"), потім відлік
+        let Some((kind, swing, direction, remaining)) = self.buffered_attack else {
+            return false;
+        };
+        if self.can_attack() || self.can_chain(kind) {
+            self.buffered_attack = None;
+            let direction = if self.resample_buffered_direction { facing } else { direction };
+            return self.start_attack(direction, kind, swing);
+        }
+
+        let remaining = remaining - delta;
+        self.buffered_attack = (remaining > 0.0).then_some((kind, swing, direction, remaining));
+        false
+    }
+
+    /// Заряд: час утримання (до max_charge), меч іде в замах важкої атаки
+    fn update_charge(&mut self, delta: f32) {
+        let Some(held) = self.charge else {
+            return;
+        };
+        let held = (held + delta).min(self.max_charge);
+        self.charge = Some(held);
+
+        // Замах за час Anticipation важкої атаки, далі тримається
+        let heavy = AttackKind::Heavy.definition(&self.weapon);
+        let (swing_start, _) = self.swing_direction.arc(&heavy);
+        let wind_up = (held / heavy.phases.anticipation).min(1.0);
+        self.weapon_pose = WeaponPose::NEUTRAL.lerp(swing_start, wind_up);
+    }
 
-        let total_duration = self.attack_duration();
+    /// Крок state machine (Ready → Attacking → Cooldown → Ready)
+    fn update_state(&mut self, delta: f32) {
+        // Swing animation - дуга з типу атаки та напрямку удару
+        let definition = self.definition();
+        let phases = definition.phases;
+        let (swing_start, swing_end) = self.swing_direction.arc(&definition);
+
+        let total_duration = phases.total_duration();
 
         match self.state {
             AttackState::Ready => {
                 // Повертаємо меч в нейтральну позицію
-                self.weapon_swing_angle = 0.0;
+                self.weapon_pose = WeaponPose::NEUTRAL;
             }
             AttackState::Attacking(remaining) => {
                 let new_remaining = remaining - delta;
@@ -204,33 +732,34 @@ impl Combat {
                 // Оновлюємо прогрес (0→1)
                 self.attack_progress = 1.0 - (new_remaining / total_duration).max(0.0);
 
-                // Swing angle залежить від фази
+                // Поза меча залежить від фази
                 match self.get_phase() {
                     Some(AttackPhase::Anticipation) => {
-                        // Замах: від 0 до swing_start
-                        let phase_progress = self.attack_progress / (self.phases.anticipation / total_duration);
-                        self.weapon_swing_angle = swing_start * phase_progress.min(1.0);
+                        // Замах: від нейтральної до swing_start
+                        let phase_progress = self.attack_progress / (phases.anticipation / total_duration);
+                        self.weapon_pose = WeaponPose::NEUTRAL.lerp(swing_start, phase_progress.min(1.0));
                     }
                     Some(AttackPhase::Action) => {
                         // Удар: від swing_start до swing_end (ease-out)
-                        let phase_start = self.phases.anticipation / total_duration;
-                        let phase_end = (self.phases.anticipation + self.phases.action) / total_duration;
+                        let phase_start = phases.anticipation / total_duration;
+                        let phase_end = (phases.anticipation + phases.action) / total_duration;
                         let phase_progress = (self.attack_progress - phase_start) / (phase_end - phase_start);
                         let eased = phase_progress * (2.0 - phase_progress);  // ease-out
-                        self.weapon_swing_angle = swing_start + eased * swing_range;
+                        self.weapon_pose = swing_start.lerp(swing_end, eased);
                     }
                     Some(AttackPhase::Recovery) => {
                         // Залишаємо в кінцевій позиції
-                        self.weapon_swing_angle = swing_end;
+                        self.weapon_pose = swing_end;
                     }
                     None => {}
                 }
 
                 if new_remaining <= 0.0 {
-                    // Атака завершена → cooldown
-                    self.state = AttackState::Cooldown(self.attack_cooldown);
+                    // Атака завершена → cooldown, комбо обривається
+                    self.state = AttackState::Cooldown(definition.cooldown);
+                    self.combo_index = 0;
                     self.attack_progress = 1.0;
-                    self.weapon_swing_angle = swing_end;
+                    self.weapon_pose = swing_end;
                 } else {
                     self.state = AttackState::Attacking(new_remaining);
                 }
@@ -239,14 +768,14 @@ impl Combat {
                 let new_remaining = remaining - delta;
 
                 // Повертаємо меч назад (easing)
-                let cooldown_progress = 1.0 - (new_remaining / self.attack_cooldown).max(0.0);
-                self.weapon_swing_angle = swing_end * (1.0 - cooldown_progress);
+                let cooldown_progress = 1.0 - (new_remaining / definition.cooldown).max(0.0);
+                self.weapon_pose = swing_end.lerp(WeaponPose::NEUTRAL, cooldown_progress);
 
                 if new_remaining <= 0.0 {
                     // Cooldown завершено → ready
                     self.state = AttackState::Ready;
                     self.attack_progress = 0.0;
-                    self.weapon_swing_angle = 0.0;
+                    self.weapon_pose = WeaponPose::NEUTRAL;
                 } else {
                     self.state = AttackState::Cooldown(new_remaining);
                 }
@@ -270,3 +799,310 @@ impl Default for Combat {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Крок симуляції в тестах (секунди)
+    const DT: f32 = 1.0 / 120.0;
+
+    /// `seconds` кадрів update з поглядом `facing`
+    fn run(combat: &mut Combat, seconds: f32, facing: Vec3) -> bool {
+        let mut started = false;
+        for _ in 0..(seconds / DT).round() as usize {
+            started |= combat.update(DT, facing);
+        }
+        started
+    }
+
+    /// Атака до кінця, стан - Cooldown
+    fn finish_attack(combat: &mut Combat) {
+        while combat.is_attacking() {
+            combat.update(DT, Vec3::NEG_Z);
+        }
+    }
+
+    #[test]
+    fn buffered_attack_starts_when_cooldown_ends() {
+        let mut combat = Combat::new();
+        assert!(combat.queue_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default()));
+        finish_attack(&mut combat);
+        assert!(combat.is_cooldown());
+
+        // Клік у cooldown - в буфер
+        assert!(!combat.queue_attack(Vec3::X, AttackKind::Light, AttackDirection::default()));
+        assert!(combat.has_buffered_attack());
+        let cooldown = combat.weapon.cooldown;
+        assert!(run(&mut combat, cooldown + DT, Vec3::Z), "атака з буфера не почалась");
+        assert!(combat.is_attacking());
+        assert!(!combat.has_buffered_attack());
+        // Напрямок - погляд на момент старту (resample_buffered_direction)
+        assert_eq!(combat.attack_direction, Vec3::Z);
+    }
+
+    #[test]
+    fn buffered_attack_keeps_click_direction_without_resample() {
+        let mut combat = Combat::new();
+        combat.resample_buffered_direction = false;
+        combat.queue_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default());
+        finish_attack(&mut combat);
+        combat.queue_attack(Vec3::X, AttackKind::Light, AttackDirection::default());
+        let cooldown = combat.weapon.cooldown;
+        assert!(run(&mut combat, cooldown + DT, Vec3::Z));
+        assert_eq!(combat.attack_direction, Vec3::X);
+    }
+
+    #[test]
+    fn buffered_attack_expires() {
+        let mut combat = Combat::new();
+        combat.input_buffer = 0.05;
+        combat.weapon.cooldown = 0.5;
+        combat.queue_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default());
+        finish_attack(&mut combat);
+
+        combat.queue_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default());
+        run(&mut combat, 0.1, Vec3::NEG_Z);
+        assert!(!combat.has_buffered_attack(), "старий клік досі в буфері");
+        // Cooldown скінчився - а атаки немає
+        assert!(!run(&mut combat, 0.5, Vec3::NEG_Z));
+        assert_eq!(combat.state, AttackState::Ready);
+    }
+
+    #[test]
+    fn no_buffer_drops_click() {
+        let mut combat = Combat::new();
+        combat.input_buffer = 0.0;
+        combat.queue_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default());
+        finish_attack(&mut combat);
+        assert!(!combat.queue_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default()));
+        assert!(!combat.has_buffered_attack());
+    }
+
+    /// Фаза атаки через `elapsed` секунд від старту (одним кроком update)
+    fn phase_after(kind: AttackKind, elapsed: f32) -> Option<AttackPhase> {
+        let mut combat = Combat::new();
+        assert!(combat.start_attack(Vec3::NEG_Z, kind, AttackDirection::default()));
+        combat.update(elapsed, Vec3::NEG_Z);
+        combat.get_phase()
+    }
+
+    #[test]
+    fn phase_boundaries_per_kind() {
+        let weapon = Weapon::default();
+        for kind in [AttackKind::Light, AttackKind::Heavy] {
+            let phases = kind.definition(&weapon).phases;
+            let action_start = phases.anticipation;
+            let recovery_start = phases.anticipation + phases.action;
+            let eps = 0.005;
+
+            assert_eq!(phase_after(kind, action_start - eps), Some(AttackPhase::Anticipation), "{:?}", kind);
+            assert_eq!(phase_after(kind, action_start + eps), Some(AttackPhase::Action), "{:?}", kind);
+            assert_eq!(phase_after(kind, recovery_start - eps), Some(AttackPhase::Action), "{:?}", kind);
+            assert_eq!(phase_after(kind, recovery_start + eps), Some(AttackPhase::Recovery), "{:?}", kind);
+            assert_eq!(phase_after(kind, phases.total_duration() + eps), None, "{:?}", kind);
+        }
+    }
+
+    #[test]
+    fn heavy_is_slower_and_stronger() {
+        let weapon = Weapon::default();
+        let (light, heavy) = (AttackKind::Light.definition(&weapon), AttackKind::Heavy.definition(&weapon));
+        assert!(heavy.phases.anticipation > light.phases.anticipation);
+        assert!(heavy.phases.total_duration() > light.phases.total_duration());
+        assert!(heavy.cooldown > light.cooldown);
+        assert!(heavy.damage > light.damage);
+        assert!(heavy.hitbox_radius > light.hitbox_radius);
+    }
+
+    #[test]
+    fn heavy_cannot_be_canceled_in_anticipation() {
+        let mut combat = Combat::new();
+        combat.start_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default());
+        assert!(combat.can_cancel());
+        // Блок скасовує легкий замах
+        combat.set_blocking(true);
+        assert!(!combat.is_attacking());
+        assert!(combat.is_blocking());
+
+        let mut combat = Combat::new();
+        combat.start_attack(Vec3::NEG_Z, AttackKind::Heavy, AttackDirection::default());
+        assert_eq!(combat.get_phase(), Some(AttackPhase::Anticipation));
+        assert!(!combat.can_cancel());
+        combat.set_blocking(true);
+        assert!(combat.is_attacking());
+        assert!(!combat.is_blocking());
+        // Легка атака теж не переб'є важкий замах (фінт - лише з легкої)
+        assert!(!combat.queue_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default()));
+        assert_eq!(combat.kind, AttackKind::Heavy);
+    }
+
+    /// Легка атака зброєю за замовчуванням, доведена до фази `phase`
+    fn light_attack_in(phase: AttackPhase) -> Combat {
+        let mut combat = Combat::new();
+        combat.start_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default());
+        while combat.get_phase() != Some(phase) {
+            combat.update(DT, Vec3::NEG_Z);
+        }
+        combat
+    }
+
+    #[test]
+    fn timely_press_advances_combo() {
+        let mut combat = light_attack_in(AttackPhase::Recovery);
+        assert!(combat.start_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default()));
+        assert_eq!(combat.combo_index, 1);
+        assert_eq!(combat.get_phase(), Some(AttackPhase::Anticipation));
+        // Другий удар сильніший за перший
+        let first = AttackKind::Light.definition(&combat.weapon).damage;
+        assert!(combat.definition().damage > first);
+
+        // Третій - останній крок ланцюжка
+        while combat.get_phase() != Some(AttackPhase::Recovery) {
+            combat.update(DT, Vec3::NEG_Z);
+        }
+        assert!(combat.start_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default()));
+        assert_eq!(combat.combo_index, 2);
+        while combat.get_phase() != Some(AttackPhase::Recovery) {
+            combat.update(DT, Vec3::NEG_Z);
+        }
+        assert!(!combat.can_chain(AttackKind::Light));
+    }
+
+    #[test]
+    fn late_press_starts_fresh() {
+        let mut combat = light_attack_in(AttackPhase::Recovery);
+        combat.start_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default());
+        assert_eq!(combat.combo_index, 1);
+
+        // Recovery минув без продовження - комбо обривається
+        finish_attack(&mut combat);
+        assert_eq!(combat.combo_index, 0);
+        let cooldown = combat.definition().cooldown;
+        run(&mut combat, cooldown + DT, Vec3::NEG_Z);
+        assert!(combat.start_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default()));
+        assert_eq!(combat.combo_index, 0);
+    }
+
+    #[test]
+    fn combo_blocked_outside_recovery() {
+        let combat = light_attack_in(AttackPhase::Anticipation);
+        assert!(!combat.can_attack());
+        assert!(!combat.can_chain(AttackKind::Light));
+        let combat = light_attack_in(AttackPhase::Action);
+        assert!(!combat.can_chain(AttackKind::Light));
+        // Важка атака не продовжує комбо легких
+        let combat = light_attack_in(AttackPhase::Recovery);
+        assert!(!combat.can_chain(AttackKind::Heavy));
+    }
+
+    #[test]
+    fn buffered_press_chains_on_recovery() {
+        let mut combat = light_attack_in(AttackPhase::Action);
+        assert!(!combat.queue_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default()));
+        assert!(combat.has_buffered_attack());
+        // Буфер спрацьовує, щойно настає Recovery
+        let mut started = false;
+        while !started && combat.is_attacking() {
+            started = combat.update(DT, Vec3::NEG_Z);
+        }
+        assert!(started);
+        assert_eq!(combat.combo_index, 1);
+    }
+
+    /// Заряд `held` секунд, відпускання та важка атака; повертає рівень заряду
+    fn charged_heavy(combat: &mut Combat, held: f32) -> f32 {
+        assert!(combat.begin_charge(AttackDirection::default()));
+        run(combat, held, Vec3::NEG_Z);
+        let level = combat.release_charge();
+        assert!(combat.start_attack(Vec3::NEG_Z, AttackKind::Heavy, AttackDirection::default()));
+        level
+    }
+
+    #[test]
+    fn charge_level_follows_hold_time() {
+        let mut combat = Combat::new();
+        let max_charge = combat.max_charge;
+        assert!((charged_heavy(&mut combat, 0.0) - 0.0).abs() < 1e-3);
+
+        let mut combat = Combat::new();
+        let half = charged_heavy(&mut combat, max_charge * 0.5);
+        assert!((half - 0.5).abs() < 0.02, "половина заряду: {}", half);
+
+        // Довше за max_charge - не більше 1
+        let mut combat = Combat::new();
+        assert_eq!(charged_heavy(&mut combat, max_charge * 2.0), 1.0);
+
+        // Без begin_charge відпускати нічого
+        let mut combat = Combat::new();
+        assert_eq!(combat.release_charge(), 0.0);
+    }
+
+    #[test]
+    fn charge_scales_damage_and_radius() {
+        let base = AttackKind::Heavy.definition(&Weapon::default());
+
+        let mut combat = Combat::new();
+        charged_heavy(&mut combat, 0.0);
+        assert!((combat.definition().damage - base.damage).abs() < 1e-3);
+        assert!((combat.definition().hitbox_radius - base.hitbox_radius).abs() < 1e-3);
+
+        let mut combat = Combat::new();
+        let max_charge = combat.max_charge;
+        charged_heavy(&mut combat, max_charge);
+        let full = combat.definition();
+        assert!((full.damage - base.damage * (1.0 + CHARGE_DAMAGE_BONUS)).abs() < 1e-3);
+        assert!((full.hitbox_radius - base.hitbox_radius * (1.0 + CHARGE_RADIUS_BONUS)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn charged_strike_skips_anticipation() {
+        let mut combat = Combat::new();
+        charged_heavy(&mut combat, 0.2);
+        assert_eq!(combat.get_phase(), Some(AttackPhase::Action));
+
+        // Заряд дістається лише важкій атаці
+        let mut combat = Combat::new();
+        combat.begin_charge(AttackDirection::default());
+        run(&mut combat, 0.2, Vec3::NEG_Z);
+        combat.release_charge();
+        assert!(combat.start_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default()));
+        assert_eq!(combat.charge_level, 0.0);
+        assert_eq!(combat.get_phase(), Some(AttackPhase::Anticipation));
+    }
+
+    #[test]
+    fn charging_holds_wind_up_pose() {
+        let mut combat = Combat::new();
+        assert!(combat.begin_charge(AttackDirection::default()));
+        assert!(!combat.can_attack());
+        let anticipation = AttackKind::Heavy.definition(&combat.weapon).phases.anticipation;
+        run(&mut combat, anticipation + 0.1, Vec3::NEG_Z);
+        let wind_up = combat.weapon_pose;
+        assert_ne!(wind_up, WeaponPose::NEUTRAL);
+        run(&mut combat, 0.3, Vec3::NEG_Z);
+        assert_eq!(combat.weapon_pose, wind_up, "меч зрушив із замаху під час заряду");
+    }
+
+    #[test]
+    fn tip_speed_scales_light_sword_damage() {
+        let base = AttackKind::Light.definition(&Weapon::sword()).damage;
+        let damage = |speed: f32| base * tip_speed_damage_scale(speed);
+
+        // Тичок (і нерухомий меч) - 10, повний замах і швидше - 60+
+        for slow in [0.0, 1.0, SLOW_TIP_SPEED] {
+            assert!((damage(slow) - 10.0).abs() < 1e-3, "тичок {} м/с: {}", slow, damage(slow));
+        }
+        for fast in [FULL_SWING_TIP_SPEED, 25.0, 1000.0] {
+            assert!(damage(fast) >= 60.0, "замах {} м/с: {}", fast, damage(fast));
+            assert_eq!(tip_speed_damage_scale(fast), MAX_TIP_DAMAGE_SCALE);
+        }
+
+        // Між ними - монотонно
+        let mut last = damage(SLOW_TIP_SPEED);
+        for speed in (3..15).map(|v| v as f32) {
+            assert!(damage(speed) > last, "шкода не росте на {} м/с", speed);
+            last = damage(speed);
+        }
+    }
+}
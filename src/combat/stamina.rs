@@ -0,0 +1,197 @@
+/*
+===============================================================================
+ ФАЙЛ: src/combat/stamina.rs
+===============================================================================
+
+📋 ПРИЗНАЧЕННЯ:
+  Витривалість - ресурс для атак та спринту.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+  - current / max витривалості
+  - Вартість легкої / важкої атаки, витрата спринту за секунду
+  - Відновлення regen_rate за секунду після паузи regen_delay
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+  - Атака без достатньої витривалості не починається (Combat::start_attack)
+  - Будь-яка витрата (атака, спринт) відкладає відновлення на regen_delay
+  - Відновлення не йде під час атаки - вирішує Combat::update
+  - fraction() - для UI смуги
+
+🕐 ІСТОРІЯ:
+  2026-10-16: Створено - витривалість для атак та спринту
+
+===============================================================================
+*/
+
+/// Витривалість
+#[derive(Debug, Clone, Copy)]
+pub struct Stamina {
+    /// Поточна витривалість
+    pub current: f32,
+
+    /// Максимальна витривалість
+    pub max: f32,
+
+    /// Відновлення (одиниць за секунду)
+    pub regen_rate: f32,
+
+    /// Пауза перед відновленням після витрати (секунди)
+    pub regen_delay: f32,
+
+    /// Вартість легкої атаки
+    pub attack_cost: f32,
+
+    /// Вартість важкої атаки
+    pub heavy_attack_cost: f32,
+
+    /// Витрата спринту (одиниць за секунду)
+    pub sprint_drain: f32,
+
+    /// Скільки ще чекати до відновлення (секунди)
+    regen_timer: f32,
+}
+
+impl Stamina {
+    /// Створює повну витривалість
+    pub fn new(max: f32) -> Self {
+        Self {
+            current: max,
+            max,
+            regen_rate: 25.0,
+            regen_delay: 0.5,
+            attack_cost: 15.0,
+            heavy_attack_cost: 30.0,
+            sprint_drain: 20.0,
+            regen_timer: 0.0,
+        }
+    }
+
+    /// Частка витривалості (0..1)
+    pub fn fraction(&self) -> f32 {
+        if self.max <= 0.0 {
+            return 0.0;
+        }
+        (self.current / self.max).clamp(0.0, 1.0)
+    }
+
+    /// Чи вистачає на витрату `cost`
+    pub fn can_spend(&self, cost: f32) -> bool {
+        self.current >= cost
+    }
+
+    /// Витрачає `cost`, якщо вистачає
+    ///
+    /// # Повертає
+    /// `true` якщо витрачено
+    pub fn try_spend(&mut self, cost: f32) -> bool {
+        if !self.can_spend(cost) {
+            return false;
+        }
+        self.current -= cost;
+        self.regen_timer = self.regen_delay;
+        true
+    }
+
+    /// Витрата спринту за кадр
+    ///
+    /// # Повертає
+    /// `true` якщо витривалість ще є (спринт можливий)
+    pub fn drain_sprint(&mut self, delta: f32) -> bool {
+        if self.current <= 0.0 {
+            return false;
+        }
+        self.current = (self.current - self.sprint_drain * delta).max(0.0);
+        self.regen_timer = self.regen_delay;
+        true
+    }
+
+    /// Відновлення (після паузи regen_delay)
+    pub fn regenerate(&mut self, delta: f32) {
+        if self.regen_timer > 0.0 {
+            self.regen_timer = (self.regen_timer - delta).max(0.0);
+            return;
+        }
+        self.current = (self.current + self.regen_rate * delta).min(self.max);
+    }
+
+    /// Відновлює повну витривалість (рестарт)
+    pub fn reset(&mut self) {
+        self.current = self.max;
+        self.regen_timer = 0.0;
+    }
+}
+
+impl Default for Stamina {
+    fn default() -> Self {
+        Self::new(100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat::{AttackDirection, AttackKind, Combat};
+    use glam::Vec3;
+
+    #[test]
+    fn attack_refused_at_low_stamina() {
+        let mut combat = Combat::new();
+        combat.stamina.current = combat.stamina.attack_cost - 1.0;
+        assert!(!combat.start_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default()));
+        assert!(!combat.is_attacking());
+        assert_eq!(combat.stamina.current, combat.stamina.attack_cost - 1.0, "відмова не має витрачати");
+
+        // На легку вистачає, на важку - ні
+        combat.stamina.current = combat.stamina.attack_cost;
+        assert!(!combat.start_attack(Vec3::NEG_Z, AttackKind::Heavy, AttackDirection::default()));
+        assert!(combat.start_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default()));
+        assert_eq!(combat.stamina.current, 0.0);
+    }
+
+    #[test]
+    fn regen_waits_for_delay_then_refills() {
+        let mut stamina = Stamina::default();
+        assert!(stamina.try_spend(50.0));
+        assert_eq!(stamina.fraction(), 0.5);
+
+        // Пауза regen_delay - без відновлення
+        stamina.regenerate(stamina.regen_delay * 0.5);
+        assert_eq!(stamina.current, 50.0);
+        stamina.regenerate(stamina.regen_delay);
+
+        let dt = 1.0 / 60.0;
+        for _ in 0..60 {
+            stamina.regenerate(dt);
+        }
+        assert!((stamina.current - (50.0 + stamina.regen_rate)).abs() < 0.5, "за секунду +regen_rate: {}", stamina.current);
+        for _ in 0..600 {
+            stamina.regenerate(dt);
+        }
+        assert_eq!(stamina.current, stamina.max);
+    }
+
+    #[test]
+    fn no_regen_while_attacking() {
+        let mut combat = Combat::new();
+        assert!(combat.start_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default()));
+        let after_cost = combat.stamina.current;
+        while combat.is_attacking() {
+            combat.update(1.0 / 120.0, Vec3::NEG_Z);
+        }
+        assert_eq!(combat.stamina.current, after_cost);
+    }
+
+    #[test]
+    fn sprint_drains_until_empty() {
+        let mut stamina = Stamina::default();
+        assert!(stamina.drain_sprint(1.0));
+        assert_eq!(stamina.current, stamina.max - stamina.sprint_drain);
+        // Спринт відкладає відновлення
+        stamina.regenerate(stamina.regen_delay * 0.5);
+        assert_eq!(stamina.current, stamina.max - stamina.sprint_drain);
+
+        while stamina.drain_sprint(0.5) {}
+        assert_eq!(stamina.current, 0.0);
+        assert!(!stamina.drain_sprint(0.5));
+    }
+}
@@ -0,0 +1,170 @@
+/*
+===============================================================================
+ ФАЙЛ: src/combat/swing.rs
+===============================================================================
+
+📋 ПРИЗНАЧЕННЯ:
+  Напрямок удару з руху миші (як в Hellish Quart).
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+  - AttackDirection: зліва направо, справа наліво, зверху, укол
+  - SwingSampler: raw mouse delta за останні SAMPLE_WINDOW секунд
+  - WeaponPose: поза меча (yaw, pitch, висування) - для renderer та hitbox
+  - Дуга замаху кожного напрямку (з кутів AttackDefinition)
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+  - Класифікується рух миші ДО кліку (вікно SAMPLE_WINDOW) - атака
+    стартує одразу, без затримки на "збір" руху
+  - Рух менше MIN_SWING_DELTA - напрямок за замовчуванням (справа наліво,
+    як один замах до появи напрямків)
+  - Миша вниз (delta.y > 0) = удар зверху, вгору = укол
+  - Кути: yaw - поворот навколо Y (+ = вліво), pitch - навколо осі руки
+    (+ = меч вгору), thrust - висування вздовж меча (м)
+
+🕐 ІСТОРІЯ:
+  2026-10-16: Створено - напрямок атаки з руху миші
+
+===============================================================================
+*/
+
+use std::collections::VecDeque;
+
+use glam::{Quat, Vec2};
+
+use super::AttackDefinition;
+
+/// Вікно руху миші перед кліком (секунди)
+const SAMPLE_WINDOW: f32 = 0.12;
+
+/// Мінімальний рух миші за вікно для напрямку (raw одиниці)
+const MIN_SWING_DELTA: f32 = 12.0;
+
+/// Удар зверху: меч піднятий / опущений відносно горизонтальної дуги
+const OVERHEAD_END_PITCH_RATIO: f32 = 0.5;
+
+/// Укол: відведення назад та висування вперед (м)
+const THRUST_PULLBACK: f32 = 0.3;
+const THRUST_EXTENSION: f32 = 0.5;
+
+/// Укол: невеликий підйом вістря в замаху (радіани)
+const THRUST_PITCH: f32 = 0.2;
+
+/// Напрямок удару
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AttackDirection {
+    /// Горизонтальний зліва направо
+    LeftToRight,
+    /// Горизонтальний справа наліво
+    #[default]
+    RightToLeft,
+    /// Зверху вниз
+    Overhead,
+    /// Укол вперед
+    Thrust,
+}
+
+impl AttackDirection {
+    /// Напрямок з руху миші (None - рух замалий)
+    pub fn from_mouse_delta(delta: Vec2) -> Option<Self> {
+        if delta.length() < MIN_SWING_DELTA {
+            return None;
+        }
+
+        Some(if delta.x.abs() >= delta.y.abs() {
+            if delta.x > 0.0 { Self::LeftToRight } else { Self::RightToLeft }
+        } else if delta.y > 0.0 {
+            Self::Overhead
+        } else {
+            Self::Thrust
+        })
+    }
+
+    /// Дуга замаху: (поза в кінці Anticipation, поза в кінці Action)
+    ///
+    /// Амплітуди - з swing_start / swing_end типу атаки
+    pub fn arc(self, definition: &AttackDefinition) -> (WeaponPose, WeaponPose) {
+        let start = definition.swing_start;
+        let end = definition.swing_end;
+        match self {
+            Self::RightToLeft => (WeaponPose::yaw(start), WeaponPose::yaw(end)),
+            Self::LeftToRight => (WeaponPose::yaw(-start), WeaponPose::yaw(-end)),
+            Self::Overhead => (
+                WeaponPose { pitch: -start, ..WeaponPose::NEUTRAL },
+                WeaponPose { pitch: -end * OVERHEAD_END_PITCH_RATIO, ..WeaponPose::NEUTRAL },
+            ),
+            Self::Thrust => (
+                WeaponPose { pitch: THRUST_PITCH, thrust: -THRUST_PULLBACK, ..WeaponPose::NEUTRAL },
+                WeaponPose { thrust: THRUST_EXTENSION, ..WeaponPose::NEUTRAL },
+            ),
+        }
+    }
+}
+
+/// Поза меча відносно нейтральної (меч вперед від правого плеча)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct WeaponPose {
+    /// Поворот навколо Y (радіани, + = вліво)
+    pub yaw: f32,
+    /// Нахил навколо осі руки (радіани, + = вістря вгору)
+    pub pitch: f32,
+    /// Висування вздовж меча (м)
+    pub thrust: f32,
+}
+
+impl WeaponPose {
+    /// Нейтральна поза
+    pub const NEUTRAL: Self = Self { yaw: 0.0, pitch: 0.0, thrust: 0.0 };
+
+    /// Тільки поворот навколо Y
+    pub fn yaw(yaw: f32) -> Self {
+        Self { yaw, ..Self::NEUTRAL }
+    }
+
+    /// Ротація меча в world space
+    ///
+    /// `player_yaw` - yaw гравця (базовий поворот, як у renderer)
+    pub fn rotation(&self, player_yaw: f32) -> Quat {
+        Quat::from_rotation_y(player_yaw + self.yaw) * Quat::from_rotation_x(self.pitch)
+    }
+
+    /// Лінійна інтерполяція
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        Self {
+            yaw: self.yaw + (other.yaw - self.yaw) * t,
+            pitch: self.pitch + (other.pitch - self.pitch) * t,
+            thrust: self.thrust + (other.thrust - self.thrust) * t,
+        }
+    }
+}
+
+/// Рух миші за останні SAMPLE_WINDOW секунд
+#[derive(Debug, Default)]
+pub struct SwingSampler {
+    /// (delta кадру, вік секунди)
+    samples: VecDeque<(Vec2, f32)>,
+}
+
+impl SwingSampler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Додає raw mouse delta кадру та забуває старіші за вікно
+    pub fn push(&mut self, delta: Vec2, frame_time: f32) {
+        for (_, age) in &mut self.samples {
+            *age += frame_time;
+        }
+        while self.samples.front().is_some_and(|(_, age)| *age > SAMPLE_WINDOW) {
+            self.samples.pop_front();
+        }
+        self.samples.push_back((delta, 0.0));
+    }
+
+    /// Напрямок удару з руху за вікно
+    ///
+    /// `pending` - delta поточного кадру, ще не додана через push
+    pub fn direction(&self, pending: Vec2) -> AttackDirection {
+        let total = self.samples.iter().map(|(delta, _)| *delta).sum::<Vec2>() + pending;
+        AttackDirection::from_mouse_delta(total).unwrap_or_default()
+    }
+}
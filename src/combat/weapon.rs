@@ -0,0 +1,190 @@
+/*
+===============================================================================
+ ФАЙЛ: src/combat/weapon.rs
+===============================================================================
+
+📋 ПРИЗНАЧЕННЯ:
+  Зброя гравця - довжина леза, шкода та темп ударів.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+  - Weapon: reach, base_damage, phases, cooldown, hitbox_radius
+  - Пресети: dagger / sword / greatsword
+  - from_name / from_settings (combat.weapon)
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+  - Параметри - легкої атаки; важка та кроки комбо масштабують їх
+    (AttackKind::definition, ComboStep::phase_scale)
+  - reach - довжина леза: hitbox меча (weapon_blade_segment) та mesh
+    руки з мечем (generate_weapon_arm)
+  - sword - значення, що були захардкоджені до появи зброї
+
+🕐 ІСТОРІЯ:
+  2026-10-16: Створено - кинджал, меч, дворучний меч
+
+===============================================================================
+*/
+
+use super::AttackPhases;
+use crate::settings::Settings;
+
+/// Зброя в руці гравця
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Weapon {
+    /// Назва (для логів та налаштувань)
+    pub name: &'static str,
+
+    /// Довжина леза від кінця руки (м)
+    pub reach: f32,
+
+    /// Шкода легкої атаки
+    pub base_damage: f32,
+
+    /// Тайминги фаз легкої атаки
+    pub phases: AttackPhases,
+
+    /// Cooldown після легкої атаки (секунди)
+    pub cooldown: f32,
+
+    /// Радіус hitbox'а леза
+    pub hitbox_radius: f32,
+}
+
+impl Weapon {
+    /// Кинджал - швидкий, короткий, слабкий
+    pub fn dagger() -> Self {
+        Self {
+            name: "dagger",
+            reach: 0.5,
+            base_damage: 30.0,
+            phases: AttackPhases {
+                anticipation: 0.06,
+                action: 0.1,
+                recovery: 0.06,
+            },
+            cooldown: 0.08,
+            hitbox_radius: 0.35,
+        }
+    }
+
+    /// Меч - зброя за замовчуванням
+    pub fn sword() -> Self {
+        Self {
+            name: "sword",
+            reach: 1.0,
+            base_damage: 50.0,
+            phases: AttackPhases::default(),
+            cooldown: 0.15,
+            hitbox_radius: 0.5,
+        }
+    }
+
+    /// Дворучний меч - повільний, довгий, важкий
+    pub fn greatsword() -> Self {
+        Self {
+            name: "greatsword",
+            reach: 1.6,
+            base_damage: 85.0,
+            phases: AttackPhases {
+                anticipation: 0.2,
+                action: 0.22,
+                recovery: 0.2,
+            },
+            cooldown: 0.3,
+            hitbox_radius: 0.7,
+        }
+    }
+
+    /// Парсить назву пресету ("dagger" / "sword" / "greatsword")
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "dagger" => Some(Self::dagger()),
+            "sword" => Some(Self::sword()),
+            "greatsword" => Some(Self::greatsword()),
+            _ => None,
+        }
+    }
+
+    /// Читає `combat.weapon` (невідома назва - меч з попередженням)
+    pub fn from_settings(settings: &Settings) -> Self {
+        match settings.get_str("combat.weapon") {
+            Some(name) => Self::from_name(name).unwrap_or_else(|| {
+                log::warn!("settings: невідома зброя {:?}, використовуємо sword", name);
+                Self::sword()
+            }),
+            None => Self::default(),
+        }
+    }
+}
+
+impl Default for Weapon {
+    fn default() -> Self {
+        Self::sword()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat::hitbox::HitboxShape;
+    use crate::combat::{AttackDirection, AttackKind, Combat, HitboxManager};
+    use glam::Vec3;
+
+    /// Combat зі зброєю `weapon`
+    fn armed(weapon: Weapon) -> Combat {
+        let mut combat = Combat::new();
+        combat.set_weapon(weapon);
+        combat
+    }
+
+    /// Найдальша від гравця точка леза посередині удару
+    fn swing_reach(weapon: Weapon) -> f32 {
+        let mut combat = armed(weapon);
+        combat.start_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default());
+        let phases = combat.definition().phases;
+        combat.update(phases.anticipation + phases.action * 0.5, Vec3::NEG_Z);
+        let mut manager = HitboxManager::new();
+        manager.update_swing_hitbox(&combat, Vec3::ZERO, 0.0, 1.0, 0.0);
+        let swing = manager.iter_mut().next().expect("hitbox меча");
+        match swing.shape {
+            HitboxShape::Capsule { start, end, .. } => start.length().max(end.length()),
+            shape => panic!("меч - не капсула: {:?}", shape),
+        }
+    }
+
+    #[test]
+    fn greatsword_slower_than_dagger() {
+        for kind in [AttackKind::Light, AttackKind::Heavy] {
+            let dagger = kind.definition(&Weapon::dagger());
+            let greatsword = kind.definition(&Weapon::greatsword());
+            assert!(greatsword.phases.total_duration() > dagger.phases.total_duration(), "{:?}", kind);
+            assert!(greatsword.damage > dagger.damage, "{:?}", kind);
+        }
+        assert!(armed(Weapon::greatsword()).attack_duration() > armed(Weapon::dagger()).attack_duration());
+        assert!(Weapon::greatsword().cooldown > Weapon::dagger().cooldown);
+    }
+
+    #[test]
+    fn greatsword_reaches_farther_than_dagger() {
+        let dagger = swing_reach(Weapon::dagger());
+        let sword = swing_reach(Weapon::sword());
+        let greatsword = swing_reach(Weapon::greatsword());
+        assert!(dagger < sword && sword < greatsword, "{} / {} / {}", dagger, sword, greatsword);
+        assert!(Weapon::greatsword().hitbox_radius > Weapon::dagger().hitbox_radius);
+    }
+
+    #[test]
+    fn set_weapon_resets_attack() {
+        let mut combat = Combat::new();
+        combat.start_attack(Vec3::NEG_Z, AttackKind::Light, AttackDirection::default());
+        combat.set_weapon(Weapon::greatsword());
+        assert!(combat.can_attack());
+        assert_eq!(combat.weapon, Weapon::greatsword());
+    }
+
+    #[test]
+    fn from_name_parses_presets() {
+        assert_eq!(Weapon::from_name(" Dagger "), Some(Weapon::dagger()));
+        assert_eq!(Weapon::from_name("greatsword"), Some(Weapon::greatsword()));
+        assert_eq!(Weapon::from_name("axe"), None);
+    }
+}
@@ -9,34 +9,183 @@
 🎯 ВІДПОВІДАЛЬНІСТЬ:
   - Enemy struct (position, health, state)
   - Enemy spawning
-  - (Майбутнє) Enemy AI, pathfinding
-  - (Майбутнє) Enemy attacks
+  - AI: переслідування гравця (Chasing), замах (Attacking), удар та
+    cooldown (Cooldown) - try_attack
+  - Розштовхування ворогів (apply_separation) - не злипаються в одну точку
+  - EnemyConfig - параметри AI з settings.ini ([enemies])
+  - Опційний ActiveRagdoll ворога (йде за AI, падає при смерті)
+  - (Майбутнє) pathfinding
 
 ⚠️  ВАЖЛИВІ ДЕТАЛІ:
-  - Enemies статичні поки що (без AI)
+  - AI без pathfinding: прямо до гравця по XZ (стіни арени - тільки для ragdoll)
+  - Атака ворога - EnemyAttack → Hitbox (owner = Enemy) перед ворогом
+  - Замах триває attack_windup - телеграф для гравця (windup_progress →
+    червоний tint у renderer); вихід гравця з attack_range або stagger
+    скасовує замах
   - Health: 0 = мертвий
   - Position в world space (Y-up)
+  - Мертвий ворог зникає (fade-out) за death_fade_duration, потім видаляється
+  - Knockback: удар штовхає ворога (knockback_velocity, затухає з damping),
+    поки триває stagger - ворог не атакує і не переслідує
+  - Ragdoll не керує AI: position лишається за AI, ragdoll йде за нею
+    (follow); перед видаленням ворога - detach_ragdoll (тіла з фізики)
 
 🕐 ІСТОРІЯ:
   2025-12-14: Створено - базовий Enemy struct
+  2026-10-16: Death timer - fade-out та видалення мертвих ворогів
+  2026-10-16: Knockback та stagger від ударів
+  2026-10-16: Плавний поворот (target_yaw + turn_speed) замість миттєвого
+  2026-10-16: AI - переслідування, атака з cooldown, розштовхування
+  2026-10-16: Атака ворога через HitboxManager (HitboxOwner::Enemy)
+  2026-10-16: ActiveRagdoll ворога замість манекена ([enemies] ragdoll)
+  2026-10-16: Замах перед ударом (attack_windup) - Attacking → Cooldown
+  2026-10-16: Спалах при отриманні шкоди (hit_flash)
 
 ===============================================================================
 */
 
 use glam::Vec3;
 
+use crate::combat::{Hitbox, HitboxOwner};
+use crate::physics::{ActiveRagdoll, PhysicsWorld, RagdollStiffness};
+use crate::settings::Settings;
+use crate::transform::rotate_towards;
+
+/// Половина кута конусу атаки ворога (cos) - гравець має бути попереду
+const ATTACK_CONE_COS: f32 = 0.5;
+
+/// Висота pelvis ragdoll над позицією ворога при створенні (трохи вище стійки)
+const RAGDOLL_SPAWN_HEIGHT: f32 = 1.1;
+
+/// Тривалість спалаху після отримання шкоди (секунди)
+const HIT_FLASH_DURATION: f32 = 0.1;
+
 /// Стан ворога
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum EnemyState {
-    /// Живий, активний
+    /// Живий, стоїть на місці (до першого update з гравцем)
+    #[default]
     Alive,
+    /// Йде до гравця
+    Chasing,
+    /// Замах - через attack_windup удар (телеграф для гравця)
+    Attacking,
+    /// Гравець в attack_range - чекає cooldown після удару
+    Cooldown,
     /// Мертвий (для cleanup або respawn)
     Dead,
 }
 
-impl Default for EnemyState {
+/// Висота центру hitbox атаки ворога (груди)
+const ATTACK_HITBOX_HEIGHT: f32 = 1.0;
+
+/// Час життя hitbox атаки ворога (секунди)
+const ATTACK_HITBOX_LIFETIME: f32 = 0.15;
+
+/// Атака ворога (удар перед ворогом)
+#[derive(Debug, Clone, Copy)]
+pub struct EnemyAttack {
+    /// Звідки б'є ворог
+    pub origin: Vec3,
+
+    /// Напрямок удару (нормалізований, XZ)
+    pub direction: Vec3,
+
+    /// Дальність удару
+    pub reach: f32,
+
+    /// Шкода
+    pub damage: f32,
+}
+
+impl EnemyAttack {
+    /// Hitbox удару: сфера на половині reach перед ворогом
+    ///
+    /// Радіус = половина reach - сфера покриває від ворога до кінця удару.
+    ///
+    /// # Аргументи
+    /// * `enemy_index` - Індекс ворога в `enemies` (власник hitbox)
+    pub fn hitbox(&self, enemy_index: usize) -> Hitbox {
+        let center = self.origin + self.direction * (self.reach * 0.5) + Vec3::Y * ATTACK_HITBOX_HEIGHT;
+        Hitbox::new(center, self.reach * 0.5, ATTACK_HITBOX_LIFETIME, self.damage)
+            .with_owner(HitboxOwner::Enemy(enemy_index))
+    }
+}
+
+/// Параметри AI ворогів
+#[derive(Debug, Clone, Copy)]
+pub struct EnemyConfig {
+    /// Кількість ворогів при старті (0 = без ворогів)
+    pub count: usize,
+
+    /// Радіус кола спавну навколо центру арени
+    pub spawn_radius: f32,
+
+    /// Швидкість руху (units/second)
+    pub move_speed: f32,
+
+    /// Дистанція атаки (ворог зупиняється на ній)
+    pub attack_range: f32,
+
+    /// Пауза між атаками (секунди)
+    pub attack_cooldown: f32,
+
+    /// Тривалість замаху перед ударом (секунди)
+    pub attack_windup: f32,
+
+    /// Шкода однієї атаки
+    pub attack_damage: f32,
+
+    /// Радіус ворога для розштовхування
+    pub radius: f32,
+
+    /// Вороги з ActiveRagdoll (false - манекени)
+    pub ragdoll: bool,
+}
+
+impl EnemyConfig {
+    /// Читає секцію [enemies] з налаштувань
+    ///
+    /// Ключі: `count`, `spawn_radius`, `move_speed`, `attack_range`,
+    /// `attack_cooldown`, `attack_windup`, `attack_damage`, `radius`, `ragdoll`
+    pub fn from_settings(settings: &Settings) -> Self {
+        let default = Self::default();
+        Self {
+            count: settings.get_u32("enemies.count", default.count as u32) as usize,
+            spawn_radius: settings.get_f32("enemies.spawn_radius", default.spawn_radius),
+            move_speed: settings.get_f32("enemies.move_speed", default.move_speed).max(0.0),
+            attack_range: settings.get_f32("enemies.attack_range", default.attack_range).max(0.1),
+            attack_cooldown: settings.get_f32("enemies.attack_cooldown", default.attack_cooldown).max(0.0),
+            attack_windup: settings.get_f32("enemies.attack_windup", default.attack_windup).max(0.0),
+            attack_damage: settings.get_f32("enemies.attack_damage", default.attack_damage),
+            radius: settings.get_f32("enemies.radius", default.radius).max(0.0),
+            ragdoll: settings.get_bool("enemies.ragdoll", default.ragdoll),
+        }
+    }
+
+    /// Застосовує параметри AI до ворога
+    pub fn apply(&self, enemy: &mut Enemy) {
+        enemy.move_speed = self.move_speed;
+        enemy.attack_range = self.attack_range;
+        enemy.attack_cooldown = self.attack_cooldown;
+        enemy.attack_windup = self.attack_windup;
+        enemy.attack_damage = self.attack_damage;
+    }
+}
+
+impl Default for EnemyConfig {
     fn default() -> Self {
-        Self::Alive
+        Self {
+            count: 0,
+            spawn_radius: 6.0,
+            move_speed: 2.5,
+            attack_range: 1.5,
+            attack_cooldown: 1.5,
+            attack_windup: 0.4,
+            attack_damage: 10.0,
+            radius: 0.5,
+            ragdoll: true,
+        }
     }
 }
 
@@ -48,6 +197,12 @@ pub struct Enemy {
     /// Кут повороту навколо Y (радіани)
     pub yaw: f32,
 
+    /// Цільовий кут (ворог плавно повертається до нього в update)
+    pub target_yaw: f32,
+
+    /// Швидкість повороту (radians/second)
+    pub turn_speed: f32,
+
     /// Поточне здоров'я
     pub health: f32,
 
@@ -56,6 +211,51 @@ pub struct Enemy {
 
     /// Стан ворога
     pub state: EnemyState,
+
+    /// Час з моменту смерті (секунди)
+    pub death_timer: f32,
+
+    /// Тривалість fade-out після смерті (секунди)
+    pub death_fade_duration: f32,
+
+    /// Швидкість відштовхування від удару (затухає в update)
+    pub knockback_velocity: Vec3,
+
+    /// Коефіцієнт затухання knockback (1/секунду, експоненційне)
+    pub knockback_damping: f32,
+
+    /// Час stagger що залишився (секунди)
+    pub stagger_timer: f32,
+
+    /// Скільки ще триває спалах від удару (секунди)
+    pub hit_flash_timer: f32,
+
+    /// Тривалість stagger після удару (секунди)
+    pub stagger_duration: f32,
+
+    /// Швидкість руху до гравця (units/second)
+    pub move_speed: f32,
+
+    /// Дистанція атаки (ворог зупиняється на ній)
+    pub attack_range: f32,
+
+    /// Пауза між атаками (секунди)
+    pub attack_cooldown: f32,
+
+    /// Час до наступної можливої атаки (секунди)
+    pub attack_timer: f32,
+
+    /// Тривалість замаху перед ударом (секунди)
+    pub attack_windup: f32,
+
+    /// Скільки ще триває замах (секунди, Attacking)
+    pub windup_timer: f32,
+
+    /// Шкода однієї атаки
+    pub attack_damage: f32,
+
+    /// Фізичне тіло (None - манекен)
+    pub ragdoll: Option<ActiveRagdoll>,
 }
 
 impl Enemy {
@@ -64,29 +264,41 @@ impl Enemy {
         Self {
             position,
             yaw: 0.0,
+            target_yaw: 0.0,
+            turn_speed: 6.0,
             health: 100.0,
             max_health: 100.0,
             state: EnemyState::Alive,
+            death_timer: 0.0,
+            death_fade_duration: 3.0,
+            knockback_velocity: Vec3::ZERO,
+            knockback_damping: 8.0,
+            stagger_timer: 0.0,
+            hit_flash_timer: 0.0,
+            stagger_duration: 0.35,
+            move_speed: 2.5,
+            attack_range: 1.5,
+            attack_cooldown: 1.5,
+            attack_timer: 0.0,
+            attack_windup: 0.4,
+            windup_timer: 0.0,
+            attack_damage: 10.0,
+            ragdoll: None,
         }
     }
 
     /// Створює ворога з поворотом до цілі
     pub fn new_facing(position: Vec3, look_at: Vec3) -> Self {
-        let dir = look_at - position;
-        let yaw = dir.x.atan2(-dir.z);
-
-        Self {
-            position,
-            yaw,
-            health: 100.0,
-            max_health: 100.0,
-            state: EnemyState::Alive,
-        }
+        let mut enemy = Self::new(position);
+        enemy.face_towards(look_at);
+        // При спавні - одразу дивимось на ціль (без повороту)
+        enemy.yaw = enemy.target_yaw;
+        enemy
     }
 
     /// Чи живий ворог
     pub fn is_alive(&self) -> bool {
-        self.state == EnemyState::Alive && self.health > 0.0
+        self.state != EnemyState::Dead && self.health > 0.0
     }
 
     /// Завдає шкоди ворогу
@@ -97,17 +309,262 @@ impl Enemy {
 
         self.health = (self.health - damage).max(0.0);
 
+        // Кілька ударів підряд перезапускають спалах, а не подовжують
+        self.hit_flash_timer = HIT_FLASH_DURATION;
+
         if self.health <= 0.0 {
             self.state = EnemyState::Dead;
+            if let Some(ragdoll) = &mut self.ragdoll {
+                ragdoll.go_ragdoll();
+            }
+        }
+    }
+
+    /// Створює ragdoll ворога (стоїть на позиції ворога, м'язи активні)
+    pub fn attach_ragdoll(&mut self, physics: &mut PhysicsWorld, stiffness: RagdollStiffness) {
+        if let Some(old) = self.ragdoll.take() {
+            old.despawn(physics);
+        }
+
+        let mut ragdoll = ActiveRagdoll::new(physics, self.position + Vec3::Y * RAGDOLL_SPAWN_HEIGHT, stiffness);
+        ragdoll.log_bones = false;
+        ragdoll.target_yaw = self.yaw;
+        ragdoll.move_speed = self.move_speed;
+        ragdoll.activate();
+        self.ragdoll = Some(ragdoll);
+    }
+
+    /// Прибирає ragdoll з фізичного світу (перед видаленням ворога)
+    pub fn detach_ragdoll(&mut self, physics: &mut PhysicsWorld) {
+        if let Some(ragdoll) = self.ragdoll.take() {
+            ragdoll.despawn(physics);
+        }
+    }
+
+    /// Веде ragdoll за AI (кожен fixed step, до кроку фізики)
+    pub fn sync_ragdoll(&mut self, delta: f32) {
+        if !self.is_alive() {
+            return;
+        }
+        if let Some(ragdoll) = &mut self.ragdoll {
+            ragdoll.follow(self.position, self.yaw, delta);
         }
     }
 
+    /// Відштовхує ворога від удару
+    ///
+    /// # Аргументи
+    /// * `direction` - Напрямок удару (від атакуючого до ворога), Y ігнорується
+    /// * `speed` - Початкова швидкість відштовхування (units/second)
+    pub fn apply_knockback(&mut self, direction: Vec3, speed: f32) {
+        let direction = Vec3::new(direction.x, 0.0, direction.z).normalize_or_zero();
+        self.knockback_velocity += direction * speed;
+        self.stagger_timer = self.stagger_duration;
+    }
+
+    /// Встановлює цільовий напрямок погляду на точку (поворот - плавно в update)
+    pub fn face_towards(&mut self, point: Vec3) {
+        let dir = point - self.position;
+        if dir.x.abs() > 1e-4 || dir.z.abs() > 1e-4 {
+            // forward() = (-sin(yaw), 0, -cos(yaw)) → yaw = atan2(-x, -z)
+            self.target_yaw = (-dir.x).atan2(-dir.z);
+        }
+    }
+
+    /// Приголомшує ворога на `duration` секунд (парирування)
+    ///
+    /// Довший поточний stagger не скорочується
+    pub fn stagger(&mut self, duration: f32) {
+        self.stagger_timer = self.stagger_timer.max(duration);
+    }
+
+    /// Чи ворог приголомшений ударом (не переслідує і не атакує)
+    pub fn is_staggered(&self) -> bool {
+        self.stagger_timer > 0.0
+    }
+
+    /// Оновлює ворога (AI, knockback, stagger, death timer)
+    ///
+    /// Рух від knockback додається до позиції ПІСЛЯ власного руху ворога -
+    /// ворог відлітає назад, а коли швидкість затухне, продовжує як раніше.
+    ///
+    /// # Аргументи
+    /// * `player_pos` - Позиція гравця (ціль переслідування)
+    /// * `delta` - Delta time в секундах
+    ///
+    /// # Повертає
+    /// Атаку, якщо ворог вдарив цього кадру
+    pub fn update(&mut self, player_pos: Vec3, delta: f32) -> Option<EnemyAttack> {
+        self.attack_timer = (self.attack_timer - delta).max(0.0);
+        if self.state == EnemyState::Attacking {
+            self.windup_timer = (self.windup_timer - delta).max(0.0);
+        }
+
+        // AI - тільки живі та не приголомшені (stagger перериває замах)
+        let attack = if self.is_alive() && !self.is_staggered() {
+            self.update_ai(player_pos, delta)
+        } else {
+            if self.state == EnemyState::Attacking {
+                self.state = EnemyState::Cooldown;
+            }
+            None
+        };
+
+        // Knockback з експоненційним затуханням (не залежить від FPS)
+        if self.knockback_velocity.length_squared() > 1e-6 {
+            self.position += self.knockback_velocity * delta;
+            self.knockback_velocity *= (-self.knockback_damping * delta).exp();
+        } else {
+            self.knockback_velocity = Vec3::ZERO;
+        }
+
+        self.stagger_timer = (self.stagger_timer - delta).max(0.0);
+        self.hit_flash_timer = (self.hit_flash_timer - delta).max(0.0);
+
+        // Плавний поворот до target_yaw (мертві не повертаються)
+        if self.is_alive() {
+            self.yaw = rotate_towards(self.yaw, self.target_yaw, self.turn_speed * delta);
+        }
+
+        if self.state == EnemyState::Dead {
+            self.death_timer += delta;
+        }
+
+        attack
+    }
+
+    /// Переслідування та атака гравця
+    ///
+    /// Тільки крок AI - таймери, stagger та knockback рахує update, тому
+    /// ззовні викликається update, а не цей метод.
+    fn update_ai(&mut self, player_pos: Vec3, delta: f32) -> Option<EnemyAttack> {
+        let to_player = Vec3::new(player_pos.x - self.position.x, 0.0, player_pos.z - self.position.z);
+        let distance = to_player.length();
+        self.face_towards(player_pos);
+
+        if distance > self.attack_range {
+            // Chasing - йдемо до гравця, але не заходимо всередину attack_range
+            // (гравець вийшов з attack_range - замах скасовано)
+            self.state = EnemyState::Chasing;
+            let step = (self.move_speed * delta).min(distance - self.attack_range);
+            self.position += to_player / distance * step;
+            return None;
+        }
+
+        self.try_attack(player_pos)
+    }
+
+    /// Бій в attack_range: cooldown → замах → удар → cooldown
+    ///
+    /// Замах починається, коли cooldown минув і ворог повернувся до цілі;
+    /// через attack_windup (таймер - в update) - удар.
+    ///
+    /// # Повертає
+    /// Атаку, якщо замах завершився цього кадру
+    pub fn try_attack(&mut self, target: Vec3) -> Option<EnemyAttack> {
+        if self.state != EnemyState::Attacking {
+            // Замахуємось тільки коли вже повернулись до цілі (плавний поворот)
+            let to_target = Vec3::new(target.x - self.position.x, 0.0, target.z - self.position.z);
+            if self.attack_timer > 0.0 || self.forward().dot(to_target.normalize_or_zero()) < ATTACK_CONE_COS {
+                self.state = EnemyState::Cooldown;
+                return None;
+            }
+            self.state = EnemyState::Attacking;
+            self.windup_timer = self.attack_windup;
+        }
+
+        if self.windup_timer > 0.0 {
+            return None;
+        }
+
+        self.state = EnemyState::Cooldown;
+        self.attack_timer = self.attack_cooldown;
+        Some(EnemyAttack {
+            origin: self.position,
+            direction: self.forward(),
+            reach: self.attack_range,
+            damage: self.attack_damage,
+        })
+    }
+
+    /// Прогрес замаху 0..1 (0 - не замахується)
+    pub fn windup_progress(&self) -> f32 {
+        if self.state != EnemyState::Attacking || self.attack_windup <= 0.0 {
+            return 0.0;
+        }
+        (1.0 - self.windup_timer / self.attack_windup).clamp(0.0, 1.0)
+    }
+
+    /// Сила спалаху від удару 0..1 (1 - щойно влучили)
+    pub fn hit_flash(&self) -> f32 {
+        (self.hit_flash_timer / HIT_FLASH_DURATION).clamp(0.0, 1.0)
+    }
+
+    /// Прозорість для рендерингу (1.0 = живий, 0.0 = повністю зник)
+    pub fn fade_alpha(&self) -> f32 {
+        if self.state != EnemyState::Dead {
+            return 1.0;
+        }
+        if self.death_fade_duration <= 0.0 {
+            return 0.0;
+        }
+        (1.0 - self.death_timer / self.death_fade_duration).clamp(0.0, 1.0)
+    }
+
+    /// Чи можна видалити ворога (fade-out завершено)
+    pub fn should_remove(&self) -> bool {
+        self.state == EnemyState::Dead && self.death_timer >= self.death_fade_duration
+    }
+
     /// Напрямок куди дивиться ворог
     pub fn forward(&self) -> Vec3 {
         Vec3::new(-self.yaw.sin(), 0.0, -self.yaw.cos())
     }
 }
 
+/// Розштовхує живих ворогів, що перекриваються
+///
+/// Кожна пара ближче 2 × radius розсувається вздовж лінії між центрами
+/// (по XZ). O(n²) - для десятків ворогів достатньо.
+///
+/// # Аргументи
+/// * `enemies` - Всі вороги
+/// * `radius` - Радіус ворога
+/// * `delta` - Delta time (корекція плавна, не за один кадр)
+pub fn apply_separation(enemies: &mut [Enemy], radius: f32, delta: f32) {
+    let min_distance = radius * 2.0;
+    // Частка перекриття, що прибирається за кадр
+    let strength = (10.0 * delta).min(1.0);
+
+    for i in 0..enemies.len() {
+        for j in (i + 1)..enemies.len() {
+            if !enemies[i].is_alive() || !enemies[j].is_alive() {
+                continue;
+            }
+
+            let mut offset = enemies[j].position - enemies[i].position;
+            offset.y = 0.0;
+            let distance = offset.length();
+            if distance >= min_distance {
+                continue;
+            }
+
+            // Точно в одній точці - розводимо по X
+            let direction = if distance > 1e-4 { offset / distance } else { Vec3::X };
+            let push = direction * (min_distance - distance) * 0.5 * strength;
+            enemies[i].position -= push;
+            enemies[j].position += push;
+        }
+    }
+}
+
+/// Створює ragdoll всім ворогам (див. Enemy::attach_ragdoll)
+pub fn attach_ragdolls(enemies: &mut [Enemy], physics: &mut PhysicsWorld, stiffness: RagdollStiffness) {
+    for enemy in enemies {
+        enemy.attach_ragdoll(physics, stiffness);
+    }
+}
+
 /// Спавнить ворогів по колу навколо центру
 pub fn spawn_enemies_circle(center: Vec3, radius: f32, count: usize) -> Vec<Enemy> {
     let mut enemies = Vec::with_capacity(count);
@@ -124,3 +581,104 @@ pub fn spawn_enemies_circle(center: Vec3, radius: f32, count: usize) -> Vec<Enem
 
     enemies
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Крок симуляції в тестах (секунди)
+    const DT: f32 = 1.0 / 120.0;
+
+    /// Гравець в attack_range перед ворогом
+    const PLAYER: Vec3 = Vec3::new(0.0, 0.0, -1.0);
+
+    /// Кадрів update до першої атаки (None - не вдарив за `max_seconds`)
+    fn frames_until_attack(enemy: &mut Enemy, player: Vec3, max_seconds: f32) -> Option<usize> {
+        (1..=(max_seconds / DT) as usize).find(|_| enemy.update(player, DT).is_some())
+    }
+
+    #[test]
+    fn chases_and_halts_at_attack_range() {
+        let target = Vec3::new(3.0, 0.0, -8.0);
+        let mut enemy = Enemy::new(Vec3::ZERO);
+        let distance = |enemy: &Enemy| (target - enemy.position).length();
+
+        let mut last = distance(&enemy);
+        for _ in 0..(5.0 / DT) as usize {
+            enemy.update(target, DT);
+            let now = distance(&enemy);
+            assert!(now <= last + 1e-5, "ворог віддаляється: {} → {}", last, now);
+            assert!(now >= enemy.attack_range - 1e-4, "зайшов всередину attack_range: {}", now);
+            last = now;
+        }
+
+        // ~8.5м зі швидкістю 2.5 м/с - дійшов і стоїть на межі attack_range
+        assert!((last - enemy.attack_range).abs() < 1e-3, "зупинився на {} замість {}", last, enemy.attack_range);
+        assert_ne!(enemy.state, EnemyState::Chasing);
+        assert!(enemy.forward().dot((target - enemy.position).normalize()) > 0.99, "не дивиться на ціль");
+    }
+
+    #[test]
+    fn windup_then_fire_then_cooldown() {
+        let mut enemy = Enemy::new_facing(Vec3::ZERO, PLAYER);
+        assert!(enemy.update(PLAYER, DT).is_none());
+        assert_eq!(enemy.state, EnemyState::Attacking, "замах не почався");
+
+        // Удар - через attack_windup
+        let frames = frames_until_attack(&mut enemy, PLAYER, 1.0).expect("ворог не вдарив");
+        let windup = frames as f32 * DT;
+        assert!((windup - enemy.attack_windup).abs() <= 2.0 * DT, "замах {} замість {}", windup, enemy.attack_windup);
+        assert_eq!(enemy.state, EnemyState::Cooldown);
+        assert_eq!(enemy.windup_progress(), 0.0);
+
+        // Наступний удар - cooldown + замах (замах стартує кадром пізніше)
+        let frames = frames_until_attack(&mut enemy, PLAYER, 3.0).expect("другого удару немає");
+        let interval = frames as f32 * DT;
+        let expected = enemy.attack_cooldown + enemy.attack_windup;
+        assert!((interval - expected).abs() <= 3.0 * DT, "інтервал {} замість {}", interval, expected);
+    }
+
+    #[test]
+    fn windup_progress_telegraphs_attack() {
+        let mut enemy = Enemy::new_facing(Vec3::ZERO, PLAYER);
+        let mut last = 0.0;
+        while enemy.update(PLAYER, DT).is_none() {
+            let progress = enemy.windup_progress();
+            assert!(progress >= last, "прогрес замаху спадає");
+            last = progress;
+        }
+        assert!(last > 0.9, "замах не дійшов до кінця: {}", last);
+    }
+
+    #[test]
+    fn attack_hits_towards_target() {
+        let mut enemy = Enemy::new_facing(Vec3::ZERO, PLAYER);
+        let attack = (0..120).find_map(|_| enemy.update(PLAYER, DT)).expect("ворог не вдарив");
+        assert!(attack.direction.dot(Vec3::NEG_Z) > 0.99);
+        assert_eq!(attack.damage, enemy.attack_damage);
+        assert_eq!(attack.reach, enemy.attack_range);
+    }
+
+    #[test]
+    fn leaving_range_or_stagger_cancels_windup() {
+        let mut enemy = Enemy::new_facing(Vec3::ZERO, PLAYER);
+        enemy.update(PLAYER, DT);
+        assert_eq!(enemy.state, EnemyState::Attacking);
+        let far = Vec3::new(0.0, 0.0, -10.0);
+        assert!(enemy.update(far, DT).is_none());
+        assert_eq!(enemy.state, EnemyState::Chasing);
+
+        let mut enemy = Enemy::new_facing(Vec3::ZERO, PLAYER);
+        enemy.update(PLAYER, DT);
+        enemy.stagger(1.0);
+        assert!(frames_until_attack(&mut enemy, PLAYER, 0.9).is_none(), "удар під час stagger");
+        assert_eq!(enemy.state, EnemyState::Cooldown);
+    }
+
+    #[test]
+    fn dead_enemy_never_attacks() {
+        let mut enemy = Enemy::new_facing(Vec3::ZERO, PLAYER);
+        enemy.take_damage(enemy.max_health);
+        assert!(frames_until_attack(&mut enemy, PLAYER, 2.0).is_none());
+    }
+}
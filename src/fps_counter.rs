@@ -13,13 +13,15 @@
    - Підрахунок FPS на основі часу між кадрами
    - Збереження історії для згладженого (averaged) FPS
    - Надання поточного FPS значення
+   - Перцентилі часу кадру (p95 / p99 / max) за історію - видно ривки,
+     які ховає усереднений FPS
 
 🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
    Імпортує:
    - std::time::Instant - для вимірювання часу
 
    Експортує для:
-   - main.rs - відображення FPS в заголовку вікна
+   - main.rs - відображення FPS в заголовку вікна та debug HUD
 
 📦 ЗАЛЕЖНОСТІ:
    - Немає зовнішніх (тільки std)
@@ -28,6 +30,8 @@
    1. Це НЕ детермінована логіка (використовує system time)
    2. FPS counter НЕ впливає на game logic
    3. Тільки для відображення, не для контролю frame rate
+   4. Перцентилі рахуються в tick() сортуванням копії історії в буфер,
+      виділений один раз у with_samples (без алокацій за кадр)
 
 🧪 ТЕСТУВАННЯ:
    ```rust
@@ -42,6 +46,7 @@
 
 🕐 ІСТОРІЯ:
    2025-12-14: Створено - базовий FPS counter з усередненням
+   2026-10-16: p95_ms / p99_ms / max_ms - перцентилі часу кадру
 
 ═══════════════════════════════════════════════════════════════════════════════
 */
@@ -66,13 +71,18 @@ pub struct FpsCounter {
 
     /// Поточне значення FPS
     current_fps: f32,
+
+    /// Відсортована копія frame_times (буфер для перцентилів)
+    sorted_frame_times: Vec<f32>,
+
+    /// Перцентилі часу кадру за історію (секунди)
+    p95: f32,
+    p99: f32,
+    max: f32,
 }
 
 impl FpsCounter {
-    /// Створює новий FPS counter
-    ///
-    /// # Аргументи
-    /// * `samples` - кількість кадрів для усереднення (за замовчуванням 60)
+    /// Створює новий FPS counter (історія - 60 кадрів)
     ///
     /// # Повертає
     /// Новий FpsCounter
@@ -83,14 +93,19 @@ impl FpsCounter {
     /// Створює FPS counter з заданою кількістю семплів для усереднення
     ///
     /// # Аргументи
-    /// * `samples` - кількість кадрів для усереднення
+    /// * `samples` - кількість кадрів для усереднення та перцентилів
     pub fn with_samples(samples: usize) -> Self {
+        let samples = samples.max(1);
         Self {
             last_frame_time: Instant::now(),
             frame_times: vec![0.016; samples], // ~60 FPS за замовчуванням
             max_samples: samples,
             current_index: 0,
             current_fps: 60.0,
+            sorted_frame_times: Vec::with_capacity(samples),
+            p95: 0.016,
+            p99: 0.016,
+            max: 0.016,
         }
     }
 
@@ -101,7 +116,11 @@ impl FpsCounter {
         let now = Instant::now();
         let delta = now.duration_since(self.last_frame_time).as_secs_f32();
         self.last_frame_time = now;
+        self.record_frame_time(delta);
+    }
 
+    /// Додає час кадру (секунди) в історію та оновлює FPS і перцентилі
+    fn record_frame_time(&mut self, delta: f32) {
         // Додати новий frame time в circular buffer
         self.frame_times[self.current_index] = delta;
         self.current_index = (self.current_index + 1) % self.max_samples;
@@ -113,6 +132,21 @@ impl FpsCounter {
         if avg_frame_time > 0.0 {
             self.current_fps = 1.0 / avg_frame_time;
         }
+
+        // Перцентилі (nearest-rank) по відсортованій копії історії
+        self.sorted_frame_times.clear();
+        self.sorted_frame_times.extend_from_slice(&self.frame_times);
+        self.sorted_frame_times.sort_unstable_by(f32::total_cmp);
+        self.p95 = self.percentile(0.95);
+        self.p99 = self.percentile(0.99);
+        self.max = self.percentile(1.0);
+    }
+
+    /// Перцентиль з sorted_frame_times (fraction 0..=1, nearest-rank)
+    fn percentile(&self, fraction: f32) -> f32 {
+        let count = self.sorted_frame_times.len();
+        let rank = (fraction * count as f32).ceil() as usize;
+        self.sorted_frame_times[rank.clamp(1, count) - 1]
     }
 
     /// Повертає поточне значення FPS
@@ -134,6 +168,21 @@ impl FpsCounter {
             0.0
         }
     }
+
+    /// 95-й перцентиль часу кадру за історію (мс)
+    pub fn p95_ms(&self) -> f32 {
+        self.p95 * 1000.0
+    }
+
+    /// 99-й перцентиль часу кадру за історію (мс)
+    pub fn p99_ms(&self) -> f32 {
+        self.p99 * 1000.0
+    }
+
+    /// Найдовший кадр за історію (мс)
+    pub fn max_ms(&self) -> f32 {
+        self.max * 1000.0
+    }
 }
 
 impl Default for FpsCounter {
@@ -141,3 +190,63 @@ impl Default for FpsCounter {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counter з історією `frame_times` (секунди)
+    fn counter_with(frame_times: &[f32]) -> FpsCounter {
+        let mut fps = FpsCounter::with_samples(frame_times.len());
+        for &frame_time in frame_times {
+            fps.record_frame_time(frame_time);
+        }
+        fps
+    }
+
+    #[test]
+    fn percentiles_of_known_frame_times() {
+        // 100 кадрів: 1..=100 мс
+        let frame_times: Vec<f32> = (1..=100).map(|ms| ms as f32 / 1000.0).collect();
+        let fps = counter_with(&frame_times);
+        assert!((fps.p95_ms() - 95.0).abs() < 1e-3, "p95 {}", fps.p95_ms());
+        assert!((fps.p99_ms() - 99.0).abs() < 1e-3, "p99 {}", fps.p99_ms());
+        assert!((fps.max_ms() - 100.0).abs() < 1e-3, "max {}", fps.max_ms());
+        // Середнє 50.5 мс
+        assert!((fps.frame_time_ms() - 50.5).abs() < 1e-2);
+    }
+
+    #[test]
+    fn rare_hitches_show_in_p99_not_p95() {
+        // 1 ривок з 120 - лише max, 2 - вже p99 (nearest-rank: 119-й з 120)
+        let mut frame_times = vec![0.016; 120];
+        frame_times[40] = 0.2;
+        let fps = counter_with(&frame_times);
+        assert!((fps.max_ms() - 200.0).abs() < 1e-3);
+        assert!((fps.p99_ms() - 16.0).abs() < 1e-3);
+
+        frame_times[80] = 0.1;
+        let fps = counter_with(&frame_times);
+        assert!((fps.p99_ms() - 100.0).abs() < 1e-3, "p99 {}", fps.p99_ms());
+        assert!((fps.p95_ms() - 16.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn old_frames_leave_history() {
+        let mut fps = FpsCounter::with_samples(10);
+        fps.record_frame_time(0.5);
+        for _ in 0..10 {
+            fps.record_frame_time(0.01);
+        }
+        assert!((fps.max_ms() - 10.0).abs() < 1e-3, "ривок досі в історії");
+        assert!((fps.fps() - 100.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn zero_samples_is_one_sample() {
+        let mut fps = FpsCounter::with_samples(0);
+        fps.record_frame_time(0.02);
+        assert!((fps.max_ms() - 20.0).abs() < 1e-3);
+        assert!((fps.fps() - 50.0).abs() < 0.1);
+    }
+}
@@ -0,0 +1,355 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/input/gamepad.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   Gamepad - ввід з геймпада через gilrs: кнопки та стіки активного пада.
+
+   gilrs - опційний cargo feature `gamepad` (на Linux тягне системний
+   libudev). Без нього Gamepad - заглушка: пад ніколи не підключений.
+
+   InputState опитує його раз на кадр (begin_frame). Кнопки працюють як
+   прив'язки дій (Binding::Gamepad), стіки - як осі руху та камери.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Опитування подій gilrs: кнопки, підключення / відключення
+   - Активний пад - той, з якого прийшла остання подія
+   - Утримувані кнопки та edges кадру (як клавіші в InputState)
+   - Лівий / правий стік з радіальною deadzone
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Імпортує:
+   - gilrs - події та стан падів
+   - settings.rs - [input] gamepad_deadzone, gamepad_look_speed
+
+   Експортує для:
+   - input_state.rs - InputState::begin_frame, movement_axis, camera_axis
+   - input_map.rs - GamepadButton (Binding::Gamepad)
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+   - gilrs не ініціалізувався (немає backend'у) - пад просто не
+     підключений: кнопки не натиснуті, стіки в нулі
+   - Пад відключився - його кнопки відпускаються (edges), стіки в нуль
+   - Y стіка: вгору = +1 (gilrs), так само як "вперед" руху
+   - GamepadButton - власний enum (назви як у gilrs::Button), щоб прив'язки
+     в keybindings.toml парсились і без feature `gamepad`
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - gilrs, стіки з deadzone, кнопки як прив'язки дій
+   2026-10-17: gilrs за feature `gamepad`; власний GamepadButton; тести deadzone
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use std::collections::HashSet;
+
+#[cfg(feature = "gamepad")]
+use gilrs::{Axis, EventType, GamepadId, Gilrs};
+use glam::Vec2;
+
+use crate::settings::Settings;
+
+/// Кнопка геймпада (South / East / North / West, тригери, ...)
+///
+/// Назви варіантів збігаються з gilrs::Button (у файлі прив'язок - "Pad" + назва).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GamepadButton {
+    South,
+    East,
+    North,
+    West,
+    LeftTrigger,
+    LeftTrigger2,
+    RightTrigger,
+    RightTrigger2,
+    Select,
+    Start,
+    Mode,
+    LeftThumb,
+    RightThumb,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+}
+
+#[cfg(feature = "gamepad")]
+impl GamepadButton {
+    /// Кнопка gilrs → GamepadButton (None - Unknown / C / Z)
+    fn from_gilrs(button: gilrs::Button) -> Option<Self> {
+        use gilrs::Button;
+        Some(match button {
+            Button::South => Self::South,
+            Button::East => Self::East,
+            Button::North => Self::North,
+            Button::West => Self::West,
+            Button::LeftTrigger => Self::LeftTrigger,
+            Button::LeftTrigger2 => Self::LeftTrigger2,
+            Button::RightTrigger => Self::RightTrigger,
+            Button::RightTrigger2 => Self::RightTrigger2,
+            Button::Select => Self::Select,
+            Button::Start => Self::Start,
+            Button::Mode => Self::Mode,
+            Button::LeftThumb => Self::LeftThumb,
+            Button::RightThumb => Self::RightThumb,
+            Button::DPadUp => Self::DPadUp,
+            Button::DPadDown => Self::DPadDown,
+            Button::DPadLeft => Self::DPadLeft,
+            Button::DPadRight => Self::DPadRight,
+            _ => return None,
+        })
+    }
+}
+
+/// Deadzone стіків за замовчуванням (частка повного відхилення)
+pub const DEFAULT_GAMEPAD_DEADZONE: f32 = 0.15;
+
+/// Швидкість камери від правого стіка на повному відхиленні (пікселів миші
+/// за секунду; далі - як рух миші з [camera] mouse_sensitivity)
+pub const DEFAULT_GAMEPAD_LOOK_SPEED: f32 = 600.0;
+
+/// Стан геймпада
+#[derive(Debug)]
+pub struct Gamepad {
+    /// gilrs (None - не ініціалізувався, падів немає)
+    #[cfg(feature = "gamepad")]
+    gilrs: Option<Gilrs>,
+
+    /// Активний пад (остання подія)
+    #[cfg(feature = "gamepad")]
+    active: Option<GamepadId>,
+
+    /// Радіальна deadzone стіків (0..1)
+    pub deadzone: f32,
+
+    /// Швидкість камери від правого стіка (пікселів миші за секунду)
+    pub look_speed: f32,
+
+    /// Сирі стіки активного пада (deadzone - при читанні)
+    left_stick: Vec2,
+    right_stick: Vec2,
+
+    /// Утримувані кнопки активного пада
+    pressed_buttons: HashSet<GamepadButton>,
+
+    /// Кнопки, натиснуті / відпущені в цьому кадрі
+    frame_presses: HashSet<GamepadButton>,
+    frame_releases: HashSet<GamepadButton>,
+}
+
+impl Gamepad {
+    /// Ініціалізує gilrs; не вдалося - геймпад вимкнено (warning в лог)
+    pub fn new() -> Self {
+        #[cfg(feature = "gamepad")]
+        let gilrs = match Gilrs::new() {
+            Ok(gilrs) => {
+                for (_, pad) in gilrs.gamepads() {
+                    log::info!("Геймпад: {}", pad.name());
+                }
+                Some(gilrs)
+            }
+            Err(e) => {
+                log::warn!("Геймпад недоступний: {}", e);
+                None
+            }
+        };
+        Self {
+            #[cfg(feature = "gamepad")]
+            gilrs,
+            #[cfg(feature = "gamepad")]
+            active: None,
+            deadzone: DEFAULT_GAMEPAD_DEADZONE,
+            look_speed: DEFAULT_GAMEPAD_LOOK_SPEED,
+            left_stick: Vec2::ZERO,
+            right_stick: Vec2::ZERO,
+            pressed_buttons: HashSet::new(),
+            frame_presses: HashSet::new(),
+            frame_releases: HashSet::new(),
+        }
+    }
+
+    /// Читає `[input] gamepad_deadzone`, `gamepad_look_speed`
+    pub fn apply_settings(&mut self, settings: &Settings) {
+        self.deadzone = settings
+            .get_f32("input.gamepad_deadzone", DEFAULT_GAMEPAD_DEADZONE)
+            .clamp(0.0, 0.9);
+        self.look_speed = settings
+            .get_f32("input.gamepad_look_speed", DEFAULT_GAMEPAD_LOOK_SPEED)
+            .max(0.0);
+    }
+
+    /// Опитує події з попереднього кадру (раз на кадр, InputState::begin_frame)
+    pub fn poll(&mut self) {
+        self.frame_presses.clear();
+        self.frame_releases.clear();
+        #[cfg(feature = "gamepad")]
+        self.poll_gilrs();
+    }
+
+    /// Події gilrs → кнопки та сирі стіки активного пада
+    #[cfg(feature = "gamepad")]
+    fn poll_gilrs(&mut self) {
+        let Some(gilrs) = &mut self.gilrs else {
+            return;
+        };
+
+        while let Some(event) = gilrs.next_event() {
+            match event.event {
+                EventType::Connected => {
+                    log::info!("Геймпад підключено: {}", gilrs.gamepad(event.id).name());
+                }
+                EventType::Disconnected => {
+                    log::info!("Геймпад відключено: {}", gilrs.gamepad(event.id).name());
+                    if self.active == Some(event.id) {
+                        self.active = None;
+                        self.frame_releases.extend(self.pressed_buttons.drain());
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+
+            // Інший пад - кнопки попереднього відпускаються
+            if self.active != Some(event.id) {
+                self.active = Some(event.id);
+                self.frame_releases.extend(self.pressed_buttons.drain());
+            }
+            match event.event {
+                EventType::ButtonPressed(button, _) => {
+                    if let Some(button) = GamepadButton::from_gilrs(button) {
+                        if self.pressed_buttons.insert(button) {
+                            self.frame_presses.insert(button);
+                        }
+                    }
+                }
+                EventType::ButtonReleased(button, _) => {
+                    if let Some(button) = GamepadButton::from_gilrs(button) {
+                        if self.pressed_buttons.remove(&button) {
+                            self.frame_releases.insert(button);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let stick = |x: Axis, y: Axis| {
+            self.active
+                .and_then(|id| gilrs.connected_gamepad(id))
+                .map(|pad| Vec2::new(pad.value(x), pad.value(y)))
+                .unwrap_or(Vec2::ZERO)
+        };
+        self.left_stick = stick(Axis::LeftStickX, Axis::LeftStickY);
+        self.right_stick = stick(Axis::RightStickX, Axis::RightStickY);
+    }
+
+    /// Лівий стік (x - вправо, y - вперед), після deadzone
+    pub fn left_stick(&self) -> Vec2 {
+        apply_deadzone(self.left_stick, self.deadzone)
+    }
+
+    /// Правий стік (x - вправо, y - вгору), після deadzone
+    pub fn right_stick(&self) -> Vec2 {
+        apply_deadzone(self.right_stick, self.deadzone)
+    }
+
+    /// Чи утримується кнопка
+    pub fn is_pressed(&self, button: GamepadButton) -> bool {
+        self.pressed_buttons.contains(&button)
+    }
+
+    /// Кнопку натиснуто в цьому кадрі
+    pub fn just_pressed(&self, button: GamepadButton) -> bool {
+        self.frame_presses.contains(&button)
+    }
+
+    /// Кнопку відпущено в цьому кадрі
+    pub fn just_released(&self, button: GamepadButton) -> bool {
+        self.frame_releases.contains(&button)
+    }
+}
+
+impl Default for Gamepad {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Радіальна deadzone: всередині - нуль, далі - плавно від 0 до 1
+fn apply_deadzone(stick: Vec2, deadzone: f32) -> Vec2 {
+    let length = stick.length();
+    if length <= deadzone || !length.is_finite() {
+        return Vec2::ZERO;
+    }
+    let scaled = ((length - deadzone) / (1.0 - deadzone)).min(1.0);
+    stick / length * scaled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPS: f32 = 1e-5;
+
+    #[test]
+    fn deadzone_zeroes_small_deflection() {
+        assert_eq!(apply_deadzone(Vec2::new(0.1, 0.05), 0.15), Vec2::ZERO);
+        assert_eq!(apply_deadzone(Vec2::new(0.0, 0.15), 0.15), Vec2::ZERO);
+        assert_eq!(apply_deadzone(Vec2::new(f32::NAN, 0.5), 0.15), Vec2::ZERO);
+    }
+
+    #[test]
+    fn deadzone_rescales_from_edge_to_full() {
+        // Одразу за краєм deadzone - майже нуль, повне відхилення - 1
+        let edge = apply_deadzone(Vec2::new(0.0, 0.16), 0.15);
+        assert!(edge.length() < 0.02);
+        let full = apply_deadzone(Vec2::new(0.0, 1.0), 0.15);
+        assert!((full - Vec2::Y).length() < EPS);
+        // Посередині між deadzone та 1 - половина
+        let half = apply_deadzone(Vec2::new(0.575, 0.0), 0.15);
+        assert!((half.x - 0.5).abs() < EPS);
+    }
+
+    #[test]
+    fn deadzone_keeps_direction_and_clamps_length() {
+        let stick = Vec2::new(1.0, 1.0); // кут пада: довжина √2
+        let out = apply_deadzone(stick, 0.15);
+        assert!((out.length() - 1.0).abs() < EPS);
+        assert!((out.normalize() - stick.normalize()).length() < EPS);
+    }
+
+    #[test]
+    fn sticks_apply_configured_deadzone() {
+        let mut pad = Gamepad::new();
+        pad.left_stick = Vec2::new(0.0, 0.3);
+        pad.right_stick = Vec2::new(-1.0, 0.0);
+        pad.deadzone = 0.5;
+        assert_eq!(pad.left_stick(), Vec2::ZERO);
+        assert!((pad.right_stick() - Vec2::NEG_X).length() < EPS);
+        pad.deadzone = 0.1;
+        assert!(pad.left_stick().y > 0.0);
+    }
+
+    #[test]
+    fn settings_clamp_deadzone() {
+        let mut pad = Gamepad::new();
+        pad.apply_settings(&Settings::parse("[input]\ngamepad_deadzone = 2.0\ngamepad_look_speed = -5\n"));
+        assert_eq!(pad.deadzone, 0.9);
+        assert_eq!(pad.look_speed, 0.0);
+        pad.apply_settings(&Settings::parse(""));
+        assert_eq!(pad.deadzone, DEFAULT_GAMEPAD_DEADZONE);
+        assert_eq!(pad.look_speed, DEFAULT_GAMEPAD_LOOK_SPEED);
+    }
+
+    #[cfg(feature = "gamepad")]
+    #[test]
+    fn gilrs_buttons_map_by_name() {
+        let face = [gilrs::Button::South, gilrs::Button::East, gilrs::Button::North, gilrs::Button::West];
+        for button in face {
+            let mapped = GamepadButton::from_gilrs(button).unwrap();
+            assert_eq!(format!("{:?}", mapped), format!("{:?}", button));
+        }
+        assert_eq!(GamepadButton::from_gilrs(gilrs::Button::Unknown), None);
+    }
+}
@@ -0,0 +1,480 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/input/input_map.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   InputMap - прив'язки клавіш: ігрова дія (Action) → клавіші / кнопки миші
+   / кнопки геймпада.
+
+   Гра питає "чи натиснуто Attack", а не "чи натиснуто ЛКМ" - тож клавіші
+   можна перепризначити (AZERTY, ліворукі) без зміни коду.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Action: усі дії гравця (рух, бій, зброя, пауза, debug)
+   - Binding: фізична клавіша (KeyCode), кнопка миші або кнопка геймпада
+   - Дефолтні прив'язки = попереднє захардкоджене керування
+   - Завантаження / збереження keybindings.toml поруч з exe
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Імпортує:
+   - settings.rs - парсер рядків `key = value` у [section]
+   - gamepad.rs - GamepadButton
+
+   Експортує для:
+   - input_state.rs - is_action_pressed / just_pressed_action
+   - main.rs - завантаження прив'язок при старті
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+   - KeyCode - фізична позиція клавіші (KeyW на AZERTY - це клавіша Z)
+   - Дія без ключа у файлі - дефолтні прив'язки, `[]` - дія не прив'язана
+   - Невідомі назви клавіш ігноруються (з попередженням в лог)
+   - Файлу немає - створюється з дефолтами (шаблон для редагування)
+
+📝 ПРИКЛАД ФАЙЛУ:
+   ```toml
+   [bindings]
+   move_forward = ["KeyW", "ArrowUp"]
+   attack = ["MouseLeft"]
+   ```
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - Action → Binding замість is_w_pressed() helpers
+   2026-10-16: Action::LockOn (Tab / середня кнопка миші)
+   2026-10-16: Action::DebugWireframe (F6)
+   2026-10-16: Action::DebugFreeCam (F7)
+   2026-10-16: Action::DebugHud (F3), осі кісток - на F2
+   2026-10-16: Action::CameraMode (V) - перша / третя особа
+   2026-10-16: Action::TimeScaleDown / TimeScaleUp ([ / ]) - slow-mo
+   2026-10-16: Action::DebugSaveReplay (F9)
+   2026-10-16: Action::DebugGrid (F8) - grid став debug оверлеєм
+   2026-10-16: Action::PresentMode (F10) - Fifo / Mailbox / Immediate
+   2026-10-16: Action::Screenshot (F12)
+   2026-10-16: Action::Fullscreen (F11; Alt+Enter - в main.rs)
+   2026-10-16: Binding::Gamepad - кнопки геймпада ("PadSouth", ...)
+   2026-10-17: Action::Dodge (C / PadNorth)
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use winit::event::MouseButton;
+use winit::keyboard::KeyCode;
+
+use super::gamepad::GamepadButton;
+use crate::settings::Settings;
+
+/// Назва файлу прив'язок (поруч з exe)
+pub const KEYBINDINGS_FILE: &str = "keybindings.toml";
+
+/// Секція з прив'язками у файлі
+const BINDINGS_SECTION: &str = "bindings";
+
+/// Ігрова дія
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    MoveForward,
+    MoveBack,
+    StrafeLeft,
+    StrafeRight,
+    Sprint,
+    Crouch,
+    Jump,
+    /// Клік - легка атака, утримання - важка
+    Attack,
+    Block,
+    /// Захоплення цілі (lock-on) / відпускання
+    LockOn,
+    /// Поворот камери вліво / вправо
+    TurnLeft,
+    TurnRight,
+    /// Камера від першої / третьої особи
+    CameraMode,
+    WeaponDagger,
+    WeaponSword,
+    WeaponGreatsword,
+    /// Рестарт після смерті
+    Restart,
+    /// Ragdoll в A-pose над поточною позицією
+    ResetRagdoll,
+    /// Пауза (з Shift - вихід)
+    Pause,
+    DebugBoneAxes,
+    DebugVelocities,
+    /// Текстовий debug HUD (FPS, фізика, бій)
+    DebugHud,
+    /// Wireframe mesh та скелетів
+    DebugWireframe,
+    /// Free-fly камера (спостерігач, ввід гравця призупинено)
+    DebugFreeCam,
+    /// Зберегти записаний ввід у файл replay
+    DebugSaveReplay,
+    /// Time scale ×0.5 / ×2 (slow-mo)
+    TimeScaleDown,
+    TimeScaleUp,
+    /// Координатна сітка поверх підлоги
+    DebugGrid,
+    /// Наступний present mode (VSync / Mailbox / Immediate)
+    PresentMode,
+    /// Screenshot кадру в debug/
+    Screenshot,
+    /// Borderless fullscreen / вікно (також Alt+Enter)
+    Fullscreen,
+    /// Ухилення (в кінці enum - біти дій у replay не зсуваються)
+    Dodge,
+}
+
+impl Action {
+    /// Всі дії (порядок - як у файлі прив'язок)
+    pub const ALL: [Action; 32] = [
+        Action::MoveForward,
+        Action::MoveBack,
+        Action::StrafeLeft,
+        Action::StrafeRight,
+        Action::Sprint,
+        Action::Crouch,
+        Action::Jump,
+        Action::Attack,
+        Action::Block,
+        Action::Dodge,
+        Action::LockOn,
+        Action::TurnLeft,
+        Action::TurnRight,
+        Action::CameraMode,
+        Action::WeaponDagger,
+        Action::WeaponSword,
+        Action::WeaponGreatsword,
+        Action::Restart,
+        Action::ResetRagdoll,
+        Action::Pause,
+        Action::DebugBoneAxes,
+        Action::DebugVelocities,
+        Action::DebugHud,
+        Action::DebugWireframe,
+        Action::DebugFreeCam,
+        Action::DebugSaveReplay,
+        Action::TimeScaleDown,
+        Action::TimeScaleUp,
+        Action::DebugGrid,
+        Action::PresentMode,
+        Action::Screenshot,
+        Action::Fullscreen,
+    ];
+
+    /// Назва дії у файлі прив'язок
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::MoveForward => "move_forward",
+            Action::MoveBack => "move_back",
+            Action::StrafeLeft => "strafe_left",
+            Action::StrafeRight => "strafe_right",
+            Action::Sprint => "sprint",
+            Action::Crouch => "crouch",
+            Action::Jump => "jump",
+            Action::Attack => "attack",
+            Action::Block => "block",
+            Action::Dodge => "dodge",
+            Action::LockOn => "lock_on",
+            Action::TurnLeft => "turn_left",
+            Action::TurnRight => "turn_right",
+            Action::CameraMode => "camera_mode",
+            Action::WeaponDagger => "weapon_dagger",
+            Action::WeaponSword => "weapon_sword",
+            Action::WeaponGreatsword => "weapon_greatsword",
+            Action::Restart => "restart",
+            Action::ResetRagdoll => "reset_ragdoll",
+            Action::Pause => "pause",
+            Action::DebugBoneAxes => "debug_bone_axes",
+            Action::DebugVelocities => "debug_velocities",
+            Action::DebugHud => "debug_hud",
+            Action::DebugWireframe => "debug_wireframe",
+            Action::DebugFreeCam => "debug_free_cam",
+            Action::DebugSaveReplay => "debug_save_replay",
+            Action::TimeScaleDown => "time_scale_down",
+            Action::TimeScaleUp => "time_scale_up",
+            Action::DebugGrid => "debug_grid",
+            Action::PresentMode => "present_mode",
+            Action::Screenshot => "screenshot",
+            Action::Fullscreen => "fullscreen",
+        }
+    }
+
+    /// Дефолтні прив'язки (попереднє захардкоджене керування)
+    fn default_bindings(self) -> Vec<Binding> {
+        use Binding::{Gamepad as Pad, Key, Mouse};
+        match self {
+            Action::MoveForward => vec![Key(KeyCode::KeyW)],
+            Action::MoveBack => vec![Key(KeyCode::KeyS)],
+            Action::StrafeLeft => vec![Key(KeyCode::KeyA)],
+            Action::StrafeRight => vec![Key(KeyCode::KeyD)],
+            Action::Sprint => vec![Key(KeyCode::ShiftLeft), Key(KeyCode::ShiftRight), Pad(GamepadButton::LeftThumb)],
+            Action::Crouch => vec![Key(KeyCode::ControlLeft), Key(KeyCode::ControlRight)],
+            Action::Jump => vec![Key(KeyCode::Space), Pad(GamepadButton::South)],
+            Action::Attack => vec![Mouse(MouseButton::Left), Pad(GamepadButton::West)],
+            Action::Block => vec![Mouse(MouseButton::Right), Pad(GamepadButton::East)],
+            Action::Dodge => vec![Key(KeyCode::KeyC), Pad(GamepadButton::North)],
+            Action::LockOn => vec![Key(KeyCode::Tab), Mouse(MouseButton::Middle), Pad(GamepadButton::RightThumb)],
+            Action::TurnLeft => vec![Key(KeyCode::KeyQ)],
+            Action::TurnRight => vec![Key(KeyCode::KeyE)],
+            Action::CameraMode => vec![Key(KeyCode::KeyV)],
+            Action::WeaponDagger => vec![Key(KeyCode::Digit1)],
+            Action::WeaponSword => vec![Key(KeyCode::Digit2)],
+            Action::WeaponGreatsword => vec![Key(KeyCode::Digit3)],
+            Action::Restart => vec![Key(KeyCode::KeyR)],
+            Action::ResetRagdoll => vec![Key(KeyCode::F5)],
+            Action::Pause => vec![Key(KeyCode::Escape), Pad(GamepadButton::Start)],
+            Action::DebugBoneAxes => vec![Key(KeyCode::F2)],
+            Action::DebugVelocities => vec![Key(KeyCode::F4)],
+            Action::DebugHud => vec![Key(KeyCode::F3)],
+            Action::DebugWireframe => vec![Key(KeyCode::F6)],
+            Action::DebugFreeCam => vec![Key(KeyCode::F7)],
+            Action::DebugSaveReplay => vec![Key(KeyCode::F9)],
+            Action::TimeScaleDown => vec![Key(KeyCode::BracketLeft)],
+            Action::TimeScaleUp => vec![Key(KeyCode::BracketRight)],
+            Action::DebugGrid => vec![Key(KeyCode::F8)],
+            Action::PresentMode => vec![Key(KeyCode::F10)],
+            Action::Screenshot => vec![Key(KeyCode::F12)],
+            Action::Fullscreen => vec![Key(KeyCode::F11)],
+        }
+    }
+}
+
+/// Клавіші, які можна вказати у файлі (назва = `{:?}` KeyCode, напр. "KeyW")
+const KEY_CODES: &[KeyCode] = &[
+    KeyCode::KeyA, KeyCode::KeyB, KeyCode::KeyC, KeyCode::KeyD, KeyCode::KeyE,
+    KeyCode::KeyF, KeyCode::KeyG, KeyCode::KeyH, KeyCode::KeyI, KeyCode::KeyJ,
+    KeyCode::KeyK, KeyCode::KeyL, KeyCode::KeyM, KeyCode::KeyN, KeyCode::KeyO,
+    KeyCode::KeyP, KeyCode::KeyQ, KeyCode::KeyR, KeyCode::KeyS, KeyCode::KeyT,
+    KeyCode::KeyU, KeyCode::KeyV, KeyCode::KeyW, KeyCode::KeyX, KeyCode::KeyY,
+    KeyCode::KeyZ,
+    KeyCode::Digit0, KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3, KeyCode::Digit4,
+    KeyCode::Digit5, KeyCode::Digit6, KeyCode::Digit7, KeyCode::Digit8, KeyCode::Digit9,
+    KeyCode::F1, KeyCode::F2, KeyCode::F3, KeyCode::F4, KeyCode::F5, KeyCode::F6,
+    KeyCode::F7, KeyCode::F8, KeyCode::F9, KeyCode::F10, KeyCode::F11, KeyCode::F12,
+    KeyCode::ArrowUp, KeyCode::ArrowDown, KeyCode::ArrowLeft, KeyCode::ArrowRight,
+    KeyCode::Space, KeyCode::Enter, KeyCode::Escape, KeyCode::Tab, KeyCode::Backspace,
+    KeyCode::ShiftLeft, KeyCode::ShiftRight, KeyCode::ControlLeft, KeyCode::ControlRight,
+    KeyCode::AltLeft, KeyCode::AltRight, KeyCode::CapsLock,
+    KeyCode::Backquote, KeyCode::Minus, KeyCode::Equal, KeyCode::BracketLeft,
+    KeyCode::BracketRight, KeyCode::Backslash, KeyCode::Semicolon, KeyCode::Quote,
+    KeyCode::Comma, KeyCode::Period, KeyCode::Slash,
+    KeyCode::Insert, KeyCode::Delete, KeyCode::Home, KeyCode::End,
+    KeyCode::PageUp, KeyCode::PageDown,
+    KeyCode::Numpad0, KeyCode::Numpad1, KeyCode::Numpad2, KeyCode::Numpad3, KeyCode::Numpad4,
+    KeyCode::Numpad5, KeyCode::Numpad6, KeyCode::Numpad7, KeyCode::Numpad8, KeyCode::Numpad9,
+    KeyCode::NumpadAdd, KeyCode::NumpadSubtract, KeyCode::NumpadEnter,
+];
+
+/// Кнопки геймпада, які можна вказати у файлі (назва = "Pad" + `{:?}`, напр. "PadSouth")
+const GAMEPAD_BUTTONS: &[GamepadButton] = &[
+    GamepadButton::South, GamepadButton::East, GamepadButton::North, GamepadButton::West,
+    GamepadButton::LeftTrigger, GamepadButton::LeftTrigger2,
+    GamepadButton::RightTrigger, GamepadButton::RightTrigger2,
+    GamepadButton::Select, GamepadButton::Start, GamepadButton::Mode,
+    GamepadButton::LeftThumb, GamepadButton::RightThumb,
+    GamepadButton::DPadUp, GamepadButton::DPadDown, GamepadButton::DPadLeft, GamepadButton::DPadRight,
+];
+
+/// Прив'язка: фізична клавіша, кнопка миші або кнопка геймпада
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Binding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    Gamepad(GamepadButton),
+}
+
+impl Binding {
+    /// Назва у файлі прив'язок ("KeyW", "MouseLeft", "Mouse5", "PadSouth")
+    pub fn name(self) -> String {
+        match self {
+            Binding::Gamepad(button) => format!("Pad{:?}", button),
+            Binding::Key(key_code) => format!("{:?}", key_code),
+            Binding::Mouse(MouseButton::Left) => "MouseLeft".to_string(),
+            Binding::Mouse(MouseButton::Right) => "MouseRight".to_string(),
+            Binding::Mouse(MouseButton::Middle) => "MouseMiddle".to_string(),
+            Binding::Mouse(MouseButton::Back) => "MouseBack".to_string(),
+            Binding::Mouse(MouseButton::Forward) => "MouseForward".to_string(),
+            Binding::Mouse(MouseButton::Other(id)) => format!("Mouse{}", id),
+        }
+    }
+
+    /// Прив'язка з назви (None - невідома назва)
+    pub fn from_name(name: &str) -> Option<Self> {
+        let button = match name {
+            "MouseLeft" => Some(MouseButton::Left),
+            "MouseRight" => Some(MouseButton::Right),
+            "MouseMiddle" => Some(MouseButton::Middle),
+            "MouseBack" => Some(MouseButton::Back),
+            "MouseForward" => Some(MouseButton::Forward),
+            _ => name.strip_prefix("Mouse").and_then(|id| id.parse().ok()).map(MouseButton::Other),
+        };
+        if let Some(button) = button {
+            return Some(Binding::Mouse(button));
+        }
+        if let Some(pad_name) = name.strip_prefix("Pad") {
+            return GAMEPAD_BUTTONS
+                .iter()
+                .find(|button| format!("{:?}", button) == pad_name)
+                .map(|&button| Binding::Gamepad(button));
+        }
+        KEY_CODES
+            .iter()
+            .find(|key_code| format!("{:?}", key_code) == name)
+            .map(|&key_code| Binding::Key(key_code))
+    }
+}
+
+/// Прив'язки всіх дій
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputMap {
+    bindings: HashMap<Action, Vec<Binding>>,
+}
+
+impl Default for InputMap {
+    /// Керування як до появи InputMap (WASD, ЛКМ / ПКМ, Space, ...)
+    fn default() -> Self {
+        Self {
+            bindings: Action::ALL.iter().map(|&action| (action, action.default_bindings())).collect(),
+        }
+    }
+}
+
+impl InputMap {
+    /// Шлях до keybindings.toml поруч з exe (або в поточній директорії)
+    pub fn default_path() -> PathBuf {
+        std::env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(|dir| dir.join(KEYBINDINGS_FILE)))
+            .unwrap_or_else(|| PathBuf::from(KEYBINDINGS_FILE))
+    }
+
+    /// Прив'язки дії
+    pub fn bindings(&self, action: Action) -> &[Binding] {
+        self.bindings.get(&action).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Перепризначає дію (порожній список - дія не прив'язана)
+    pub fn bind(&mut self, action: Action, bindings: Vec<Binding>) {
+        self.bindings.insert(action, bindings);
+    }
+
+    /// Завантажує прив'язки; файлу немає - створює його з дефолтами
+    pub fn load_or_create(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        match std::fs::read_to_string(path) {
+            Ok(text) => {
+                log::info!("Прив'язки клавіш завантажено з {:?}", path);
+                Self::parse(&text)
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                let map = Self::default();
+                match map.save(path) {
+                    Ok(()) => log::info!("Створено {:?} з дефолтними прив'язками", path),
+                    Err(e) => log::warn!("Не вдалося створити {:?} ({})", path, e),
+                }
+                map
+            }
+            Err(e) => {
+                log::warn!("Не вдалося прочитати {:?} ({}), дефолтні прив'язки", path, e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Парсить файл прив'язок (дії без ключа - дефолтні прив'язки)
+    pub fn parse(text: &str) -> Self {
+        let settings = Settings::parse(text);
+        let mut map = Self::default();
+        for action in Action::ALL {
+            let key = format!("{}.{}", BINDINGS_SECTION, action.name());
+            let Some(raw) = settings.get_str(&key) else {
+                continue;
+            };
+            let bindings = raw
+                .trim()
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .split(',')
+                .map(|name| name.trim().trim_matches('"'))
+                .filter(|name| !name.is_empty())
+                .filter_map(|name| {
+                    let binding = Binding::from_name(name);
+                    if binding.is_none() {
+                        log::warn!("keybindings: невідома клавіша {:?} для {}", name, action.name());
+                    }
+                    binding
+                })
+                .collect();
+            map.bind(action, bindings);
+        }
+        map
+    }
+
+    /// Текст файлу прив'язок (TOML: [bindings], action = ["Key", ...])
+    pub fn to_toml(&self) -> String {
+        let mut text = String::from(
+            "# Прив'язки клавіш Arena Combat\n\
+             # Назви клавіш - фізичні позиції (KeyW, ShiftLeft, F5, ArrowUp, ...),\n\
+             # миша - MouseLeft / MouseRight / MouseMiddle / MouseBack / MouseForward,\n\
+             # геймпад - PadSouth / PadEast / PadNorth / PadWest / PadStart / PadLeftThumb / ...\n\
+             # [] - дія не прив'язана, видалений рядок - дефолтна прив'язка.\n\n",
+        );
+        text.push_str(&format!("[{}]\n", BINDINGS_SECTION));
+        for action in Action::ALL {
+            let names: Vec<String> = self.bindings(action).iter().map(|b| format!("\"{}\"", b.name())).collect();
+            text.push_str(&format!("{} = [{}]\n", action.name(), names.join(", ")));
+        }
+        text
+    }
+
+    /// Зберігає прив'язки у файл
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_toml())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dodge_has_default_binding() {
+        let map = InputMap::default();
+        assert_eq!(map.bindings(Action::Dodge), &[Binding::Key(KeyCode::KeyC), Binding::Gamepad(GamepadButton::North)]);
+        // Дефолтна клавіша ухилення не зайнята іншою дією
+        for action in Action::ALL.into_iter().filter(|&action| action != Action::Dodge) {
+            assert!(!map.bindings(action).contains(&Binding::Key(KeyCode::KeyC)), "{} на KeyC", action.name());
+        }
+    }
+
+    #[test]
+    fn parse_rebinds_dodge() {
+        let map = InputMap::parse("[bindings]\ndodge = [\"AltLeft\", \"Mouse4\"]\n");
+        assert_eq!(
+            map.bindings(Action::Dodge),
+            &[Binding::Key(KeyCode::AltLeft), Binding::Mouse(MouseButton::Other(4))]
+        );
+        // Інші дії - дефолтні
+        assert_eq!(map.bindings(Action::Block), InputMap::default().bindings(Action::Block));
+
+        let unbound = InputMap::parse("[bindings]\ndodge = []\n");
+        assert!(unbound.bindings(Action::Dodge).is_empty());
+    }
+
+    #[test]
+    fn unknown_names_are_skipped() {
+        let map = InputMap::parse("[bindings]\ndodge = [\"KeyNope\", \"Space\"]\n");
+        assert_eq!(map.bindings(Action::Dodge), &[Binding::Key(KeyCode::Space)]);
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        let mut map = InputMap::default();
+        map.bind(Action::Dodge, vec![Binding::Key(KeyCode::KeyX), Binding::Gamepad(GamepadButton::RightTrigger)]);
+        let text = map.to_toml();
+        assert!(text.contains("dodge = [\"KeyX\", \"PadRightTrigger\"]"));
+        assert_eq!(InputMap::parse(&text), map);
+    }
+}
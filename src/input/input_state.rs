@@ -4,7 +4,7 @@
 ═══════════════════════════════════════════════════════════════════════════════
 
 📋 ПРИЗНАЧЕННЯ:
-   InputState - структура для tracking стану клавіатури та миші.
+   InputState - структура для tracking стану клавіатури, миші та геймпада.
 
 🎯 ВІДПОВІДАЛЬНІСТЬ:
    - Зберігання поточної позиції миші
@@ -12,11 +12,17 @@
    - Tracking стану кнопок миші (ліва/права/середня)
    - Tracking натиснутих клавіш (WASD, Shift, Ctrl, тощо)
    - Надання методів для перевірки стану
+   - Edge-triggered стан кадру: just_pressed / just_released (begin_frame)
+   - Дії через InputMap: is_action_pressed / just_pressed_action
+   - Осі руху та камери (movement_axis / camera_axis): клавіші та миша
+     разом зі стіками геймпада - game loop не розрізняє пристрої
 
 🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
    Імпортує:
    - winit::event::{MouseButton, ElementState}
-   - winit::keyboard::{PhysicalKey, KeyCode}
+   - winit::keyboard::KeyCode
+   - input_map.rs - Action, Binding, InputMap
+   - gamepad.rs - Gamepad (gilrs)
 
    Експортує для:
    - main.rs - обробка input events
@@ -25,6 +31,11 @@
    1. Стан миші оновлюється ТІЛЬКИ в event handler
    2. Delta обчислюється як різниця між поточною і попередньою позицією
    3. После обчислення delta, треба викликати reset_mouse_delta()
+   4. begin_frame() - рівно раз на кадр, ДО читання input: натискання /
+      відпускання з подій між кадрами стають "цього кадру". Подія фіксується
+      одразу, тож клік коротший за кадр дає і just_pressed, і just_released
+   5. Кадр replay (set_replay_frame) підміняє дії, mouse_delta та стіки;
+      клавіші та кнопки (is_key_pressed, is_shift_pressed) лишаються живими
 
 📝 ПРИКЛАД ВИКОРИСТАННЯ:
    ```rust
@@ -46,6 +57,10 @@
    }
 
    // В update loop
+   input_state.begin_frame();
+   if input_state.just_pressed_action(Action::Jump) {
+       // Стрибок (лише в кадрі натискання)
+   }
    let mouse_delta = input_state.mouse_delta();
    if mouse_delta != (0.0, 0.0) {
        // Оновити камеру
@@ -55,15 +70,26 @@
 
 🕐 ІСТОРІЯ:
    2025-12-14: Створено - tracking миші та клавіатури для camera controls
+   2026-10-16: just_pressed / just_released / mouse_just_pressed (begin_frame)
+   2026-10-16: Дії (Action) через InputMap замість is_w_pressed() helpers
+   2026-10-16: Кадр replay (set_replay_frame) замість живого вводу
+   2026-10-16: Геймпад (gilrs): кнопки як прив'язки, movement_axis / camera_axis
+   2026-10-16: release_all() - втрата фокусу вікна
 
 ═══════════════════════════════════════════════════════════════════════════════
 */
 
+use glam::Vec2;
 use winit::event::{MouseButton, ElementState};
-use winit::keyboard::{PhysicalKey, KeyCode};
+use winit::keyboard::KeyCode;
+
+use super::gamepad::Gamepad;
+use super::replay::ReplayFrame;
 use std::collections::HashSet;
 
-/// Стан введення (клавіатура + миша)
+use super::input_map::{Action, Binding, InputMap};
+
+/// Стан введення (клавіатура + миша + геймпад)
 ///
 /// Зберігає поточний стан всіх input пристроїв для використання в game loop.
 #[derive(Debug)]
@@ -88,9 +114,41 @@ pub struct InputState {
     /// Середня кнопка миші натиснута
     pub mouse_middle: bool,
 
+    /// Утримувані кнопки миші (включно з Back / Forward / Other)
+    pressed_buttons: HashSet<MouseButton>,
+
     // === Keyboard state ===
     /// Set натиснутих клавіш (використовуємо HashSet для швидкого lookup)
     pressed_keys: HashSet<KeyCode>,
+
+    /// Прив'язки дій до клавіш / кнопок миші / кнопок геймпада
+    pub input_map: InputMap,
+
+    // === Gamepad ===
+    /// Активний геймпад (опитується в begin_frame)
+    pub gamepad: Gamepad,
+
+    // === Edges (події з моменту останнього begin_frame) ===
+    /// Клавіші, натиснуті після останнього begin_frame (без auto-repeat)
+    pending_key_presses: HashSet<KeyCode>,
+
+    /// Клавіші, відпущені після останнього begin_frame
+    pending_key_releases: HashSet<KeyCode>,
+
+    /// Кнопки миші, натиснуті після останнього begin_frame
+    pending_mouse_presses: HashSet<MouseButton>,
+
+    /// Кнопки миші, відпущені після останнього begin_frame
+    pending_mouse_releases: HashSet<MouseButton>,
+
+    // === Edges поточного кадру (зафіксовані в begin_frame) ===
+    frame_key_presses: HashSet<KeyCode>,
+    frame_key_releases: HashSet<KeyCode>,
+    frame_mouse_presses: HashSet<MouseButton>,
+    frame_mouse_releases: HashSet<MouseButton>,
+
+    /// Кадр replay, що відтворюється (None - живий ввід)
+    replay_frame: Option<ReplayFrame>,
 }
 
 impl InputState {
@@ -103,10 +161,67 @@ impl InputState {
             mouse_left: false,
             mouse_right: false,
             mouse_middle: false,
+            pressed_buttons: HashSet::new(),
             pressed_keys: HashSet::new(),
+            input_map: InputMap::default(),
+            gamepad: Gamepad::new(),
+            pending_key_presses: HashSet::new(),
+            pending_key_releases: HashSet::new(),
+            pending_mouse_presses: HashSet::new(),
+            pending_mouse_releases: HashSet::new(),
+            frame_key_presses: HashSet::new(),
+            frame_key_releases: HashSet::new(),
+            frame_mouse_presses: HashSet::new(),
+            frame_mouse_releases: HashSet::new(),
+            replay_frame: None,
+        }
+    }
+
+    /// InputState з заданими прив'язками (keybindings.toml)
+    pub fn with_input_map(input_map: InputMap) -> Self {
+        Self {
+            input_map,
+            ..Self::new()
         }
     }
 
+    // ========================================================================
+    // FRAME EDGES
+    // ========================================================================
+
+    /// Початок кадру: натискання / відпускання з подій після попереднього
+    /// begin_frame стають edges цього кадру
+    ///
+    /// Викликати рівно раз на кадр (RedrawRequested), до читання input.
+    /// Тут же опитується геймпад.
+    pub fn begin_frame(&mut self) {
+        self.gamepad.poll();
+        self.frame_key_presses = std::mem::take(&mut self.pending_key_presses);
+        self.frame_key_releases = std::mem::take(&mut self.pending_key_releases);
+        self.frame_mouse_presses = std::mem::take(&mut self.pending_mouse_presses);
+        self.frame_mouse_releases = std::mem::take(&mut self.pending_mouse_releases);
+    }
+
+    /// Клавішу натиснуто в цьому кадрі (утримання - лише перший кадр)
+    pub fn just_pressed(&self, key_code: KeyCode) -> bool {
+        self.frame_key_presses.contains(&key_code)
+    }
+
+    /// Клавішу відпущено в цьому кадрі
+    pub fn just_released(&self, key_code: KeyCode) -> bool {
+        self.frame_key_releases.contains(&key_code)
+    }
+
+    /// Кнопку миші натиснуто в цьому кадрі
+    pub fn mouse_just_pressed(&self, button: MouseButton) -> bool {
+        self.frame_mouse_presses.contains(&button)
+    }
+
+    /// Кнопку миші відпущено в цьому кадрі
+    pub fn mouse_just_released(&self, button: MouseButton) -> bool {
+        self.frame_mouse_releases.contains(&button)
+    }
+
     // ========================================================================
     // MOUSE METHODS
     // ========================================================================
@@ -124,6 +239,7 @@ impl InputState {
     }
 
     /// Повертає поточну позицію миші
+    #[allow(dead_code)]
     pub fn mouse_position(&self) -> (f64, f64) {
         self.mouse_position
     }
@@ -137,6 +253,9 @@ impl InputState {
     /// # Повертає
     /// (delta_x, delta_y) в screen space
     pub fn mouse_delta(&self) -> (f64, f64) {
+        if let Some(frame) = &self.replay_frame {
+            return frame.mouse_delta;
+        }
         // Якщо є raw delta - використовуємо його (краще для locked cursor)
         if self.raw_mouse_delta.0.abs() > 0.001 || self.raw_mouse_delta.1.abs() > 0.001 {
             return self.raw_mouse_delta;
@@ -155,6 +274,22 @@ impl InputState {
     pub fn reset_mouse_delta(&mut self) {
         self.previous_mouse_position = self.mouse_position;
         self.raw_mouse_delta = (0.0, 0.0);
+        if let Some(frame) = &mut self.replay_frame {
+            frame.mouse_delta = (0.0, 0.0);
+        }
+    }
+
+    /// Відпускає всі клавіші та кнопки миші і скидає mouse delta
+    ///
+    /// Для втрати фокусу вікна: winit не надсилає відпускання клавіш, які
+    /// відпустили поза вікном. Відпускання стають edges наступного кадру.
+    pub fn release_all(&mut self) {
+        self.pending_key_releases.extend(self.pressed_keys.drain());
+        self.pending_mouse_releases.extend(self.pressed_buttons.drain());
+        self.mouse_left = false;
+        self.mouse_right = false;
+        self.mouse_middle = false;
+        self.reset_mouse_delta();
     }
 
     /// Додає raw mouse motion delta (з DeviceEvent::MouseMotion)
@@ -174,6 +309,13 @@ impl InputState {
     /// * `state` - ElementState (Pressed/Released)
     pub fn update_mouse_button(&mut self, button: MouseButton, state: ElementState) {
         let pressed = state == ElementState::Pressed;
+        if pressed {
+            self.pressed_buttons.insert(button);
+            self.pending_mouse_presses.insert(button);
+        } else {
+            self.pressed_buttons.remove(&button);
+            self.pending_mouse_releases.insert(button);
+        }
 
         match button {
             MouseButton::Left => self.mouse_left = pressed,
@@ -197,10 +339,15 @@ impl InputState {
     pub fn update_key(&mut self, key_code: KeyCode, state: ElementState) {
         match state {
             ElementState::Pressed => {
-                self.pressed_keys.insert(key_code);
+                // Auto-repeat утримуваної клавіші - не нове натискання
+                if self.pressed_keys.insert(key_code) {
+                    self.pending_key_presses.insert(key_code);
+                }
             }
             ElementState::Released => {
-                self.pressed_keys.remove(&key_code);
+                if self.pressed_keys.remove(&key_code) {
+                    self.pending_key_releases.insert(key_code);
+                }
             }
         }
     }
@@ -217,53 +364,126 @@ impl InputState {
     }
 
     // ========================================================================
-    // CONVENIENCE METHODS (для WASD та інших популярних клавіш)
+    // ACTIONS (InputMap)
     // ========================================================================
 
-    /// Перевіряє чи натиснута W (вперед)
-    pub fn is_w_pressed(&self) -> bool {
-        self.is_key_pressed(KeyCode::KeyW)
+    /// Чи утримується кнопка миші
+    pub fn is_mouse_pressed(&self, button: MouseButton) -> bool {
+        self.pressed_buttons.contains(&button)
+    }
+
+    /// Чи утримується хоч одна прив'язка дії
+    pub fn is_action_pressed(&self, action: Action) -> bool {
+        if let Some(frame) = &self.replay_frame {
+            return frame.is_pressed(action);
+        }
+        self.input_map.bindings(action).iter().any(|binding| match *binding {
+            Binding::Key(key_code) => self.is_key_pressed(key_code),
+            Binding::Mouse(button) => self.is_mouse_pressed(button),
+            Binding::Gamepad(button) => self.gamepad.is_pressed(button),
+        })
+    }
+
+    /// Чи натиснуто прив'язку дії в цьому кадрі
+    pub fn just_pressed_action(&self, action: Action) -> bool {
+        if let Some(frame) = &self.replay_frame {
+            return frame.just_pressed(action);
+        }
+        self.input_map.bindings(action).iter().any(|binding| match *binding {
+            Binding::Key(key_code) => self.just_pressed(key_code),
+            Binding::Mouse(button) => self.mouse_just_pressed(button),
+            Binding::Gamepad(button) => self.gamepad.just_pressed(button),
+        })
     }
 
-    /// Перевіряє чи натиснута A (вліво)
-    pub fn is_a_pressed(&self) -> bool {
-        self.is_key_pressed(KeyCode::KeyA)
+    /// Чи відпущено прив'язку дії в цьому кадрі
+    pub fn just_released_action(&self, action: Action) -> bool {
+        if let Some(frame) = &self.replay_frame {
+            return frame.just_released(action);
+        }
+        self.input_map.bindings(action).iter().any(|binding| match *binding {
+            Binding::Key(key_code) => self.just_released(key_code),
+            Binding::Mouse(button) => self.mouse_just_released(button),
+            Binding::Gamepad(button) => self.gamepad.just_released(button),
+        })
     }
 
-    /// Перевіряє чи натиснута S (назад)
-    pub fn is_s_pressed(&self) -> bool {
-        self.is_key_pressed(KeyCode::KeyS)
+    // ========================================================================
+    // AXES (клавіатура / миша + геймпад)
+    // ========================================================================
+
+    /// Лівий стік геймпада (x - вправо, y - вперед); при replay - з кадру
+    pub fn pad_move(&self) -> Vec2 {
+        match &self.replay_frame {
+            Some(frame) => frame.pad_move,
+            None => self.gamepad.left_stick(),
+        }
     }
 
-    /// Перевіряє чи натиснута D (вправо)
-    pub fn is_d_pressed(&self) -> bool {
-        self.is_key_pressed(KeyCode::KeyD)
+    /// Правий стік геймпада (x - вправо, y - вгору); при replay - з кадру
+    pub fn pad_look(&self) -> Vec2 {
+        match &self.replay_frame {
+            Some(frame) => frame.pad_look,
+            None => self.gamepad.right_stick(),
+        }
     }
 
-    /// Перевіряє чи натиснута Space (вгору / jump)
-    pub fn is_space_pressed(&self) -> bool {
-        self.is_key_pressed(KeyCode::Space)
+    /// Вісь руху: x - вправо (strafe), y - вперед
+    ///
+    /// Дії MoveForward / MoveBack / StrafeLeft / StrafeRight плюс лівий стік;
+    /// довжина не більша за 1.
+    pub fn movement_axis(&self) -> Vec2 {
+        let axis = |positive: Action, negative: Action| {
+            let pressed = |action| if self.is_action_pressed(action) { 1.0 } else { 0.0 };
+            pressed(positive) - pressed(negative)
+        };
+        let keys = Vec2::new(
+            axis(Action::StrafeRight, Action::StrafeLeft),
+            axis(Action::MoveForward, Action::MoveBack),
+        );
+        (keys + self.pad_move()).clamp_length_max(1.0)
     }
 
-    /// Перевіряє чи натиснута Shift (вниз / sprint)
-    pub fn is_shift_pressed(&self) -> bool {
-        self.is_key_pressed(KeyCode::ShiftLeft) || self.is_key_pressed(KeyCode::ShiftRight)
+    /// Поворот камери за кадр у пікселях миші (x - вправо, y - вниз)
+    ///
+    /// mouse_delta плюс правий стік × Gamepad::look_speed × `delta` (секунди
+    /// кадру) - чутливість далі однакова для миші та стіка.
+    pub fn camera_axis(&self, delta: f32) -> Vec2 {
+        let (mouse_x, mouse_y) = self.mouse_delta();
+        let look = self.pad_look() * self.gamepad.look_speed * delta;
+        Vec2::new(mouse_x as f32 + look.x, mouse_y as f32 - look.y)
+    }
+
+    // ========================================================================
+    // REPLAY
+    // ========================================================================
+
+    /// Підміняє дії та mouse_delta кадром replay (None - живий ввід)
+    ///
+    /// Викликати після begin_frame, до читання input.
+    pub fn set_replay_frame(&mut self, frame: Option<ReplayFrame>) {
+        self.replay_frame = frame;
     }
 
-    /// Перевіряє чи натиснута Ctrl (special action)
-    pub fn is_ctrl_pressed(&self) -> bool {
-        self.is_key_pressed(KeyCode::ControlLeft) || self.is_key_pressed(KeyCode::ControlRight)
+    /// Кадр replay, що відтворюється
+    pub fn replay_frame(&self) -> Option<&ReplayFrame> {
+        self.replay_frame.as_ref()
     }
 
-    /// Перевіряє чи натиснута Q (поворот вліво)
-    pub fn is_q_pressed(&self) -> bool {
-        self.is_key_pressed(KeyCode::KeyQ)
+    // ========================================================================
+    // MODIFIERS
+    // ========================================================================
+
+    /// Перевіряє чи натиснута Shift (модифікатор: Shift + Pause = вихід)
+    pub fn is_shift_pressed(&self) -> bool {
+        self.is_key_pressed(KeyCode::ShiftLeft) || self.is_key_pressed(KeyCode::ShiftRight)
     }
 
-    /// Перевіряє чи натиснута E (поворот вправо)
-    pub fn is_e_pressed(&self) -> bool {
-        self.is_key_pressed(KeyCode::KeyE)
+    /// Перевіряє чи натиснута Alt (модифікатор: Alt + Enter = fullscreen)
+    pub fn is_alt_pressed(&self) -> bool {
+        self.is_key_pressed(KeyCode::AltLeft) || self.is_key_pressed(KeyCode::AltRight)
     }
+
 }
 
 impl Default for InputState {
@@ -271,3 +491,111 @@ impl Default for InputState {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Кадри, на яких спрацювали just_pressed / just_released: події
+    /// `events(frame)` надходять перед begin_frame кадру `frame`
+    fn edge_frames(
+        frames: usize,
+        mut events: impl FnMut(&mut InputState, usize),
+        edges: impl Fn(&InputState) -> (bool, bool),
+    ) -> (Vec<usize>, Vec<usize>) {
+        let mut input = InputState::new();
+        let (mut presses, mut releases) = (Vec::new(), Vec::new());
+        for frame in 0..frames {
+            events(&mut input, frame);
+            input.begin_frame();
+            let (pressed, released) = edges(&input);
+            if pressed {
+                presses.push(frame);
+            }
+            if released {
+                releases.push(frame);
+            }
+        }
+        (presses, releases)
+    }
+
+    #[test]
+    fn key_edges_fire_once_through_press_hold_release() {
+        let key = KeyCode::KeyF;
+        // Кадр 0 - натискання, 1..=5 - утримання з auto-repeat, 6 - відпускання
+        let (presses, releases) = edge_frames(
+            10,
+            |input, frame| match frame {
+                0..=5 => input.update_key(key, ElementState::Pressed),
+                6 => input.update_key(key, ElementState::Released),
+                _ => {}
+            },
+            |input| (input.just_pressed(key), input.just_released(key)),
+        );
+        assert_eq!(presses, [0]);
+        assert_eq!(releases, [6]);
+    }
+
+    #[test]
+    fn mouse_edges_fire_once_through_press_hold_release() {
+        let button = MouseButton::Left;
+        // Кадр 0 - натискання, 1..=5 - утримання, 6 - відпускання,
+        // 8 - клік коротший за кадр (обидва edges в одному кадрі)
+        let (presses, releases) = edge_frames(
+            10,
+            |input, frame| match frame {
+                0 => input.update_mouse_button(button, ElementState::Pressed),
+                1..=5 => assert!(input.is_mouse_pressed(button)),
+                6 => input.update_mouse_button(button, ElementState::Released),
+                8 => {
+                    input.update_mouse_button(button, ElementState::Pressed);
+                    input.update_mouse_button(button, ElementState::Released);
+                }
+                _ => {}
+            },
+            |input| (input.mouse_just_pressed(button), input.mouse_just_released(button)),
+        );
+        assert_eq!(presses, [0, 8]);
+        assert_eq!(releases, [6, 8]);
+    }
+
+    #[test]
+    fn dodge_just_pressed_fires_once_per_press() {
+        let mut input = InputState::new();
+        input.update_key(KeyCode::KeyC, ElementState::Pressed);
+        input.begin_frame();
+        assert!(input.just_pressed_action(Action::Dodge));
+        assert!(input.is_action_pressed(Action::Dodge));
+
+        // Утримання з auto-repeat - не нове натискання
+        input.update_key(KeyCode::KeyC, ElementState::Pressed);
+        input.begin_frame();
+        assert!(!input.just_pressed_action(Action::Dodge));
+        assert!(input.is_action_pressed(Action::Dodge));
+
+        input.update_key(KeyCode::KeyC, ElementState::Released);
+        input.begin_frame();
+        assert!(input.just_released_action(Action::Dodge));
+        assert!(!input.is_action_pressed(Action::Dodge));
+
+        input.update_key(KeyCode::KeyC, ElementState::Pressed);
+        input.begin_frame();
+        assert!(input.just_pressed_action(Action::Dodge));
+    }
+
+    #[test]
+    fn rebound_dodge_ignores_default_key() {
+        let mut map = InputMap::default();
+        map.bind(Action::Dodge, vec![Binding::Mouse(MouseButton::Back)]);
+        let mut input = InputState::with_input_map(map);
+
+        input.update_key(KeyCode::KeyC, ElementState::Pressed);
+        input.begin_frame();
+        assert!(!input.just_pressed_action(Action::Dodge));
+
+        input.update_mouse_button(MouseButton::Back, ElementState::Pressed);
+        input.begin_frame();
+        assert!(input.just_pressed_action(Action::Dodge));
+        assert!(input.is_action_pressed(Action::Dodge));
+    }
+}
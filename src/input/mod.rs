@@ -4,26 +4,38 @@
 ═══════════════════════════════════════════════════════════════════════════════
 
 📋 ПРИЗНАЧЕННЯ:
-   Модуль input - відповідає за обробку введення з клавіатури та миші.
+   Модуль input - відповідає за обробку введення з клавіатури, миші та геймпада.
 
 🎯 ВІДПОВІДАЛЬНІСТЬ:
    - Експорт InputState для використання в main.rs
+   - Прив'язки клавіш (InputMap: Action → клавіші / кнопки миші)
    - Організація input компонентів
 
 🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
    Імпортує:
    - input_state.rs - структура для зберігання стану input
+   - input_map.rs - Action, Binding, InputMap (keybindings.toml)
+   - replay.rs - запис / відтворення вводу (ReplayRecorder, Replay)
+   - gamepad.rs - геймпад через gilrs (Gamepad)
 
    Експортує для:
-   - main.rs - InputState struct
+   - main.rs - InputState struct, Action, InputMap
 
 🕐 ІСТОРІЯ:
    2025-12-14: Створено модуль для input підсистеми
+   2026-10-16: input_map - перепризначувані прив'язки дій
+   2026-10-16: replay - детермінований запис / відтворення вводу
+   2026-10-16: gamepad - геймпад (gilrs)
 
 ═══════════════════════════════════════════════════════════════════════════════
 */
 
+pub mod gamepad;
 pub mod input_state;
+pub mod input_map;
+pub mod replay;
 
-// Реєкспортуємо InputState для зручності
+// Реєкспортуємо для зручності
 pub use input_state::InputState;
+pub use input_map::{Action, InputMap};
+pub use replay::{Replay, ReplayFrame, ReplayRecorder};
@@ -0,0 +1,408 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/input/replay.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   Replay - запис вводу гравця по кадрах та його відтворення.
+
+   "Вибух" ragdoll, що трапився раз, можна зберегти (F9) і програти знову
+   (`--replay <file>`): той самий ввід + ті самі delta кадрів = ті самі
+   кроки фізики і та сама поза кісток.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - ReplayFrame: delta кадру, рух миші, стіки геймпада, стан дій
+     (утримання та edges), номер кроку фізики на початку кадру
+   - ReplayRecorder: кадри з початку гри, збереження у файл
+   - Replay: завантаження файлу, кадри по черзі
+   - replay_path_from_args: `--replay <file>` / `--replay=<file>`
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Імпортує:
+   - input_state.rs - InputState (стан дій кадру для запису)
+   - input_map.rs - Action (біт дії = порядковий номер варіанту)
+
+   Експортує для:
+   - input_state.rs - кадр replay замість живого вводу
+   - main.rs - запис кожного кадру гри, F9, --replay
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+   - Пишеться ввід, а не стан світу: відтворення збігається лише з тими
+     самими settings.ini та --arena-size (стартовий стан - як при записі)
+   - delta - реальна (до time scale): time scale теж змінюється діями
+   - Кадри паузи не пишуться; Action::Pause, Action::DebugSaveReplay та
+     Action::Fullscreen не пишуться (відтворення не ставить себе на паузу,
+     не зберігає себе і не змінює вікно)
+   - Формат файлу: little-endian, REPLAY_MAGIC, версія, кількість кадрів,
+     далі кадри по FRAME_SIZE байт
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - запис та відтворення вводу
+   2026-10-16: Версія 2 - стіки геймпада (pad_move, pad_look)
+   2026-10-17: Тести - формат файлу та детермінізм відтворення Simulation
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use std::path::{Path, PathBuf};
+
+use glam::Vec2;
+
+use super::input_map::Action;
+use super::input_state::InputState;
+
+/// Сигнатура файлу replay
+const REPLAY_MAGIC: &[u8; 4] = b"ACRP";
+
+/// Версія формату (змінюється разом з ReplayFrame)
+const REPLAY_VERSION: u32 = 2;
+
+/// Розмір заголовка: magic + версія + кількість кадрів
+const HEADER_SIZE: usize = 12;
+
+/// Розмір кадру у файлі (байт)
+const FRAME_SIZE: usize = 56;
+
+/// Дії, які не пишуться в replay
+const UNRECORDED_ACTIONS: [Action; 3] = [Action::Pause, Action::DebugSaveReplay, Action::Fullscreen];
+
+// Біти дій - u32
+const _: () = assert!(Action::ALL.len() <= 32);
+
+/// Біт дії в масках ReplayFrame
+fn action_bit(action: Action) -> u32 {
+    1 << action as u32
+}
+
+/// Ввід одного кадру гри
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ReplayFrame {
+    /// Реальна delta кадру (секунди, до time scale)
+    pub delta: f32,
+
+    /// Рух миші за кадр
+    pub mouse_delta: (f64, f64),
+
+    /// Маска утримуваних дій
+    pub pressed: u32,
+
+    /// Маска дій, натиснутих в цьому кадрі
+    pub just_pressed: u32,
+
+    /// Маска дій, відпущених в цьому кадрі
+    pub just_released: u32,
+
+    /// Кроків фізики на початку кадру (перевірка розходження)
+    pub physics_step: u64,
+
+    /// Лівий / правий стік геймпада (після deadzone)
+    pub pad_move: Vec2,
+    pub pad_look: Vec2,
+}
+
+impl ReplayFrame {
+    /// Знімає ввід кадру (після begin_frame, до reset_mouse_delta)
+    pub fn capture(input: &InputState, delta: f32, physics_step: u64) -> Self {
+        let mask = |query: &dyn Fn(Action) -> bool| {
+            Action::ALL
+                .into_iter()
+                .filter(|action| !UNRECORDED_ACTIONS.contains(action) && query(*action))
+                .fold(0, |mask, action| mask | action_bit(action))
+        };
+        Self {
+            delta,
+            mouse_delta: input.mouse_delta(),
+            pressed: mask(&|action| input.is_action_pressed(action)),
+            just_pressed: mask(&|action| input.just_pressed_action(action)),
+            just_released: mask(&|action| input.just_released_action(action)),
+            physics_step,
+            pad_move: input.pad_move(),
+            pad_look: input.pad_look(),
+        }
+    }
+
+    pub fn is_pressed(&self, action: Action) -> bool {
+        self.pressed & action_bit(action) != 0
+    }
+
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.just_pressed & action_bit(action) != 0
+    }
+
+    pub fn just_released(&self, action: Action) -> bool {
+        self.just_released & action_bit(action) != 0
+    }
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.delta.to_le_bytes());
+        bytes.extend_from_slice(&self.mouse_delta.0.to_le_bytes());
+        bytes.extend_from_slice(&self.mouse_delta.1.to_le_bytes());
+        bytes.extend_from_slice(&self.pressed.to_le_bytes());
+        bytes.extend_from_slice(&self.just_pressed.to_le_bytes());
+        bytes.extend_from_slice(&self.just_released.to_le_bytes());
+        bytes.extend_from_slice(&self.physics_step.to_le_bytes());
+        for value in [self.pad_move.x, self.pad_move.y, self.pad_look.x, self.pad_look.y] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+
+    fn read(bytes: &[u8; FRAME_SIZE]) -> Self {
+        let u32_at = |at: usize| u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap());
+        let u64_at = |at: usize| u64::from_le_bytes(bytes[at..at + 8].try_into().unwrap());
+        let f32_at = |at: usize| f32::from_bits(u32_at(at));
+        Self {
+            delta: f32_at(0),
+            mouse_delta: (f64::from_bits(u64_at(4)), f64::from_bits(u64_at(12))),
+            pressed: u32_at(20),
+            just_pressed: u32_at(24),
+            just_released: u32_at(28),
+            physics_step: u64_at(32),
+            pad_move: Vec2::new(f32_at(40), f32_at(44)),
+            pad_look: Vec2::new(f32_at(48), f32_at(52)),
+        }
+    }
+}
+
+/// Запис кадрів з початку гри
+#[derive(Debug, Default)]
+pub struct ReplayRecorder {
+    frames: Vec<ReplayFrame>,
+}
+
+impl ReplayRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Додає кадр гри
+    pub fn record(&mut self, frame: ReplayFrame) {
+        self.frames.push(frame);
+    }
+
+    /// Кількість записаних кадрів
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Зберігає всі кадри у файл (директорія створюється)
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir).map_err(|e| format!("{:?}: {}", dir, e))?;
+        }
+
+        std::fs::write(path, self.to_bytes()).map_err(|e| format!("{:?}: {}", path, e))
+    }
+
+    /// Вміст файлу replay (Replay::parse читає його назад)
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_SIZE + self.frames.len() * FRAME_SIZE);
+        bytes.extend_from_slice(REPLAY_MAGIC);
+        bytes.extend_from_slice(&REPLAY_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&(self.frames.len() as u32).to_le_bytes());
+        for frame in &self.frames {
+            frame.write(&mut bytes);
+        }
+        bytes
+    }
+}
+
+/// Відтворення записаного вводу
+#[derive(Debug)]
+pub struct Replay {
+    frames: Vec<ReplayFrame>,
+
+    /// Наступний кадр
+    cursor: usize,
+}
+
+impl Replay {
+    /// Завантажує replay з файлу
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path).map_err(|e| format!("{:?}: {}", path, e))?;
+        Self::parse(&bytes).map_err(|e| format!("{:?}: {}", path, e))
+    }
+
+    /// Розбирає вміст файлу replay
+    pub fn parse(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.len() < HEADER_SIZE || &bytes[0..4] != REPLAY_MAGIC {
+            return Err("не файл replay".to_string());
+        }
+        let version = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        if version != REPLAY_VERSION {
+            return Err(format!("версія {} (підтримується {})", version, REPLAY_VERSION));
+        }
+        let count = u32::from_le_bytes(bytes[8..12].try_into().unwrap()) as usize;
+        let body = &bytes[HEADER_SIZE..];
+        if body.len() != count * FRAME_SIZE {
+            return Err(format!("{} байт кадрів замість {}", body.len(), count * FRAME_SIZE));
+        }
+
+        let frames = body
+            .chunks_exact(FRAME_SIZE)
+            .map(|chunk| ReplayFrame::read(chunk.try_into().unwrap()))
+            .collect();
+        Ok(Self { frames, cursor: 0 })
+    }
+
+    /// Наступний кадр (None - replay скінчився)
+    pub fn next_frame(&mut self) -> Option<ReplayFrame> {
+        let frame = self.frames.get(self.cursor).copied()?;
+        self.cursor += 1;
+        Some(frame)
+    }
+
+    /// Номер наступного кадру
+    pub fn position(&self) -> usize {
+        self.cursor
+    }
+
+    /// Кількість кадрів
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+}
+
+/// Шлях replay з аргументів (`--replay <file>` або `--replay=<file>`)
+pub fn replay_path_from_args(args: impl IntoIterator<Item = String>) -> Option<PathBuf> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if let Some(path) = arg.strip_prefix("--replay=") {
+            return Some(PathBuf::from(path));
+        }
+        if arg == "--replay" {
+            return args.next().map(PathBuf::from);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec3;
+
+    use crate::arena::ArenaConfig;
+    use crate::settings::Settings;
+    use crate::sim::{SimInput, Simulation};
+
+    /// Кадрів запису (~4 секунди при нерівних delta)
+    const FRAMES: usize = 240;
+
+    /// Синтетичний ввід: вперед, стрейф, спринт і стрибок на різних кадрах
+    fn scripted_frame(index: usize) -> ReplayFrame {
+        let mut pressed = action_bit(Action::MoveForward);
+        if (60..120).contains(&index) {
+            pressed |= action_bit(Action::StrafeRight) | action_bit(Action::Sprint);
+        }
+        let just_pressed = if index == 150 { action_bit(Action::Jump) } else { 0 };
+        ReplayFrame {
+            // Нерівні кадри - залишок accumulator теж має відтворитись
+            delta: [1.0 / 60.0, 1.0 / 45.0, 1.0 / 144.0][index % 3],
+            mouse_delta: (index as f64 * 0.5, -1.0),
+            pressed,
+            just_pressed,
+            just_released: 0,
+            physics_step: 0,
+            pad_move: Vec2::ZERO,
+            pad_look: Vec2::ZERO,
+        }
+    }
+
+    /// Ввід Simulation з кадру (як у main.rs: рух у площині XZ)
+    fn sim_input(frame: &ReplayFrame) -> SimInput {
+        let mut input = InputState::new();
+        input.set_replay_frame(Some(*frame));
+        let axis = input.movement_axis();
+        let sprinting = input.is_action_pressed(Action::Sprint);
+        SimInput {
+            move_dir: Vec3::new(axis.x, 0.0, -axis.y),
+            speed_scale: if sprinting { 1.5 } else { 1.0 },
+            sprinting,
+            jump: input.just_pressed_action(Action::Jump),
+            player_position: None,
+        }
+    }
+
+    fn new_simulation() -> Simulation {
+        let settings = Settings::parse("[enemies]\ncount = 2\n");
+        Simulation::from_settings(&settings, &ArenaConfig::default())
+    }
+
+    /// Позиції та повороти всіх тіл світу (порядок handles стабільний)
+    fn body_transforms(sim: &Simulation) -> Vec<[f32; 7]> {
+        sim.physics_world
+            .rigid_body_set
+            .iter()
+            .map(|(_, body)| {
+                let t = body.translation();
+                let r = body.rotation();
+                [t.x, t.y, t.z, r.i, r.j, r.k, r.w]
+            })
+            .collect()
+    }
+
+    /// Відтворює replay; перевіряє кроки фізики на початку кожного кадру
+    fn play(mut replay: Replay) -> (u64, Vec<[f32; 7]>) {
+        let mut sim = new_simulation();
+        while let Some(frame) = replay.next_frame() {
+            assert_eq!(sim.stepper.step_count(), frame.physics_step, "кадр {}", replay.position() - 1);
+            let ticks = sim.stepper.steps_for(frame.delta);
+            sim.advance(ticks, &sim_input(&frame));
+        }
+        (sim.stepper.step_count(), body_transforms(&sim))
+    }
+
+    #[test]
+    fn file_round_trip() {
+        let mut recorder = ReplayRecorder::new();
+        for index in 0..5 {
+            recorder.record(ReplayFrame {
+                physics_step: index as u64 * 2,
+                pad_move: Vec2::new(0.25, -0.5),
+                ..scripted_frame(index)
+            });
+        }
+        let mut replay = Replay::parse(&recorder.to_bytes()).unwrap();
+        assert_eq!(replay.frame_count(), 5);
+        for expected in &recorder.frames {
+            assert_eq!(replay.next_frame().as_ref(), Some(expected));
+        }
+        assert_eq!(replay.next_frame(), None);
+    }
+
+    #[test]
+    fn rejects_foreign_and_truncated_files() {
+        assert!(Replay::parse(b"PNG\0whatever").is_err());
+        let mut recorder = ReplayRecorder::new();
+        recorder.record(scripted_frame(0));
+        let bytes = recorder.to_bytes();
+        assert!(Replay::parse(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn replay_is_deterministic() {
+        // Запис: живий прогін, номер кроку на початку кожного кадру
+        let mut sim = new_simulation();
+        let mut recorder = ReplayRecorder::new();
+        for index in 0..FRAMES {
+            let frame = ReplayFrame {
+                physics_step: sim.stepper.step_count(),
+                ..scripted_frame(index)
+            };
+            let ticks = sim.stepper.steps_for(frame.delta);
+            sim.advance(ticks, &sim_input(&frame));
+            recorder.record(frame);
+        }
+        let recorded = (sim.stepper.step_count(), body_transforms(&sim));
+        assert!(recorded.0 > 200, "кроків: {}", recorded.0);
+
+        // Два відтворення з файлу - ті самі кроки та ті самі тіла, що й запис
+        let bytes = recorder.to_bytes();
+        let first = play(Replay::parse(&bytes).unwrap());
+        let second = play(Replay::parse(&bytes).unwrap());
+        assert_eq!(first, second);
+        assert_eq!(first, recorded);
+    }
+}
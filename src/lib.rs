@@ -0,0 +1,41 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/lib.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   Бібліотека arena_combat - все, що працює без вікна та GPU: фізика,
+   бій, вороги, гравець, камера, час, налаштування, Simulation.
+
+   main.rs (вікно, рендер, камера, ввід) підключає її як `arena_combat::*`,
+   integration тести в tests/ - так само.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Оголошення headless модулів
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Експортує для:
+   - main.rs - sim, physics, combat, enemy, player, camera, time, settings, ...
+   - tests/ - Simulation та fixed-step прогони
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+   - Тут тільки модулі без winit / wgpu - інакше тести потягнуть вікно
+
+🕐 ІСТОРІЯ:
+   2026-10-17: Створено - headless модулі з main.rs для tests/
+   2026-10-17: camera, player - теж без вікна
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+pub mod arena;
+pub mod camera;
+pub mod combat;
+pub mod debug_log;
+pub mod enemy;
+pub mod physics;
+pub mod player;
+pub mod settings;
+pub mod sim;
+pub mod time;
+pub mod transform;
@@ -42,9 +42,9 @@
    ```
 
    Очікуваний результат:
-   - Відкривається вікно 800x600
+   - Відкривається вікно 800x600 ([window] width / height у settings.ini)
    - Заголовок "Arena Combat Prototype"
-   - Вікно можна закрити через ESC або [X]
+   - Вікно можна закрити через Shift+ESC або [X] (ESC - пауза)
 
 📝 ПРИКЛАД ВИКОРИСТАННЯ:
    ```bash
@@ -58,89 +58,886 @@
 🕐 ІСТОРІЯ:
    2025-12-11: Створено базову структуру - вікно + event loop
    2025-12-14: Додано wgpu renderer з очищенням екрану темно-синім кольором
+   2026-10-16: Заголовок вікна з шаблону settings.ini (WindowInfo)
+   2026-10-16: Висота grid з settings.ini ([rendering] grid_y_offset)
+   2026-10-16: F3/F4 - debug осі кісток та швидкості ragdoll
+   2026-10-16: ArenaConfig - єдиний розмір землі, grid та стін
+   2026-10-16: Aim assist для атаки ([combat] в settings.ini)
+   2026-10-16: Фізика з фіксованим кроком + інтерполяція скелета
+   2026-10-16: F5 - reset ragdoll в A-pose без перезапуску
+   2026-10-16: Межі pitch камери з settings.ini ([camera])
+   2026-10-16: Knockback ворогів від ударів
+   2026-10-16: Clip planes камери з розміру арени
+   2026-10-16: Ctrl - crouch (нижча камера та висота атаки)
+   2026-10-16: Профіль жорсткості ragdoll з settings.ini ([physics])
+   2026-10-16: Удари кінцівок ragdoll → пил + звук (поки тільки лог)
+   2026-10-16: Адаптивна якість за frame time ([quality] в settings.ini)
+   2026-10-16: ESC - пауза (GameState), Shift+ESC - вихід
+   2026-10-16: AI ворогів - переслідування та атака гравця ([enemies])
+   2026-10-16: Здоров'я гравця, смерть (ragdoll, без керування), R - рестарт
+   2026-10-16: Поріг падіння ragdoll та авто-підйом з [physics]
+   2026-10-16: Hitbox меча ведеться Combat (фаза Action), а не спавниться при кліку
+   2026-10-16: Foot IK ходьби ragdoll ([physics] foot_ik)
+   2026-10-16: Буфер атаки - клік під час атаки / cooldown стартує пізніше
+   2026-10-16: ПКМ - важка атака (AttackKind::Heavy)
+   2026-10-16: Рука зі зброєю не розмахується при ходьбі під час атаки
+   2026-10-16: Удар ворога відкидає кістку ragdoll гравця (react_to_hit)
+   2026-10-16: Напрямок удару з руху миші перед кліком (SwingSampler)
+   2026-10-16: ПКМ - блок / парирування, важка атака - утримання ЛКМ
+   2026-10-16: Колізії hitbox'ів гравця з ворогами - HitboxManager::check_collisions
+   2026-10-16: Шкода меча (physics гравець) залежить від швидкості вістря
+   2026-10-16: Вороги з ActiveRagdoll ([enemies] ragdoll) - крок фізики та рендер скелетів
+   2026-10-16: Удар меча по кістці ragdoll ворога - множник шкоди та імпульс у кістку
+   2026-10-16: Утримання ЛКМ заряджає важку атаку, відпускання - удар
+   2026-10-16: Ragdoll гравця ходить кроками ([physics] stepping, start_active)
+   2026-10-16: Shift - спринт, атаки та спринт витрачають витривалість
+   2026-10-16: Під час підйому ragdoll з землі рух не передається
+   2026-10-16: 1 / 2 / 3 - зміна зброї (кинджал / меч / дворучний меч)
+   2026-10-16: Фізичний меч у руці ragdoll гравця, лог контактів леза з кістками
+   2026-10-16: Collision events кадру - контакти леза через події, лог сильного удару головою
+   2026-10-16: Нокдаун та шкода від жорсткого приземлення ([physics] knockdown_*)
+   2026-10-16: Скелети ворогів червоніють під час замаху атаки
+   2026-10-16: Смуги здоров'я ворогів ([rendering] health_bar_hide_when_full)
+   2026-10-16: Стан локомоції рагдолу - спринт (Run) та стрейф
+   2026-10-16: Стрибок ragdoll гравця на Space ([physics] jump_speed)
+   2026-10-16: Атака (ЛКМ) та стрибок через edges InputState (begin_frame) в update
+   2026-10-16: MSAA сцени ([rendering] msaa_samples)
+   2026-10-16: Керування через дії (InputMap, keybindings.toml) замість KeyCode
+   2026-10-16: Колізія third person камери (sphere-cast, плавне відновлення)
+   2026-10-16: Lock-on на ворога (Action::LockOn, LockOnTarget, маркер над ціллю)
+   2026-10-16: Кольори неба з settings.ini ([rendering] sky_*_color)
+   2026-10-16: F6 - wireframe mesh та скелетів (Action::DebugWireframe)
+   2026-10-16: F7 - free-fly debug камера ([FREECAM] в заголовку, ввід гравця призупинено)
+   2026-10-16: F3 - debug HUD (FPS, фізика, бій), осі кісток ragdoll - на F2
+   2026-10-16: V - камера від першої / третьої особи (CameraMode)
+   2026-10-16: Hitstop при влучанні (бій / фізика / вороги на world_delta), спалах ворога
+   2026-10-16: Slow-mo - [ / ] змінюють time scale; камера, ввід та UI на real_delta
+   2026-10-16: F9 - зберегти replay вводу (debug/replay_*.bin), --replay <file> - відтворення
+   2026-10-16: Тряска камери при влучанні гравця та по гравцю (camera.shake_*)
+   2026-10-16: Фізика (світ, ragdoll, вороги) - в Simulation (sim.rs), --headless <ticks>
+   2026-10-16: Світло сцени з settings.ini (rendering.light_*, ambient, sun_rotation)
+   2026-10-16: Стіни арени видно, вороги не виходять за них; F8 - debug grid
+   2026-10-16: Present mode ([rendering] present_mode, F10) та max_fps (FrameLimiter)
+   2026-10-16: F12 - screenshot кадру в debug/
+   2026-10-16: Вікно, курсор, чутливість миші та show_skeleton з settings.ini;
+               debug перемикачі та present mode зберігаються при виході
+   2026-10-16: Borderless fullscreen (F11 / Alt+Enter), розмір вікна відновлюється
+   2026-10-16: Геймпад (gilrs): лівий стік - рух, правий - камера, кнопки - дії
+   2026-10-16: Втрата фокусу вікна - світ стоїть, курсор і клавіші відпускаються
+   2026-10-17: Hitbox меча від ragdoll гравця (player_swing_origin), а не від кінематичного
+   2026-10-17: Headless модулі (sim, physics, combat, player, camera, time, ...) - в lib.rs
+   2026-10-17: Контакти леза з кістками ворогів - влучання замаху (apply_player_hit)
+   2026-10-17: AI ворогів та час життя hitbox'ів - в Simulation::advance (кроки кадру)
 
 ═══════════════════════════════════════════════════════════════════════════════
 */
 
 mod rendering;
 mod fps_counter;
-mod camera;
 mod input;
-mod transform;
-mod time;
-mod player;
-mod combat;
-mod enemy;
-mod physics;
-pub mod debug_log;
-
-use rendering::WgpuRenderer;
+mod window_info;
+mod particles;
+mod quality;
+
+// Headless частина (фізика, бій, гравець, камера, час) - бібліотека arena_combat
+use arena_combat::{arena, camera, combat, debug_log, enemy, physics, player, settings, sim, time, transform};
+
+use rendering::{WgpuRenderer, OutlineTarget, RagdollDebug};
 use fps_counter::FpsCounter;
-use input::InputState;
-use time::GameTime;
+use input::{Action, InputMap, InputState, Replay, ReplayFrame, ReplayRecorder};
+use time::{FrameLimiter, GameTime};
+use camera::{CameraMode, LockOnTarget};
 use player::Player;
-use combat::{Combat, HitboxManager};
-use enemy::Enemy;
-use physics::{PhysicsWorld, ActiveRagdoll};
+use combat::{Combat, AttackDirection, AttackKind, HitboxHit, HitboxOwner, HitOutcome, AimAssist, Hitstop, SwingSampler};
+use physics::ImpactEvent;
+use particles::ParticleSystem;
+use quality::QualityController;
+use settings::Settings;
+use arena::ArenaConfig;
+use window_info::{WindowInfo, TitleStats};
+use sim::{Simulation, SimInput, RAGDOLL_SPAWN_HEIGHT};
 use std::sync::Arc;
 use winit::{
     application::ApplicationHandler,
-    event::{WindowEvent, MouseButton, ElementState},
+    event::WindowEvent,
     event_loop::{ActiveEventLoop, ControlFlow, EventLoop},
-    keyboard::{PhysicalKey, KeyCode},
-    window::{Window, WindowId, CursorGrabMode},
+    keyboard::{KeyCode, PhysicalKey},
+    window::{Fullscreen, Window, WindowId, CursorGrabMode},
 };
 
+/// Розмір вікна за замовчуванням (логічні пікселі, [window] width / height)
+const DEFAULT_WINDOW_SIZE: (u32, u32) = (800, 600);
+
+/// Чутливість миші за замовчуванням (радіан на піксель, [camera] mouse_sensitivity)
+const DEFAULT_MOUSE_SENSITIVITY: f32 = 0.003;
+
+/// Початкова швидкість відштовхування ворога від удару (units/second)
+const ENEMY_KNOCKBACK_SPEED: f32 = 4.0;
+
+/// Радіус гравця для hitbox'ів ворогів
+const PLAYER_HIT_RADIUS: f32 = 0.4;
+
+/// Імпульс удару ворога по ragdoll гравця (N·s)
+const PLAYER_HIT_IMPULSE: f32 = 15.0;
+
+/// Імпульс удару меча по кістці ragdoll ворога (N·s)
+const ENEMY_HIT_IMPULSE: f32 = 15.0;
+
+/// Розмах правої руки (зі зброєю) під час атаки (радіани)
+const WEAPON_ARM_SWING: f32 = 0.05;
+
+/// Утримання ЛКМ довше за цей час (секунди) - заряд важкої атаки замість легкої
+const HEAVY_HOLD_TIME: f32 = 0.25;
+
+/// Множник швидкості руху в спринті (Shift, витрачає витривалість)
+const SPRINT_SPEED_SCALE: f32 = 1.6;
+
+/// Тряска камери, коли удар гравця влучає (trauma, 0..1)
+const HIT_SHAKE_TRAUMA: f32 = 0.25;
+
+/// Тряска камери, коли ворог влучає по гравцю (trauma, 0..1)
+const PLAYER_HIT_SHAKE_TRAUMA: f32 = 0.5;
+
+/// Тривалість плавної зміни time scale клавішами [ / ] (секунди реального часу)
+const TIME_SCALE_RAMP: f32 = 0.25;
+
+/// Стартова позиція кінематичного гравця (трохи попереду центру)
+const PLAYER_START_POSITION: glam::Vec3 = glam::Vec3::new(0.0, 0.0, 5.0);
+
 // ============================================================================
 // APPLICATION STATE
 // ============================================================================
 
+/// Реакція на удар кінцівки: пил + звук
+///
+/// Аудіо підсистеми ще немає - "thud" поки тільки логується з гучністю,
+/// пропорційною швидкості удару (ImpactEvent::intensity).
+fn play_impact(impact: &ImpactEvent, particles: &mut ParticleSystem) {
+    let intensity = impact.intensity();
+    particles.spawn_dust(impact.position, intensity);
+    log::debug!(
+        "Thud: {:?} speed={:.2} m/s force={:.0} N volume={:.2}",
+        impact.bone, impact.speed, impact.force, intensity
+    );
+}
+
+/// Стан гри
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GameState {
+    /// Звичайна гра - всі системи оновлюються
+    Playing,
+    /// Пауза - час, фізика та бій заморожені, renderer малює останній кадр
+    Paused,
+}
+
+/// Захоплює / відпускає курсор (FPS-style керування камерою)
+fn set_cursor_captured(window: &Window, captured: bool) {
+    if captured {
+        if let Err(e) = window.set_cursor_grab(CursorGrabMode::Confined) {
+            log::warn!("Не вдалося захопити курсор (Confined): {:?}", e);
+            // Спробуємо Locked як fallback
+            if let Err(e2) = window.set_cursor_grab(CursorGrabMode::Locked) {
+                log::warn!("Не вдалося захопити курсор (Locked): {:?}", e2);
+            }
+        }
+    } else if let Err(e) = window.set_cursor_grab(CursorGrabMode::None) {
+        log::warn!("Не вдалося відпустити курсор: {:?}", e);
+    }
+    window.set_cursor_visible(!captured);
+}
+
 /// Головна структура додатку
 struct App {
     window: Option<Arc<Window>>,
     renderer: Option<WgpuRenderer>,
     fps_counter: FpsCounter,
+    quality: QualityController,
+    window_info: WindowInfo,
+    game_state: GameState,
+    settings: Settings,
+
+    /// Чутливість миші (радіан на піксель) та інверсія осі Y ([camera])
+    mouse_sensitivity: f32,
+    invert_y: bool,
+
+    /// Захоплювати курсор під час гри ([window] cursor_grab)
+    cursor_grab: bool,
+
+    /// Вікно у фокусі; без фокусу світ стоїть, курсор відпущено
+    focused: bool,
+
+    /// Розмір вікна до fullscreen - відновлюється при виході з нього
+    windowed_size: Option<winit::dpi::PhysicalSize<u32>>,
     input_state: InputState,
     game_time: GameTime,
+    /// Обмеження частоти кадрів ([rendering] max_fps)
+    frame_limiter: FrameLimiter,
     player: Player,
     combat: Combat,
-    hitbox_manager: HitboxManager,
-    enemies: Vec<Enemy>,
+    aim_assist: AimAssist,
+
+    /// Зупинка світу при влучанні гравця
+    hitstop: Hitstop,
+
+    /// Ввід усіх кадрів гри (F9 - зберегти у файл)
+    replay_recorder: ReplayRecorder,
+
+    /// Відтворення replay (`--replay <file>`), None - живий ввід
+    replay: Option<Replay>,
+
+    /// Розходження фізики з replay вже залоговано
+    replay_diverged: bool,
+
+    /// Захоплена ціль (lock-on)
+    lock_on: LockOnTarget,
+
+    /// Рух миші перед кліком - напрямок удару
+    swing_sampler: SwingSampler,
+
+    /// Утримання ЛКМ: (скільки секунд, напрямок удару на момент натискання)
+    attack_hold: Option<(f32, AttackDirection)>,
     enemies_spawned: bool,
 
-    // Physics-based ragdoll
-    physics_world: Option<PhysicsWorld>,
-    ragdoll: Option<ActiveRagdoll>,
+    /// Фізика: світ, ragdoll гравця, вороги з AI, hitbox'и (без вікна - sim.rs)
+    sim: Simulation,
+
+    /// Ввід ragdoll з цього кадру - для кроків наступного (sim.advance)
+    sim_input: SimInput,
     use_physics_player: bool,
+
+    // Удари кінцівок ragdoll → пил та звук
+    particles: ParticleSystem,
+}
+
+impl App {
+    /// Чи має курсор бути захоплений (гра йде, вікно у фокусі, [window] cursor_grab)
+    fn wants_cursor_captured(&self) -> bool {
+        self.game_state == GameState::Playing && self.focused && self.cursor_grab
+    }
+
+    /// Фокус вікна змінився (WindowEvent::Focused)
+    ///
+    /// Втрата: курсор відпускається, клавіші "відпускаються" (winit не
+    /// надішле відпускання поза вікном), ігровий час стоїть. Повернення:
+    /// курсор захоплюється знову, годинник і mouse delta скидаються - перший
+    /// кадр після фокусу має delta ≈ 0, камера не смикається. Replay
+    /// відтворюється й без фокусу (ввід не живий).
+    fn set_focused(&mut self, focused: bool) {
+        if self.focused == focused || self.replay.is_some() {
+            return;
+        }
+        self.focused = focused;
+        if focused {
+            if self.game_state == GameState::Playing {
+                self.game_time.resume();
+            }
+            self.input_state.reset_mouse_delta();
+        } else {
+            self.game_time.pause();
+            self.input_state.release_all();
+        }
+        if let Some(window) = &self.window {
+            set_cursor_captured(window, self.wants_cursor_captured());
+        }
+        log::info!("Фокус вікна: {}", focused);
+    }
+
+    /// Перемикає borderless fullscreen (F11 / Alt+Enter)
+    ///
+    /// Розмір вікна до fullscreen відновлюється при виході з нього; renderer
+    /// переконфігурується на WindowEvent::Resized. Режим - в settings.ini.
+    fn toggle_fullscreen(&mut self) {
+        let Some(window) = &self.window else {
+            return;
+        };
+        let fullscreen = window.fullscreen().is_none();
+        if fullscreen {
+            self.windowed_size = Some(window.inner_size());
+            window.set_fullscreen(Some(Fullscreen::Borderless(None)));
+        } else {
+            window.set_fullscreen(None);
+            if let Some(size) = self.windowed_size.take() {
+                let _ = window.request_inner_size(size);
+            }
+        }
+        // Деякі платформи відпускають курсор при зміні режиму
+        set_cursor_captured(window, self.wants_cursor_captured());
+        self.settings.set("window.fullscreen", fullscreen);
+        log::info!("Fullscreen: {}", fullscreen);
+    }
+
+    /// Перемикає паузу (ESC)
+    ///
+    /// Пауза відпускає курсор і зупиняє GameTime; продовження захоплює
+    /// курсор знову та скидає годинник - без стрибка delta після довгої паузи.
+    fn toggle_pause(&mut self) {
+        self.game_state = match self.game_state {
+            GameState::Playing => {
+                self.game_time.pause();
+                GameState::Paused
+            }
+            GameState::Paused => {
+                self.game_time.resume();
+                GameState::Playing
+            }
+        };
+
+        if let Some(window) = &self.window {
+            set_cursor_captured(window, self.wants_cursor_captured());
+            if self.game_state == GameState::Paused {
+                window.set_title("Arena Combat - PAUSED (ESC - продовжити, Shift+ESC - вихід)");
+            }
+        }
+        self.input_state.reset_mouse_delta();
+        log::info!("Game state: {:?}", self.game_state);
+    }
+
+    /// Дії за натисканням: пауза, зброя, рестарт, reset ragdoll, debug
+    ///
+    /// Раз на кадр після begin_frame (і на паузі - щоб її можна було зняти).
+    fn handle_action_presses(&mut self, event_loop: &ActiveEventLoop) {
+        // Pause - пауза / продовжити, Shift + Pause - закриття
+        if self.input_state.just_pressed_action(Action::Pause) {
+            if self.input_state.is_shift_pressed() {
+                log::info!("Shift+ESC натиснуто - закриття...");
+                event_loop.exit();
+                return;
+            }
+            self.toggle_pause();
+        }
+
+        // Borderless fullscreen / вікно: F11 або Alt+Enter
+        if self.input_state.just_pressed_action(Action::Fullscreen)
+            || (self.input_state.just_pressed(KeyCode::Enter) && self.input_state.is_alt_pressed())
+        {
+            self.toggle_fullscreen();
+        }
+
+        // Debug осі кісток / швидкості ragdoll / HUD / wireframe / grid
+        if let Some(renderer) = &mut self.renderer {
+            // Debug перемикачі зберігаються в settings.ini при виході
+            if self.input_state.just_pressed_action(Action::DebugBoneAxes) {
+                renderer.ragdoll_debug.show_bone_axes = !renderer.ragdoll_debug.show_bone_axes;
+                self.settings.set("debug.bone_axes", renderer.ragdoll_debug.show_bone_axes);
+                log::info!("Debug bone axes: {}", renderer.ragdoll_debug.show_bone_axes);
+            }
+            if self.input_state.just_pressed_action(Action::DebugVelocities) {
+                renderer.ragdoll_debug.show_velocities = !renderer.ragdoll_debug.show_velocities;
+                self.settings.set("debug.velocities", renderer.ragdoll_debug.show_velocities);
+                log::info!("Debug velocities: {}", renderer.ragdoll_debug.show_velocities);
+            }
+            if self.input_state.just_pressed_action(Action::DebugHud) {
+                renderer.debug_hud.visible = !renderer.debug_hud.visible;
+                self.settings.set("debug.hud", renderer.debug_hud.visible);
+                log::info!("Debug HUD: {}", renderer.debug_hud.visible);
+            }
+            if self.input_state.just_pressed_action(Action::DebugWireframe) {
+                let wireframe = renderer.set_wireframe(!renderer.is_wireframe());
+                log::info!("Режим рендерингу: {}", if wireframe { "wireframe" } else { "fill" });
+            }
+            if self.input_state.just_pressed_action(Action::DebugGrid) {
+                renderer.show_grid = !renderer.show_grid;
+                self.settings.set("debug.grid", renderer.show_grid);
+                log::info!("Debug grid: {}", renderer.show_grid);
+            }
+            if self.input_state.just_pressed_action(Action::PresentMode) {
+                let present_mode = renderer.cycle_present_mode();
+                self.settings.set("rendering.present_mode", rendering::renderer::present_mode_name(present_mode));
+                self.window_info.request_update();
+            }
+            if self.input_state.just_pressed_action(Action::Screenshot) {
+                renderer.request_screenshot();
+            }
+
+            // Free-fly камера: гравець стоїть, фізика працює далі
+            if self.input_state.just_pressed_action(Action::DebugFreeCam) && self.game_state == GameState::Playing {
+                let free_fly = !renderer.camera.is_free_fly();
+                renderer.camera.set_free_fly(free_fly);
+                if free_fly {
+                    self.lock_on.release();
+                }
+                self.window_info.request_update();
+                log::info!("Free-fly камера: {}", free_fly);
+            }
+
+            // Перша / третя особа (напрямок погляду зберігається)
+            if self.input_state.just_pressed_action(Action::CameraMode)
+                && self.game_state == GameState::Playing
+                && !renderer.camera.is_free_fly()
+            {
+                let mode = renderer.camera.toggle_mode();
+                // Outline гравця зсередини (inverted hull) заливає весь екран
+                match mode {
+                    CameraMode::FirstPerson => renderer.set_outline_targets(&[]),
+                    CameraMode::ThirdPerson => renderer.set_outline_targets(&[OutlineTarget::Player]),
+                }
+                log::info!("Камера: {:?}", mode);
+            }
+        }
+
+        // Replay: зберегти ввід з початку гри
+        if self.input_state.just_pressed_action(Action::DebugSaveReplay) {
+            let secs = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            let path = std::path::PathBuf::from(format!("debug/replay_{}.bin", secs));
+            match self.replay_recorder.save(&path) {
+                Ok(()) => log::info!("Replay збережено: {:?} ({} кадрів)", path, self.replay_recorder.frame_count()),
+                Err(e) => log::warn!("Не вдалося зберегти replay: {}", e),
+            }
+        }
+
+        // Slow-mo: [ - ×0.5, ] - ×2 (плавно, межі - в GameTime)
+        // Лише під час гри: кадри паузи не пишуться в replay
+        let time_scale_step = if self.game_state != GameState::Playing {
+            None
+        } else if self.input_state.just_pressed_action(Action::TimeScaleDown) {
+            Some(0.5)
+        } else if self.input_state.just_pressed_action(Action::TimeScaleUp) {
+            Some(2.0)
+        } else {
+            None
+        };
+        if let Some(step) = time_scale_step {
+            self.game_time.ramp_time_scale(self.game_time.target_time_scale() * step, TIME_SCALE_RAMP);
+            log::info!("Time scale: x{:.2}", self.game_time.target_time_scale());
+        }
+
+        // Кинджал / меч / дворучний меч
+        let weapon = if self.input_state.just_pressed_action(Action::WeaponDagger) {
+            Some(combat::Weapon::dagger())
+        } else if self.input_state.just_pressed_action(Action::WeaponSword) {
+            Some(combat::Weapon::sword())
+        } else if self.input_state.just_pressed_action(Action::WeaponGreatsword) {
+            Some(combat::Weapon::greatsword())
+        } else {
+            None
+        };
+        if let Some(weapon) = weapon.filter(|_| self.game_state == GameState::Playing) {
+            log::info!("Зброя: {}", weapon.name);
+            self.combat.set_weapon(weapon);
+        }
+
+        // Lock-on: найближчий живий ворог перед камерою / відпустити
+        if self.input_state.just_pressed_action(Action::LockOn)
+            && self.game_state == GameState::Playing
+            && self.player.is_alive()
+            && !self.free_fly()
+        {
+            let origin = self.player_position();
+            let forward = match &self.renderer {
+                Some(renderer) => renderer.camera.forward_xz(),
+                None => self.player_forward(),
+            };
+            let candidates = self.sim.enemies.iter().enumerate().filter(|(_, e)| e.is_alive()).map(|(i, e)| (i, e.position));
+            match self.lock_on.toggle(origin, forward, candidates) {
+                Some(target) => log::info!("Lock-on: ворог {}", target),
+                None => log::info!("Lock-on: вимкнено"),
+            }
+        }
+
+        // Рестарт після смерті
+        if self.input_state.just_pressed_action(Action::Restart) && !self.player.is_alive() {
+            self.restart();
+        }
+
+        // Скинути ragdoll в A-pose (над поточною позицією)
+        if self.input_state.just_pressed_action(Action::ResetRagdoll)
+            && self.game_state == GameState::Playing
+            && self.player.is_alive()
+        {
+            let current = self.sim.ragdoll.get_position(&self.sim.physics_world);
+            let spawn = if current.is_finite() {
+                glam::Vec3::new(current.x, RAGDOLL_SPAWN_HEIGHT, current.z)
+            } else {
+                glam::Vec3::new(0.0, RAGDOLL_SPAWN_HEIGHT, 0.0)
+            };
+            self.sim.ragdoll.reset(&mut self.sim.physics_world, spawn);
+            log::info!("Ragdoll reset");
+        }
+    }
+
+    /// Replay: наступний записаний кадр замість живого вводу
+    ///
+    /// Раз на кадр після begin_frame. Replay скінчився - назад до живого вводу.
+    fn apply_replay_frame(&mut self) {
+        let Some(replay) = &mut self.replay else {
+            return;
+        };
+        let frame = replay.next_frame();
+        if frame.is_none() {
+            log::info!("Replay завершено ({} кадрів), живий ввід", replay.frame_count());
+            self.replay = None;
+        }
+        self.input_state.set_replay_frame(frame);
+    }
+
+    /// Пише ввід кадру в replay; під час відтворення - перевіряє кроки фізики
+    fn record_replay_frame(&mut self) {
+        let physics_step = self.sim.stepper.step_count();
+        if let Some(frame) = self.input_state.replay_frame() {
+            if frame.physics_step != physics_step && !self.replay_diverged {
+                self.replay_diverged = true;
+                let position = self.replay.as_ref().map_or(0, |replay| replay.position());
+                log::warn!(
+                    "Replay розійшовся на кадрі {}: крок фізики {} замість {}",
+                    position,
+                    physics_step,
+                    frame.physics_step
+                );
+            }
+        }
+        let frame = ReplayFrame::capture(&self.input_state, self.game_time.real_delta(), physics_step);
+        self.replay_recorder.record(frame);
+    }
+
+    /// Чи активна free-fly камера (ввід гравця призупинено)
+    fn free_fly(&self) -> bool {
+        self.renderer.as_ref().is_some_and(|renderer| renderer.camera.is_free_fly())
+    }
+
+    /// Рядки debug HUD (лише коли він показаний)
+    fn update_debug_hud(&mut self) {
+        let Some(renderer) = &mut self.renderer else {
+            return;
+        };
+        let hud = &mut renderer.debug_hud;
+        if !hud.visible {
+            return;
+        }
+
+        hud.set_line(
+            "fps",
+            format!("FPS: {:.0} ({:.1} ms)", self.fps_counter.fps(), self.fps_counter.frame_time_ms()),
+        );
+        hud.set_line(
+            "frame_times",
+            format!(
+                "Frame: p95 {:.1} / p99 {:.1} / max {:.1} ms",
+                self.fps_counter.p95_ms(),
+                self.fps_counter.p99_ms(),
+                self.fps_counter.max_ms()
+            ),
+        );
+        let mode = match self.sim.ragdoll.mode {
+            _ if !self.use_physics_player => "Kinematic".to_string(),
+            physics::ragdoll::RagdollMode::Active => "Active".to_string(),
+            physics::ragdoll::RagdollMode::Ragdoll => "Ragdoll".to_string(),
+            physics::ragdoll::RagdollMode::Recovery { progress } => format!("Recovery {:.0}%", progress * 100.0),
+        };
+        hud.set_line("mode", format!("Player: {}", mode));
+        let mut attack = match (self.combat.get_phase(), self.combat.state) {
+            (Some(phase), _) => format!("{:?}", phase),
+            (None, combat::AttackState::Cooldown(remaining)) => format!("Cooldown {:.2}s", remaining),
+            _ if self.combat.is_blocking() => "Block".to_string(),
+            _ => "Ready".to_string(),
+        };
+        if self.combat.has_buffered_attack() {
+            attack.push_str(" +buffered");
+        }
+        hud.set_line("attack", format!("Attack: {}", attack));
+        hud.set_line("bones", format!("Bones: {}", self.sim.ragdoll.skeleton.bones.len()));
+        hud.set_line("hitboxes", format!("Hitboxes: {}", self.sim.hitbox_manager.active_count()));
+        hud.set_line("bodies", format!("Rigid bodies: {}", self.sim.physics_world.rigid_body_set.len()));
+        let alive = self.sim.enemies.iter().filter(|e| e.is_alive()).count();
+        hud.set_line("enemies", format!("Enemies: {}/{}", alive, self.sim.enemies.len()));
+        hud.set_line("time", format!("Time scale: x{:.2}", self.game_time.time_scale()));
+    }
+
+    /// Позиція гравця: фізичний ragdoll (інтерпольована, як і скелет) або кінематичний
+    fn player_position(&self) -> glam::Vec3 {
+        if self.use_physics_player {
+            self.sim.ragdoll.get_position_interpolated(self.sim.stepper.interpolation_alpha())
+        } else {
+            self.player.position
+        }
+    }
+
+    /// Позиція очей гравця (камера від першої особи)
+    ///
+    /// Ragdoll - інтерпольований центр кістки голови, кінематичний - eye_height
+    fn player_eye_position(&self) -> glam::Vec3 {
+        let head = self.use_physics_player.then(|| {
+            let alpha = self.sim.stepper.interpolation_alpha();
+            self.sim.ragdoll.get_bone_position_interpolated(physics::BoneId::Head, alpha)
+        });
+        head.flatten().unwrap_or_else(|| self.player.position + glam::Vec3::Y * self.player.eye_height())
+    }
+
+    /// Центр тіла гравця (для hitbox'ів ворогів)
+    ///
+    /// Ragdoll - pelvis (вже на висоті тіла), кінематичний - позиція + 1м
+    fn player_center(&self) -> glam::Vec3 {
+        if self.use_physics_player {
+            self.player_position()
+        } else {
+            self.player.position + glam::Vec3::Y
+        }
+    }
+
+    /// Основа тіла гравця, від якої hitbox меча рахує висоту плеча
+    ///
+    /// Ragdoll - під pelvis на висоті стійки (падає разом з тілом),
+    /// кінематичний - позиція на землі
+    fn player_swing_origin(&self) -> glam::Vec3 {
+        if self.use_physics_player {
+            let standing_height = self.sim.ragdoll.skeleton.standing_pelvis_height();
+            self.player_position() - glam::Vec3::Y * standing_height
+        } else {
+            self.player.position
+        }
+    }
+
+    /// Куди дивиться гравець (ragdoll або кінематичний)
+    fn player_forward(&self) -> glam::Vec3 {
+        if self.use_physics_player {
+            let yaw = self.sim.ragdoll.target_yaw;
+            glam::Vec3::new(-yaw.sin(), 0.0, -yaw.cos())
+        } else {
+            self.player.forward()
+        }
+    }
+
+    /// Напрямок атаки: куди дивиться гравець
+    /// з aim assist до найближчого ворога в конусі
+    fn attack_direction(&self) -> glam::Vec3 {
+        let origin = if self.use_physics_player {
+            self.sim.ragdoll.get_position(&self.sim.physics_world)
+        } else {
+            self.player.position
+        };
+
+        self.aim_assist.adjust(
+            origin,
+            self.player_forward(),
+            self.sim.enemies.iter().filter(|e| e.is_alive()).map(|e| e.position),
+        )
+    }
+
+    /// Ставить атаку (поза Ready - в буфер, стартує в Combat::update)
+    fn queue_attack(&mut self, kind: AttackKind, swing: AttackDirection) {
+        let attack_dir = self.attack_direction();
+        if self.combat.queue_attack(attack_dir, kind, swing) {
+            self.face_attack_direction();
+
+            // Hitbox з'явиться у фазі Action (HITBOX UPDATE)
+            log::info!("Attack! ({:?}, {:?}, combo {})", kind, swing, self.combat.combo_index + 1);
+        }
+    }
+
+    /// Повертає гравця в напрямку атаки, що щойно почалась
+    fn face_attack_direction(&mut self) {
+        let attack_dir = self.combat.attack_direction;
+        let attack_yaw = (-attack_dir.x).atan2(-attack_dir.z);
+        if self.use_physics_player {
+            self.sim.ragdoll.target_yaw = attack_yaw;
+        } else {
+            self.player.set_yaw(attack_yaw);
+        }
+    }
+
+    /// Влучання гравця у ворога: шкода, hitstop, тряска камери, knockback,
+    /// імпульс у влучену кістку
+    ///
+    /// Спільне для hitbox'ів (check_collisions) та контактів леза фізичного
+    /// меча (register_blade_contact). `tip_velocity` - швидкість вістря меча
+    /// ragdoll (physics гравець, weapon_tip_velocity), шкода росте з нею.
+    fn apply_player_hit(&mut self, hit: HitboxHit, tip_velocity: Option<glam::Vec3>) {
+        let Some(hitbox) = self.sim.hitbox_manager.get(hit.hitbox_index) else {
+            return;
+        };
+        let (hitbox_position, hitbox_velocity) = (hitbox.position, hitbox.velocity);
+        let i = hit.enemy_index;
+        let Some(enemy) = self.sim.enemies.get_mut(i) else {
+            return;
+        };
+        let enemy_center = enemy.position + glam::Vec3::new(0.0, 1.0, 0.0);
+
+        // Влучання в кістку ragdoll - множник за кісткою
+        let damage = match hit.bone {
+            Some(bone) => {
+                let multiplier = combat::bone_damage_multiplier(bone);
+                log::info!("Enemy {} hit in {:?} (x{:.1})", i, bone, multiplier);
+                hit.damage * multiplier
+            }
+            None => hit.damage,
+        };
+
+        // Швидкість вістря - тільки з тіла меча. hitbox.velocity - той самий
+        // замах (weapon_pose), який м'язи вже передали мечу; сума рахувала б
+        // його двічі
+        let damage = match tip_velocity {
+            Some(tip_velocity) => {
+                let tip_speed = tip_velocity.length();
+                let scaled = damage * combat::tip_speed_damage_scale(tip_speed);
+                log::info!("Tip speed {:.1} m/s → damage {:.1} (base {:.0})", tip_speed, scaled, damage);
+                scaled
+            }
+            None => damage,
+        };
+
+        // HIT! (ворог спалахує, світ на мить зупиняється, камера трясеться)
+        enemy.take_damage(damage);
+        self.hitstop.trigger();
+        if let Some(renderer) = &mut self.renderer {
+            renderer.camera.add_shake(HIT_SHAKE_TRAUMA);
+        }
+        enemy.apply_knockback(enemy_center - hitbox_position, ENEMY_KNOCKBACK_SPEED);
+
+        // Влучену кістку відкидає в напрямку руху меча
+        if let (Some(ragdoll), Some(bone)) = (&mut enemy.ragdoll, hit.bone) {
+            let away = enemy_center - hitbox_position;
+            let direction = hitbox_velocity
+                .try_normalize()
+                .unwrap_or_else(|| glam::Vec3::new(away.x, 0.0, away.z).normalize_or_zero());
+            ragdoll.apply_impact(&mut self.sim.physics_world, bone, direction * ENEMY_HIT_IMPULSE);
+        }
+        log::info!("Enemy {} hit! Health: {}", i, enemy.health);
+
+        if !enemy.is_alive() {
+            log::info!("Enemy {} killed!", i);
+        }
+    }
+
+    /// Рестарт після смерті (R): гравець, ragdoll, вороги
+    fn restart(&mut self) {
+        self.player = Player::new(PLAYER_START_POSITION);
+        self.combat.reset();
+        self.attack_hold = None;
+        self.sim_input = SimInput::default();
+        self.hitstop.cancel();
+        self.lock_on.release();
+
+        // Ragdoll гравця на місце, вороги та hitbox'и заново
+        self.sim.reset();
+        // Меші ворогів створяться заново в наступному кадрі
+        self.enemies_spawned = false;
+
+        log::info!("Restart");
+    }
+
+    /// Рендерить кадр та обробляє помилки surface
+    fn render_frame(&mut self, event_loop: &ActiveEventLoop) {
+        self.update_debug_hud();
+        if let Some(renderer) = &mut self.renderer {
+            match renderer.render() {
+                Ok(_) => {}
+                Err(e @ (wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated)) => {
+                    // Surface втрачено / застаріла (resize, зміна present mode) - конфігуруємо знову
+                    log::warn!("Surface {:?}, recreating...", e);
+                    if let Some(window) = &self.window {
+                        let size = window.inner_size();
+                        renderer.resize(size);
+                    }
+                }
+                Err(wgpu::SurfaceError::OutOfMemory) => {
+                    log::error!("Out of memory!");
+                    event_loop.exit();
+                }
+                Err(e) => {
+                    log::error!("Render error: {:?}", e);
+                }
+            }
+        }
+    }
 }
 
 impl ApplicationHandler for App {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
         // Створюємо вікно при старті
+        // Розмір та повноекранний режим з settings.ini ([window])
+        let width = self.settings.get_u32("window.width", DEFAULT_WINDOW_SIZE.0).max(1);
+        let height = self.settings.get_u32("window.height", DEFAULT_WINDOW_SIZE.1).max(1);
+        let fullscreen = self.settings.get_bool("window.fullscreen", false);
         let window_attributes = Window::default_attributes()
             .with_title("Arena Combat Prototype")
-            .with_inner_size(winit::dpi::LogicalSize::new(800, 600));
+            .with_inner_size(winit::dpi::LogicalSize::new(width, height))
+            .with_fullscreen(fullscreen.then_some(Fullscreen::Borderless(None)));
 
         let window = Arc::new(event_loop.create_window(window_attributes).unwrap());
 
-        log::info!("Вікно створено: 800x600");
+        log::info!("Вікно створено: {}x{}{}", width, height, if fullscreen { " (fullscreen)" } else { "" });
 
         // Ініціалізуємо wgpu renderer
         log::info!("Ініціалізація renderer...");
         let mut renderer = pollster::block_on(WgpuRenderer::new(window.clone()));
-        renderer.show_skeleton = true;  // Увімкнути візуалізацію скелета
+        // Візуалізація скелета замість mesh гравця ([debug] show_skeleton)
+        renderer.show_skeleton = self.settings.get_bool("debug.show_skeleton", true);
+        renderer.set_outline_targets(&[OutlineTarget::Player]);
+        renderer.ragdoll_debug = RagdollDebug::from_settings(&self.settings);
+        renderer.health_bars.hide_when_full = self.settings.get_bool("rendering.health_bar_hide_when_full", false);
+        renderer.camera.set_pitch_limits(
+            self.settings.get_f32("camera.min_pitch", camera::DEFAULT_MIN_PITCH_DEG),
+            self.settings.get_f32("camera.max_pitch", camera::DEFAULT_MAX_PITCH_DEG),
+        );
+        renderer.camera.free_fly_speed =
+            self.settings.get_f32("camera.free_fly_speed", camera::DEFAULT_FREE_FLY_SPEED).max(0.1);
+        renderer.camera.free_fly_boost =
+            self.settings.get_f32("camera.free_fly_boost", camera::DEFAULT_FREE_FLY_BOOST).max(1.0);
+        renderer.camera.collision_enabled = self.settings.get_bool("camera.collision", true);
+        renderer.camera.projection = camera::ProjectionMode::from_settings(&self.settings);
+        renderer.camera.follow_stiffness =
+            self.settings.get_f32("camera.follow_stiffness", camera::DEFAULT_FOLLOW_STIFFNESS).max(0.1);
+        renderer.camera.skin_width =
+            self.settings.get_f32("camera.skin_width", camera::DEFAULT_CAMERA_SKIN_WIDTH).max(0.0);
+        renderer.camera.shake_decay = self.settings.get_f32("camera.shake_decay", camera::DEFAULT_SHAKE_DECAY).max(0.01);
+        renderer.camera.shake_max_offset =
+            self.settings.get_f32("camera.shake_max_offset", camera::DEFAULT_SHAKE_MAX_OFFSET).max(0.0);
+        renderer.camera.shake_max_angle = self
+            .settings
+            .get_f32("camera.shake_max_angle", camera::DEFAULT_SHAKE_MAX_ANGLE_DEG)
+            .max(0.0)
+            .to_radians();
+
+        // Clip planes з розміру арени (settings.ini може перевизначити)
+        let zfar = self.settings.get_f32(
+            "camera.zfar",
+            camera::Camera::far_plane_for_scene(self.sim.arena.half_extent, camera::THIRD_PERSON_MAX_DISTANCE),
+        );
+        let znear = self.settings.get_f32("camera.znear", camera::Camera::near_plane_for_far(zfar));
+        renderer.camera.set_clip_planes(znear, zfar);
+        log::info!("Camera clip planes: znear = {:.3}, zfar = {:.1}", renderer.camera.znear, renderer.camera.zfar);
+        renderer.set_grid_size(self.sim.arena.grid_size());
+        renderer.set_arena(&self.sim.arena);
+        renderer.set_shadow_resolution(
+            self.settings.get_u32("rendering.shadow_resolution", rendering::shadow::DEFAULT_SHADOW_RESOLUTION),
+        );
+        renderer.set_sample_count(
+            self.settings.get_u32("rendering.msaa_samples", rendering::renderer::DEFAULT_SAMPLE_COUNT),
+        );
+        let present_mode = self.settings.get_str("rendering.present_mode").unwrap_or("fifo");
+        if let Some(mode) = rendering::renderer::present_mode_from_name(present_mode) {
+            renderer.set_present_mode(mode);
+        } else {
+            log::warn!("[rendering] present_mode: невідомий режим {:?} - fifo", present_mode);
+        }
+        renderer.set_quality(self.quality.tier());
+        self.particles.set_max_particles(self.quality.tier().max_particles());
+        renderer.set_grid_y_offset(
+            self.settings.get_f32("rendering.grid_y_offset", rendering::DEFAULT_GRID_Y_OFFSET),
+        );
+        renderer.skybox.apply_settings(&self.settings);
+        let light = rendering::light::Light::default();
+        renderer.set_light(
+            glam::Vec3::from(self.settings.get_vec3("rendering.light_direction", light.direction.to_array())),
+            glam::Vec3::from(self.settings.get_vec3("rendering.light_color", light.color.to_array())),
+            self.settings.get_f32("rendering.ambient", light.ambient),
+        );
+        renderer.light.sun_rotation = self.settings.get_bool("rendering.sun_rotation", false);
+        renderer.light.sun_rotation_speed = self
+            .settings
+            .get_f32("rendering.sun_rotation_speed", rendering::light::DEFAULT_SUN_ROTATION_SPEED_DEG)
+            .to_radians();
+        renderer.debug_hud.visible = self.settings.get_bool("debug.hud", false);
+        renderer.show_grid = self.settings.get_bool("debug.grid", false);
 
         // Захоплюємо та ховаємо курсор для FPS-style керування камерою
         // Курсор буде прихований і миша завжди обертатиме камеру
-        if let Err(e) = window.set_cursor_grab(CursorGrabMode::Confined) {
-            log::warn!("Не вдалося захопити курсор (Confined): {:?}", e);
-            // Спробуємо Locked як fallback
-            if let Err(e2) = window.set_cursor_grab(CursorGrabMode::Locked) {
-                log::warn!("Не вдалося захопити курсор (Locked): {:?}", e2);
-            }
+        set_cursor_captured(&window, self.cursor_grab);
+        if self.cursor_grab {
+            log::info!("Курсор захоплено та приховано");
         }
-        window.set_cursor_visible(false);
-        log::info!("Курсор захоплено та приховано");
 
         self.window = Some(window);
         self.renderer = Some(renderer);
@@ -160,22 +957,8 @@ impl ApplicationHandler for App {
 
             // Mouse buttons (для drag rotation та атаки)
             WindowEvent::MouseInput { button, state, .. } => {
+                // ЛКМ (атака) та ПКМ (блок) читаються в COMBAT UPDATE
                 self.input_state.update_mouse_button(button, state);
-
-                // Ліва кнопка миші = атака
-                if button == MouseButton::Left && state == ElementState::Pressed {
-                    // Напрямок атаки = куди дивиться гравець
-                    let attack_dir = self.player.forward();
-                    if self.combat.start_attack(attack_dir) {
-                        // Spawn hitbox на кінці зброї
-                        self.hitbox_manager.spawn_attack_hitbox(
-                            self.player.position,
-                            self.player.yaw,
-                            50.0, // damage
-                        );
-                        log::info!("Attack! Hitbox spawned");
-                    }
-                }
             }
 
             // Mouse wheel (для zoom)
@@ -185,24 +968,30 @@ impl ApplicationHandler for App {
                     winit::event::MouseScrollDelta::PixelDelta(pos) => (pos.y / 50.0) as f32,
                 };
 
+                // Free-fly - колесо змінює швидкість польоту
                 if let Some(renderer) = &mut self.renderer {
-                    renderer.camera.zoom_third_person(zoom_amount);
+                    if renderer.camera.is_free_fly() {
+                        renderer.camera.adjust_free_fly_speed(zoom_amount * 2.0);
+                        log::info!("Free-fly швидкість: {:.1} м/с", renderer.camera.free_fly_speed);
+                    } else {
+                        renderer.camera.zoom_third_person(zoom_amount);
+                    }
                 }
             }
 
             // Keyboard input
             WindowEvent::KeyboardInput { event: key_event, .. } => {
                 if let PhysicalKey::Code(key_code) = key_event.physical_key {
+                    // Дії (пауза, зброя, рестарт, ...) читаються в handle_action_presses
                     self.input_state.update_key(key_code, key_event.state);
-
-                    // ESC - закриття
-                    if key_code == KeyCode::Escape && key_event.state == ElementState::Pressed {
-                        log::info!("ESC натиснуто - закриття...");
-                        event_loop.exit();
-                    }
                 }
             }
 
+            // Фокус вікна (alt-tab, клік поза вікном)
+            WindowEvent::Focused(focused) => {
+                self.set_focused(focused);
+            }
+
             // Закрити вікно
             WindowEvent::CloseRequested => {
                 log::info!("Закриття вікна...");
@@ -211,109 +1000,398 @@ impl ApplicationHandler for App {
 
             // Redraw request
             WindowEvent::RedrawRequested => {
-                // Оновити час
-                self.game_time.update();
+                // Натискання / відпускання з подій після попереднього кадру
+                self.input_state.begin_frame();
+                self.apply_replay_frame();
+                self.handle_action_presses(event_loop);
+
+                // Пауза або вікно без фокусу - тільки малюємо останній кадр
+                // (час, фізика, бій заморожені)
+                if self.game_state == GameState::Paused || !self.focused {
+                    // Атаку відпущено на паузі - скасовується
+                    if self.input_state.just_released_action(Action::Attack) && self.attack_hold.take().is_some() {
+                        self.combat.release_charge();
+                    }
+                    self.fps_counter.tick();
+                    self.input_state.reset_mouse_delta();
+                    self.render_frame(event_loop);
+                    return;
+                }
+
+                // Оновити час (replay - записана delta кадру)
+                match self.input_state.replay_frame() {
+                    Some(frame) => self.game_time.update_with_delta(frame.delta),
+                    None => self.game_time.update(),
+                }
+                self.record_replay_frame();
+
+                // Delta світу (бій, фізика, вороги, частинки): з time scale, 0 під
+                // час hitstop; камера, ввід та UI - real_delta (без slow-mo)
+                let world_delta = self.hitstop.update(self.game_time.delta());
+                let real_delta = self.game_time.real_delta();
 
                 // Оновити FPS counter
                 self.fps_counter.tick();
 
-                // Оновити заголовок вікна з FPS (кожні 30 кадрів для зменшення overhead)
-                if self.game_time.frame_count() % 30 == 0 {
+                // Адаптивна якість: знижуємо / підвищуємо рівень за frame time
+                if let Some(tier) = self.quality.update(&self.fps_counter, real_delta) {
+                    self.particles.set_max_particles(tier.max_particles());
+                    if let Some(renderer) = &mut self.renderer {
+                        renderer.set_quality(tier);
+                    }
+                }
+
+                // Оновити заголовок вікна (шаблон та інтервал з settings.ini)
+                if self.window_info.tick(real_delta) {
                     if let Some(window) = &self.window {
-                        let fps = self.fps_counter.fps();
-                        let title = format!(
-                            "Arena Combat Prototype - {:.1} FPS ({:.2}ms)",
-                            fps,
-                            self.fps_counter.frame_time_ms()
-                        );
-                        window.set_title(&title);
+                        let stats = TitleStats {
+                            fps: self.fps_counter.fps(),
+                            frame_time_ms: self.fps_counter.frame_time_ms(),
+                            health: Some(self.player.health.current),
+                            enemies_alive: Some(self.sim.enemies.iter().filter(|e| e.is_alive()).count()),
+                            quality: Some(self.quality.tier().name()),
+                            stamina: Some(self.combat.stamina.fraction()),
+                            present_mode: self
+                                .renderer
+                                .as_ref()
+                                .map(|r| rendering::renderer::present_mode_name(r.present_mode())),
+                            tag: self.free_fly().then_some("FREECAM"),
+                            ..Default::default()
+                        };
+                        window.set_title(&self.window_info.format_title(&stats));
                     }
                 }
 
                 // === ENEMY SPAWNING (one-time) ===
                 if !self.enemies_spawned {
                     if let Some(renderer) = &mut self.renderer {
-                        renderer.spawn_enemies(&self.enemies);
+                        renderer.spawn_enemies(&self.sim.enemies);
                         self.enemies_spawned = true;
                     }
                 }
 
                 // === COMBAT UPDATE ===
-                self.combat.update(self.game_time.delta());
+                let can_act = self.game_state == GameState::Playing && self.player.is_alive() && !self.free_fly();
+
+                // Attack: клік = легка атака, утримання = важка (не мертвий)
+                if can_act && self.input_state.just_pressed_action(Action::Attack) {
+                    // Напрямок удару - з руху миші перед натисканням
+                    let (dx, dy) = self.input_state.mouse_delta();
+                    let swing = self.swing_sampler.direction(glam::Vec2::new(dx as f32, dy as f32));
+                    self.attack_hold = Some((0.0, swing));
+                }
+
+                // ЛКМ утримується довше HEAVY_HOLD_TIME - заряд важкої атаки
+                // (почнеться, щойно можна атакувати; удар - при відпусканні)
+                if let Some((held, swing)) = &mut self.attack_hold {
+                    *held += real_delta;
+                    if *held >= HEAVY_HOLD_TIME && can_act && !self.combat.is_charging() {
+                        self.combat.begin_charge(*swing);
+                    }
+                }
+
+                // Відпущено до HEAVY_HOLD_TIME - легка атака, після - важка
+                // (з зарядом, якщо він встиг початись)
+                if self.input_state.just_released_action(Action::Attack) {
+                    if let Some((held, swing)) = self.attack_hold.take() {
+                        let charging = self.combat.is_charging();
+                        let charge = self.combat.release_charge();
+                        if can_act {
+                            if held < HEAVY_HOLD_TIME {
+                                self.queue_attack(AttackKind::Light, swing);
+                            } else {
+                                if charging {
+                                    log::info!("Charged attack: {:.0}%", charge * 100.0);
+                                }
+                                self.queue_attack(AttackKind::Heavy, swing);
+                            }
+                        }
+                    }
+                }
+
+                // Блок - поки утримується ПКМ
+                self.combat.set_blocking(can_act && self.input_state.is_action_pressed(Action::Block));
+
+                let facing = self.attack_direction();
+                if self.combat.update(world_delta, facing) {
+                    self.face_attack_direction();
+                    log::info!("Attack! (buffered, combo {})", self.combat.combo_index + 1);
+                }
+
+                // Права рука (зі зброєю) під час атаки не розмахується при ходьбі
+                self.sim.ragdoll.walk_cycle.right_arm_swing = if self.combat.is_attacking() {
+                    WEAPON_ARM_SWING
+                } else {
+                    physics::muscle::DEFAULT_ARM_SWING
+                };
 
                 // === HITBOX UPDATE & COLLISION ===
                 {
-                    let delta = self.game_time.delta();
-                    self.hitbox_manager.update(delta);
+                    // Час життя hitbox'ів - в кроках sim.advance
+                    let delta = world_delta;
+                    self.player.health.update(delta);
 
-                    // Перевіряємо колізії hitbox ↔ enemies
-                    let enemy_radius = 0.5; // Приблизний радіус ворога
-                    for hitbox in &mut self.hitbox_manager.hitboxes {
-                        for (i, enemy) in self.enemies.iter_mut().enumerate() {
-                            // Пропускаємо мертвих та вже вражених
-                            if !enemy.is_alive() || hitbox.has_hit(i) {
+                    // Hitbox меча - за фазою атаки, там де меч зараз
+                    let player_yaw = if self.use_physics_player {
+                        self.sim.ragdoll.target_yaw
+                    } else {
+                        self.player.yaw
+                    };
+                    let swing_origin = self.player_swing_origin();
+                    self.sim.hitbox_manager.update_swing_hitbox(
+                        &self.combat,
+                        swing_origin,
+                        player_yaw,
+                        self.player.attack_height_scale(),
+                        delta,
+                    );
+
+                    let player_center = self.player_center();
+                    let player_forward = self.player_forward();
+
+                    // Hitbox'и ворогів ↔ гравець
+                    for hitbox in self.sim.hitbox_manager.iter_mut() {
+                        let HitboxOwner::Enemy(attacker) = hitbox.owner else {
+                            continue;
+                        };
+                        if !self.player.is_alive()
+                            || !hitbox.collides_with_sphere(player_center, PLAYER_HIT_RADIUS)
+                        {
+                            continue;
+                        }
+
+                        // Блок / парирування (удар спереду, ПКМ утримується)
+                        let attack_dir = player_center - hitbox.position;
+                        let outcome = self.combat.resolve_incoming_hit(
+                            hitbox.damage,
+                            glam::Vec3::new(attack_dir.x, 0.0, attack_dir.z),
+                            player_forward,
+                        );
+                        match outcome {
+                            HitOutcome::Hit { .. } => {}
+                            HitOutcome::Blocked { damage } => {
+                                // Заблокований удар не перевіряється повторно
+                                hitbox.lifetime = 0.0;
+                                log::info!("Blocked! Damage {:.1}", damage);
+                            }
+                            HitOutcome::Parried { stagger } => {
+                                hitbox.lifetime = 0.0;
+                                if let Some(enemy) = self.sim.enemies.get_mut(attacker) {
+                                    enemy.stagger(stagger);
+                                }
+                                log::info!("Parry! Enemy {} staggered", attacker);
                                 continue;
                             }
+                        }
+
+                        if self.player.take_damage(outcome.damage()) {
+                            log::info!("Player hit! Health: {}", self.player.health.current);
+                            if let Some(renderer) = &mut self.renderer {
+                                renderer.camera.add_shake(PLAYER_HIT_SHAKE_TRAUMA);
+                            }
 
-                            // Collision check (enemy position + height offset для центру)
-                            let enemy_center = enemy.position + glam::Vec3::new(0.0, 1.0, 0.0);
-                            if hitbox.collides_with_sphere(enemy_center, enemy_radius) {
-                                // HIT!
-                                enemy.take_damage(hitbox.damage);
-                                hitbox.mark_hit(i);
-                                log::info!("Enemy {} hit! Health: {}", i, enemy.health);
+                            // Ragdoll гравця відкидає в напрямку удару
+                            if self.use_physics_player {
+                                let direction = player_center - hitbox.position;
+                                self.sim.ragdoll.react_to_hit(
+                                    &mut self.sim.physics_world,
+                                    hitbox.position,
+                                    glam::Vec3::new(direction.x, 0.0, direction.z),
+                                    PLAYER_HIT_IMPULSE,
+                                );
+                            }
 
-                                if !enemy.is_alive() {
-                                    log::info!("Enemy {} killed!", i);
-                                }
+                            if !self.player.is_alive() {
+                                log::info!("Player killed! R - рестарт");
+                                self.sim.ragdoll.go_ragdoll();
                             }
                         }
                     }
+
+                    // Швидкість вістря меча (physics гравець) - шкода від неї
+                    let tip_velocity = self
+                        .use_physics_player
+                        .then(|| self.sim.ragdoll.weapon_tip_velocity(&self.sim.physics_world));
+
+                    // Hitbox'и гравця ↔ вороги (broadphase через сітку в HitboxManager)
+                    let enemy_radius = 0.5; // Приблизний радіус ворога
+                    let hits = self.sim.hitbox_manager.check_collisions(&self.sim.enemies, enemy_radius, Some(&self.sim.physics_world));
+                    for hit in hits {
+                        self.apply_player_hit(hit, tip_velocity);
+                    }
                 }
 
                 // === PHYSICS UPDATE ===
-                if let (Some(physics), Some(ragdoll)) = (&mut self.physics_world, &mut self.ragdoll) {
-                    let delta = self.game_time.delta();
+                {
+                    // Fixed timestep: кроки, що накопичились за кадр - фізика,
+                    // AI ворогів (атаки → hitbox'и), час життя hitbox'ів
+                    let ticks = self.sim.stepper.steps_for(world_delta);
+                    // Кінематичний гравець - ціль AI ворогів замість ragdoll
+                    let input = SimInput {
+                        player_position: (!self.use_physics_player).then_some(self.player.position),
+                        ..self.sim_input
+                    };
+                    let events = self.sim.advance(ticks, &input);
+                    // Стрибок - раз, у кадрі натискання
+                    self.sim_input.jump = false;
+
+                    // Вороги, що зникли, - з lock-on та renderer (індекси вже зсунуті в sim)
+                    for &i in &events.removed_enemies {
+                        self.lock_on.on_enemy_removed(i);
+                        if let Some(renderer) = &mut self.renderer {
+                            renderer.remove_enemy_mesh(i);
+                        }
+                    }
+
+                    let impacts = events.impacts;
+                    let (physics, ragdoll) = (&mut self.sim.physics_world, &mut self.sim.ragdoll);
 
-                    // Оновлюємо ragdoll (м'язи + цільова поза)
-                    ragdoll.update(physics, delta);
+                    // Шкода від жорстких приземлень (нокдаун вже в ActiveRagdoll::update)
+                    let fall_damage = ragdoll.take_fall_damage();
+                    if self.use_physics_player && fall_damage > 0.0 && self.player.take_damage(fall_damage) {
+                        log::info!("Fall damage {:.1}! Health: {}", fall_damage, self.player.health.current);
+                        if !self.player.is_alive() {
+                            log::info!("Player killed! R - рестарт");
+                            ragdoll.go_ragdoll();
+                        }
+                    }
+                    for enemy in &mut self.sim.enemies {
+                        let fall_damage = enemy.ragdoll.as_mut().map_or(0.0, |r| r.take_fall_damage());
+                        if fall_damage > 0.0 {
+                            enemy.take_damage(fall_damage);
+                        }
+                    }
 
-                    // Крок фізики
-                    physics.step(delta);
+                    for impact in &impacts {
+                        play_impact(impact, &mut self.particles);
+                        if impact.bone == physics::BoneId::Head && impact.on_ground && impact.is_hard() {
+                            log::info!("Голова сильно вдарилась об землю: force={:.0} N", impact.force);
+                        }
+                    }
 
-                    // Оновлюємо skeleton renderer з bone transforms
+                    // Collision events кадру: лезо меча по кістках інших скелетів
+                    // (влучання - після оновлення скелетів, BLADE HITS)
+                    let blade_contacts: Vec<_> = physics
+                        .drain_collision_events()
+                        .into_iter()
+                        .filter(|event| event.started())
+                        .filter_map(|event| {
+                            let (c1, c2) = (event.collider1(), event.collider2());
+                            let other = if ragdoll.skeleton.is_weapon_collider(c1) {
+                                c2
+                            } else if ragdoll.skeleton.is_weapon_collider(c2) {
+                                c1
+                            } else {
+                                return None;
+                            };
+                            physics.collider_bone(other)
+                        })
+                        .collect();
+
+                    // Оновлюємо skeleton renderer з інтерпольованими bone transforms
                     if let Some(renderer) = &mut self.renderer {
-                        let bone_transforms = ragdoll.get_bone_transforms(physics);
+                        let alpha = self.sim.stepper.interpolation_alpha();
+                        let bone_transforms = ragdoll.get_bone_transforms_interpolated(alpha);
                         renderer.update_skeleton(&bone_transforms);
+
+                        // Debug лінії (порожній список, якщо вимкнено - очищає старі)
+                        let bone_velocities = if renderer.ragdoll_debug.show_velocities {
+                            ragdoll.get_bone_velocities(physics)
+                        } else {
+                            Vec::new()
+                        };
+                        renderer.update_ragdoll_debug(&bone_transforms, &bone_velocities);
+
+                        // Скелети ворогів з ragdoll
+                        let enemy_skeletons: Vec<_> = self
+                            .sim
+                            .enemies
+                            .iter()
+                            .filter_map(|e| {
+                                let ragdoll = e.ragdoll.as_ref()?;
+                                Some((
+                                    ragdoll.get_bone_transforms_interpolated(alpha),
+                                    e.is_alive(),
+                                    e.windup_progress(),
+                                    e.hit_flash(),
+                                ))
+                            })
+                            .collect();
+                        renderer.update_enemy_skeletons(&enemy_skeletons);
+                    }
+
+                    // === BLADE HITS ===
+                    // Лезо фізичного меча по кістці ворога під час замаху - влучання
+                    // як від hitbox'а (раз на ворога за замах, та сама шкода й реакції)
+                    let tip_velocity = self
+                        .use_physics_player
+                        .then(|| self.sim.ragdoll.weapon_tip_velocity(&self.sim.physics_world));
+                    for (group, bone) in blade_contacts {
+                        let Some(enemy_index) = self.sim.enemies.iter().position(|enemy| {
+                            enemy.is_alive()
+                                && enemy.ragdoll.as_ref().is_some_and(|r| r.skeleton.collision_group == group)
+                        }) else {
+                            continue;
+                        };
+                        if let Some(hit) = self.sim.hitbox_manager.register_blade_contact(enemy_index, bone) {
+                            log::info!("Лезо влучило в {:?} ворога {}", bone, enemy_index);
+                            self.apply_player_hit(hit, tip_velocity);
+                        }
                     }
                 }
 
                 // === ANIMATION UPDATE ===
                 if let Some(renderer) = &mut self.renderer {
-                    // Обертаємо куби з використанням delta time
-                    renderer.update_animations(self.game_time.delta());
+                    // Обертаємо куби (і сонце, якщо sun_rotation) з використанням delta time
+                    renderer.update_animations(world_delta);
                 }
 
-                // === ENEMY UPDATE ===
+                // === PARTICLES UPDATE ===
+                self.particles.update(world_delta);
                 if let Some(renderer) = &mut self.renderer {
-                    renderer.update_enemies(&self.enemies);
+                    renderer.update_particles(&self.particles);
                 }
 
+                // === ENEMY UPDATE (AI - в кроках sim.advance) ===
+                if let Some(renderer) = &mut self.renderer {
+                    renderer.update_enemies(&self.sim.enemies);
+                }
+
+                // === LOCK-ON (ціль мертва / далеко - відпускається; ривок миші - наступна) ===
+                let lock_target = if self.player.is_alive() {
+                    let origin = self.player_position();
+                    let (mouse_dx, _) = self.input_state.mouse_delta();
+                    let candidates =
+                        self.sim.enemies.iter().enumerate().filter(|(_, e)| e.is_alive()).map(|(i, e)| (i, e.position));
+                    self.lock_on.update(origin, mouse_dx as f32, real_delta, candidates)
+                } else {
+                    self.lock_on.release();
+                    None
+                };
+
+                // Куди повертати гравця при lock-on (спринт - біжить куди біжить)
+                let lock_facing = lock_target.map(|target| target - self.player_position());
+
                 // === CAMERA + PLAYER UPDATE (в одному блоці!) ===
                 if let Some(renderer) = &mut self.renderer {
-                    let delta = self.game_time.delta();
+                    let delta = real_delta;
+                    let free_fly = renderer.camera.is_free_fly();
 
                     // Mouse look - миша ЗАВЖДИ обертає камеру (як в екшн іграх)
                     // Курсор захоплений та прихований, тому немає потреби тримати кнопку
+                    // Правий стік геймпада - так само (camera_axis)
                     {
-                        let (delta_x, delta_y) = self.input_state.mouse_delta();
+                        let (mouse_x, mouse_y) = self.input_state.mouse_delta();
+                        self.swing_sampler.push(glam::Vec2::new(mouse_x as f32, mouse_y as f32), delta);
+                        let look = self.input_state.camera_axis(delta);
+                        let (delta_x, delta_y) = (look.x, look.y);
 
-                        // Базова чутливість для звичайної миші
+                        // Базова чутливість для звичайної миші ([camera] mouse_sensitivity)
                         // Тачпад зазвичай дає менші дельти, тому автоматично підвищуємо
-                        let base_sensitivity = 0.003;
+                        let base_sensitivity = self.mouse_sensitivity;
 
-                        // Якщо delta дуже мала (тачпад) - збільшуємо чутливість
-                        let magnitude = (delta_x * delta_x + delta_y * delta_y).sqrt();
+                        // Якщо delta миші дуже мала (тачпад) - збільшуємо чутливість
+                        let magnitude = (mouse_x * mouse_x + mouse_y * mouse_y).sqrt();
                         let sensitivity = if magnitude > 0.0 && magnitude < 5.0 {
                             // Тачпад дає малі delta - підвищуємо чутливість
                             base_sensitivity * 3.0
@@ -321,54 +1399,93 @@ impl ApplicationHandler for App {
                             base_sensitivity
                         };
 
-                        let delta_yaw = (delta_x as f32) * sensitivity;
-                        let delta_pitch = (delta_y as f32) * sensitivity;
+                        // Lock-on - yaw веде ціль (рух миші по X перемикає ціль)
+                        let delta_yaw = if lock_target.is_some() { 0.0 } else { delta_x * sensitivity };
+                        let delta_pitch = delta_y * sensitivity * if self.invert_y { -1.0 } else { 1.0 };
 
                         // Знижений поріг для тачпада
                         if delta_x.abs() > 0.01 || delta_y.abs() > 0.01 {
-                            renderer.camera.rotate_third_person(delta_yaw, delta_pitch);
+                            if free_fly {
+                                renderer.camera.rotate_free_fly(delta_yaw, delta_pitch);
+                            } else {
+                                renderer.camera.rotate_third_person(delta_yaw, delta_pitch);
+                            }
                         }
                     }
                     self.input_state.reset_mouse_delta();
 
-                    // Q/E - обертає камеру
+                    // TurnLeft / TurnRight - обертає камеру (не при lock-on та free-fly)
                     let turn_speed = 2.0_f32; // радіан/секунда
-                    if self.input_state.is_q_pressed() {
+                    let can_turn = lock_target.is_none() && !free_fly;
+                    if can_turn && self.input_state.is_action_pressed(Action::TurnLeft) {
                         renderer.camera.rotate_third_person(-turn_speed * delta, 0.0);
                     }
-                    if self.input_state.is_e_pressed() {
+                    if can_turn && self.input_state.is_action_pressed(Action::TurnRight) {
                         renderer.camera.rotate_third_person(turn_speed * delta, 0.0);
                     }
 
+                    // Crouch (утримання; у free-fly Ctrl - вниз)
+                    let alive = self.player.is_alive();
+                    self.player.update_crouch(
+                        alive && !free_fly && self.input_state.is_action_pressed(Action::Crouch),
+                        world_delta,
+                    );
+
                     // Отримуємо camera directions для camera-relative руху
                     let cam_forward = renderer.camera.forward_xz();
                     let cam_right = renderer.camera.right_xz();
 
-                    // Обчислюємо input direction
-                    let mut move_dir = glam::Vec3::ZERO;
+                    // Input direction: вперед/назад та strafe відносно камери
+                    // (клавіші + лівий стік геймпада)
+                    let move_axis = self.input_state.movement_axis();
+                    let mut move_dir = cam_forward * move_axis.y + cam_right * move_axis.x;
 
-                    // W/S - рух вперед/назад (відносно камери)
-                    if self.input_state.is_w_pressed() {
-                        move_dir += cam_forward;
-                    }
-                    if self.input_state.is_s_pressed() {
-                        move_dir -= cam_forward;
+                    // Мертвий гравець не керується (до рестарту R),
+                    // ragdoll, що піднімається з землі, - до кінця підйому
+                    let getting_up = self.use_physics_player && self.sim.ragdoll.is_getting_up();
+                    if !alive || getting_up {
+                        move_dir = glam::Vec3::ZERO;
                     }
 
-                    // A/D - strafe вліво/вправо (відносно камери)
-                    if self.input_state.is_a_pressed() {
-                        move_dir -= cam_right;
-                    }
-                    if self.input_state.is_d_pressed() {
-                        move_dir += cam_right;
+                    // Free-fly: WASD / Jump / Crouch рухають камеру, Sprint - прискорення;
+                    // гравець стоїть (без сил руху), фізика працює далі
+                    if free_fly {
+                        let pressed = |action| if self.input_state.is_action_pressed(action) { 1.0 } else { 0.0 };
+                        let fly_input = glam::Vec3::new(
+                            move_axis.x,
+                            pressed(Action::Jump) - pressed(Action::Crouch),
+                            move_axis.y,
+                        );
+                        let boost = self.input_state.is_action_pressed(Action::Sprint);
+                        renderer.camera.update_free_fly(fly_input, boost, delta);
+                        move_dir = glam::Vec3::ZERO;
                     }
 
+                    // Спринт, поки є витривалість
+                    let sprinting = self.input_state.is_action_pressed(Action::Sprint)
+                        && move_dir.length_squared() > 0.01
+                        && self.combat.stamina.drain_sprint(world_delta);
+                    let speed_scale = if sprinting { SPRINT_SPEED_SCALE } else { 1.0 };
+
+                    // Lock-on: погляд на ціль, рух вбік / назад - стрейф
+                    let lock_yaw = lock_facing
+                        .filter(|to_target| !sprinting && alive && !getting_up && to_target.x.abs() + to_target.z.abs() > 1e-3)
+                        .map(|to_target| (-to_target.x).atan2(-to_target.z));
+
                     // === ТРЕТЯ ОСОБА: ПЕРСОНАЖ ДИВИТЬСЯ В НАПРЯМКУ РУХУ ===
                     if self.use_physics_player {
-                        // Фізичний ragdoll - передаємо напрямок руху
-                        if let Some(ragdoll) = &mut self.ragdoll {
-                            ragdoll.set_move_direction(move_dir);
+                        // Фізичний ragdoll - напрямок руху для кроків наступного кадру;
+                        // стрибок - лише в кадрі натискання, не утримання
+                        if let Some(yaw) = lock_yaw {
+                            self.sim.ragdoll.target_yaw = yaw;
                         }
+                        self.sim_input = SimInput {
+                            move_dir,
+                            speed_scale,
+                            sprinting,
+                            jump: alive && !free_fly && self.input_state.just_pressed_action(Action::Jump),
+                            player_position: None,
+                        };
                     } else {
                         // Старий кінематичний гравець
                         if move_dir.length_squared() > 0.01 {
@@ -378,14 +1495,18 @@ impl ApplicationHandler for App {
                             self.player.set_target_direction(move_dir);
 
                             // Рухаємо гравця
-                            self.player.position += move_dir * self.player.move_speed * delta;
+                            self.player.position += move_dir * self.player.move_speed * speed_scale * world_delta;
                         } else {
                             // Коли не рухаємось - персонаж зберігає поточний напрямок
                             self.player.is_moving = false;
                         }
 
+                        if let Some(yaw) = lock_yaw {
+                            self.player.target_yaw = yaw;
+                        }
+
                         // Плавне обертання персонажа до target_yaw
-                        self.player.smooth_rotate(delta);
+                        self.player.smooth_rotate(world_delta);
                     }
                 }
 
@@ -396,42 +1517,47 @@ impl ApplicationHandler for App {
                     }
                 }
 
-                // === CAMERA POSITION UPDATE (слідує за гравцем) ===
-                if let Some(renderer) = &mut self.renderer {
-                    let player_pos = if self.use_physics_player {
-                        // Позиція з фізичного ragdoll
-                        if let (Some(physics), Some(ragdoll)) = (&self.physics_world, &self.ragdoll) {
-                            ragdoll.get_position(physics)
-                        } else {
-                            self.player.position
-                        }
-                    } else {
-                        self.player.position
+                // === CAMERA POSITION UPDATE (слідує за гравцем; free-fly - вже оновлена) ===
+                let player_pos = self.player_position();
+                let camera_height = self.player.camera_height();
+                let first_person = self.renderer.as_ref().is_some_and(|renderer| {
+                    !renderer.camera.is_free_fly() && renderer.camera.mode() == CameraMode::FirstPerson
+                });
+                if first_person {
+                    // Перша особа: камера в голові, колізія не потрібна
+                    let eye = self.player_eye_position();
+                    let look_at = lock_target.map(|target| target + glam::Vec3::Y * camera_height);
+                    if let Some(renderer) = &mut self.renderer {
+                        renderer.camera.update_first_person(eye, look_at, real_delta);
+                    }
+                } else if let Some(renderer) = self.renderer.as_mut().filter(|renderer| !renderer.camera.is_free_fly()) {
+                    // Колізія: sphere-cast від точки погляду до бажаної позиції камери
+                    // (від імені групи гравця - власні кістки та зброя не заважають)
+                    // Lock-on - фокус посередині між гравцем та ціллю
+                    let focus = player_pos + glam::Vec3::Y * camera_height;
+                    let target_focus = lock_target.map(|target| target + glam::Vec3::Y * camera_height);
+                    let (cast_origin, cast_distance) = match target_focus {
+                        Some(target_focus) => (
+                            focus.lerp(target_focus, 0.5),
+                            renderer.camera.lock_on_distance(focus, target_focus),
+                        ),
+                        None => (focus, renderer.camera.distance),
                     };
-                    renderer.camera.update_third_person(player_pos, 1.2);
+                    let as_group = self.use_physics_player.then_some(self.sim.ragdoll.skeleton.collision_group);
+                    let max_distance =
+                        renderer.camera.obstruction_distance(&self.sim.physics_world, cast_origin, cast_distance, as_group);
+                    match target_focus {
+                        Some(target_focus) => renderer.camera.update_lock_on(focus, target_focus, max_distance, real_delta),
+                        None => renderer.camera.update_third_person(player_pos, camera_height, max_distance, real_delta),
+                    }
                 }
-
-                // Рендеринг
                 if let Some(renderer) = &mut self.renderer {
-                    match renderer.render() {
-                        Ok(_) => {}
-                        Err(wgpu::SurfaceError::Lost) => {
-                            // Surface втрачено - треба пересоздать
-                            log::warn!("Surface lost, recreating...");
-                            if let Some(window) = &self.window {
-                                let size = window.inner_size();
-                                renderer.resize(size);
-                            }
-                        }
-                        Err(wgpu::SurfaceError::OutOfMemory) => {
-                            log::error!("Out of memory!");
-                            event_loop.exit();
-                        }
-                        Err(e) => {
-                            log::error!("Render error: {:?}", e);
-                        }
-                    }
+                    renderer.camera.update_shake(real_delta);
+                    renderer.set_lock_on_marker(lock_target);
                 }
+
+                // Рендеринг
+                self.render_frame(event_loop);
             }
 
             // Resize вікна
@@ -439,6 +1565,12 @@ impl ApplicationHandler for App {
                 if let Some(renderer) = &mut self.renderer {
                     renderer.resize(physical_size);
                 }
+                // Захоплення курсору - знову (fullscreen ↔ вікно його інколи скидає)
+                if let Some(window) = &self.window {
+                    if self.wants_cursor_captured() {
+                        set_cursor_captured(window, true);
+                    }
+                }
             }
 
             _ => {}
@@ -446,12 +1578,20 @@ impl ApplicationHandler for App {
     }
 
     fn about_to_wait(&mut self, _event_loop: &ActiveEventLoop) {
-        // Запит на перемальовування
+        // Залишок бюджету кадру ([rendering] max_fps), потім запит на перемальовування
+        self.frame_limiter.wait();
         if let Some(window) = &self.window {
             window.request_redraw();
         }
     }
 
+    fn exiting(&mut self, _event_loop: &ActiveEventLoop) {
+        // Зміни під час гри (debug перемикачі, present mode) - в settings.ini
+        if let Err(e) = self.settings.save(settings::DEFAULT_SETTINGS_PATH) {
+            log::warn!("Не вдалося зберегти налаштування: {}", e);
+        }
+    }
+
     fn device_event(
         &mut self,
         _event_loop: &ActiveEventLoop,
@@ -459,7 +1599,11 @@ impl ApplicationHandler for App {
         event: winit::event::DeviceEvent,
     ) {
         // Raw mouse motion - краще працює коли курсор захоплений
+        // (DeviceEvent приходять і без фокусу - тоді ігноруємо)
         if let winit::event::DeviceEvent::MouseMotion { delta } = event {
+            if !self.focused {
+                return;
+            }
             // Debug: раскоментуй для діагностики тачпада
             // log::debug!("RAW_DELTA: x={:.3}, y={:.3}", delta.0, delta.1);
             self.input_state.accumulate_raw_mouse_delta(delta.0, delta.1);
@@ -501,36 +1645,83 @@ fn main() {
     log::info!("Версія: 0.1.0");
     log::info!("Phase 1: Week 1-2 - Basic Rendering");
 
+    // Завантажуємо налаштування (відсутній файл = дефолти)
+    let settings = Settings::load(settings::DEFAULT_SETTINGS_PATH);
+
+    // Розмір арени (settings.ini [arena], CLI --arena-size має пріоритет)
+    let arena = ArenaConfig::from_settings(&settings).with_args(std::env::args().skip(1));
+    log::info!("Арена: half_extent = {}", arena.half_extent);
+
+    // Headless (CLI --headless <ticks>): фізика без вікна, інваріанти кожен крок
+    if let Some(ticks) = sim::headless_ticks_from_args(std::env::args().skip(1)) {
+        match sim::run_headless(&settings, &arena, ticks) {
+            Ok(()) => return,
+            Err(e) => {
+                log::error!("Headless: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    // Відтворення вводу (CLI --replay <file>)
+    let replay = input::replay::replay_path_from_args(std::env::args().skip(1)).and_then(|path| {
+        match Replay::load(&path) {
+            Ok(replay) => {
+                log::info!("Replay {:?}: {} кадрів", path, replay.frame_count());
+                Some(replay)
+            }
+            Err(e) => {
+                log::warn!("Не вдалося завантажити replay: {}", e);
+                None
+            }
+        }
+    });
+
     // Створити event loop
     let event_loop = EventLoop::new().unwrap();
     event_loop.set_control_flow(ControlFlow::Poll);
 
-    // Enemies вимкнені для тестування ragdoll
-    let enemies = Vec::new();
-
-    // Створюємо фізичний світ та ragdoll
-    let mut physics_world = PhysicsWorld::new();
-    physics_world.create_ground(0.0);  // Земля на Y=0
+    // Фізичний світ, ragdoll гравця та вороги (settings.ini)
+    let sim = Simulation::from_settings(&settings, &arena);
 
-    // Створюємо ragdoll на висоті 2м
-    let ragdoll = ActiveRagdoll::new(&mut physics_world, glam::Vec3::new(0.0, 2.0, 0.0));
-    log::info!("Physics ragdoll created");
+    // Ввід: прив'язки (keybindings.toml) та геймпад ([input])
+    let mut input_state = InputState::with_input_map(InputMap::load_or_create(InputMap::default_path()));
+    input_state.gamepad.apply_settings(&settings);
 
     // Створити app
     let mut app = App {
         window: None,
         renderer: None,
         fps_counter: FpsCounter::new(),
-        input_state: InputState::new(),
-        game_time: GameTime::new(),
-        player: Player::new(glam::Vec3::new(0.0, 0.0, 5.0)), // Старт трохи попереду
-        combat: Combat::new(),
-        hitbox_manager: HitboxManager::new(),
-        enemies,
+        quality: QualityController::from_settings(&settings),
+        window_info: WindowInfo::from_settings(&settings),
+        game_state: GameState::Playing,
+        input_state,
+        game_time: GameTime::from_settings(&settings),
+        frame_limiter: FrameLimiter::from_settings(&settings),
+        player: Player::new(PLAYER_START_POSITION),
+        combat: Combat::from_settings(&settings),
+        aim_assist: AimAssist::from_settings(&settings),
+        hitstop: Hitstop::from_settings(&settings),
+        replay_recorder: ReplayRecorder::new(),
+        replay,
+        replay_diverged: false,
+        lock_on: LockOnTarget::from_settings(&settings),
+        swing_sampler: SwingSampler::new(),
+        attack_hold: None,
         enemies_spawned: false,
-        physics_world: Some(physics_world),
-        ragdoll: Some(ragdoll),
+        sim,
+        sim_input: SimInput::default(),
         use_physics_player: true,  // Увімкнено фізичного ragdoll гравця
+        particles: ParticleSystem::new(),
+        mouse_sensitivity: settings
+            .get_f32("camera.mouse_sensitivity", DEFAULT_MOUSE_SENSITIVITY)
+            .max(0.0),
+        invert_y: settings.get_bool("camera.invert_y", false),
+        cursor_grab: settings.get_bool("window.cursor_grab", true),
+        focused: true,
+        windowed_size: None,
+        settings,  // Останнім - поля вище читають з нього
     };
 
     // Запустити event loop
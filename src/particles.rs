@@ -0,0 +1,164 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/particles.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   ParticleSystem - прості CPU частинки (пил від ударів ragdoll).
+
+   Частинка = точка з швидкістю та часом життя. Рендериться як коротка
+   лінія вздовж швидкості (WgpuRenderer::update_particles) - без окремого
+   billboard pipeline.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Спавн "хмарки" пилу в точці удару (кількість та швидкість ~ intensity)
+   - Інтеграція: легка гравітація, опір повітря, підлога на висоті спавну
+   - Видалення частинок після lifetime
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Експортує для:
+   - main.rs - spawn_dust з ImpactEvent, update кожен кадр
+   - renderer.rs - update_particles (лінії)
+
+⚠️  ВАЖЛИВІ ОБМЕЖЕННЯ:
+   1. Максимум max_particles (≤ MAX_PARTICLES, адаптивна якість) - нові
+      частинки понад ліміт відкидаються
+   2. Напрямки детерміновані (golden angle + лічильник), без rand
+   3. Частинки не взаємодіють з фізикою
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - пил від ударів кінцівок ragdoll
+   2026-10-16: Ліміт частинок змінюється на льоту (QualityTier)
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use glam::Vec3;
+
+/// Абсолютний максимум живих частинок
+pub const MAX_PARTICLES: usize = 256;
+
+/// Кількість частинок пилу при максимальній силі удару
+const DUST_PER_IMPACT: f32 = 12.0;
+
+/// Час життя частинки пилу (секунди)
+const DUST_LIFETIME: f32 = 0.6;
+
+/// Гравітація для пилу (слабша за фізичну - пил "висить" в повітрі)
+const DUST_GRAVITY: f32 = -3.0;
+
+/// Опір повітря (1/с)
+const DUST_DRAG: f32 = 3.0;
+
+/// Одна частинка
+#[derive(Debug, Clone, Copy)]
+pub struct Particle {
+    pub position: Vec3,
+    pub velocity: Vec3,
+
+    /// Вік (секунди)
+    pub age: f32,
+
+    /// Час життя (секунди)
+    pub lifetime: f32,
+
+    /// Нижче цієї висоти частинка не падає
+    floor_y: f32,
+}
+
+impl Particle {
+    /// Частка прожитого часу (0 = щойно створена, 1 = зникає)
+    pub fn life_fraction(&self) -> f32 {
+        (self.age / self.lifetime).clamp(0.0, 1.0)
+    }
+}
+
+/// Система частинок
+pub struct ParticleSystem {
+    particles: Vec<Particle>,
+
+    /// Поточний ліміт живих частинок
+    max_particles: usize,
+
+    /// Лічильник для розкиду напрямків між спавнами
+    spawn_counter: u32,
+}
+
+impl ParticleSystem {
+    /// Створює порожню систему частинок
+    pub fn new() -> Self {
+        Self {
+            particles: Vec::with_capacity(MAX_PARTICLES),
+            max_particles: MAX_PARTICLES,
+            spawn_counter: 0,
+        }
+    }
+
+    /// Спавнить хмарку пилу
+    ///
+    /// # Аргументи
+    /// * `position` - Точка удару
+    /// * `intensity` - Сила удару 0..1 (кількість та швидкість частинок)
+    pub fn spawn_dust(&mut self, position: Vec3, intensity: f32) {
+        let intensity = intensity.clamp(0.0, 1.0);
+        let count = ((DUST_PER_IMPACT * intensity).ceil() as usize).max(2);
+        let speed = 0.5 + 1.5 * intensity;
+
+        for _ in 0..count {
+            if self.particles.len() >= self.max_particles {
+                return;
+            }
+
+            // Golden angle дає рівномірне коло без rand
+            let i = self.spawn_counter as f32;
+            self.spawn_counter = self.spawn_counter.wrapping_add(1);
+            let angle = i * 2.399_963;
+            let spread = 0.6 + 0.4 * (i * 0.618_034).fract();
+            let radial = Vec3::new(angle.cos(), 0.0, angle.sin()) * speed * spread;
+            let up = Vec3::Y * speed * (0.4 + 0.6 * (1.0 - spread));
+
+            self.particles.push(Particle {
+                position,
+                velocity: radial + up,
+                age: 0.0,
+                lifetime: DUST_LIFETIME * spread,
+                floor_y: position.y,
+            });
+        }
+    }
+
+    /// Оновлює частинки та видаляє "мертві"
+    pub fn update(&mut self, delta: f32) {
+        let drag = (-DUST_DRAG * delta).exp();
+        for particle in &mut self.particles {
+            particle.age += delta;
+            particle.velocity.y += DUST_GRAVITY * delta;
+            particle.velocity *= drag;
+            particle.position += particle.velocity * delta;
+
+            if particle.position.y < particle.floor_y {
+                particle.position.y = particle.floor_y;
+                particle.velocity.y = 0.0;
+            }
+        }
+        self.particles.retain(|p| p.age < p.lifetime);
+    }
+
+    /// Змінює ліміт частинок (найстаріші понад ліміт видаляються)
+    pub fn set_max_particles(&mut self, max_particles: usize) {
+        self.max_particles = max_particles.min(MAX_PARTICLES);
+        let excess = self.particles.len().saturating_sub(self.max_particles);
+        self.particles.drain(..excess);
+    }
+
+    /// Живі частинки
+    pub fn particles(&self) -> &[Particle] {
+        &self.particles
+    }
+}
+
+impl Default for ParticleSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
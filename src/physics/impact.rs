@@ -0,0 +1,197 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/physics/impact.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   ImpactDetector - сильні удари кінцівок ragdoll об землю / стіни / props.
+
+   Rapier генерує ContactForceEvent для colliders кісток (поріг
+   CONTACT_FORCE_EVENT_THRESHOLD). Тут події фільтруються за силою та
+   швидкістю удару і перетворюються на ImpactEvent з BoneId - щоб падіння
+   ragdoll давало пил та звук "thud" саме там, де вдарилась кінцівка.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Запам'ятати швидкості кісток ДО кроку фізики
+   - Після кроку: contact force events → ImpactEvent
+   - collider handle → BoneId через Skeleton::bone_for_collider
+   - Cooldown на кістку (одна подія на удар, не кожен крок контакту)
+   - on_ground / is_hard - сильний удар об землю (голова → лог у main)
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Імпортує:
+   - mod.rs - PhysicsWorld::drain_contact_force_events / contact_point
+   - skeleton.rs - Skeleton, BoneId
+   - settings.rs - секція [physics]
+
+   Експортує для:
+   - main.rs - частинки пилу та звук удару
+
+⚠️  ВАЖЛИВІ ОБМЕЖЕННЯ:
+   1. record_velocities() викликати ПЕРЕД physics.step, collect() - ПІСЛЯ
+      (після кроку контакт вже погасив швидкість)
+   2. Швидкість удару - проєкція швидкості до кроку на напрямок сили контакту
+   3. Спокійний контакт (ragdoll стоїть / лежить) подій не дає - мала швидкість
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - удари кінцівок для частинок та звуку
+   2026-10-16: on_ground та HARD_IMPACT_FORCE - сильні удари об землю
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use glam::Vec3;
+use std::collections::HashMap;
+
+use super::{PhysicsWorld, Skeleton, BoneId, rapier_to_vec3};
+use crate::settings::Settings;
+
+/// Швидкість удару (m/s), при якій гучність звуку максимальна
+pub const FULL_VOLUME_IMPACT_SPEED: f32 = 8.0;
+
+/// Сила контакту (N), з якої удар вважається сильним (ImpactEvent::is_hard)
+pub const HARD_IMPACT_FORCE: f32 = 1000.0;
+
+/// Удар кінцівки об оточення
+#[derive(Debug, Clone, Copy)]
+pub struct ImpactEvent {
+    /// Кістка, що вдарилась
+    pub bone: BoneId,
+
+    /// Точка контакту (world space)
+    pub position: Vec3,
+
+    /// Швидкість удару вздовж нормалі контакту (m/s)
+    pub speed: f32,
+
+    /// Сумарна сила контакту (N)
+    pub force: f32,
+
+    /// Чи удар об землю (а не об стіну / prop / іншу кістку)
+    pub on_ground: bool,
+}
+
+impl ImpactEvent {
+    /// Сила удару 0..1 (гучність звуку, кількість частинок)
+    pub fn intensity(&self) -> f32 {
+        (self.speed / FULL_VOLUME_IMPACT_SPEED).clamp(0.0, 1.0)
+    }
+
+    /// Чи сильний удар (сила контакту >= HARD_IMPACT_FORCE)
+    pub fn is_hard(&self) -> bool {
+        self.force >= HARD_IMPACT_FORCE
+    }
+}
+
+/// Детектор ударів кінцівок
+pub struct ImpactDetector {
+    /// Мінімальна сила контакту (N)
+    pub force_threshold: f32,
+
+    /// Мінімальна швидкість удару (m/s)
+    pub min_speed: f32,
+
+    /// Пауза між подіями однієї кістки (секунди)
+    pub cooldown: f32,
+
+    /// Швидкості кісток перед поточним кроком
+    pre_step_velocities: HashMap<BoneId, Vec3>,
+
+    /// Залишок cooldown для кожної кістки
+    cooldowns: HashMap<BoneId, f32>,
+}
+
+impl ImpactDetector {
+    /// Читає секцію [physics] з налаштувань
+    ///
+    /// Ключі: `impact_force_threshold`, `impact_min_speed`, `impact_cooldown`
+    pub fn from_settings(settings: &Settings) -> Self {
+        let default = Self::default();
+        Self {
+            force_threshold: settings.get_f32("physics.impact_force_threshold", default.force_threshold),
+            min_speed: settings.get_f32("physics.impact_min_speed", default.min_speed),
+            cooldown: settings.get_f32("physics.impact_cooldown", default.cooldown).max(0.0),
+            ..default
+        }
+    }
+
+    /// Запам'ятовує швидкості кісток (викликати ПЕРЕД physics.step)
+    pub fn record_velocities(&mut self, physics: &PhysicsWorld, skeleton: &Skeleton) {
+        self.pre_step_velocities.clear();
+        for (bone_id, handle) in &skeleton.bodies {
+            if let Some(body) = physics.rigid_body_set.get(*handle) {
+                self.pre_step_velocities.insert(*bone_id, rapier_to_vec3(body.linvel()));
+            }
+        }
+    }
+
+    /// Перетворює contact force events останнього кроку на удари
+    ///
+    /// # Аргументи
+    /// * `physics` - Фізичний світ (після step)
+    /// * `skeleton` - Скелет, удари якого шукаємо
+    /// * `delta` - Крок фізики (для cooldown)
+    pub fn collect(&mut self, physics: &PhysicsWorld, skeleton: &Skeleton, delta: f32) -> Vec<ImpactEvent> {
+        for remaining in self.cooldowns.values_mut() {
+            *remaining -= delta;
+        }
+        self.cooldowns.retain(|_, remaining| *remaining > 0.0);
+
+        let mut impacts = Vec::new();
+        for event in physics.drain_contact_force_events() {
+            if event.total_force_magnitude < self.force_threshold {
+                continue;
+            }
+
+            // Кістка може бути будь-яким з двох colliders пари
+            let Some(bone) = skeleton
+                .bone_for_collider(physics, event.collider1)
+                .or_else(|| skeleton.bone_for_collider(physics, event.collider2))
+            else {
+                continue;
+            };
+
+            if self.cooldowns.contains_key(&bone) {
+                continue;
+            }
+
+            let velocity = self.pre_step_velocities.get(&bone).copied().unwrap_or(Vec3::ZERO);
+            let normal = rapier_to_vec3(&event.max_force_direction).normalize_or_zero();
+            let speed = if normal == Vec3::ZERO {
+                velocity.length()
+            } else {
+                velocity.dot(normal).abs()
+            };
+            if speed < self.min_speed {
+                continue;
+            }
+
+            let position = physics
+                .contact_point(event.collider1, event.collider2)
+                .or_else(|| skeleton.bodies.get(&bone).and_then(|h| physics.get_body_position(*h)))
+                .unwrap_or(Vec3::ZERO);
+
+            self.cooldowns.insert(bone, self.cooldown);
+            impacts.push(ImpactEvent {
+                bone,
+                position,
+                speed,
+                force: event.total_force_magnitude,
+                on_ground: physics.is_ground(event.collider1) || physics.is_ground(event.collider2),
+            });
+        }
+        impacts
+    }
+}
+
+impl Default for ImpactDetector {
+    fn default() -> Self {
+        Self {
+            force_threshold: 200.0,
+            min_speed: 1.5,
+            cooldown: 0.15,
+            pre_step_velocities: HashMap::new(),
+            cooldowns: HashMap::new(),
+        }
+    }
+}
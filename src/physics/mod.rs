@@ -13,6 +13,22 @@
    - Skeleton: ієрархія кісток з фізичними тілами
    - Muscle: PD-контролер для керування суглобом
    - ActiveRagdoll: комбінація скелета + м'язів
+   - RagdollStiffness: один профіль жорсткості для joints та м'язів
+   - ImpactDetector: сильні удари кінцівок з contact force events
+   - Collision events: черга CollisionEvent (початок / кінець контакту) з
+     усіх fixed steps кадру (drain_collision_events); collider_bone - collider
+     → (група скелета, BoneId) через user_data
+   - Групи колізій ragdoll: кожен ragdoll - своя група (allocate_ragdoll_group),
+     кістки не б'ються між собою, але б'ються з кістками інших ragdoll
+   - bones_in_sphere: які кістки яких ragdoll перетинає сфера (влучання
+     hitbox'ів); collider кістки несе BoneId в user_data
+   - cast_sphere: sphere-cast від імені групи ragdoll (колізія камери)
+   - Fixed timestep: fixed_timestep - крок step(); accumulator кадрів -
+     FixedStepper (time/) у Simulation
+   - Арена: create_ground, create_walls (box'и з поворотом), create_arena -
+     кругла стіна з сегментів (arena::ring_wall_boxes)
+   - RagdollWatchdog: NaN / вибух кісток після кроку → обмеження швидкостей
+     або повернення пози з історії
 
 ═══════════════════════════════════════════════════════════════════════════════
 */
@@ -20,15 +36,86 @@
 pub mod skeleton;
 pub mod muscle;
 pub mod ragdoll;
+pub mod impact;
+pub mod watchdog;
 
 pub use skeleton::{Skeleton, Bone, BoneId};
 pub use muscle::{Muscle, MuscleSystem};
 pub use ragdoll::ActiveRagdoll;
+pub use impact::{ImpactDetector, ImpactEvent};
+pub use watchdog::{RagdollWatchdog, WatchdogOutcome};
 
 use rapier3d::prelude::*;
+use rapier3d::crossbeam;
 pub use rapier3d::prelude::nalgebra;
+pub use rapier3d::prelude::Group;
 use glam::{Vec3, Quat};
 
+use crate::arena::{ring_wall_boxes, WallBox, RING_WALL_THICKNESS};
+use crate::settings::Settings;
+
+/// Група статичних colliders (земля, стіни)
+const STATIC_GROUP: Group = Group::GROUP_2;
+
+/// Профіль жорсткості ragdoll
+///
+/// Масштабує ВСІ joint motors (stiffness, damping, max force) та м'язи
+/// (kp, kd, max_torque) разом - один параметр замість десятків констант
+/// в create_joints та MuscleSystem::create_humanoid.
+/// Застосовується при створенні скелета та м'язів.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RagdollStiffness {
+    /// М'який, "ватний" ragdoll
+    Soft,
+    /// Поточні значення констант
+    #[default]
+    Normal,
+    /// Жорсткий, майже твердий
+    Stiff,
+}
+
+impl RagdollStiffness {
+    /// Множник жорсткості (motor stiffness, muscle kp)
+    pub fn stiffness_scale(self) -> f32 {
+        match self {
+            Self::Soft => 0.5,
+            Self::Normal => 1.0,
+            Self::Stiff => 2.0,
+        }
+    }
+
+    /// Множник демпфування (√stiffness - зберігає співвідношення до критичного)
+    pub fn damping_scale(self) -> f32 {
+        self.stiffness_scale().sqrt()
+    }
+
+    /// Множник максимальної сили (motor max force, muscle max_torque)
+    pub fn force_scale(self) -> f32 {
+        self.stiffness_scale()
+    }
+
+    /// Читає `physics.ragdoll_stiffness` (невідома назва - Normal з попередженням)
+    pub fn from_settings(settings: &Settings) -> Self {
+        match settings.get_str("physics.ragdoll_stiffness") {
+            Some(name) => Self::from_name(name).unwrap_or_else(|| {
+                log::warn!("settings: невідомий ragdoll_stiffness {:?}, використовуємо normal", name);
+                Self::Normal
+            }),
+            None => Self::default(),
+        }
+    }
+
+    /// Парсить назву профілю ("soft" / "normal" / "stiff")
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "soft" => Some(Self::Soft),
+            "normal" => Some(Self::Normal),
+            "stiff" => Some(Self::Stiff),
+            _ => None,
+        }
+    }
+}
+
 /// Обгортка над Rapier3D фізичним світом
 pub struct PhysicsWorld {
     /// Параметри гравітації
@@ -40,6 +127,9 @@ pub struct PhysicsWorld {
     pub impulse_joint_set: ImpulseJointSet,
     pub multibody_joint_set: MultibodyJointSet,
 
+    /// Фіксований крок симуляції (секунди)
+    pub fixed_timestep: f32,
+
     /// Integration parameters
     integration_parameters: IntegrationParameters,
 
@@ -50,19 +140,42 @@ pub struct PhysicsWorld {
     narrow_phase: NarrowPhase,
     ccd_solver: CCDSolver,
     query_pipeline: QueryPipeline,
+
+    /// Збирати collision events у чергу (drain_collision_events)
+    pub collect_collision_events: bool,
+
+    /// Збирач подій Rapier (collision / contact force events → канали)
+    event_collector: ChannelEventCollector,
+    collision_events: crossbeam::channel::Receiver<CollisionEvent>,
+    contact_force_events: crossbeam::channel::Receiver<ContactForceEvent>,
+
+    /// Collision events з останнього drain (накопичуються між кроками)
+    collision_queue: Vec<CollisionEvent>,
+
+    /// Collider землі (create_ground)
+    ground_collider: Option<ColliderHandle>,
+
+    /// Лічильник виданих груп колізій ragdoll
+    ragdoll_groups_allocated: u32,
 }
 
 impl PhysicsWorld {
     /// Створює новий фізичний світ (оптимізовано для ragdolls)
     pub fn new() -> Self {
         // Оптимізовані параметри для active ragdolls
-        let mut integration_parameters = IntegrationParameters::default();
         // Note: Rapier 0.22 uses different solver parameters
         // Solver iterations are configured per-joint via motor parameters
-        integration_parameters.dt = 1.0 / 60.0;  // 60 Hz physics
+        let integration_parameters = IntegrationParameters {
+            dt: 1.0 / 60.0, // 60 Hz physics
+            ..Default::default()
+        };
+
+        let (collision_send, collision_events) = crossbeam::channel::unbounded();
+        let (contact_force_send, contact_force_events) = crossbeam::channel::unbounded();
 
         Self {
             gravity: vector![0.0, -9.81, 0.0],
+            fixed_timestep: integration_parameters.dt,
             rigid_body_set: RigidBodySet::new(),
             collider_set: ColliderSet::new(),
             impulse_joint_set: ImpulseJointSet::new(),
@@ -74,9 +187,45 @@ impl PhysicsWorld {
             narrow_phase: NarrowPhase::new(),
             ccd_solver: CCDSolver::new(),
             query_pipeline: QueryPipeline::new(),
+            collect_collision_events: false,
+            event_collector: ChannelEventCollector::new(collision_send, contact_force_send),
+            collision_events,
+            contact_force_events,
+            collision_queue: Vec::new(),
+            ground_collider: None,
+            ragdoll_groups_allocated: 0,
         }
     }
 
+    /// Група колізій для нового ragdoll
+    ///
+    /// Кістки одного ragdoll в одній групі і не колізують між собою;
+    /// з кістками інших ragdoll - колізують. Груп 31 (всі, крім
+    /// STATIC_GROUP) - далі видаються по колу, і ragdoll зі спільною
+    /// групою проходять один крізь одного.
+    pub fn allocate_ragdoll_group(&mut self) -> Group {
+        let free_groups = 31;
+        let index = self.ragdoll_groups_allocated % free_groups;
+        self.ragdoll_groups_allocated += 1;
+
+        // Біти 0..32 без біта STATIC_GROUP
+        let static_bit = STATIC_GROUP.bits().trailing_zeros();
+        let bit = if index >= static_bit { index + 1 } else { index };
+        Group::from_bits_truncate(1 << bit)
+    }
+
+    /// Видаляє rigid body разом з його colliders та joints
+    pub fn remove_rigid_body(&mut self, handle: RigidBodyHandle) {
+        self.rigid_body_set.remove(
+            handle,
+            &mut self.island_manager,
+            &mut self.collider_set,
+            &mut self.impulse_joint_set,
+            &mut self.multibody_joint_set,
+            true,
+        );
+    }
+
     /// Крок фізичної симуляції
     pub fn step(&mut self, delta: f32) {
         self.integration_parameters.dt = delta;
@@ -94,11 +243,217 @@ impl PhysicsWorld {
             &mut self.ccd_solver,
             Some(&mut self.query_pipeline),
             &(),
-            &(),
+            &self.event_collector,
+        );
+
+        // Сили / torque користувача в Rapier не скидаються самі - без цього
+        // м'язи та контролери накопичуються з кожним кроком
+        for (_, body) in self.rigid_body_set.iter_mut() {
+            body.reset_forces(false);
+            body.reset_torques(false);
+        }
+
+        // Канал читаємо завжди (інакше росте), буферизуємо тільки якщо увімкнено
+        for event in self.collision_events.try_iter() {
+            if self.collect_collision_events {
+                self.collision_queue.push(event);
+            }
+        }
+    }
+
+    /// Забирає collision events (початок та кінець контакту) з останнього виклику
+    ///
+    /// Працює тільки з `collect_collision_events = true`. Події генеруються
+    /// для colliders з ActiveEvents::COLLISION_EVENTS (кістки, меч, земля).
+    /// Події накопичуються між кількома fixed steps одного кадру.
+    pub fn drain_collision_events(&mut self) -> Vec<CollisionEvent> {
+        std::mem::take(&mut self.collision_queue)
+    }
+
+    /// Кістка, якій належить collider
+    ///
+    /// # Повертає
+    /// (група колізій скелета, кістка) з user_data collider'а; None для
+    /// землі, стін, props та видалених colliders
+    pub fn collider_bone(&self, collider: ColliderHandle) -> Option<(Group, BoneId)> {
+        let collider = self.collider_set.get(collider)?;
+        let bone_id = BoneId::from_user_data(collider.user_data)?;
+        Some((collider.collision_groups().memberships, bone_id))
+    }
+
+    /// Чи це collider землі
+    pub fn is_ground(&self, collider: ColliderHandle) -> bool {
+        self.ground_collider == Some(collider)
+    }
+
+    /// Забирає всі contact force events, накопичені з останнього виклику
+    ///
+    /// Події генеруються тільки для colliders з ActiveEvents::CONTACT_FORCE_EVENTS
+    /// і тільки якщо сила контакту > contact_force_event_threshold collider'а.
+    pub fn drain_contact_force_events(&self) -> Vec<ContactForceEvent> {
+        self.contact_force_events.try_iter().collect()
+    }
+
+    /// Точка контакту між двома colliders (world space)
+    ///
+    /// Середнє всіх solver contacts пари. None якщо пара вже не в контакті.
+    pub fn contact_point(&self, collider1: ColliderHandle, collider2: ColliderHandle) -> Option<Vec3> {
+        let pair = self.narrow_phase.contact_pair(collider1, collider2)?;
+        let mut sum = Vec3::ZERO;
+        let mut count = 0;
+        for manifold in &pair.manifolds {
+            for contact in &manifold.data.solver_contacts {
+                sum += Vec3::new(contact.point.x, contact.point.y, contact.point.z);
+                count += 1;
+            }
+        }
+        (count > 0).then(|| sum / count as f32)
+    }
+
+    /// Поточна гравітація (m/s²)
+    pub fn gravity(&self) -> Vec3 {
+        rapier_to_vec3(&self.gravity)
+    }
+
+    /// Змінює гравітацію (діє з наступного step)
+    ///
+    /// Vec3::ZERO - допустимо (невагомість, ragdoll "плаває").
+    /// Сплячі тіла будяться - інакше вони не відчують нову гравітацію.
+    pub fn set_gravity(&mut self, gravity: Vec3) {
+        self.gravity = vec3_to_rapier(gravity);
+        for (_, body) in self.rigid_body_set.iter_mut() {
+            if body.is_dynamic() {
+                body.wake_up(true);
+            }
+        }
+    }
+
+    /// Кидає промінь і повертає перше тіло на шляху
+    ///
+    /// # Аргументи
+    /// * `origin` - Початок променя (world space)
+    /// * `dir` - Напрямок (нормалізується - toi в world units)
+    /// * `max_toi` - Максимальна відстань
+    ///
+    /// # Повертає
+    /// (батьківське тіло collider'а, відстань). None - промінь нічого не зачепив
+    /// або collider без тіла. Промінь зсередини collider'а дає toi = 0.
+    ///
+    /// Query pipeline оновлюється в step() - до першого кроку світ "порожній".
+    pub fn cast_ray(&self, origin: Vec3, dir: Vec3, max_toi: f32) -> Option<(RigidBodyHandle, f32)> {
+        self.cast_ray_filtered(origin, dir, max_toi, QueryFilter::default())
+    }
+
+    /// Як cast_ray, але тільки по нерухомих тілах (земля, стіни)
+    ///
+    /// Dynamic тіла (кістки ragdoll, props) пропускаються - промінь від
+    /// стопи вниз не зачепить саму гомілку.
+    ///
+    /// # Повертає
+    /// Відстань до першого нерухомого collider'а
+    pub fn cast_ray_static(&self, origin: Vec3, dir: Vec3, max_toi: f32) -> Option<f32> {
+        self.cast_ray_filtered(origin, dir, max_toi, QueryFilter::exclude_dynamic())
+            .map(|(_, toi)| toi)
+    }
+
+    /// Кістки ragdoll, які перетинає сфера
+    ///
+    /// # Повертає
+    /// (група колізій скелета, кістка) для кожного collider'а кістки в сфері;
+    /// ragdoll розрізняються за Skeleton::collision_group. Позиції - з
+    /// останнього step() (query pipeline оновлюється там).
+    pub fn bones_in_sphere(&self, position: Vec3, radius: f32) -> Vec<(Group, BoneId)> {
+        let shape = Ball::new(radius);
+        let shape_pos = Isometry::translation(position.x, position.y, position.z);
+        let mut bones = Vec::new();
+        self.query_pipeline.intersections_with_shape(
+            &self.rigid_body_set,
+            &self.collider_set,
+            &shape_pos,
+            &shape,
+            QueryFilter::only_dynamic(),
+            |handle| {
+                bones.extend(self.collider_bone(handle));
+                true
+            },
         );
+        bones
+    }
+
+    /// Sphere-cast: на якій відстані сфера вздовж dir вперше торкнеться collider'а
+    ///
+    /// # Аргументи
+    /// * `as_group` - кидати від імені групи ragdoll (Skeleton::collision_group):
+    ///   collider'и, які з цією групою не стикаються (власні кістки та зброя),
+    ///   пропускаються. None - враховуються всі collider'и.
+    ///
+    /// # Повертає
+    /// Відстань руху центру сфери до контакту; 0 - сфера вже перетинає collider
+    pub fn cast_sphere(
+        &self,
+        origin: Vec3,
+        dir: Vec3,
+        max_toi: f32,
+        radius: f32,
+        as_group: Option<Group>,
+    ) -> Option<f32> {
+        let dir = dir.normalize_or_zero();
+        if dir == Vec3::ZERO || radius <= 0.0 {
+            return None;
+        }
+
+        let mut filter = QueryFilter::default().exclude_sensors();
+        if let Some(group) = as_group {
+            filter = filter.groups(InteractionGroups::new(group, Group::ALL));
+        }
+
+        let shape = Ball::new(radius);
+        let shape_pos = Isometry::translation(origin.x, origin.y, origin.z);
+        let options = rapier3d::parry::query::ShapeCastOptions {
+            max_time_of_impact: max_toi,
+            target_distance: 0.0,
+            stop_at_penetration: true,
+            compute_impact_geometry_on_penetration: false,
+        };
+        let (_, hit) = self.query_pipeline.cast_shape(
+            &self.rigid_body_set,
+            &self.collider_set,
+            &shape_pos,
+            &vec3_to_rapier(dir),
+            &shape,
+            options,
+            filter,
+        )?;
+        Some(hit.time_of_impact)
+    }
+
+    fn cast_ray_filtered(
+        &self,
+        origin: Vec3,
+        dir: Vec3,
+        max_toi: f32,
+        filter: QueryFilter,
+    ) -> Option<(RigidBodyHandle, f32)> {
+        let dir = dir.normalize_or_zero();
+        if dir == Vec3::ZERO {
+            return None;
+        }
+
+        let ray = Ray::new(vec3_to_rapier(origin).into(), vec3_to_rapier(dir));
+        let (collider, toi) = self.query_pipeline.cast_ray(
+            &self.rigid_body_set,
+            &self.collider_set,
+            &ray,
+            max_toi,
+            true, // solid - старт всередині collider'а = toi 0
+            filter,
+        )?;
+
+        let body = self.collider_set.get(collider)?.parent()?;
+        Some((body, toi))
     }
 
-    /// Додає rigid body і повертає handle
+        /// Додає rigid body і повертає handle
     pub fn add_rigid_body(&mut self, body: RigidBody) -> RigidBodyHandle {
         self.rigid_body_set.insert(body)
     }
@@ -149,24 +504,66 @@ impl PhysicsWorld {
     }
 
     /// Створює землю (статичний collider)
-    pub fn create_ground(&mut self, y: f32) {
+    ///
+    /// # Аргументи
+    /// * `y` - висота поверхні землі
+    /// * `half_extent` - половина розміру по X та Z (див. ArenaConfig)
+    pub fn create_ground(&mut self, y: f32, half_extent: f32) {
         let ground = RigidBodyBuilder::fixed()
             .translation(vector![0.0, y, 0.0])
             .build();
         let ground_handle = self.rigid_body_set.insert(ground);
 
-        // Ground має колізуватись з GROUP_1 (кістками скелета)
+        // Ground має колізуватись з кістками всіх скелетів
         // membership: GROUP_2 (ground group)
         // filter: ALL (колізія з усіма)
-        let ground_collider = ColliderBuilder::cuboid(50.0, 0.1, 50.0)
+        let ground_collider = ColliderBuilder::cuboid(half_extent, 0.1, half_extent)
             .friction(0.8)
             .restitution(0.0)  // Без відскоку
             .collision_groups(InteractionGroups::new(
-                Group::GROUP_2,  // Ground is in GROUP_2
-                Group::ALL,      // Collide with everything
+                STATIC_GROUP,  // Ground is in GROUP_2
+                Group::ALL,    // Collide with everything
             ))
+            .active_events(ActiveEvents::COLLISION_EVENTS)
             .build();
-        self.collider_set.insert_with_parent(ground_collider, ground_handle, &mut self.rigid_body_set);
+        self.ground_collider = Some(
+            self.collider_set.insert_with_parent(ground_collider, ground_handle, &mut self.rigid_body_set),
+        );
+    }
+
+    /// Створює стіни арени (статичні colliders, як земля)
+    ///
+    /// # Аргументи
+    /// * `walls` - box'и стін з поворотом навколо Y (ArenaConfig::wall_boxes)
+    pub fn create_walls(&mut self, walls: &[WallBox]) {
+        for wall in walls {
+            let half = wall.half_extents;
+            let wall = RigidBodyBuilder::fixed()
+                .translation(vec3_to_rapier(wall.center))
+                .rotation(vector![0.0, wall.yaw, 0.0])
+                .build();
+            let wall_handle = self.rigid_body_set.insert(wall);
+
+            let wall_collider = ColliderBuilder::cuboid(half.x, half.y, half.z)
+                .friction(0.5)
+                .restitution(0.0)
+                .collision_groups(InteractionGroups::new(
+                    STATIC_GROUP,  // Та сама група що й земля
+                    Group::ALL,
+                ))
+                .build();
+            self.collider_set.insert_with_parent(wall_collider, wall_handle, &mut self.rigid_body_set);
+        }
+    }
+
+    /// Створює круглу арену: землю на Y=0 та стіну-кільце з сегментів
+    ///
+    /// # Аргументи
+    /// * `radius` - радіус внутрішньої грані стіни
+    /// * `wall_height` - висота стіни (0 - лише земля)
+    pub fn create_arena(&mut self, radius: f32, wall_height: f32) {
+        self.create_ground(0.0, radius + RING_WALL_THICKNESS);
+        self.create_walls(&ring_wall_boxes(radius, wall_height, RING_WALL_THICKNESS));
     }
 }
 
@@ -195,3 +592,81 @@ pub fn quat_to_rapier(q: Quat) -> nalgebra::UnitQuaternion<f32> {
 pub fn rapier_to_quat(q: &nalgebra::UnitQuaternion<f32>) -> Quat {
     Quat::from_xyzw(q.i, q.j, q.k, q.w)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_gravity_round_trips() {
+        let mut physics = PhysicsWorld::new();
+        assert_eq!(physics.gravity(), Vec3::new(0.0, -9.81, 0.0));
+        physics.set_gravity(Vec3::new(0.0, -1.62, 0.0));
+        assert_eq!(physics.gravity(), Vec3::new(0.0, -1.62, 0.0));
+        physics.set_gravity(Vec3::ZERO);
+        assert_eq!(physics.gravity(), Vec3::ZERO);
+    }
+
+    /// Pelvis Y ragdoll'а без землі: (на старті, через 2 с)
+    fn pelvis_drop(gravity: Vec3) -> (f32, f32) {
+        let mut physics = PhysicsWorld::new();
+        physics.set_gravity(gravity);
+        let ragdoll = ActiveRagdoll::new(&mut physics, Vec3::new(0.0, 2.0, 0.0), RagdollStiffness::default());
+        let pelvis = |physics: &PhysicsWorld| {
+            ragdoll.skeleton.get_bone_position(physics, BoneId::Pelvis).expect("pelvis")
+        };
+        let start = pelvis(&physics);
+        for _ in 0..120 {
+            physics.step(physics.fixed_timestep);
+        }
+        let end = pelvis(&physics);
+        assert!(end.is_finite());
+        (start.y, end.y)
+    }
+
+    #[test]
+    fn zero_gravity_ragdoll_does_not_fall() {
+        // Суглоби трохи стягують кістки на старті - допуск 5 см
+        let (start, end) = pelvis_drop(Vec3::ZERO);
+        assert!(end > start - 0.05, "pelvis опустився з {:.3} до {:.3}", start, end);
+
+        let (start, end) = pelvis_drop(Vec3::new(0.0, -9.81, 0.0));
+        assert!(end < start - 1.0, "з гравітацією pelvis мав падати: {:.3} → {:.3}", start, end);
+    }
+
+    #[test]
+    fn ragdoll_dropped_on_ground_produces_events() {
+        let mut physics = PhysicsWorld::new();
+        physics.create_ground(0.0, 10.0);
+        physics.collect_collision_events = true;
+        let ragdoll = ActiveRagdoll::new(&mut physics, Vec3::new(0.0, 1.5, 0.0), RagdollStiffness::default());
+        let ground = physics.ground_collider.expect("земля");
+
+        let mut events = Vec::new();
+        for _ in 0..120 {
+            physics.step(physics.fixed_timestep);
+            events.extend(physics.drain_collision_events());
+        }
+        // Кістка торкнулась землі
+        let bone_on_ground = events.iter().filter(|event| event.started()).any(|event| {
+            let (c1, c2) = (event.collider1(), event.collider2());
+            let other = if c1 == ground { c2 } else if c2 == ground { c1 } else { return false };
+            physics
+                .collider_bone(other)
+                .is_some_and(|(group, _)| group == ragdoll.skeleton.collision_group)
+        });
+        assert!(bone_on_ground, "немає події контакту кістки з землею ({} подій)", events.len());
+        assert!(physics.drain_collision_events().is_empty());
+    }
+
+    #[test]
+    fn collision_events_off_by_default() {
+        let mut physics = PhysicsWorld::new();
+        physics.create_ground(0.0, 10.0);
+        let _ragdoll = ActiveRagdoll::new(&mut physics, Vec3::new(0.0, 1.5, 0.0), RagdollStiffness::default());
+        for _ in 0..120 {
+            physics.step(physics.fixed_timestep);
+        }
+        assert!(physics.drain_collision_events().is_empty());
+    }
+}
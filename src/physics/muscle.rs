@@ -13,13 +13,44 @@
    Kp (Proportional) - жорсткість м'яза (як сильно тягне до цілі)
    Kd (Derivative) - демпфування (запобігає осциляціям)
 
+⚡ ОНОВЛЕННЯ (MuscleSystem::update) - 3 фази:
+   1. Read (послідовно) - ротації та angular velocity з Rapier
+   2. Compute (rayon, якщо м'язів >= PARALLEL_MUSCLE_THRESHOLD) - calculate_torque
+      (P / D обмежені інерцією кістки - без розгойдування легких кісток)
+   3. Apply (послідовно) - apply_torque в Rapier
+   Rapier borrows залишаються в одному потоці, паралельна тільки математика.
+
+🦶 FOOT IK (WalkCycle::with_foot_ik, foot_ik_enabled):
+   Raycast вниз від кульшового суглоба (верхній кінець стегна,
+   PhysicsWorld::cast_ray_static) - висота землі під кожною ногою.
+   Нога над нижчою землею пряма, нога над вищою коротшає на різницю -
+   обидві капсули гомілок торкаються землі, коли стегна на висоті прямої
+   ноги над нижчою точкою. Ціль залежить лише від землі, не від поточної
+   висоти стегон - просіле тіло не "закріплюється" в присіді.
+   В опорній фазі (і в стійці) two-bone IK (apply_foot_ik) підбирає кути
+   стегна та коліна; напрямок стегно→стопа лишається з відкритої пози.
+   У фазі переносу вага IK плавно спадає - ногу піднімає сам цикл.
+
+🏃 ХОДА (Gait, get_strafe_pose):
+   WALK_GAIT / RUN_GAIT - швидкість циклу, довжина кроку та нахил торсу
+   (set_gait, ActiveRagdoll при зміні стану локомоції). Стрейф - окремий
+   генератор: ноги махають вбік (навколо Z) в протифазі - крок з
+   перехрестям, торс майже не крутиться, руки махають слабше.
+
+🧍 ПІДЙОМ (getup_sequence):
+   Ключові кадри з тривалостями: sit_up / all_fours → get_up → standing.
+   Пози задані для грудей в +Z, ragdoll повертає їх rotated_y на свій yaw.
+
 ═══════════════════════════════════════════════════════════════════════════════
 */
 
 use glam::{Vec3, Quat};
+use rapier3d::prelude::RigidBodyHandle;
+use rayon::prelude::*;
 use std::collections::HashMap;
 
 use super::skeleton::{Skeleton, BoneId};
+use super::RagdollStiffness;
 
 /// Smooth step function для плавної інтерполяції
 /// Ease-in-ease-out: повільний старт, швидка середина, повільний кінець
@@ -32,6 +63,49 @@ pub fn smooth_step(t: f32) -> f32 {
 
 use super::PhysicsWorld;
 
+/// Амплітуда розмаху рук при ходьбі за замовчуванням (радіани)
+pub const DEFAULT_ARM_SWING: f32 = 0.3;
+
+/// Параметри ходи, що відрізняють ходьбу від бігу
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gait {
+    /// Швидкість циклу (WalkCycle::speed)
+    pub speed: f32,
+
+    /// Довжина кроку (радіани повороту стегна)
+    pub stride_length: f32,
+
+    /// Нахил торсу вперед
+    pub spine_lean_forward: f32,
+}
+
+/// Хода - значення WalkCycle::new
+pub const WALK_GAIT: Gait = Gait { speed: 1.0, stride_length: 0.5, spine_lean_forward: 0.1 };
+
+/// Біг (спринт) - частіше, ширше, з нахилом вперед
+pub const RUN_GAIT: Gait = Gait { speed: 1.6, stride_length: 0.7, spine_lean_forward: 0.3 };
+
+/// Стрейф: частка stride_length для бокового маху ніг
+const STRAFE_STRIDE_SCALE: f32 = 0.6;
+
+/// Стрейф: частка розмаху рук та повороту торсу відносно ходьби
+const STRAFE_SWING_SCALE: f32 = 0.3;
+
+/// Foot IK: на скільки глибше за пряму ногу шукати землю від стегна (м)
+const FOOT_RAY_DEPTH: f32 = 0.5;
+
+/// Foot IK: максимальний згин коліна (радіани, як у циклі ходьби)
+const FOOT_IK_MAX_BEND: f32 = 1.2;
+
+/// Foot IK: швидкість наближення корекції до цілі (1/с)
+const FOOT_IK_RATE: f32 = 12.0;
+
+/// Кістки ніг для foot IK та кроків: (стегно, гомілка), [ліва, права]
+pub const FOOT_IK_LEGS: [(BoneId, BoneId); 2] = [
+    (BoneId::LeftUpperLeg, BoneId::LeftLowerLeg),
+    (BoneId::RightUpperLeg, BoneId::RightLowerLeg),
+];
+
 /// PD-контролер для одного суглоба
 #[derive(Debug, Clone)]
 pub struct Muscle {
@@ -78,10 +152,19 @@ impl Muscle {
     }
 
     /// Обчислює torque для досягнення цільової пози
+    ///
+    /// Явний PD з kp × dt² або kd × dt порядку моменту інерції перестрибує
+    /// ціль за один крок - легкі кістки (гомілки, передпліччя) розгойдуються
+    /// навіть без навантаження. По кожній головній осі інерції I:
+    /// P не більше I × кут / dt² (дійти до цілі за крок), D не більше
+    /// I × |ω| / dt (зупинити, але не розвернути обертання).
     pub fn calculate_torque(
         &self,
         current_rotation: Quat,
         angular_velocity: Vec3,
+        inertia_frame: Quat,
+        inertia: Vec3,
+        dt: f32,
     ) -> Vec3 {
         if self.strength < 0.01 {
             return Vec3::ZERO;
@@ -103,15 +186,21 @@ impl Muscle {
             angle
         };
 
-        // PD control
-        // P term: пропорційний до помилки
-        let p_term = axis * angle * self.kp;
+        // PD control в головних осях інерції кістки
+        let to_local = inertia_frame.inverse();
+        let error = to_local * (axis * angle);
+        let omega = to_local * angular_velocity;
+
+        // P term: пропорційний до помилки, не далі цілі за крок
+        let p_max = inertia * error.abs() / (dt * dt);
+        let p_term = (error * self.kp * self.strength).clamp(-p_max, p_max);
 
-        // D term: демпфування на основі angular velocity
-        let d_term = -angular_velocity * self.kd;
+        // D term: демпфування на основі angular velocity, без розвороту
+        let d_max = inertia * omega.abs() / dt;
+        let d_term = (-omega * self.kd * self.strength).clamp(-d_max, d_max);
 
         // Сумарний torque
-        let mut torque = (p_term + d_term) * self.strength;
+        let mut torque = inertia_frame * (p_term + d_term);
 
         // Обмежуємо максимальний torque
         let torque_magnitude = torque.length();
@@ -123,6 +212,23 @@ impl Muscle {
     }
 }
 
+/// Стан кістки для одного оновлення м'язів (фаза Read)
+struct MuscleState<'a> {
+    handle: RigidBodyHandle,
+    muscle: &'a Muscle,
+    rotation: Quat,
+    angvel: Vec3,
+    /// Головні осі інерції в world space
+    inertia_frame: Quat,
+    /// Головні моменти інерції (кг·м²)
+    inertia: Vec3,
+}
+
+/// Мінімальна кількість м'язів для паралельного обчислення
+///
+/// Для одного скелета (10 м'язів) накладні витрати rayon більші за виграш.
+pub const PARALLEL_MUSCLE_THRESHOLD: usize = 64;
+
 /// Система м'язів для всього скелета
 pub struct MuscleSystem {
     /// М'язи для кожної кістки
@@ -134,7 +240,9 @@ pub struct MuscleSystem {
 
 impl MuscleSystem {
     /// Створює систему м'язів для гуманоїдного скелета (оптимізовано: 11 кісток)
-    pub fn create_humanoid() -> Self {
+    ///
+    /// `stiffness` масштабує kp / kd / max_torque всіх м'язів
+    pub fn create_humanoid(stiffness: RagdollStiffness) -> Self {
         let mut muscles = HashMap::new();
 
         // Торс - сильні м'язи для підтримки вертикального положення
@@ -157,6 +265,13 @@ impl MuscleSystem {
         muscles.insert(BoneId::RightUpperLeg, Muscle::new(BoneId::RightUpperLeg, 1000.0, 100.0, 800.0));
         muscles.insert(BoneId::RightLowerLeg, Muscle::new(BoneId::RightLowerLeg, 800.0, 80.0, 600.0));
 
+        // Профіль жорсткості (ті самі множники, що й для joint motors)
+        for muscle in muscles.values_mut() {
+            muscle.kp *= stiffness.stiffness_scale();
+            muscle.kd *= stiffness.damping_scale();
+            muscle.max_torque *= stiffness.force_scale();
+        }
+
         Self {
             muscles,
             global_strength: 1.0,
@@ -165,23 +280,47 @@ impl MuscleSystem {
 
     /// Оновлює м'язи і застосовує torque до фізичних тіл
     pub fn update(&self, physics: &mut PhysicsWorld, skeleton: &Skeleton) {
-        for (bone_id, muscle) in &self.muscles {
-            // Отримуємо поточну ротацію кістки
-            if let Some(body_handle) = skeleton.bodies.get(bone_id) {
-                if let Some(body) = physics.rigid_body_set.get(*body_handle) {
-                    let current_rotation = super::rapier_to_quat(body.rotation());
-                    let angular_velocity = super::rapier_to_vec3(body.angvel());
-
-                    // Обчислюємо torque
-                    let mut torque = muscle.calculate_torque(current_rotation, angular_velocity);
-
-                    // Застосовуємо глобальний множник
-                    torque *= self.global_strength;
+        // 1. Read: поточна ротація, angular velocity та інерція кожної кістки
+        let dt = physics.fixed_timestep;
+        let states: Vec<MuscleState> = self.muscles
+            .iter()
+            .filter_map(|(bone_id, muscle)| {
+                let body_handle = *skeleton.bodies.get(bone_id)?;
+                let body = physics.rigid_body_set.get(body_handle)?;
+                let mass_properties = body.mass_properties().local_mprops;
+                let rotation = super::rapier_to_quat(body.rotation());
+                Some(MuscleState {
+                    handle: body_handle,
+                    muscle,
+                    rotation,
+                    angvel: super::rapier_to_vec3(body.angvel()),
+                    inertia_frame: rotation * super::rapier_to_quat(&mass_properties.principal_inertia_local_frame),
+                    inertia: super::rapier_to_vec3(&mass_properties.principal_inertia()),
+                })
+            })
+            .collect();
+
+        // 2. Compute: torque для кожного м'яза (з глобальним множником)
+        let global_strength = self.global_strength;
+        let compute = |state: &MuscleState| {
+            let torque = state.muscle.calculate_torque(
+                state.rotation,
+                state.angvel,
+                state.inertia_frame,
+                state.inertia,
+                dt,
+            );
+            (state.handle, torque * global_strength)
+        };
+        let torques: Vec<(RigidBodyHandle, Vec3)> = if states.len() >= PARALLEL_MUSCLE_THRESHOLD {
+            states.par_iter().map(compute).collect()
+        } else {
+            states.iter().map(compute).collect()
+        };
 
-                    // Застосовуємо torque
-                    physics.apply_torque(*body_handle, torque);
-                }
-            }
+        // 3. Apply: застосовуємо torque (послідовно - Rapier не thread-safe для запису)
+        for (body_handle, torque) in torques {
+            physics.apply_torque(body_handle, torque);
         }
     }
 
@@ -254,6 +393,121 @@ impl TargetPose {
         Self { bone_rotations: rotations }
     }
 
+    /// Поза підйому з землі (присід: коліна зігнуті, торс вперед)
+    ///
+    /// Спільний передостанній кадр getup_sequence - далі до standing().
+    /// Ротації world-space, груди дивляться в +Z (туди згинаються коліна).
+    pub fn get_up() -> Self {
+        let mut rotations = HashMap::new();
+
+        for bone_id in BoneId::all_bones() {
+            rotations.insert(bone_id, Quat::IDENTITY);
+        }
+
+        // Стегна вперед, коліна сильно зігнуті - стопи під тазом
+        rotations.insert(BoneId::LeftUpperLeg, Quat::from_rotation_x(-1.0));
+        rotations.insert(BoneId::RightUpperLeg, Quat::from_rotation_x(-1.0));
+        rotations.insert(BoneId::LeftLowerLeg, Quat::from_rotation_x(0.4));
+        rotations.insert(BoneId::RightLowerLeg, Quat::from_rotation_x(0.4));
+
+        // Торс нахилений вперед - центр мас над стопами
+        rotations.insert(BoneId::Spine, Quat::from_rotation_x(0.5));
+
+        // Руки вперед для балансу
+        rotations.insert(BoneId::LeftUpperArm, Quat::from_rotation_z(-0.3) * Quat::from_rotation_x(-0.6));
+        rotations.insert(BoneId::RightUpperArm, Quat::from_rotation_z(0.3) * Quat::from_rotation_x(-0.6));
+
+        Self { bone_rotations: rotations }
+    }
+
+    /// Сід з лежання на спині: торс піднятий над ногами, коліна підтягнуті,
+    /// руки впираються в землю позаду
+    pub fn sit_up() -> Self {
+        let mut rotations = HashMap::new();
+
+        for bone_id in BoneId::all_bones() {
+            rotations.insert(bone_id, Quat::IDENTITY);
+        }
+
+        // Торс та голова вперед, до колін
+        rotations.insert(BoneId::Spine, Quat::from_rotation_x(0.6));
+        rotations.insert(BoneId::Head, Quat::from_rotation_x(0.3));
+
+        // Стегна горизонтально вперед, гомілки майже вертикально - стопи біля тазу
+        rotations.insert(BoneId::LeftUpperLeg, Quat::from_rotation_x(-1.6));
+        rotations.insert(BoneId::RightUpperLeg, Quat::from_rotation_x(-1.6));
+        rotations.insert(BoneId::LeftLowerLeg, Quat::from_rotation_x(0.3));
+        rotations.insert(BoneId::RightLowerLeg, Quat::from_rotation_x(0.3));
+
+        // Руки назад-вниз - упор
+        rotations.insert(BoneId::LeftUpperArm, Quat::from_rotation_z(-0.3) * Quat::from_rotation_x(0.6));
+        rotations.insert(BoneId::RightUpperArm, Quat::from_rotation_z(0.3) * Quat::from_rotation_x(0.6));
+        rotations.insert(BoneId::LeftLowerArm, Quat::from_rotation_x(0.6));
+        rotations.insert(BoneId::RightLowerArm, Quat::from_rotation_x(0.6));
+
+        Self { bone_rotations: rotations }
+    }
+
+    /// Рачки з лежання на животі: торс горизонтально, стегна та руки
+    /// вертикально, гомілки лежать на землі
+    pub fn all_fours() -> Self {
+        let mut rotations = HashMap::new();
+
+        for bone_id in BoneId::all_bones() {
+            rotations.insert(bone_id, Quat::IDENTITY);
+        }
+
+        // Торс горизонтально вперед, голова трохи піднята
+        rotations.insert(BoneId::Spine, Quat::from_rotation_x(1.4));
+        rotations.insert(BoneId::Head, Quat::from_rotation_x(1.2));
+
+        // Стегна вниз (коліна під тазом), гомілки назад по землі
+        rotations.insert(BoneId::LeftLowerLeg, Quat::from_rotation_x(1.5));
+        rotations.insert(BoneId::RightLowerLeg, Quat::from_rotation_x(1.5));
+
+        // Руки прямо вниз під плечима
+        rotations.insert(BoneId::LeftUpperArm, Quat::from_rotation_z(-0.1));
+        rotations.insert(BoneId::RightUpperArm, Quat::from_rotation_z(0.1));
+
+        Self { bone_rotations: rotations }
+    }
+
+    /// Поза в повітрі (стрибок): коліна підібрані, руки в сторони для балансу
+    pub fn air() -> Self {
+        let mut rotations = HashMap::new();
+
+        for bone_id in BoneId::all_bones() {
+            rotations.insert(bone_id, Quat::IDENTITY);
+        }
+
+        // Стегна вперед, гомілки назад - ноги підібрані під тазом
+        rotations.insert(BoneId::LeftUpperLeg, Quat::from_rotation_x(-0.8));
+        rotations.insert(BoneId::RightUpperLeg, Quat::from_rotation_x(-0.8));
+        rotations.insert(BoneId::LeftLowerLeg, Quat::from_rotation_x(0.3));
+        rotations.insert(BoneId::RightLowerLeg, Quat::from_rotation_x(0.3));
+
+        // Торс трохи вперед
+        rotations.insert(BoneId::Spine, Quat::from_rotation_x(0.2));
+
+        // Руки розведені та трохи вперед
+        rotations.insert(BoneId::LeftUpperArm, Quat::from_rotation_z(-0.8) * Quat::from_rotation_x(-0.3));
+        rotations.insert(BoneId::RightUpperArm, Quat::from_rotation_z(0.8) * Quat::from_rotation_x(-0.3));
+        rotations.insert(BoneId::LeftLowerArm, Quat::from_rotation_x(0.3));
+        rotations.insert(BoneId::RightLowerArm, Quat::from_rotation_x(0.3));
+
+        Self { bone_rotations: rotations }
+    }
+
+    /// Поза, повернута навколо вертикалі на `yaw` (пози задані для грудей в +Z)
+    pub fn rotated_y(&self, yaw: f32) -> Self {
+        let turn = Quat::from_rotation_y(yaw);
+        let rotations = self.bone_rotations.iter()
+            .map(|(bone_id, rotation)| (*bone_id, turn * *rotation))
+            .collect();
+
+        Self { bone_rotations: rotations }
+    }
+
     /// Інтерполює між двома позами
     pub fn lerp(a: &TargetPose, b: &TargetPose, t: f32) -> Self {
         let mut rotations = HashMap::new();
@@ -269,6 +523,28 @@ impl TargetPose {
     }
 }
 
+/// Ключовий кадр підйому з землі
+#[derive(Debug, Clone)]
+pub struct GetupKeyframe {
+    /// Поза кадру
+    pub pose: TargetPose,
+
+    /// Час переходу від попереднього кадру (секунди)
+    pub duration: f32,
+}
+
+/// Послідовність підйому: лицем вгору - через сід, лицем вниз - через рачки,
+/// далі присід і стійка
+pub fn getup_sequence(face_down: bool) -> Vec<GetupKeyframe> {
+    let first = if face_down { TargetPose::all_fours() } else { TargetPose::sit_up() };
+
+    vec![
+        GetupKeyframe { pose: first, duration: 0.6 },
+        GetupKeyframe { pose: TargetPose::get_up(), duration: 0.7 },
+        GetupKeyframe { pose: TargetPose::standing(), duration: 0.7 },
+    ]
+}
+
 /// Цикл ходьби - генерує пози для анімації ходьби
 #[derive(Debug, Clone)]
 pub struct WalkCycle {
@@ -290,20 +566,167 @@ pub struct WalkCycle {
     /// Нахил торсу вперед при ходьбі/бігу
     pub spine_lean_forward: f32,
 
-    /// Амплітуда розмаху рук
-    pub arm_swing_amount: f32,
+    /// Амплітуда розмаху лівої руки (радіани)
+    pub left_arm_swing: f32,
+
+    /// Амплітуда розмаху правої руки (радіани, рука зі зброєю - менше)
+    pub right_arm_swing: f32,
+
+    /// Foot IK: стопа в опорній фазі стає на землю (raycast + two-bone IK)
+    pub foot_ik_enabled: bool,
+
+    /// Вага foot IK [ліва, права]: 1 - опора, 0 - перенос / землі немає
+    foot_ik_weight: [f32; 2],
+
+    /// Потрібна відстань по вертикалі від кульшового суглоба до кінця
+    /// гомілки [ліва, права] (м)
+    foot_ik_drop: [f32; 2],
 }
 
 impl WalkCycle {
     pub fn new() -> Self {
         Self {
             phase: 0.0,
-            speed: 1.0,
-            stride_length: 0.5,       // радіани (~30°)
+            speed: WALK_GAIT.speed,
+            stride_length: WALK_GAIT.stride_length,  // радіани (~30°)
             step_height: 0.15,        // висота підйому ноги
             hip_sway: 0.05,           // бокове розгойдування
-            spine_lean_forward: 0.1,  // нахил вперед при русі
-            arm_swing_amount: 0.3,    // розмах рук
+            spine_lean_forward: WALK_GAIT.spine_lean_forward,  // нахил вперед при русі
+            left_arm_swing: DEFAULT_ARM_SWING,   // розмах рук
+            right_arm_swing: DEFAULT_ARM_SWING,
+            foot_ik_enabled: false,
+            foot_ik_weight: [0.0; 2],
+            foot_ik_drop: [0.0; 2],
+        }
+    }
+
+    /// Вмикає / вимикає foot IK
+    pub fn with_foot_ik(mut self, enabled: bool) -> Self {
+        self.foot_ik_enabled = enabled;
+        self
+    }
+
+    /// Встановлює параметри ходи (ходьба / біг)
+    pub fn set_gait(&mut self, gait: Gait) {
+        self.speed = gait.speed;
+        self.stride_length = gait.stride_length;
+        self.spine_lean_forward = gait.spine_lean_forward;
+    }
+
+    /// Чи нога в опорній фазі (стопа має стояти на землі)
+    ///
+    /// Коліно ноги згинається, коли sin(фази) має "її" знак - це фаза
+    /// переносу. Решта циклу - опора.
+    fn is_stance(&self, leg: usize) -> bool {
+        let s = (smooth_step(self.phase) * std::f32::consts::TAU).sin();
+        if leg == 0 { s >= 0.0 } else { s <= 0.0 }
+    }
+
+    /// Прогрес фази переносу ноги (0 - відрив, 1 - постановка), None - опора
+    ///
+    /// Ліва нога переноситься в другій половині згладженої фази, права - в першій.
+    pub fn swing_progress(&self, leg: usize) -> Option<f32> {
+        if self.is_stance(leg) {
+            return None;
+        }
+        let smoothed = smooth_step(self.phase);
+        Some(if leg == 0 { (smoothed - 0.5) * 2.0 } else { smoothed * 2.0 }.clamp(0.0, 1.0))
+    }
+
+    /// Тривалість повного циклу (два кроки), секунди
+    pub fn cycle_duration(&self) -> f32 {
+        1.0 / (2.0 * self.speed.max(0.01))
+    }
+
+    /// Foot IK: raycast вниз від кожного кульшового суглоба
+    ///
+    /// Запам'ятовує, на скільки нижче стегна має бути кінець гомілки:
+    /// пряма нога над нижчою землею, коротша на перепад висот - над вищою.
+    /// Вага IK росте в опорній фазі (`walking` = false - обидві ноги
+    /// опорні) і спадає у фазі переносу або без землі під ногою.
+    ///
+    /// Викликати перед apply_foot_ik.
+    pub fn update_foot_contact(&mut self, physics: &PhysicsWorld, skeleton: &Skeleton, walking: bool, delta: f32) {
+        let blend = (FOOT_IK_RATE * delta).min(1.0);
+        let leg_length = skeleton.leg_length();
+
+        // Висота землі під кожним стегном (шукаємо трохи глибше прямої ноги)
+        let ground = FOOT_IK_LEGS.map(|(upper, _)| {
+            let hip = skeleton.bone_start_position(physics, upper)?;
+            let toi = physics.cast_ray_static(hip, Vec3::NEG_Y, leg_length + FOOT_RAY_DEPTH)?;
+            Some(hip.y - toi)
+        });
+        let lowest = ground.iter().flatten().copied().reduce(f32::min);
+
+        for (leg, height) in ground.into_iter().enumerate() {
+            let stance = !walking || self.is_stance(leg);
+            let drop = match (height, lowest) {
+                (Some(height), Some(lowest)) if self.foot_ik_enabled && stance => {
+                    Some(leg_length - (height - lowest))
+                }
+                _ => None,
+            };
+
+            let weight = self.foot_ik_weight[leg];
+            if let Some(drop) = drop {
+                // Без ваги - одразу нова висота, інакше плавно (сходинки, нерівності)
+                self.foot_ik_drop[leg] = if weight <= 0.0 {
+                    drop
+                } else {
+                    self.foot_ik_drop[leg] + (drop - self.foot_ik_drop[leg]) * blend
+                };
+            }
+
+            let target = if drop.is_some() { 1.0 } else { 0.0 };
+            self.foot_ik_weight[leg] = weight + (target - weight) * blend;
+            if self.foot_ik_weight[leg] < 1e-3 {
+                self.foot_ik_weight[leg] = 0.0;
+            }
+        }
+    }
+
+    /// Foot IK: two-bone IK ніг поверх пози (після get_pose / standing)
+    ///
+    /// Для кожної ноги з вагою > 0: напрямок стегно→стопа береться з пози
+    /// (кути навколо X), довжина - така, щоб по вертикалі стопа опустилась
+    /// на foot_ik_drop. Закон косинусів дає згин коліна та кут стегна;
+    /// результат змішується з позою за вагою. Згин не більше FOOT_IK_MAX_BEND.
+    pub fn apply_foot_ik(&self, pose: &mut TargetPose, skeleton: &Skeleton) {
+        let length = |bone_id: BoneId| skeleton.bones.get(&bone_id).map_or(0.0, |bone| bone.length);
+        let angle_x = |pose: &TargetPose, bone_id: BoneId| {
+            pose.bone_rotations
+                .get(&bone_id)
+                .map_or(0.0, |rotation| rotation.to_euler(glam::EulerRot::XYZ).0)
+        };
+
+        for (leg, (upper, lower)) in FOOT_IK_LEGS.into_iter().enumerate() {
+            let weight = self.foot_ik_weight[leg];
+            let (a, b) = (length(upper), length(lower));
+            if weight <= 0.0 || a <= 0.0 || b <= 0.0 {
+                continue;
+            }
+
+            // Відкрита поза: кут θ навколо X повертає кістку з -Y в (-sin θ, -cos θ) по (Z, Y)
+            let thigh = angle_x(pose, upper);
+            let knee = angle_x(pose, lower);
+            let foot_z = -(a * thigh.sin() + b * (thigh + knee).sin());
+            let foot_y = -(a * thigh.cos() + b * (thigh + knee).cos());
+            let direction = (-foot_z).atan2(-foot_y);
+
+            // Відстань стегно→стопа: по вертикалі foot_ik_drop, не далі прямої ноги
+            let min_reach = (a * a + b * b + 2.0 * a * b * FOOT_IK_MAX_BEND.cos()).sqrt();
+            let reach = (self.foot_ik_drop[leg] / direction.cos().max(0.1)).clamp(min_reach, (a + b) * 0.999);
+
+            // Закон косинусів: кут у коліні та між стегном і напрямком на стопу
+            let knee_interior = ((a * a + b * b - reach * reach) / (2.0 * a * b)).clamp(-1.0, 1.0).acos();
+            let thigh_offset = ((a * a + reach * reach - b * b) / (2.0 * a * reach)).clamp(-1.0, 1.0).acos();
+            let ik_knee = std::f32::consts::PI - knee_interior;
+            let ik_thigh = direction - thigh_offset;
+
+            let thigh = thigh + (ik_thigh - thigh) * weight;
+            let knee = knee + (ik_knee - knee) * weight;
+            pose.bone_rotations.insert(upper, Quat::from_rotation_x(thigh));
+            pose.bone_rotations.insert(lower, Quat::from_rotation_x(knee));
         }
     }
 
@@ -345,12 +768,12 @@ impl WalkCycle {
         let right_knee_bend = ((leg_swing).max(0.0) * (1.5 + self.step_height)).min(1.2);
         rotations.insert(BoneId::RightLowerLeg, Quat::from_rotation_x(right_knee_bend));
 
-        // Руки - протилежно ногам, використовуємо arm_swing_amount
-        let arm_swing = phase_rad.sin() * self.arm_swing_amount;
+        // Руки - протилежно ногам, кожна зі своєю амплітудою
+        let arm_phase = phase_rad.sin();
         rotations.insert(BoneId::LeftUpperArm,
-            Quat::from_rotation_z(-0.2) * Quat::from_rotation_x(arm_swing));
+            Quat::from_rotation_z(-0.2) * Quat::from_rotation_x(arm_phase * self.left_arm_swing));
         rotations.insert(BoneId::RightUpperArm,
-            Quat::from_rotation_z(0.2) * Quat::from_rotation_x(-arm_swing));
+            Quat::from_rotation_z(0.2) * Quat::from_rotation_x(-arm_phase * self.right_arm_swing));
 
         // Лікті завжди трохи зігнуті
         rotations.insert(BoneId::LeftLowerArm, Quat::from_rotation_x(0.3));
@@ -364,6 +787,76 @@ impl WalkCycle {
 
         TargetPose { bone_rotations: rotations }
     }
+
+    /// Генерує позу стрейфу для поточної фази
+    ///
+    /// Ноги махають вбік (навколо Z) в протифазі - провідна нога йде в бік
+    /// руху, друга переступає за нею; коліно згинається у фазі переносу,
+    /// як у get_pose. Торс без нахилу та майже без повороту.
+    ///
+    /// # Аргументи
+    /// * `direction` - бік руху відносно погляду: +1 вправо (+X), -1 вліво
+    pub fn get_strafe_pose(&self, direction: f32) -> TargetPose {
+        let mut rotations = HashMap::new();
+        for bone_id in BoneId::all_bones() {
+            rotations.insert(bone_id, Quat::IDENTITY);
+        }
+
+        let phase_rad = smooth_step(self.phase) * std::f32::consts::TAU;
+        let side = direction.signum();
+        let leg_swing = phase_rad.sin() * self.stride_length * STRAFE_STRIDE_SCALE;
+        let knee_bend = |swing: f32| (swing.max(0.0) * (1.5 + self.step_height)).min(1.2);
+
+        // Rotation Z на +θ зсуває стопу (-Y кістки) в +X
+        let right_swing = leg_swing * side;
+        rotations.insert(BoneId::RightUpperLeg, Quat::from_rotation_z(right_swing));
+        rotations.insert(BoneId::RightLowerLeg,
+            Quat::from_rotation_z(right_swing) * Quat::from_rotation_x(knee_bend(-leg_swing)));
+        rotations.insert(BoneId::LeftUpperLeg, Quat::from_rotation_z(-right_swing));
+        rotations.insert(BoneId::LeftLowerLeg,
+            Quat::from_rotation_z(-right_swing) * Quat::from_rotation_x(knee_bend(leg_swing)));
+
+        // Руки - слабкий розмах, лікті трохи зігнуті
+        let arm_phase = phase_rad.sin() * STRAFE_SWING_SCALE;
+        rotations.insert(BoneId::LeftUpperArm,
+            Quat::from_rotation_z(-0.2) * Quat::from_rotation_x(arm_phase * self.left_arm_swing));
+        rotations.insert(BoneId::RightUpperArm,
+            Quat::from_rotation_z(0.2) * Quat::from_rotation_x(-arm_phase * self.right_arm_swing));
+        rotations.insert(BoneId::LeftLowerArm, Quat::from_rotation_x(0.3));
+        rotations.insert(BoneId::RightLowerArm, Quat::from_rotation_x(0.3));
+
+        // Стегна крутяться менше, ніж у ходьбі
+        rotations.insert(BoneId::Spine, Quat::from_rotation_y(phase_rad.sin() * 0.1 * STRAFE_SWING_SCALE));
+
+        TargetPose { bone_rotations: rotations }
+    }
+}
+
+/// Two-bone IK ноги в world space
+///
+/// Коліно - в площині стегно→ціль, з боку `pole`. Ціль далі за пряму
+/// ногу - нога тягнеться прямо до неї.
+///
+/// # Повертає
+/// World ротації (стегно, гомілка): локальна -Y кістки → напрямок кістки
+pub fn solve_two_bone(hip: Vec3, target: Vec3, upper_length: f32, lower_length: f32, pole: Vec3) -> (Quat, Quat) {
+    let (a, b) = (upper_length.max(0.01), lower_length.max(0.01));
+    let to_target = target - hip;
+    let direction = to_target.try_normalize().unwrap_or(Vec3::NEG_Y);
+    let reach = to_target.length().clamp((a - b).abs() + 0.01, (a + b) * 0.999);
+
+    // Проекція коліна на hip→ціль та відступ від неї (закон косинусів)
+    let along = (a * a - b * b + reach * reach) / (2.0 * reach);
+    let out = (a * a - along * along).max(0.0).sqrt();
+    let bend = (pole - direction * pole.dot(direction))
+        .try_normalize()
+        .unwrap_or_else(|| direction.any_orthonormal_vector());
+
+    let knee = hip + direction * along + bend * out;
+    let foot = hip + direction * reach;
+    let thigh = Quat::from_rotation_arc(Vec3::NEG_Y, (knee - hip) / a);
+    let shin = Quat::from_rotation_arc(Vec3::NEG_Y, (foot - knee).normalize_or_zero());
+    (thigh, shin)
 }
 
 impl Default for WalkCycle {
@@ -371,3 +864,64 @@ impl Default for WalkCycle {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::ActiveRagdoll;
+
+    /// Верх collider'а землі create_ground(0.0, ..) - cuboid товщиною 0.2
+    const GROUND_TOP: f32 = 0.1;
+
+    #[test]
+    fn one_leg_always_in_stance() {
+        let mut cycle = WalkCycle::new();
+        let mut swings = [0; 2];
+        for _ in 0..200 {
+            cycle.update(1.0 / 200.0 / (cycle.speed * 2.0), true);
+            let stance = [0, 1].map(|leg| cycle.swing_progress(leg).is_none());
+            assert!(stance[0] || stance[1], "обидві ноги в переносі на фазі {:.3}", cycle.phase);
+            for (leg, count) in swings.iter_mut().enumerate() {
+                if let Some(progress) = cycle.swing_progress(leg) {
+                    assert!((0.0..=1.0).contains(&progress));
+                    *count += 1;
+                }
+            }
+        }
+        // Кожна нога переноситься приблизно половину циклу
+        assert!(swings.iter().all(|&count| count > 60), "{:?}", swings);
+    }
+
+    #[test]
+    fn foot_ik_keeps_stance_foot_on_ground() {
+        let mut physics = PhysicsWorld::new();
+        physics.create_ground(0.0, 10.0);
+        let mut ragdoll = ActiveRagdoll::new(&mut physics, Vec3::new(0.0, 2.0, 0.0), RagdollStiffness::default());
+        ragdoll.walk_cycle = WalkCycle::new().with_foot_ik(true);
+        ragdoll.activate();
+        let dt = physics.fixed_timestep;
+        for _ in 0..120 {
+            ragdoll.update(&mut physics, dt);
+            physics.step(dt);
+        }
+
+        // Ходьба на місці: цикл іде, рух тіла - ні
+        ragdoll.move_speed = 0.0;
+        ragdoll.set_move_direction(Vec3::Z);
+        let (mut stance_max, mut swing_max) = (0.0f32, 0.0f32);
+        for _ in 0..240 {
+            ragdoll.update(&mut physics, dt);
+            physics.step(dt);
+            for (leg, (_, lower)) in FOOT_IK_LEGS.into_iter().enumerate() {
+                let height = ragdoll.skeleton.foot_position(&physics, lower).expect("стопа").y - GROUND_TOP;
+                match ragdoll.walk_cycle.swing_progress(leg) {
+                    None => stance_max = stance_max.max(height),
+                    Some(_) => swing_max = swing_max.max(height),
+                }
+            }
+        }
+        assert_eq!(ragdoll.mode, crate::physics::ragdoll::RagdollMode::Active);
+        assert!(stance_max < 0.1, "опорна стопа на {:.3} м над землею", stance_max);
+        assert!(swing_max > stance_max, "нога в переносі не піднімається ({:.3})", swing_max);
+    }
+}
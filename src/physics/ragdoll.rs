@@ -11,16 +11,205 @@
    - Pelvis контролюється через СИЛИ (не кінематично)
    - Це дає стабільність + можливість реагувати на удари
 
+   ВІДНОВЛЕННЯ (get-up):
+   - На старті - лицем вгору чи вниз з орієнтації Spine (груди = +Z кістки),
+     звідси послідовність ключових поз (getup_sequence) та yaw, куди
+     персонаж встане: лицем вгору - в бік ніг, лицем вниз - в бік голови
+   - Поза: lerp від фактичної пози тіла через кадри послідовності,
+     progress = час / сумарна тривалість; сила м'язів набирається за перший кадр
+   - Поки триває підйом, is_getting_up() - main не передає рух
+   - Pelvis: PD-сила вгору до висоти стійки (× ramp, обмежена
+     MAX_LIFT_WEIGHT_RATIO ваги - не підкидає) + горизонтальна сила,
+     що ставить стопи під центр мас
+
+   РЕАКЦІЯ НА УДАР (react_to_hit):
+   - Найближча до точки удару кістка отримує імпульс, батьківська - частину
+   - global_strength падає до HIT_RECOIL_MIN_STRENGTH і за hit_recoil_duration
+     повертається до норми - верх тіла помітно "відкидає"
+
+   КРОКИ (apply_stepping, stepping_enabled):
+   - Ноги в ходьбі керуються не канонічним циклом, а ціллю для стопи:
+     переносна нога (WalkCycle::swing_progress) несе стопу від точки
+     відриву до точки постановки попереду центру мас у move_direction
+     (+ запас за швидкістю), з підйомом step_height посередині переносу;
+     опорна нога тримає стопу там, де та стала
+   - Кути стегна та гомілки - world-space two-bone IK (solve_two_bone).
+     Цілі м'язів - world ротації, тож IK одразу дає цільову позу
+   - Поки стоїть хоч одна стопа, pelvis тримається на висоті стійки
+     (apply_stance_support - та сама сила, що й у підйомі)
+   - Сила на pelvis до target_position - лише м'яка допомога (step_assist)
+   - stepping_enabled = false - канонічний цикл + повна сила, як раніше
+
+   FOOT IK (update):
+   - Raycast від кульшових суглобів до землі, two-bone IK опорних ніг
+     поверх пози ходьби / стійки (WalkCycle::apply_foot_ik)
+   - foot_ik_enabled = false - відкрита поза циклу, як раніше
+
+   ЛОКОМОЦІЯ (LocomotionState, locomotion_pose):
+   - Idle / Walk / Run / Strafe - з is_walking (set_move_direction / follow)
+     та set_sprinting; Run - RUN_GAIT циклу ходьби, решта - WALK_GAIT
+   - Strafe: рух вбік від погляду (|cos| < STRAFE_MAX_FORWARD_DOT, без
+     спринту) - target_yaw не змінюється, поза WalkCycle::get_strafe_pose
+   - Зміна стану - поза переходить від поточної за LOCOMOTION_BLEND_TIME
+     (TargetPose::lerp), м'язи не смикаються
+
+   СТРИБОК (jump):
+   - Лише в Active і на землі (raycast від pelvis вниз до висоти стійки +
+     JUMP_GROUND_MARGIN) - імпульс вгору pelvis, менший spine та ногам
+     (JUMP_IMPULSE_SHARES), щоб тіло злітало разом, а не розтягувалось
+   - У повітрі стан Air: поза TargetPose::air, цикл ходьби, кроки, foot IK
+     та баланс вимкнені
+   - Приземлення - земля знову під pelvis (не раніше JUMP_MIN_AIR_TIME);
+     жорстке - нокдаун через check_hard_landing, інакше далі Active
+
+   ЗБРОЯ (weapon_tip_velocity):
+   - Меч тримає права рука - швидкість вістря BoneId::Weapon
+     (Skeleton::attach_weapon), без меча - нижнього кінця RightLowerArm
+     (linvel + angvel × зміщення від центру кістки)
+   - Transforms / швидкості для рендеру включають меч (BoneId::attachments)
+
+   ВОРОГИ (follow):
+   - Позицію веде AI ворога (кінематично), ragdoll "йде" за нею:
+     швидкість ходьби = відставання target_position від позиції AI / кадр
+   - Кілька ragdoll в одному світі - кожен зі своєю групою колізій
+
+   БАЛАНС (apply_balance_control):
+   - Центр мас усього тіла (зважений за масою кісток) проектується на
+     землю і порівнюється з серединою між нижніми кінцями гомілок
+   - PD: balance_kp × зсув - balance_kd × швидкість центру мас → горизонтальна
+     сила на pelvis (обмежена MAX_BALANCE_ACCEL) + контр-torque, що гасить
+     обертання від сили, прикладеної не в центрі мас - тіло зсувається, а не
+     перекидається. Лише в стійці (ходьба - кероване "падіння" вперед)
+
+   ПАДІННЯ:
+   - Active: нахил pelvis від вертикалі > fall_threshold → Ragdoll
+     (м'язи розслаблені - тіло падає природно, а не "бореться" стояти)
+   - balance_recovery_enabled: через FALL_RECOVERY_DELAY після падіння - Recovery
+
+   НОКДАУН (check_hard_landing):
+   - Кожен крок - на скільки впала швидкість pelvis та голови з
+     попереднього кроку. Більше knockdown_speed → Ragdoll на
+     knockdown_duration, далі підйом (незалежно від balance_recovery_enabled)
+   - Шкода fall_damage_per_speed за кожен м/с понад поріг накопичується,
+     main забирає її take_fall_damage() у здоров'я гравця / ворога
+   - Приріст швидкості (удар, поштовх) нокдауну не дає - тільки гальмування
+
+   WATCHDOG (check_watchdog):
+   - Після кроку фізики, до snapshot: NaN, кістка за межами арени або
+     надто швидка → RagdollWatchdog обмежує швидкості / повертає позу
+   - Після втручання швидкості нокдауну скидаються - обмеження швидкості
+     не вважається жорстким приземленням
+
+   ІНТЕРПОЛЯЦІЯ:
+   - Фізика крокує з фіксованим dt, рендер - з будь-яким FPS
+   - Після кожного кроку snapshot_transforms() зберігає previous/current
+   - get_bone_transforms_interpolated(alpha) - lerp/slerp між ними
+
 ═══════════════════════════════════════════════════════════════════════════════
 */
 
+use std::collections::HashMap;
+
 use glam::{Vec3, Quat};
 use rapier3d::prelude::*;
 
-use super::{PhysicsWorld, Skeleton, MuscleSystem, BoneId};
-use super::muscle::{TargetPose, WalkCycle};
+use super::{PhysicsWorld, Skeleton, MuscleSystem, BoneId, RagdollStiffness, RagdollWatchdog, WatchdogOutcome};
+use super::muscle::{
+    TargetPose, WalkCycle, GetupKeyframe, getup_sequence, smooth_step, solve_two_bone, FOOT_IK_LEGS,
+    WALK_GAIT, RUN_GAIT,
+};
 use crate::debug_log::log_debug;
 
+/// Максимальна сила підйому pelvis відносно ваги тіла
+const MAX_LIFT_WEIGHT_RATIO: f32 = 1.3;
+
+/// PD підйому pelvis до висоти стійки (на кг маси)
+const LIFT_STIFFNESS: f32 = 60.0;
+const LIFT_DAMPING: f32 = 12.0;
+
+/// Максимальне прискорення від балансу (m/s²)
+const MAX_BALANCE_ACCEL: f32 = 6.0;
+
+/// Максимальний контр-torque балансу (N·m)
+const MAX_BALANCE_TORQUE: f32 = 150.0;
+
+/// Нахил pelvis, після якого персонаж вважається таким, що впав (радіани, 60°)
+pub const DEFAULT_FALL_THRESHOLD: f32 = std::f32::consts::FRAC_PI_3;
+
+/// Пауза між падінням та автоматичним підйомом (секунди)
+const FALL_RECOVERY_DELAY: f32 = 1.5;
+
+/// Кістки, гальмування яких перевіряється на нокдаун
+const KNOCKDOWN_BONES: [BoneId; 2] = [BoneId::Pelvis, BoneId::Head];
+
+/// Падіння швидкості за крок, з якого - нокдаун (м/с)
+const DEFAULT_KNOCKDOWN_SPEED: f32 = 8.0;
+
+/// Мінімальний час на землі після нокдауну (секунди)
+const DEFAULT_KNOCKDOWN_DURATION: f32 = 1.5;
+
+/// Шкода за кожен м/с гальмування понад поріг нокдауну
+const DEFAULT_FALL_DAMAGE_PER_SPEED: f32 = 5.0;
+
+/// Частка імпульсу удару, що йде в батьківську кістку
+const HIT_PARENT_IMPULSE_RATIO: f32 = 0.4;
+
+/// Сила м'язів одразу після удару (далі росте до 1.0)
+const HIT_RECOIL_MIN_STRENGTH: f32 = 0.3;
+
+/// Швидкість ходьби за замовчуванням (м/с)
+pub const DEFAULT_MOVE_SPEED: f32 = 3.0;
+
+/// Швидкість (м/с), з якої follow вважає ragdoll таким, що йде
+const FOLLOW_WALK_SPEED: f32 = 0.2;
+
+/// Кроки: на скільки секунд розбіжності швидкостей (фактична - бажана)
+/// зсувається точка постановки стопи (гальмування / розгін)
+const STEP_VELOCITY_FEEDBACK: f32 = 0.1;
+
+/// Кроки: звідки шукати землю над точкою постановки (м)
+const STEP_GROUND_PROBE: f32 = 1.0;
+
+/// Тривалість переходу пози між станами локомоції (секунди)
+const LOCOMOTION_BLEND_TIME: f32 = 0.2;
+
+/// Стрейф: рух з |cos| кута до погляду менше цього (60°..120° вбік)
+const STRAFE_MAX_FORWARD_DOT: f32 = 0.5;
+
+/// Швидкість вгору, яку стрибок дає pelvis, за замовчуванням (м/с)
+pub const DEFAULT_JUMP_SPEED: f32 = 4.0;
+
+/// Частки швидкості стрибка для кісток (імпульс = маса × jump_speed × частка)
+const JUMP_IMPULSE_SHARES: [(BoneId, f32); 6] = [
+    (BoneId::Pelvis, 1.0),
+    (BoneId::Spine, 0.6),
+    (BoneId::LeftUpperLeg, 0.5),
+    (BoneId::RightUpperLeg, 0.5),
+    (BoneId::LeftLowerLeg, 0.3),
+    (BoneId::RightLowerLeg, 0.3),
+];
+
+/// Запас над висотою стійки, в якому pelvis ще "на землі" (м)
+const JUMP_GROUND_MARGIN: f32 = 0.25;
+
+/// Мінімальний час у повітрі - на відриві земля ще під ногами (секунди)
+const JUMP_MIN_AIR_TIME: f32 = 0.15;
+
+/// Стан локомоції (поза та параметри циклу ходьби)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocomotionState {
+    /// Стоїть
+    Idle,
+    /// Йде в напрямку погляду
+    Walk,
+    /// Біжить (спринт)
+    Run,
+    /// Йде вбік, не повертаючись
+    Strafe,
+    /// У повітрі після стрибка
+    Air,
+}
+
 /// Режим роботи ragdoll
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum RagdollMode {
@@ -28,7 +217,7 @@ pub enum RagdollMode {
     Active,
     /// Ragdoll - м'язи розслаблені, падає під фізикою
     Ragdoll,
-    /// Відновлення - поступове повернення контролю
+    /// Відновлення - підйом з землі (progress 0..1 по всій послідовності)
     Recovery { progress: f32 },
 }
 
@@ -52,9 +241,37 @@ pub struct ActiveRagdoll {
     /// Цільовий напрямок руху (world space)
     pub move_direction: Vec3,
 
+    // === LOCOMOTION ===
+    /// Поточний стан локомоції (оновлюється в update)
+    pub locomotion: LocomotionState,
+
+    /// Спринт (set_sprinting)
+    sprinting: bool,
+
+    /// Бік стрейфу відносно погляду: +1 вправо, -1 вліво, 0 - не стрейф
+    strafe_side: f32,
+
+    /// Поза на момент зміни стану локомоції (початок переходу)
+    locomotion_blend_from: TargetPose,
+
+    /// Час з моменту зміни стану локомоції (секунди)
+    locomotion_blend_time: f32,
+
+    /// Швидкість вгору, яку стрибок дає pelvis (м/с)
+    pub jump_speed: f32,
+
+    /// Час з моменту стрибка (None - на землі)
+    air_time: Option<f32>,
+
     /// Поточна цільова поза
     current_pose: TargetPose,
 
+    /// Поза тіла на старті підйому (звідки lerp до першого кадру)
+    getup_start: TargetPose,
+
+    /// Ключові кадри підйому (вже повернуті на target_yaw)
+    getup_keyframes: Vec<GetupKeyframe>,
+
     // === MOVEMENT CONTROL ===
     /// Цільова позиція (куди хочемо рухатись)
     pub target_position: Vec3,
@@ -74,32 +291,140 @@ pub struct ActiveRagdoll {
     /// Сила для обертання
     pub rotation_force: f32,
 
+    // === STEPPING ===
+    /// Ноги ходять кроками до точок постановки (інакше - канонічний цикл)
+    pub stepping_enabled: bool,
+
+    /// Частка movement_force, що тягне pelvis при кроках (м'яка допомога)
+    pub step_assist: f32,
+
+    /// Де стоїть стопа опорної ноги [ліва, права] (кінець гомілки)
+    foot_plant: [Option<Vec3>; 2],
+
+    /// Звідки почався перенос стопи [ліва, права]
+    swing_from: [Option<Vec3>; 2],
+
+    // === BALANCE ===
+    /// Баланс: прискорення на метр зсуву центру мас від середини стоп (1/с²)
+    pub balance_kp: f32,
+
+    /// Баланс: гасіння горизонтальної швидкості центру мас (1/с)
+    pub balance_kd: f32,
+
+    /// Нахил pelvis від вертикалі, після якого Active → Ragdoll (радіани)
+    pub fall_threshold: f32,
+
+    /// Автоматичний підйом після падіння (через FALL_RECOVERY_DELAY)
+    pub balance_recovery_enabled: bool,
+
+    /// Після reset одразу Active (м'язи на повну), інакше - Ragdoll
+    pub start_active: bool,
+
+    /// Час з моменту автоматичного падіння (None - не падав сам)
+    fallen_time: Option<f32>,
+
+    // === KNOCKDOWN ===
+    /// Падіння швидкості pelvis / голови за один крок, з якого - нокдаун (м/с)
+    pub knockdown_speed: f32,
+
+    /// Мінімальний час на землі після нокдауну (секунди), далі - підйом
+    pub knockdown_duration: f32,
+
+    /// Шкода за кожен м/с гальмування понад knockdown_speed
+    pub fall_damage_per_speed: f32,
+
+    /// Швидкості KNOCKDOWN_BONES на попередньому кроці
+    knockdown_velocities: HashMap<BoneId, Vec3>,
+
+    /// Скільки ще лежати після нокдауну (None - не в нокдауні)
+    knockdown_timer: Option<f32>,
+
+    /// Шкода від падінь, ще не забрана take_fall_damage
+    pending_fall_damage: f32,
+
+    // === HIT REACTION ===
+    /// Тривалість ослаблення м'язів після удару (секунди)
+    pub hit_recoil_duration: f32,
+
+    /// Залишок ослаблення після останнього удару (секунди)
+    hit_recoil_timer: f32,
+
     /// Лічильник кадрів для логування
     frame_count: u32,
+
+    /// Логувати позиції кісток раз на 60 кадрів (вимкнено для ворогів)
+    pub log_bones: bool,
+
+    /// Запобіжник від вибуху фізики
+    pub watchdog: RagdollWatchdog,
+
+    /// Transforms кісток після передостаннього кроку фізики
+    previous_transforms: HashMap<BoneId, (Vec3, Quat)>,
+
+    /// Transforms кісток після останнього кроку фізики
+    current_transforms: HashMap<BoneId, (Vec3, Quat)>,
 }
 
 impl ActiveRagdoll {
     /// Створює нового персонажа
-    pub fn new(physics: &mut PhysicsWorld, position: Vec3) -> Self {
-        let skeleton = Skeleton::create_humanoid(physics, position);
-        let muscles = MuscleSystem::create_humanoid();
+    ///
+    /// `stiffness` - профіль жорсткості joints та м'язів
+    pub fn new(physics: &mut PhysicsWorld, position: Vec3, stiffness: RagdollStiffness) -> Self {
+        let skeleton = Skeleton::create_humanoid(physics, position, stiffness);
+        let muscles = MuscleSystem::create_humanoid(stiffness);
 
-        Self {
+        let mut ragdoll = Self {
             skeleton,
             muscles,
             mode: RagdollMode::Ragdoll,  // Починаємо з чистого ragdoll - тільки гравітація!
-            walk_cycle: WalkCycle::new(),
+            walk_cycle: WalkCycle::new().with_foot_ik(true),
             is_walking: false,
             move_direction: Vec3::NEG_Z,
+            locomotion: LocomotionState::Idle,
+            sprinting: false,
+            strafe_side: 0.0,
+            locomotion_blend_from: TargetPose::standing(),
+            locomotion_blend_time: LOCOMOTION_BLEND_TIME,
+            jump_speed: DEFAULT_JUMP_SPEED,
+            air_time: None,
             current_pose: TargetPose::standing(),
+            getup_start: TargetPose::standing(),
+            getup_keyframes: Vec::new(),
             target_position: position,
             target_yaw: 0.0,
-            move_speed: 3.0,
+            move_speed: DEFAULT_MOVE_SPEED,
             upright_force: 500.0,
             movement_force: 200.0,
             rotation_force: 100.0,
+            stepping_enabled: true,
+            step_assist: 0.3,
+            foot_plant: [None; 2],
+            swing_from: [None; 2],
+            balance_kp: 40.0,
+            balance_kd: 10.0,
+            fall_threshold: DEFAULT_FALL_THRESHOLD,
+            balance_recovery_enabled: false,
+            start_active: false,
+            fallen_time: None,
+            knockdown_speed: DEFAULT_KNOCKDOWN_SPEED,
+            knockdown_duration: DEFAULT_KNOCKDOWN_DURATION,
+            fall_damage_per_speed: DEFAULT_FALL_DAMAGE_PER_SPEED,
+            knockdown_velocities: HashMap::new(),
+            knockdown_timer: None,
+            pending_fall_damage: 0.0,
+            hit_recoil_duration: 0.4,
+            hit_recoil_timer: 0.0,
             frame_count: 0,
-        }
+            log_bones: true,
+            watchdog: RagdollWatchdog::default(),
+            previous_transforms: HashMap::new(),
+            current_transforms: HashMap::new(),
+        };
+
+        // Два snapshot - previous == current, інтерполяція до першого кроку стабільна
+        ragdoll.snapshot_transforms(physics);
+        ragdoll.snapshot_transforms(physics);
+        ragdoll
     }
 
     /// Оновлює ragdoll
@@ -107,10 +432,35 @@ impl ActiveRagdoll {
         self.frame_count += 1;
 
         // Логування кожні 60 кадрів (раз на секунду при 60 FPS)
-        if self.frame_count % 60 == 1 {
+        if self.log_bones && self.frame_count % 60 == 1 {
             self.log_bone_positions(physics);
         }
 
+        // Впав (pelvis надто нахилений) - відпускаємо м'язи
+        if self.mode == RagdollMode::Active && !self.check_balance(physics) {
+            log_debug(&format!(
+                "Падіння: нахил pelvis {:.0}° > {:.0}° - Ragdoll",
+                self.tilt_angle(physics).to_degrees(),
+                self.fall_threshold.to_degrees()
+            ));
+            self.go_ragdoll();
+            self.fallen_time = Some(0.0);
+        }
+
+        // Жорстке приземлення - нокдаун та шкода
+        self.check_hard_landing(physics);
+
+        // Після стрибка: земля знову під pelvis - приземлення
+        if let Some(time) = self.air_time.map(|time| time + delta) {
+            if time >= JUMP_MIN_AIR_TIME && self.is_grounded(physics) {
+                log_debug(&format!("Приземлення після {:.2} с у повітрі", time));
+                self.air_time = None;
+            } else {
+                self.air_time = Some(time);
+            }
+        }
+        let airborne = self.air_time.is_some();
+
         // Оновлюємо режим
         match self.mode {
             RagdollMode::Active => {
@@ -118,12 +468,35 @@ impl ActiveRagdoll {
             }
             RagdollMode::Ragdoll => {
                 self.muscles.global_strength = 0.0;
+
+                if let Some(time) = self.fallen_time.as_mut() {
+                    *time += delta;
+                    if self.balance_recovery_enabled && *time >= FALL_RECOVERY_DELAY {
+                        self.start_recovery();
+                    }
+                }
+
+                if let Some(timer) = self.knockdown_timer.as_mut() {
+                    *timer -= delta;
+                    if *timer <= 0.0 {
+                        log_debug("Нокдаун завершено - підйом");
+                        self.start_recovery();
+                    }
+                }
             }
             RagdollMode::Recovery { progress } => {
-                let new_progress = (progress + delta * 0.5).min(1.0);
-                self.muscles.global_strength = new_progress;
+                if progress == 0.0 {
+                    self.begin_recovery(physics);
+                }
+
+                let new_progress = (progress + delta / self.getup_duration()).min(1.0);
+                let ramp = smooth_step(new_progress);
+                self.muscles.global_strength = self.getup_strength(new_progress);
+                self.apply_upright_torque(physics, ramp);
+                self.apply_recovery_forces(physics, ramp);
 
                 if new_progress >= 1.0 {
+                    log_debug("Recovery завершено - Active");
                     self.mode = RagdollMode::Active;
                 } else {
                     self.mode = RagdollMode::Recovery { progress: new_progress };
@@ -131,20 +504,46 @@ impl ActiveRagdoll {
             }
         }
 
+        // Після удару м'язи слабші, за hit_recoil_duration - знову повна сила
+        if self.hit_recoil_timer > 0.0 {
+            self.hit_recoil_timer = (self.hit_recoil_timer - delta).max(0.0);
+            let t = 1.0 - self.hit_recoil_timer / self.hit_recoil_duration.max(1e-3);
+            self.muscles.global_strength *=
+                HIT_RECOIL_MIN_STRENGTH + (1.0 - HIT_RECOIL_MIN_STRENGTH) * smooth_step(t);
+        }
+
         // Якщо активний режим - застосовуємо контроль
         if self.mode == RagdollMode::Active {
             self.apply_movement_control(physics, delta);
-            self.apply_upright_torque(physics);
+            self.apply_upright_torque(physics, 1.0);
+            if !self.is_walking && !airborne {
+                self.apply_balance_control(physics);
+            }
         }
 
-        // Оновлюємо цикл ходьби
-        self.walk_cycle.update(delta, self.is_walking);
+        // Оновлюємо цикл ходьби (+ foot IK по землі); у повітрі цикл стоїть
+        self.walk_cycle.update(delta, self.is_walking && !airborne);
+        self.walk_cycle.update_foot_contact(physics, &self.skeleton, self.is_walking, delta);
 
         // Генеруємо цільову позу
-        if self.is_walking {
-            self.current_pose = self.walk_cycle.get_pose();
+        self.current_pose = match self.mode {
+            // Підйом: ключові кадри послідовності
+            RagdollMode::Recovery { progress } => self.getup_pose(progress),
+            _ => self.locomotion_pose(delta),
+        };
+
+        // Foot IK: стопи опорних ніг стають на землю (не під час підйому і не в повітрі)
+        if !matches!(self.mode, RagdollMode::Recovery { .. }) && !airborne {
+            self.walk_cycle.apply_foot_ik(&mut self.current_pose, &self.skeleton);
+        }
+
+        // Кроки: ноги в ходьбі йдуть до точок постановки
+        if self.stepping_enabled && self.is_walking && self.mode == RagdollMode::Active && !airborne {
+            self.apply_stepping(physics);
+            self.apply_stance_support(physics);
         } else {
-            self.current_pose = TargetPose::standing();
+            self.foot_plant = [None; 2];
+            self.swing_from = [None; 2];
         }
 
         // Застосовуємо позу до м'язів
@@ -177,8 +576,9 @@ impl ActiveRagdoll {
                         self.target_position.z - current_pos.z,
                     );
 
-                    // Застосовуємо силу в напрямку руху
-                    let force = diff * self.movement_force;
+                    // Застосовуємо силу в напрямку руху (при кроках - лише допомога)
+                    let assist = if self.stepping_enabled { self.step_assist } else { 1.0 };
+                    let force = diff * self.movement_force * assist;
                     body.add_force(vector![force.x, 0.0, force.z], true);
                 }
 
@@ -215,8 +615,293 @@ impl ActiveRagdoll {
         }
     }
 
+    /// Поза локомоції з переходом між станами
+    ///
+    /// Стан - з air_time / is_walking / sprinting / strafe_side. При зміні стану поза
+    /// переходить (smooth_step) від поточної до пози нового стану за
+    /// LOCOMOTION_BLEND_TIME; Run перемикає цикл ходьби на RUN_GAIT.
+    fn locomotion_pose(&mut self, delta: f32) -> TargetPose {
+        let state = if self.air_time.is_some() {
+            LocomotionState::Air
+        } else if !self.is_walking {
+            LocomotionState::Idle
+        } else if self.sprinting {
+            LocomotionState::Run
+        } else if self.strafe_side != 0.0 {
+            LocomotionState::Strafe
+        } else {
+            LocomotionState::Walk
+        };
+
+        if state != self.locomotion {
+            self.locomotion = state;
+            self.locomotion_blend_from = self.current_pose.clone();
+            self.locomotion_blend_time = 0.0;
+            self.walk_cycle.set_gait(if state == LocomotionState::Run { RUN_GAIT } else { WALK_GAIT });
+        }
+
+        let target = match state {
+            LocomotionState::Idle => TargetPose::standing(),
+            LocomotionState::Walk | LocomotionState::Run => self.walk_cycle.get_pose(),
+            LocomotionState::Strafe => self.walk_cycle.get_strafe_pose(self.strafe_side),
+            LocomotionState::Air => TargetPose::air().rotated_y(self.target_yaw),
+        };
+        if self.locomotion_blend_time >= LOCOMOTION_BLEND_TIME {
+            return target;
+        }
+
+        self.locomotion_blend_time += delta;
+        let t = smooth_step((self.locomotion_blend_time / LOCOMOTION_BLEND_TIME).min(1.0));
+        TargetPose::lerp(&self.locomotion_blend_from, &target, t)
+    }
+
+    /// Куди дивиться персонаж (target_yaw, -Z при yaw = 0)
+    fn facing(&self) -> Vec3 {
+        Quat::from_rotation_y(self.target_yaw) * Vec3::NEG_Z
+    }
+
+    /// Бік стрейфу для руху в `direction` (нормалізований)
+    ///
+    /// # Повертає
+    /// +1 / -1 - рух вправо / вліво від погляду, 0 - вперед чи назад
+    fn strafe_side_for(&self, direction: Vec3) -> f32 {
+        let facing = self.facing();
+        if direction.dot(facing).abs() >= STRAFE_MAX_FORWARD_DOT {
+            return 0.0;
+        }
+        let right = Vec3::new(-facing.z, 0.0, facing.x);
+        direction.dot(right).signum()
+    }
+
+    /// Початок підйому: послідовність та yaw з орієнтації Spine
+    ///
+    /// Груди - +Z кістки (туди згинаються коліна). Лежачи на спині
+    /// персонаж сідає і встає в бік ніг, на животі - стає рачки і встає
+    /// в бік голови. Майже вертикальний торс - просто в бік грудей.
+    fn begin_recovery(&mut self, physics: &PhysicsWorld) {
+        self.is_walking = false;
+
+        let spine = self.skeleton.get_bone_rotation(physics, BoneId::Spine).unwrap_or(Quat::IDENTITY);
+        let up = spine * Vec3::Y;
+        let chest = spine * Vec3::Z;
+        let face_down = chest.y < 0.0;
+
+        let flat_up = Vec3::new(up.x, 0.0, up.z);
+        let facing = if up.y > 0.7 {
+            Vec3::new(chest.x, 0.0, chest.z)
+        } else if face_down {
+            flat_up
+        } else {
+            -flat_up
+        };
+
+        // Pelvis -Z (напрямок yaw) протилежний грудям
+        if facing.length_squared() > 1e-4 {
+            self.target_yaw = facing.x.atan2(facing.z);
+        }
+
+        self.getup_start = TargetPose {
+            bone_rotations: BoneId::all_bones().into_iter()
+                .filter_map(|bone_id| {
+                    self.skeleton.get_bone_rotation(physics, bone_id).map(|rotation| (bone_id, rotation))
+                })
+                .collect(),
+        };
+        self.getup_keyframes = getup_sequence(face_down).into_iter()
+            .map(|keyframe| GetupKeyframe { pose: keyframe.pose.rotated_y(self.target_yaw), ..keyframe })
+            .collect();
+
+        let tilt = up.dot(Vec3::Y).clamp(-1.0, 1.0).acos().to_degrees();
+        let facing = if face_down { "лицем вниз" } else { "лицем вгору" };
+        log_debug(&format!("Recovery: нахил spine {:.0}°, {}", tilt, facing));
+    }
+
+    /// Сумарна тривалість послідовності підйому (секунди)
+    fn getup_duration(&self) -> f32 {
+        self.getup_keyframes.iter().map(|keyframe| keyframe.duration).sum::<f32>().max(1e-3)
+    }
+
+    /// Поза підйому: lerp між сусідніми кадрами послідовності
+    fn getup_pose(&self, progress: f32) -> TargetPose {
+        let mut time = progress * self.getup_duration();
+        let mut from = &self.getup_start;
+
+        for keyframe in &self.getup_keyframes {
+            if time < keyframe.duration {
+                return TargetPose::lerp(from, &keyframe.pose, smooth_step(time / keyframe.duration));
+            }
+            time -= keyframe.duration;
+            from = &keyframe.pose;
+        }
+
+        from.clone()
+    }
+
+    /// Сила м'язів під час підйому: набирається за перший кадр
+    fn getup_strength(&self, progress: f32) -> f32 {
+        let first = self.getup_keyframes.first().map_or(1e-3, |keyframe| keyframe.duration.max(1e-3));
+        smooth_step((progress * self.getup_duration() / first).min(1.0))
+    }
+
+    /// Сили підйому pelvis під час Recovery
+    ///
+    /// # Аргументи
+    /// * `ramp` - smooth_step(progress), 0..1
+    fn apply_recovery_forces(&self, physics: &mut PhysicsWorld, ramp: f32) {
+        let mass = self.skeleton.total_mass(physics);
+        let target_height = self.skeleton.standing_pelvis_height();
+        let gravity = physics.gravity().length();
+        let balance = self.balance_acceleration(physics);
+
+        let Some(handle) = self.skeleton.bodies.get(&BoneId::Pelvis) else {
+            return;
+        };
+        let Some(body) = physics.rigid_body_set.get_mut(*handle) else {
+            return;
+        };
+
+        // Вгору: вага + PD до висоти стійки, не більше MAX_LIFT_WEIGHT_RATIO ваги
+        let lift = support_lift(body, mass, gravity, target_height) * ramp;
+
+        // Горизонтально: центр мас над стопами (сила зникає, коли стопи під ним)
+        let horizontal = balance * mass * ramp;
+
+        body.add_force(vector![horizontal.x, lift, horizontal.z], true);
+    }
+
+    /// Кроки: цілі ніг з точок постановки стоп (поверх пози ходьби)
+    ///
+    /// Переносна нога: стопа від точки відриву до точки постановки =
+    /// центр мас + бічне зміщення кульшового суглоба + бажана швидкість ×
+    /// пів опорної фази + STEP_VELOCITY_FEEDBACK × (фактична - бажана),
+    /// на висоті землі, з підйомом step_height × sin(π·прогрес).
+    /// Опорна нога: стопа лишається, де стала (опущена на землю).
+    fn apply_stepping(&mut self, physics: &PhysicsWorld) {
+        let (Some(com), Some(pelvis)) = (
+            self.skeleton.center_of_mass(physics),
+            self.skeleton.get_bone_position(physics, BoneId::Pelvis),
+        ) else {
+            return;
+        };
+        let velocity = self.skeleton.center_of_mass_velocity(physics).unwrap_or(Vec3::ZERO);
+        let velocity = Vec3::new(velocity.x, 0.0, velocity.z);
+        let desired = self.move_direction * self.move_speed;
+        let stance_time = self.walk_cycle.cycle_duration() * 0.5;
+        let length = |bone_id: BoneId| self.skeleton.bones.get(&bone_id).map_or(0.0, |bone| bone.length);
+
+        for (leg, (upper, lower)) in FOOT_IK_LEGS.into_iter().enumerate() {
+            let (Some(hip), Some(foot)) = (
+                self.skeleton.bone_start_position(physics, upper),
+                self.skeleton.foot_position(physics, lower),
+            ) else {
+                continue;
+            };
+            let foot_radius = self.skeleton.bones.get(&lower).map_or(0.0, |bone| bone.radius);
+            // Точка на землі під `point` (висота стопи = земля + радіус гомілки)
+            let on_ground = |point: Vec3| {
+                let probe = Vec3::new(point.x, hip.y + STEP_GROUND_PROBE, point.z);
+                let y = physics
+                    .cast_ray_static(probe, Vec3::NEG_Y, STEP_GROUND_PROBE * 2.0 + hip.y.max(0.0))
+                    .map_or(point.y, |toi| probe.y - toi + foot_radius);
+                Vec3::new(point.x, y, point.z)
+            };
+
+            let target = match self.walk_cycle.swing_progress(leg) {
+                Some(progress) => {
+                    self.foot_plant[leg] = None;
+                    let from = *self.swing_from[leg].get_or_insert(foot);
+
+                    // Бічне зміщення ноги від pelvis - кожна стопа на своїй лінії
+                    let side = hip - pelvis;
+                    let side = side - self.move_direction * side.dot(self.move_direction);
+                    let placement = on_ground(
+                        Vec3::new(com.x, from.y, com.z)
+                            + Vec3::new(side.x, 0.0, side.z)
+                            + desired * (stance_time * 0.5)
+                            + (velocity - desired) * STEP_VELOCITY_FEEDBACK,
+                    );
+
+                    let lift = self.walk_cycle.step_height * (progress * std::f32::consts::PI).sin();
+                    from.lerp(placement, smooth_step(progress)) + Vec3::Y * lift
+                }
+                None => {
+                    self.swing_from[leg] = None;
+                    *self.foot_plant[leg].get_or_insert_with(|| on_ground(foot))
+                }
+            };
+
+            // Колінний суглоб згинається лише в +x (AngleLimits::knee) -
+            // при target_yaw це коліно з боку, протилежного погляду (і при стрейфі)
+            let (thigh, shin) = solve_two_bone(hip, target, length(upper), length(lower), -self.facing());
+            self.current_pose.bone_rotations.insert(upper, thigh);
+            self.current_pose.bone_rotations.insert(lower, shin);
+        }
+    }
+
+    /// Опора кроку: опорна нога тримає pelvis на висоті стійки
+    ///
+    /// Та сама вертикальна сила, що й у підйомі, але лише поки хоч одна
+    /// стопа стоїть - у польоті тіло не підтримується.
+    fn apply_stance_support(&self, physics: &mut PhysicsWorld) {
+        if self.foot_plant.iter().all(Option::is_none) {
+            return;
+        }
+        let mass = self.skeleton.total_mass(physics);
+        let target_height = self.skeleton.standing_pelvis_height();
+        let gravity = physics.gravity().length();
+        let Some(body) = self.skeleton.bodies.get(&BoneId::Pelvis).and_then(|h| physics.rigid_body_set.get_mut(*h)) else {
+            return;
+        };
+
+        let lift = support_lift(body, mass, gravity, target_height);
+        body.add_force(vector![0.0, lift, 0.0], true);
+    }
+
+    /// Горизонтальне прискорення, що повертає центр мас над стопи
+    ///
+    /// PD: balance_kp × (середина стоп - центр мас) - balance_kd × швидкість
+    /// центру мас, по XZ, не більше MAX_BALANCE_ACCEL. Нуль без стоп.
+    fn balance_acceleration(&self, physics: &PhysicsWorld) -> Vec3 {
+        let com = self.skeleton.center_of_mass(physics);
+        let feet = self.skeleton.feet_midpoint(physics);
+        let (Some(com), Some(feet)) = (com, feet) else {
+            return Vec3::ZERO;
+        };
+        let velocity = self.skeleton.center_of_mass_velocity(physics).unwrap_or(Vec3::ZERO);
+
+        let error = Vec3::new(feet.x - com.x, 0.0, feet.z - com.z);
+        let damping = Vec3::new(velocity.x, 0.0, velocity.z);
+        (error * self.balance_kp - damping * self.balance_kd).clamp_length_max(MAX_BALANCE_ACCEL)
+    }
+
+    /// Баланс у стійці: центр мас повертається над опору
+    ///
+    /// Сила на pelvis (balance_acceleration × маса тіла) та контр-torque -
+    /// сила прикладена нижче центру мас і сама по собі крутила б тіло.
+    fn apply_balance_control(&self, physics: &mut PhysicsWorld) {
+        let accel = self.balance_acceleration(physics);
+        if accel == Vec3::ZERO {
+            return;
+        }
+        let mass = self.skeleton.total_mass(physics);
+        let Some(com) = self.skeleton.center_of_mass(physics) else {
+            return;
+        };
+        let Some(body) = self.skeleton.bodies.get(&BoneId::Pelvis).and_then(|h| physics.rigid_body_set.get_mut(*h)) else {
+            return;
+        };
+
+        let force = accel * mass;
+        let lever = super::rapier_to_vec3(body.translation()) - com;
+        let torque = (-lever.cross(force)).clamp_length_max(MAX_BALANCE_TORQUE);
+        body.add_force(vector![force.x, 0.0, force.z], true);
+        body.add_torque(vector![torque.x, torque.y, torque.z], true);
+    }
+
     /// Застосовує torque для утримання вертикального положення
-    fn apply_upright_torque(&self, physics: &mut PhysicsWorld) {
+    ///
+    /// `strength` - множник (1.0 = Active, 0..1 = ramp під час Recovery)
+    fn apply_upright_torque(&self, physics: &mut PhysicsWorld, strength: f32) {
         if let Some(handle) = self.skeleton.bodies.get(&BoneId::Pelvis) {
             if let Some(body) = physics.rigid_body_set.get_mut(*handle) {
                 // Отримуємо поточну орієнтацію
@@ -244,7 +929,7 @@ impl ActiveRagdoll {
                         torque.x.clamp(-100.0, 100.0),
                         0.0, // Не впливаємо на yaw
                         torque.z.clamp(-100.0, 100.0)
-                    ];
+                    ] * strength;
                     body.add_torque(torque, true);
                 }
 
@@ -264,7 +949,7 @@ impl ActiveRagdoll {
                                     spine_torque.x.clamp(-50.0, 50.0),
                                     0.0,
                                     spine_torque.z.clamp(-50.0, 50.0)
-                                ];
+                                ] * strength;
                                 spine_body.add_torque(spine_torque, true);
                             }
                         }
@@ -274,28 +959,173 @@ impl ActiveRagdoll {
         }
     }
 
+    /// Нахил pelvis від вертикалі (радіани, 0 = стоїть рівно)
+    pub fn tilt_angle(&self, physics: &PhysicsWorld) -> f32 {
+        let up = self.get_rotation(physics) * Vec3::Y;
+        up.dot(Vec3::Y).clamp(-1.0, 1.0).acos()
+    }
+
+    /// Чи тримає персонаж баланс (нахил pelvis ≤ fall_threshold)
+    pub fn check_balance(&self, physics: &PhysicsWorld) -> bool {
+        self.tilt_angle(physics) <= self.fall_threshold
+    }
+
     /// Встановлює напрямок руху
     pub fn set_move_direction(&mut self, direction: Vec3) {
         if direction.length_squared() > 0.01 {
             self.move_direction = direction.normalize();
             self.is_walking = true;
 
-            // Цільовий yaw = напрямок руху
-            self.target_yaw = (-direction.x).atan2(-direction.z);
+            // Рух вбік (без спринту) - стрейф, погляд не змінюється
+            self.strafe_side = if self.sprinting { 0.0 } else { self.strafe_side_for(self.move_direction) };
+            if self.strafe_side == 0.0 {
+                // Цільовий yaw = напрямок руху
+                self.target_yaw = (-direction.x).atan2(-direction.z);
+            }
         } else {
             self.is_walking = false;
+            self.strafe_side = 0.0;
+        }
+    }
+
+    /// Вмикає / вимикає спринт (стан Run; викликати перед set_move_direction)
+    pub fn set_sprinting(&mut self, sprinting: bool) {
+        self.sprinting = sprinting;
+    }
+
+    /// Веде ragdoll за зовнішньою позицією (AI ворога), раз на кадр
+    ///
+    /// Швидкість ходьби - щоб target_position наздогнав `position` за
+    /// `delta`; похибка кроків фізики виправляється в наступному кадрі.
+    ///
+    /// # Аргументи
+    /// * `position` - Позиція AI (ноги, Y ігнорується)
+    /// * `yaw` - Куди дивиться AI
+    /// * `delta` - Час кадру (секунди)
+    pub fn follow(&mut self, position: Vec3, yaw: f32, delta: f32) {
+        // Підйом повертає тіло на свій yaw
+        if self.is_getting_up() {
+            return;
+        }
+
+        self.target_yaw = yaw;
+        if delta <= 0.0 || self.mode != RagdollMode::Active {
+            return;
         }
+
+        let lag = Vec3::new(position.x - self.target_position.x, 0.0, position.z - self.target_position.z);
+        let speed = lag.length() / delta;
+        self.is_walking = speed > FOLLOW_WALK_SPEED;
+        if self.is_walking {
+            self.move_direction = lag / lag.length();
+            self.move_speed = speed;
+        }
+        // AI дивиться на ціль - рух вбік від неї (розштовхування) - стрейф
+        self.strafe_side = if self.is_walking { self.strafe_side_for(self.move_direction) } else { 0.0 };
+    }
+
+    /// Видаляє ragdoll з фізичного світу
+    pub fn despawn(mut self, physics: &mut PhysicsWorld) {
+        self.skeleton.despawn(physics);
     }
 
     /// Переводить в режим ragdoll
     pub fn go_ragdoll(&mut self) {
         self.mode = RagdollMode::Ragdoll;
         self.is_walking = false;
+        self.fallen_time = None;
+        self.knockdown_timer = None;
+        self.air_time = None;
+    }
+
+    /// Стрибок: імпульс вгору, якщо персонаж в Active і стоїть на землі
+    ///
+    /// # Повертає
+    /// `true` якщо стрибок почався
+    pub fn jump(&mut self, physics: &mut PhysicsWorld) -> bool {
+        if self.mode != RagdollMode::Active || self.air_time.is_some() || !self.is_grounded(physics) {
+            return false;
+        }
+
+        for (bone_id, share) in JUMP_IMPULSE_SHARES {
+            let Some(body) = self.skeleton.bodies.get(&bone_id).and_then(|h| physics.rigid_body_set.get_mut(*h)) else {
+                continue;
+            };
+            let impulse = body.mass() * self.jump_speed * share;
+            body.apply_impulse(vector![0.0, impulse, 0.0], true);
+        }
+        self.air_time = Some(0.0);
+        log_debug(&format!("Стрибок: {:.1} м/с", self.jump_speed));
+        true
+    }
+
+    /// Чи pelvis над землею не вище висоти стійки + JUMP_GROUND_MARGIN
+    fn is_grounded(&self, physics: &PhysicsWorld) -> bool {
+        let Some(pelvis) = self.skeleton.get_bone_position(physics, BoneId::Pelvis) else {
+            return false;
+        };
+        let reach = self.skeleton.standing_pelvis_height() + JUMP_GROUND_MARGIN;
+        physics.cast_ray_static(pelvis, Vec3::NEG_Y, reach).is_some()
+    }
+
+    /// Одразу вмикає активний режим (м'язи на повну, без підйому)
+    pub fn activate(&mut self) {
+        self.mode = RagdollMode::Active;
+        self.fallen_time = None;
+        self.knockdown_timer = None;
+    }
+
+    /// Перевіряє гальмування pelvis та голови за крок
+    ///
+    /// Понад knockdown_speed - шкода в pending_fall_damage, а якщо персонаж
+    /// не лежить вже (Active / Recovery) - нокдаун на knockdown_duration.
+    fn check_hard_landing(&mut self, physics: &PhysicsWorld) {
+        let mut hardest: Option<(BoneId, f32)> = None;
+        for bone_id in KNOCKDOWN_BONES {
+            let Some(velocity) = self.skeleton.get_bone_linear_velocity(physics, bone_id) else {
+                continue;
+            };
+            let Some(previous) = self.knockdown_velocities.insert(bone_id, velocity) else {
+                continue;
+            };
+            let deceleration = previous.length() - velocity.length();
+            if deceleration > self.knockdown_speed && hardest.is_none_or(|(_, d)| deceleration > d) {
+                hardest = Some((bone_id, deceleration));
+            }
+        }
+
+        let Some((bone_id, deceleration)) = hardest else {
+            return;
+        };
+        let damage = (deceleration - self.knockdown_speed) * self.fall_damage_per_speed;
+        self.pending_fall_damage += damage;
+        log_debug(&format!(
+            "Жорстке приземлення: {:?} гальмування {:.1} м/с за крок (поріг {:.1}) - шкода {:.1}",
+            bone_id, deceleration, self.knockdown_speed, damage
+        ));
+
+        if self.mode != RagdollMode::Ragdoll {
+            self.go_ragdoll();
+            self.knockdown_timer = Some(self.knockdown_duration);
+        }
+    }
+
+    /// Забирає накопичену шкоду від падінь (0.0 - не було)
+    pub fn take_fall_damage(&mut self) -> f32 {
+        std::mem::take(&mut self.pending_fall_damage)
+    }
+
+    /// Чи персонаж піднімається з землі (рух не приймається)
+    pub fn is_getting_up(&self) -> bool {
+        matches!(self.mode, RagdollMode::Recovery { .. })
     }
 
     /// Починає відновлення після ragdoll
     pub fn start_recovery(&mut self) {
         self.mode = RagdollMode::Recovery { progress: 0.0 };
+        self.fallen_time = None;
+        self.knockdown_timer = None;
+        self.air_time = None;
     }
 
     /// Отримує позицію персонажа (центр pelvis)
@@ -324,10 +1154,84 @@ impl ActiveRagdoll {
         }
     }
 
-    /// Отримує позиції всіх кісток для рендерингу
+    /// Реакція на удар: відкидає найближчу до точки удару кістку
+    ///
+    /// # Аргументи
+    /// * `world_point` - Точка удару (world space)
+    /// * `direction` - Напрямок удару (нормалізується)
+    /// * `force` - Імпульс (N·s) для влученої кістки; батьківська отримує
+    ///   HIT_PARENT_IMPULSE_RATIO від нього
+    ///
+    /// # Повертає
+    /// Кістку, в яку влучили (None якщо скелет порожній)
+    pub fn react_to_hit(
+        &mut self,
+        physics: &mut PhysicsWorld,
+        world_point: Vec3,
+        direction: Vec3,
+        force: f32,
+    ) -> Option<BoneId> {
+        let bone_id = self.nearest_bone(physics, world_point)?;
+        let impulse = direction.normalize_or_zero() * force;
+
+        self.apply_impact(physics, bone_id, impulse);
+        if let Some(parent) = bone_id.parent() {
+            if let Some(body) = self.skeleton.bodies.get(&parent).and_then(|h| physics.rigid_body_set.get_mut(*h)) {
+                let parent_impulse = impulse * HIT_PARENT_IMPULSE_RATIO;
+                body.apply_impulse(vector![parent_impulse.x, parent_impulse.y, parent_impulse.z], true);
+            }
+        }
+
+        self.hit_recoil_timer = self.hit_recoil_duration;
+        log_debug(&format!("Hit reaction: {:?}, impulse {:.0}", bone_id, force));
+        Some(bone_id)
+    }
+
+    /// Кістка, вісь якої найближча до точки (world space)
+    fn nearest_bone(&self, physics: &PhysicsWorld, point: Vec3) -> Option<BoneId> {
+        self.skeleton
+            .bones
+            .iter()
+            .filter_map(|(bone_id, bone)| {
+                let center = self.skeleton.get_bone_position(physics, *bone_id)?;
+                let rotation = self.skeleton.get_bone_rotation(physics, *bone_id)?;
+
+                // Відстань до відрізка осі капсули
+                let half_axis = rotation * Vec3::Y * (bone.length / 2.0);
+                let t = (point - center).dot(half_axis) / half_axis.length_squared().max(1e-6);
+                let closest = center + half_axis * t.clamp(-1.0, 1.0);
+                Some((*bone_id, closest.distance_squared(point)))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(bone_id, _)| bone_id)
+    }
+
+    /// Швидкість вістря меча (без меча - кінця правого передпліччя), world space
+    ///
+    /// Швидкість точки твердого тіла: v + ω × r, де r - від центру кістки
+    /// до її нижнього кінця. Vec3::ZERO якщо кістки немає.
+    pub fn weapon_tip_velocity(&self, physics: &PhysicsWorld) -> Vec3 {
+        let bone_id = if self.skeleton.bodies.contains_key(&BoneId::Weapon) {
+            BoneId::Weapon
+        } else {
+            BoneId::RightLowerArm
+        };
+        let (Some(center), Some(tip), Some(linvel), Some(angvel)) = (
+            self.skeleton.get_bone_position(physics, bone_id),
+            self.skeleton.bone_end_position(physics, bone_id),
+            self.skeleton.get_bone_linear_velocity(physics, bone_id),
+            self.skeleton.get_bone_angular_velocity(physics, bone_id),
+        ) else {
+            return Vec3::ZERO;
+        };
+        linvel + angvel.cross(tip - center)
+    }
+
+    /// Отримує позиції всіх кісток (і меча, якщо є) для рендерингу
     pub fn get_bone_transforms(&self, physics: &PhysicsWorld) -> Vec<(BoneId, Vec3, Quat)> {
         BoneId::all_bones()
             .into_iter()
+            .chain(BoneId::attachments())
             .filter_map(|bone_id| {
                 let pos = self.skeleton.get_bone_position(physics, bone_id)?;
                 let rot = self.skeleton.get_bone_rotation(physics, bone_id)?;
@@ -336,6 +1240,124 @@ impl ActiveRagdoll {
             .collect()
     }
 
+    /// Скидає ragdoll в чисту A-pose на заданій позиції
+    ///
+    /// Для налаштування: коли ragdoll заплутався або провалився крізь землю.
+    /// Режим, м'язи та цикл ходьби повертаються до стану після new().
+    pub fn reset(&mut self, physics: &mut PhysicsWorld, position: Vec3) {
+        self.skeleton.reset_pose(physics, position);
+
+        for muscle in self.muscles.muscles.values_mut() {
+            muscle.strength = 1.0;
+        }
+        self.mode = if self.start_active { RagdollMode::Active } else { RagdollMode::Ragdoll };
+        self.muscles.global_strength = 0.0;
+        self.fallen_time = None;
+        self.knockdown_velocities.clear();
+        self.knockdown_timer = None;
+        self.pending_fall_damage = 0.0;
+        self.hit_recoil_timer = 0.0;
+        self.foot_plant = [None; 2];
+        self.swing_from = [None; 2];
+        self.watchdog.clear();
+
+        self.walk_cycle = WalkCycle::new().with_foot_ik(self.walk_cycle.foot_ik_enabled);
+        self.is_walking = false;
+        self.move_direction = Vec3::NEG_Z;
+        self.locomotion = LocomotionState::Idle;
+        self.sprinting = false;
+        self.strafe_side = 0.0;
+        self.locomotion_blend_time = LOCOMOTION_BLEND_TIME;
+        self.air_time = None;
+        self.current_pose = TargetPose::standing();
+        self.target_position = position;
+
+        // Без інтерполяції від старої (заплутаної) пози
+        self.snapshot_transforms(physics);
+        self.snapshot_transforms(physics);
+
+        log_debug(&format!(
+            "=== RAGDOLL RESET at ({:.2}, {:.2}, {:.2}) ===",
+            position.x, position.y, position.z
+        ));
+    }
+
+    /// Перевіряє кістки на вибух фізики (RagdollWatchdog)
+    ///
+    /// Викликати ПІСЛЯ physics.step(), ДО snapshot_transforms()
+    pub fn check_watchdog(&mut self, physics: &mut PhysicsWorld) {
+        match self.watchdog.check(physics, &mut self.skeleton) {
+            WatchdogOutcome::Ok => {}
+            WatchdogOutcome::Clamped => self.knockdown_velocities.clear(),
+            WatchdogOutcome::Restored => {
+                self.knockdown_velocities.clear();
+                self.foot_plant = [None; 2];
+                self.swing_from = [None; 2];
+                self.air_time = None;
+                // Без інтерполяції від зіпсованої пози
+                self.snapshot_transforms(physics);
+            }
+        }
+    }
+
+    /// Зберігає transforms кісток після кроку фізики (current → previous)
+    ///
+    /// Викликати ПІСЛЯ кожного physics.step()
+    pub fn snapshot_transforms(&mut self, physics: &PhysicsWorld) {
+        std::mem::swap(&mut self.previous_transforms, &mut self.current_transforms);
+        self.current_transforms.clear();
+        for (bone_id, position, rotation) in self.get_bone_transforms(physics) {
+            self.current_transforms.insert(bone_id, (position, rotation));
+        }
+    }
+
+    /// Transforms кісток, інтерпольовані між двома останніми кроками фізики
+    ///
+    /// # Аргументи
+    /// * `alpha` - 0.0 = previous, 1.0 = current (FixedStepper::interpolation_alpha)
+    pub fn get_bone_transforms_interpolated(&self, alpha: f32) -> Vec<(BoneId, Vec3, Quat)> {
+        BoneId::all_bones()
+            .into_iter()
+            .chain(BoneId::attachments())
+            .filter_map(|bone_id| {
+                let (curr_pos, curr_rot) = *self.current_transforms.get(&bone_id)?;
+                let (prev_pos, prev_rot) = self.previous_transforms
+                    .get(&bone_id)
+                    .copied()
+                    .unwrap_or((curr_pos, curr_rot));
+                Some((bone_id, prev_pos.lerp(curr_pos, alpha), prev_rot.slerp(curr_rot, alpha)))
+            })
+            .collect()
+    }
+
+    /// Інтерпольована позиція pelvis (для камери - без ривків як у скелета)
+    pub fn get_position_interpolated(&self, alpha: f32) -> Vec3 {
+        self.get_bone_position_interpolated(BoneId::Pelvis, alpha).unwrap_or(Vec3::ZERO)
+    }
+
+    /// Інтерпольована позиція центру кістки (None - ще немає snapshot)
+    pub fn get_bone_position_interpolated(&self, bone_id: BoneId, alpha: f32) -> Option<Vec3> {
+        let (curr, _) = *self.current_transforms.get(&bone_id)?;
+        let prev = self.previous_transforms.get(&bone_id).map_or(curr, |(prev, _)| *prev);
+        Some(prev.lerp(curr, alpha))
+    }
+
+    /// Отримує швидкості всіх кісток для debug візуалізації
+    ///
+    /// # Повертає
+    /// (bone, linear velocity, angular velocity) в world space
+    pub fn get_bone_velocities(&self, physics: &PhysicsWorld) -> Vec<(BoneId, Vec3, Vec3)> {
+        BoneId::all_bones()
+            .into_iter()
+            .chain(BoneId::attachments())
+            .filter_map(|bone_id| {
+                let linvel = self.skeleton.get_bone_linear_velocity(physics, bone_id)?;
+                let angvel = self.skeleton.get_bone_angular_velocity(physics, bone_id)?;
+                Some((bone_id, linvel, angvel))
+            })
+            .collect()
+    }
+
     /// Логує позиції всіх кісток для діагностики
     fn log_bone_positions(&self, physics: &PhysicsWorld) {
         log_debug(&format!("=== RAGDOLL FRAME {} ===", self.frame_count));
@@ -387,3 +1409,121 @@ impl ActiveRagdoll {
         log_debug("");
     }
 }
+
+/// Вертикальна сила на pelvis: вага + PD до `target_height`
+///
+/// Не менше нуля і не більше MAX_LIFT_WEIGHT_RATIO ваги тіла.
+fn support_lift(body: &RigidBody, mass: f32, gravity: f32, target_height: f32) -> f32 {
+    let height_error = target_height - body.translation().y;
+    (mass * (gravity + LIFT_STIFFNESS * height_error - LIFT_DAMPING * body.linvel().y))
+        .clamp(0.0, mass * gravity * MAX_LIFT_WEIGHT_RATIO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ragdoll у невагомості (кістки нерухомі до удару)
+    fn floating_ragdoll() -> (PhysicsWorld, ActiveRagdoll) {
+        let mut physics = PhysicsWorld::new();
+        physics.set_gravity(Vec3::ZERO);
+        let ragdoll = ActiveRagdoll::new(&mut physics, Vec3::new(0.0, 2.0, 0.0), RagdollStiffness::default());
+        (physics, ragdoll)
+    }
+
+    /// Влучена кістка та кістка з найбільшою зміною імпульсу після удару в `bone_id`
+    fn hit_bone(bone_id: BoneId) -> (Option<BoneId>, BoneId) {
+        let (mut physics, mut ragdoll) = floating_ragdoll();
+        let point = ragdoll.skeleton.get_bone_position(&physics, bone_id).expect("кістка");
+        let momentum = |physics: &PhysicsWorld, ragdoll: &ActiveRagdoll, bone_id: BoneId| {
+            let body = &physics.rigid_body_set[ragdoll.skeleton.bodies[&bone_id]];
+            let velocity = body.linvel();
+            Vec3::new(velocity.x, velocity.y, velocity.z) * body.mass()
+        };
+        let before: Vec<_> = ragdoll
+            .skeleton
+            .bodies
+            .keys()
+            .map(|&id| (id, momentum(&physics, &ragdoll, id)))
+            .collect();
+
+        let hit = ragdoll.react_to_hit(&mut physics, point, Vec3::X, 20.0);
+        let strongest = before
+            .into_iter()
+            .map(|(id, old)| (id, (momentum(&physics, &ragdoll, id) - old).length()))
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(id, _)| id)
+            .expect("кістки");
+        (hit, strongest)
+    }
+
+    #[test]
+    fn head_and_leg_hits_push_different_bones() {
+        assert_eq!(hit_bone(BoneId::Head), (Some(BoneId::Head), BoneId::Head));
+        assert_eq!(hit_bone(BoneId::LeftLowerLeg), (Some(BoneId::LeftLowerLeg), BoneId::LeftLowerLeg));
+    }
+
+    #[test]
+    fn hit_recoil_weakens_then_restores_strength() {
+        let (mut physics, mut ragdoll) = floating_ragdoll();
+        ragdoll.activate();
+        let dt = physics.fixed_timestep;
+        ragdoll.update(&mut physics, dt);
+        assert_eq!(ragdoll.muscles.global_strength, 1.0);
+
+        let head = ragdoll.skeleton.get_bone_position(&physics, BoneId::Head).expect("голова");
+        ragdoll.react_to_hit(&mut physics, head, Vec3::X, 10.0);
+        ragdoll.update(&mut physics, dt);
+        let weakened = ragdoll.muscles.global_strength;
+        assert!(weakened < 0.5, "сила одразу після удару {:.2}", weakened);
+
+        // Сила росте і за hit_recoil_duration повертається до повної
+        let steps = (ragdoll.hit_recoil_duration / dt).ceil() as usize;
+        for _ in 0..steps / 2 {
+            ragdoll.update(&mut physics, dt);
+        }
+        let halfway = ragdoll.muscles.global_strength;
+        assert!(halfway > weakened && halfway < 1.0, "сила на півдорозі {:.2}", halfway);
+        for _ in 0..steps {
+            ragdoll.update(&mut physics, dt);
+        }
+        assert_eq!(ragdoll.muscles.global_strength, 1.0);
+    }
+
+    /// Задає швидкість тіла кістки напряму, без кроку фізики
+    fn set_bone_velocity(physics: &mut PhysicsWorld, ragdoll: &ActiveRagdoll, bone_id: BoneId, linvel: Vec3, angvel: Vec3) {
+        let body = &mut physics.rigid_body_set[ragdoll.skeleton.bodies[&bone_id]];
+        body.set_linvel(vector![linvel.x, linvel.y, linvel.z], true);
+        body.set_angvel(vector![angvel.x, angvel.y, angvel.z], true);
+    }
+
+    #[test]
+    fn weapon_tip_velocity_adds_spin_at_the_tip() {
+        let (mut physics, mut ragdoll) = floating_ragdoll();
+
+        // Без меча - кінець правого передпліччя
+        set_bone_velocity(&mut physics, &ragdoll, BoneId::RightLowerArm, Vec3::X, Vec3::ZERO);
+        assert!(ragdoll.weapon_tip_velocity(&physics).abs_diff_eq(Vec3::X, 1e-5));
+
+        ragdoll.skeleton.attach_weapon(&mut physics);
+        let center = ragdoll.skeleton.get_bone_position(&physics, BoneId::Weapon).expect("меч");
+        let tip = ragdoll.skeleton.bone_end_position(&physics, BoneId::Weapon).expect("вістря");
+        let arm = tip - center;
+
+        // Лише поступальний рух - вістря рухається разом з мечем
+        let linvel = Vec3::new(0.0, 0.0, -3.0);
+        set_bone_velocity(&mut physics, &ragdoll, BoneId::Weapon, linvel, Vec3::ZERO);
+        assert!(ragdoll.weapon_tip_velocity(&physics).abs_diff_eq(linvel, 1e-5));
+
+        // Обертання навколо центру: |v| = ω · L/2, поперек леза
+        let spin = arm.any_orthonormal_vector() * (15.0 / arm.length());
+        set_bone_velocity(&mut physics, &ragdoll, BoneId::Weapon, Vec3::ZERO, spin);
+        let swing = ragdoll.weapon_tip_velocity(&physics);
+        assert!((swing.length() - 15.0).abs() < 1e-3, "швидкість вістря {}", swing.length());
+        assert!(swing.dot(arm).abs() < 1e-3, "вістря рухається вздовж леза");
+
+        // Разом - сума, без подвійного рахунку
+        set_bone_velocity(&mut physics, &ragdoll, BoneId::Weapon, linvel, spin);
+        assert!(ragdoll.weapon_tip_velocity(&physics).abs_diff_eq(linvel + swing, 1e-4));
+    }
+}
@@ -10,6 +10,29 @@
    - Collider (капсула для колізій)
    - Joint до батьківської кістки (з обмеженнями кутів)
 
+📐 ОБМЕЖЕННЯ КУТІВ (AngleLimits → Rapier):
+   Кути - відносна ротація дитини до батька в joint frame (кістки вздовж Y):
+   - twist   → JointAxis::AngY (навколо осі кістки)
+   - swing_x → JointAxis::AngX (вперед / назад; нога вперед = -x, коліно = +x)
+   - swing_z → JointAxis::AngZ (вбік; для правої сторони + = назовні)
+   Ліві кістки використовують mirrored() - дзеркально по X
+
+🧍 КІЛЬКА СКЕЛЕТІВ:
+   Кожен скелет отримує свою групу колізій (PhysicsWorld::allocate_ragdoll_group) -
+   без самозіткнень, але з колізіями між скелетами. despawn() прибирає тіла.
+
+🗡 ЗБРОЯ (attach_weapon):
+   BoneId::Weapon - не кістка тіла (немає в all_bones, без м'яза), а меч
+   у правій руці: капсула WEAPON_LENGTH, fixed joint до кінця
+   RightLowerArm, лезо вздовж напрямку згину ліктя (+Z передпліччя).
+   Своя група колізій: б'ється об землю та кістки інших скелетів, але не
+   об власника. Контакти леза - collision events (is_weapon_collider).
+
+🔁 ДЕТЕРМІНІЗМ:
+   bodies - HashMap (порядок ітерації різний між запусками). Суми по
+   кістках (маса, центр мас) - через bodies_in_order, інакше порядок
+   додавання float дає різні результати і replay розходиться.
+
 ═══════════════════════════════════════════════════════════════════════════════
 */
 
@@ -18,10 +41,24 @@ use rapier3d::prelude::nalgebra;
 use glam::{Vec3, Quat};
 use std::collections::HashMap;
 
-use super::PhysicsWorld;
+use super::{PhysicsWorld, RagdollStiffness};
 use crate::debug_log::log_debug;
 
-/// Ідентифікатор кістки (оптимізовано: 11 кісток)
+/// Мінімальна сила контакту (N), з якої collider кістки генерує ContactForceEvent
+///
+/// Грубий фільтр на рівні Rapier - остаточний поріг в ImpactDetector.
+pub const CONTACT_FORCE_EVENT_THRESHOLD: f32 = 50.0;
+
+/// Довжина меча (м)
+pub const WEAPON_LENGTH: f32 = 1.0;
+
+/// Радіус капсули меча (м)
+const WEAPON_RADIUS: f32 = 0.03;
+
+/// Маса меча (кг)
+const WEAPON_MASS: f32 = 1.2;
+
+/// Ідентифікатор кістки (оптимізовано: 11 кісток + меч)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum BoneId {
     // Торс (3 кістки)
@@ -44,6 +81,9 @@ pub enum BoneId {
     // Права нога (2 кістки)
     RightUpperLeg,
     RightLowerLeg,
+
+    // Меч у правій руці (attach_weapon)
+    Weapon,
 }
 
 impl BoneId {
@@ -65,9 +105,27 @@ impl BoneId {
 
             BoneId::RightUpperLeg => Some(BoneId::Pelvis),
             BoneId::RightLowerLeg => Some(BoneId::RightUpperLeg),
+
+            BoneId::Weapon => Some(BoneId::RightLowerArm),
         }
     }
 
+    /// user_data collider'а кістки (індекс в all_bones + attachments + 1; 0 - не кістка)
+    pub fn to_user_data(self) -> u128 {
+        self as u128 + 1
+    }
+
+    /// Кістка з user_data collider'а (None для collider'ів не-кісток)
+    pub fn from_user_data(user_data: u128) -> Option<BoneId> {
+        let index = usize::try_from(user_data.checked_sub(1)?).ok()?;
+        Self::all_bones().into_iter().chain(Self::attachments()).nth(index)
+    }
+
+    /// Прикріплені до скелета тіла, що не є кістками тіла (після all_bones)
+    pub fn attachments() -> Vec<BoneId> {
+        vec![BoneId::Weapon]
+    }
+
     /// Список всіх кісток в порядку створення (батьки перед дітьми)
     pub fn all_bones() -> Vec<BoneId> {
         vec![
@@ -101,18 +159,18 @@ pub struct Bone {
     pub angle_limits: AngleLimits,
 }
 
-/// Обмеження кутів суглоба
+/// Обмеження кутів суглоба (радіани, для правої сторони тіла)
 #[derive(Debug, Clone, Copy)]
 pub struct AngleLimits {
-    /// Twist (обертання навколо осі кістки)
+    /// Twist (обертання навколо осі кістки) → AngY
     pub twist_min: f32,
     pub twist_max: f32,
 
-    /// Swing X (нахил вперед/назад)
+    /// Swing X (нахил вперед/назад) → AngX
     pub swing_x_min: f32,
     pub swing_x_max: f32,
 
-    /// Swing Z (нахил вліво/вправо)
+    /// Swing Z (нахил вліво/вправо) → AngZ
     pub swing_z_min: f32,
     pub swing_z_max: f32,
 }
@@ -184,8 +242,8 @@ impl AngleLimits {
         Self {
             twist_min: -0.8,
             twist_max: 0.8,
-            swing_x_min: -1.5,      // Нога назад
-            swing_x_max: 2.0,       // Нога вперед
+            swing_x_min: -2.0,      // Нога вперед
+            swing_x_max: 0.6,       // Нога назад (без гіперекстензії)
             swing_z_min: -0.8,      // Нога всередину
             swing_z_max: 1.2,       // Нога назовні
         }
@@ -215,6 +273,28 @@ impl AngleLimits {
         }
     }
 
+    /// Дзеркальна копія для лівої сторони тіла
+    ///
+    /// Відображення по X змінює знак twist (Y) та swing Z - межі
+    /// міняються місцями з протилежним знаком. Swing X не змінюється.
+    pub fn mirrored(self) -> Self {
+        Self {
+            twist_min: -self.twist_max,
+            twist_max: -self.twist_min,
+            swing_x_min: self.swing_x_min,
+            swing_x_max: self.swing_x_max,
+            swing_z_min: -self.swing_z_max,
+            swing_z_max: -self.swing_z_min,
+        }
+    }
+
+    /// Застосовує межі до spherical joint (twist → AngY, swing → AngX / AngZ)
+    pub fn apply_to(&self, joint: &mut SphericalJoint) {
+        joint.set_limits(JointAxis::AngX, [self.swing_x_min, self.swing_x_max]);
+        joint.set_limits(JointAxis::AngY, [self.twist_min, self.twist_max]);
+        joint.set_limits(JointAxis::AngZ, [self.swing_z_min, self.swing_z_max]);
+    }
+
     /// Зап'ясток/гомілковостопний (середня рухливість)
     pub fn wrist_ankle() -> Self {
         Self {
@@ -241,16 +321,26 @@ pub struct Skeleton {
 
     /// Базова позиція скелета (pelvis)
     pub root_position: Vec3,
+
+    /// Група колізій кісток (своя для кожного скелета)
+    pub collision_group: Group,
+
+    /// Collider меча (None - без зброї)
+    weapon_collider: Option<ColliderHandle>,
 }
 
 impl Skeleton {
     /// Створює гуманоїдний скелет
-    pub fn create_humanoid(physics: &mut PhysicsWorld, position: Vec3) -> Self {
+    ///
+    /// `stiffness` - профіль жорсткості joint motors (див. RagdollStiffness)
+    pub fn create_humanoid(physics: &mut PhysicsWorld, position: Vec3, stiffness: RagdollStiffness) -> Self {
         let mut skeleton = Self {
             bodies: HashMap::new(),
             joints: HashMap::new(),
             bones: HashMap::new(),
             root_position: position,
+            collision_group: physics.allocate_ragdoll_group(),
+            weapon_collider: None,
         };
 
         // Визначаємо параметри кісток
@@ -260,7 +350,7 @@ impl Skeleton {
         skeleton.create_bodies(physics, position);
 
         // Створюємо joints
-        skeleton.create_joints(physics);
+        skeleton.create_joints(physics, stiffness);
 
         skeleton
     }
@@ -280,19 +370,6 @@ impl Skeleton {
     /// - 0.88 (1.58м) - chin
     /// - 1.00 (1.80м) - crown
     fn define_bones(&mut self) {
-        // === БАЗОВІ КОНСТАНТИ ===
-        const TOTAL_HEIGHT: f32 = 1.80;
-
-        // === ВЕРТИКАЛЬНІ ПОЗИЦІЇ (пропорції × 1.8м) ===
-        // const GROUND: f32 = 0.0;
-        // const ANKLE: f32 = 0.05;      // 0.03 × 1.8
-        const KNEE: f32 = 0.45;          // 0.25 × 1.8
-        const CROTCH: f32 = 0.90;        // 0.50 × 1.8 - СЕРЕДИНА!
-        // const ELBOW: f32 = 1.12;      // 0.62 × 1.8
-        const SHOULDER: f32 = 1.51;      // 0.84 × 1.8
-        // const CHIN: f32 = 1.58;       // 0.88 × 1.8
-        // const CROWN: f32 = 1.80;      // 1.00 × 1.8
-
         // === ШИРИНИ (пропорції × 1.8м) ===
         const SHOULDER_HALF_WIDTH: f32 = 0.43;  // 0.24 × 1.8 - від центру до краю плеча
         const HIP_HALF_WIDTH: f32 = 0.14;       // 0.08 × 1.8 - від центру до hip joint
@@ -363,7 +440,7 @@ impl Skeleton {
             mass: 2.5,
             // Плече кріпиться збоку від spine, трохи нижче верху
             local_offset: Vec3::new(-SHOULDER_OFFSET, 0.15, 0.0),
-            angle_limits: AngleLimits::shoulder(),
+            angle_limits: AngleLimits::shoulder().mirrored(),
         });
 
         // Ліва рука: передпліччя (forearm)
@@ -408,7 +485,7 @@ impl Skeleton {
             mass: 8.0,
             // Кріпиться до низу pelvis, збоку
             local_offset: Vec3::new(-HIP_HALF_WIDTH, -0.075, 0.0),
-            angle_limits: AngleLimits::hip(),
+            angle_limits: AngleLimits::hip().mirrored(),
         });
 
         // Ліва нога: гомілка (calf)
@@ -442,22 +519,23 @@ impl Skeleton {
         });
     }
 
-    /// Створює фізичні тіла для кісток
-    fn create_bodies(&mut self, physics: &mut PhysicsWorld, root_pos: Vec3) {
-        log_debug("=== SKELETON CREATION ===");
-        log_debug(&format!("Root position: ({:.2}, {:.2}, {:.2})", root_pos.x, root_pos.y, root_pos.z));
-
+    /// Обчислює A-pose (позиції центрів та ротації всіх кісток)
+    ///
+    /// Використовується при створенні тіл та в reset_pose().
+    ///
+    /// # Повертає
+    /// (bone, world position центру, ротація) в порядку BoneId::all_bones()
+    fn compute_rest_pose(&self, root_pos: Vec3) -> Vec<(BoneId, Vec3, nalgebra::UnitQuaternion<f32>)> {
         // Обчислюємо world positions для ЦЕНТРІВ кісток (не точок з'єднання!)
         // Це критично важливо - Rapier позиціонує тіла по центру
         let mut world_positions: HashMap<BoneId, Vec3> = HashMap::new();
+        let mut pose = Vec::new();
 
         for bone_id in BoneId::all_bones() {
             let bone = self.bones.get(&bone_id).unwrap();
 
             let world_pos = if let Some(parent_id) = bone_id.parent() {
                 let parent_pos = world_positions.get(&parent_id).unwrap();
-                let parent_bone = self.bones.get(&parent_id).unwrap();
-
                 // Точка з'єднання на батьківській кістці
                 // Для рук: local_offset.x визначає відстань до плечового суглоба
                 let joint_point = *parent_pos + bone.local_offset;
@@ -507,6 +585,42 @@ impl Skeleton {
 
             world_positions.insert(bone_id, world_pos);
 
+            // Обчислюємо початкову ротацію
+            // A-POSE: руки повернуті на ~25° від вертикалі
+            const ARM_ANGLE: f32 = 0.44;  // ~25 degrees
+            let initial_rotation = match bone_id {
+                // Ліва рука: поворот навколо Z (нахил назовні)
+                BoneId::LeftUpperArm | BoneId::LeftLowerArm => {
+                    nalgebra::UnitQuaternion::from_axis_angle(
+                        &nalgebra::Vector3::z_axis(),
+                        -ARM_ANGLE  // Negative = rotate outward for left arm
+                    )
+                }
+                // Права рука: поворот навколо Z (нахил назовні)
+                BoneId::RightUpperArm | BoneId::RightLowerArm => {
+                    nalgebra::UnitQuaternion::from_axis_angle(
+                        &nalgebra::Vector3::z_axis(),
+                        ARM_ANGLE  // Positive = rotate outward for right arm
+                    )
+                }
+                // Всі інші: без ротації
+                _ => nalgebra::UnitQuaternion::identity()
+            };
+
+            pose.push((bone_id, world_pos, initial_rotation));
+        }
+
+        pose
+    }
+
+    /// Створює фізичні тіла для кісток
+    fn create_bodies(&mut self, physics: &mut PhysicsWorld, root_pos: Vec3) {
+        log_debug("=== SKELETON CREATION ===");
+        log_debug(&format!("Root position: ({:.2}, {:.2}, {:.2})", root_pos.x, root_pos.y, root_pos.z));
+
+        for (bone_id, world_pos, initial_rotation) in self.compute_rest_pose(root_pos) {
+            let bone = self.bones.get(&bone_id).unwrap();
+
             // Логування створеної позиції
             log_debug(&format!(
                 "{:?}: center=({:.3}, {:.3}, {:.3}) length={:.2} radius={:.2}",
@@ -534,28 +648,6 @@ impl Skeleton {
                 (5.0, 1.0)
             };
 
-            // Обчислюємо початкову ротацію
-            // A-POSE: руки повернуті на ~25° від вертикалі
-            const ARM_ANGLE: f32 = 0.44;  // ~25 degrees
-            let initial_rotation = match bone_id {
-                // Ліва рука: поворот навколо Z (нахил назовні)
-                BoneId::LeftUpperArm | BoneId::LeftLowerArm => {
-                    nalgebra::UnitQuaternion::from_axis_angle(
-                        &nalgebra::Vector3::z_axis(),
-                        -ARM_ANGLE  // Negative = rotate outward for left arm
-                    )
-                }
-                // Права рука: поворот навколо Z (нахил назовні)
-                BoneId::RightUpperArm | BoneId::RightLowerArm => {
-                    nalgebra::UnitQuaternion::from_axis_angle(
-                        &nalgebra::Vector3::z_axis(),
-                        ARM_ANGLE  // Positive = rotate outward for right arm
-                    )
-                }
-                // Всі інші: без ротації
-                _ => nalgebra::UnitQuaternion::identity()
-            };
-
             let body = RigidBodyBuilder::dynamic()
                 .translation(vector![world_pos.x, world_pos.y, world_pos.z])
                 .rotation(initial_rotation.scaled_axis())
@@ -568,10 +660,11 @@ impl Skeleton {
             self.bodies.insert(bone_id, handle);
 
             // Створюємо collider з collision filtering
-            // ВИМКНЕНО самозіткнення - запобігає стрибанню кінцівок
+            // ВИМКНЕНО самозіткнення - запобігає стрибанню кінцівок;
+            // кістки інших скелетів (інші групи) - колізують
             let collision_groups = InteractionGroups::new(
-                Group::GROUP_1,
-                Group::ALL & !Group::GROUP_1  // Collide with everything EXCEPT self
+                self.collision_group,
+                Group::ALL & !self.collision_group  // Collide with everything EXCEPT self
             );
 
             // ВСІ кістки - КАПСУЛИ (capsule_y)
@@ -581,6 +674,9 @@ impl Skeleton {
                 .friction(0.8)
                 .restitution(0.1)
                 .collision_groups(collision_groups)
+                .user_data(bone_id.to_user_data())
+                .active_events(ActiveEvents::COLLISION_EVENTS | ActiveEvents::CONTACT_FORCE_EVENTS)
+                .contact_force_event_threshold(CONTACT_FORCE_EVENT_THRESHOLD)
                 .build();
 
             physics.add_collider(collider, handle);
@@ -588,9 +684,14 @@ impl Skeleton {
     }
 
     /// Створює joints між кістками (MULTIBODY - reduced coordinates, cannot violate constraints!)
-    fn create_joints(&mut self, physics: &mut PhysicsWorld) {
+    fn create_joints(&mut self, physics: &mut PhysicsWorld, stiffness: RagdollStiffness) {
         log_debug("=== MULTIBODY JOINTS CREATION ===");
 
+        // Профіль жорсткості масштабує всі motor stiffness / damping / max force
+        let ks = stiffness.stiffness_scale();
+        let kd = stiffness.damping_scale();
+        let kf = stiffness.force_scale();
+
         for bone_id in BoneId::all_bones() {
             if let Some(parent_id) = bone_id.parent() {
                 let bone = self.bones.get(&bone_id).unwrap();
@@ -679,9 +780,9 @@ impl Skeleton {
                         let joint = RevoluteJointBuilder::new(UnitVector::new_normalize(vector![1.0, 0.0, 0.0]))
                             .local_anchor1(anchor1)
                             .local_anchor2(anchor2)
-                            .limits([0.0, 2.5])
-                            .motor_position(0.0, 150.0, 30.0)
-                            .motor_max_force(1500.0)
+                            .limits([bone.angle_limits.swing_x_min, bone.angle_limits.swing_x_max])
+                            .motor_position(0.0, 150.0 * ks, 30.0 * kd)
+                            .motor_max_force(1500.0 * kf)
                             .build();
 
                         let joint_handle = physics.impulse_joint_set.insert(
@@ -698,9 +799,9 @@ impl Skeleton {
                         let joint = RevoluteJointBuilder::new(UnitVector::new_normalize(vector![1.0, 0.0, 0.0]))
                             .local_anchor1(anchor1)
                             .local_anchor2(anchor2)
-                            .limits([0.0, 2.4])
-                            .motor_position(0.0, 120.0, 25.0)
-                            .motor_max_force(1200.0)
+                            .limits([bone.angle_limits.swing_x_min, bone.angle_limits.swing_x_max])
+                            .motor_position(0.0, 120.0 * ks, 25.0 * kd)
+                            .motor_max_force(1200.0 * kf)
                             .build();
 
                         let joint_handle = physics.impulse_joint_set.insert(
@@ -722,12 +823,13 @@ impl Skeleton {
                             .local_anchor2(anchor2)
                             .build();
                         // Додаємо motor на всіх осях для жорсткості
-                        joint.set_motor_position(JointAxis::AngX, 0.0, 200.0, 40.0);
-                        joint.set_motor_position(JointAxis::AngY, 0.0, 200.0, 40.0);
-                        joint.set_motor_position(JointAxis::AngZ, 0.0, 200.0, 40.0);
-                        joint.set_motor_max_force(JointAxis::AngX, 2000.0);
-                        joint.set_motor_max_force(JointAxis::AngY, 2000.0);
-                        joint.set_motor_max_force(JointAxis::AngZ, 2000.0);
+                        joint.set_motor_position(JointAxis::AngX, 0.0, 200.0 * ks, 40.0 * kd);
+                        joint.set_motor_position(JointAxis::AngY, 0.0, 200.0 * ks, 40.0 * kd);
+                        joint.set_motor_position(JointAxis::AngZ, 0.0, 200.0 * ks, 40.0 * kd);
+                        joint.set_motor_max_force(JointAxis::AngX, 2000.0 * kf);
+                        joint.set_motor_max_force(JointAxis::AngY, 2000.0 * kf);
+                        joint.set_motor_max_force(JointAxis::AngZ, 2000.0 * kf);
+                        bone.angle_limits.apply_to(&mut joint);
 
                         let joint_handle = physics.impulse_joint_set.insert(
                             parent_handle,
@@ -745,12 +847,13 @@ impl Skeleton {
                             .local_anchor1(anchor1)
                             .local_anchor2(anchor2)
                             .build();
-                        joint.set_motor_position(JointAxis::AngX, 0.0, 100.0, 20.0);
-                        joint.set_motor_position(JointAxis::AngY, 0.0, 100.0, 20.0);
-                        joint.set_motor_position(JointAxis::AngZ, 0.0, 100.0, 20.0);
-                        joint.set_motor_max_force(JointAxis::AngX, 1000.0);
-                        joint.set_motor_max_force(JointAxis::AngY, 1000.0);
-                        joint.set_motor_max_force(JointAxis::AngZ, 1000.0);
+                        joint.set_motor_position(JointAxis::AngX, 0.0, 100.0 * ks, 20.0 * kd);
+                        joint.set_motor_position(JointAxis::AngY, 0.0, 100.0 * ks, 20.0 * kd);
+                        joint.set_motor_position(JointAxis::AngZ, 0.0, 100.0 * ks, 20.0 * kd);
+                        joint.set_motor_max_force(JointAxis::AngX, 1000.0 * kf);
+                        joint.set_motor_max_force(JointAxis::AngY, 1000.0 * kf);
+                        joint.set_motor_max_force(JointAxis::AngZ, 1000.0 * kf);
+                        bone.angle_limits.apply_to(&mut joint);
 
                         let joint_handle = physics.impulse_joint_set.insert(
                             parent_handle,
@@ -768,12 +871,13 @@ impl Skeleton {
                             .local_anchor1(anchor1)
                             .local_anchor2(anchor2)
                             .build();
-                        joint.set_motor_position(JointAxis::AngX, 0.0, 300.0, 60.0);
-                        joint.set_motor_position(JointAxis::AngY, 0.0, 300.0, 60.0);
-                        joint.set_motor_position(JointAxis::AngZ, 0.0, 300.0, 60.0);
-                        joint.set_motor_max_force(JointAxis::AngX, 3000.0);
-                        joint.set_motor_max_force(JointAxis::AngY, 3000.0);
-                        joint.set_motor_max_force(JointAxis::AngZ, 3000.0);
+                        joint.set_motor_position(JointAxis::AngX, 0.0, 300.0 * ks, 60.0 * kd);
+                        joint.set_motor_position(JointAxis::AngY, 0.0, 300.0 * ks, 60.0 * kd);
+                        joint.set_motor_position(JointAxis::AngZ, 0.0, 300.0 * ks, 60.0 * kd);
+                        joint.set_motor_max_force(JointAxis::AngX, 3000.0 * kf);
+                        joint.set_motor_max_force(JointAxis::AngY, 3000.0 * kf);
+                        joint.set_motor_max_force(JointAxis::AngZ, 3000.0 * kf);
+                        bone.angle_limits.apply_to(&mut joint);
 
                         let joint_handle = physics.impulse_joint_set.insert(
                             parent_handle,
@@ -791,12 +895,13 @@ impl Skeleton {
                             .local_anchor1(anchor1)
                             .local_anchor2(anchor2)
                             .build();
-                        joint.set_motor_position(JointAxis::AngX, 0.0, 80.0, 15.0);
-                        joint.set_motor_position(JointAxis::AngY, 0.0, 80.0, 15.0);
-                        joint.set_motor_position(JointAxis::AngZ, 0.0, 80.0, 15.0);
-                        joint.set_motor_max_force(JointAxis::AngX, 800.0);
-                        joint.set_motor_max_force(JointAxis::AngY, 800.0);
-                        joint.set_motor_max_force(JointAxis::AngZ, 800.0);
+                        joint.set_motor_position(JointAxis::AngX, 0.0, 80.0 * ks, 15.0 * kd);
+                        joint.set_motor_position(JointAxis::AngY, 0.0, 80.0 * ks, 15.0 * kd);
+                        joint.set_motor_position(JointAxis::AngZ, 0.0, 80.0 * ks, 15.0 * kd);
+                        joint.set_motor_max_force(JointAxis::AngX, 800.0 * kf);
+                        joint.set_motor_max_force(JointAxis::AngY, 800.0 * kf);
+                        joint.set_motor_max_force(JointAxis::AngZ, 800.0 * kf);
+                        bone.angle_limits.apply_to(&mut joint);
 
                         let joint_handle = physics.impulse_joint_set.insert(
                             parent_handle,
@@ -809,10 +914,11 @@ impl Skeleton {
                     },
 
                     _ => {
-                        let joint = SphericalJointBuilder::new()
+                        let mut joint = SphericalJointBuilder::new()
                             .local_anchor1(anchor1)
                             .local_anchor2(anchor2)
                             .build();
+                        bone.angle_limits.apply_to(&mut joint);
 
                         let joint_handle = physics.impulse_joint_set.insert(
                             parent_handle,
@@ -827,6 +933,161 @@ impl Skeleton {
         }
     }
 
+    /// Дає скелету меч у праву руку
+    ///
+    /// Тіло меча з'єднане fixed joint з нижнім кінцем RightLowerArm, лезо
+    /// вздовж +Z передпліччя (туди згинається лікоть). Група колізій меча
+    /// своя - власника він не зачіпає. Повторний виклик нічого не змінює.
+    pub fn attach_weapon(&mut self, physics: &mut PhysicsWorld) {
+        if self.bodies.contains_key(&BoneId::Weapon) {
+            return;
+        }
+        let (Some(forearm_pos), Some(forearm_rot), Some(forearm)) = (
+            self.get_bone_position(physics, BoneId::RightLowerArm),
+            self.get_bone_rotation(physics, BoneId::RightLowerArm),
+            self.bones.get(&BoneId::RightLowerArm).cloned(),
+        ) else {
+            return;
+        };
+        let forearm_handle = self.bodies[&BoneId::RightLowerArm];
+
+        self.bones.insert(BoneId::Weapon, Bone {
+            id: BoneId::Weapon,
+            length: WEAPON_LENGTH,
+            radius: WEAPON_RADIUS,
+            mass: WEAPON_MASS,
+            local_offset: Vec3::new(0.0, -forearm.length / 2.0, 0.0),
+            angle_limits: AngleLimits::free(),
+        });
+
+        let (position, rotation) = Self::weapon_transform(forearm_pos, forearm_rot, forearm.length);
+        let body = RigidBodyBuilder::dynamic()
+            .translation(super::vec3_to_rapier(position))
+            .rotation(super::quat_to_rapier(rotation).scaled_axis())
+            .angular_damping(5.0)
+            .linear_damping(1.0)
+            .ccd_enabled(true)
+            .build();
+        let handle = physics.add_rigid_body(body);
+        self.bodies.insert(BoneId::Weapon, handle);
+
+        // Своя група: земля та інші скелети - так, власник - ні
+        let weapon_group = physics.allocate_ragdoll_group();
+        let half_length = WEAPON_LENGTH / 2.0;
+        let collider = ColliderBuilder::capsule_y(half_length - WEAPON_RADIUS, WEAPON_RADIUS)
+            .mass(WEAPON_MASS)
+            .friction(0.5)
+            .restitution(0.1)
+            .collision_groups(InteractionGroups::new(weapon_group, Group::ALL & !self.collision_group))
+            .user_data(BoneId::Weapon.to_user_data())
+            .active_events(ActiveEvents::COLLISION_EVENTS | ActiveEvents::CONTACT_FORCE_EVENTS)
+            .contact_force_event_threshold(CONTACT_FORCE_EVENT_THRESHOLD)
+            .build();
+        self.weapon_collider = Some(physics.add_collider(collider, handle));
+
+        // Руків'я (+Y кінець меча) - в кисті, лезо (-Y меча) = +Z передпліччя
+        let joint = FixedJointBuilder::new()
+            .local_frame1(Isometry::from_parts(
+                Translation::new(0.0, -forearm.length / 2.0, 0.0),
+                super::quat_to_rapier(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+            ))
+            .local_frame2(Isometry::translation(0.0, half_length, 0.0))
+            .build();
+        let joint_handle = physics.impulse_joint_set.insert(forearm_handle, handle, joint, true);
+        self.joints.insert(BoneId::Weapon, joint_handle);
+        log_debug(&format!("Weapon attached: length={:.2} mass={:.1}", WEAPON_LENGTH, WEAPON_MASS));
+    }
+
+    /// Центр та ротація меча для заданого transform передпліччя
+    fn weapon_transform(forearm_pos: Vec3, forearm_rot: Quat, forearm_length: f32) -> (Vec3, Quat) {
+        let hand = forearm_pos - forearm_rot * Vec3::Y * (forearm_length / 2.0);
+        let rotation = forearm_rot * Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2);
+        (hand - rotation * Vec3::Y * (WEAPON_LENGTH / 2.0), rotation)
+    }
+
+    /// Чи це collider меча цього скелета
+    pub fn is_weapon_collider(&self, collider: ColliderHandle) -> bool {
+        self.weapon_collider == Some(collider)
+    }
+
+    /// Видаляє тіла, colliders та joints скелета з фізичного світу
+    pub fn despawn(&mut self, physics: &mut PhysicsWorld) {
+        for (_, handle) in self.bodies.drain() {
+            physics.remove_rigid_body(handle);
+        }
+        self.joints.clear();
+        self.weapon_collider = None;
+    }
+
+    /// Повертає всі кістки в A-pose на заданій позиції
+    ///
+    /// Обнуляє швидкості та накопичені сили, будить тіла.
+    /// Joints не перестворюються - вони й так задовольняються в A-pose.
+    pub fn reset_pose(&mut self, physics: &mut PhysicsWorld, root_pos: Vec3) {
+        self.root_position = root_pos;
+
+        let mut pose = self.compute_rest_pose(root_pos);
+
+        // Меч - в руці A-pose
+        let forearm = pose.iter().find(|(bone_id, _, _)| *bone_id == BoneId::RightLowerArm).copied();
+        if let (Some((_, forearm_pos, forearm_rot)), Some(bone)) = (forearm, self.bones.get(&BoneId::RightLowerArm)) {
+            let (position, rotation) =
+                Self::weapon_transform(forearm_pos, super::rapier_to_quat(&forearm_rot), bone.length);
+            pose.push((BoneId::Weapon, position, super::quat_to_rapier(rotation)));
+        }
+
+        for (bone_id, world_pos, rotation) in pose {
+            let Some(handle) = self.bodies.get(&bone_id) else {
+                continue;
+            };
+            if let Some(body) = physics.rigid_body_set.get_mut(*handle) {
+                body.set_translation(vector![world_pos.x, world_pos.y, world_pos.z], true);
+                body.set_rotation(rotation, true);
+                body.set_linvel(vector![0.0, 0.0, 0.0], true);
+                body.set_angvel(vector![0.0, 0.0, 0.0], true);
+                body.reset_forces(true);
+                body.reset_torques(true);
+            }
+        }
+    }
+
+    /// Ставить кістки у збережену позу (Ragdoll watchdog)
+    ///
+    /// Обнуляє швидкості та накопичені сили, будить тіла. Кістки, яких
+    /// немає в `pose`, лишаються на місці.
+    pub fn set_pose(&self, physics: &mut PhysicsWorld, pose: &[(BoneId, Vec3, Quat)]) {
+        for &(bone_id, position, rotation) in pose {
+            let Some(body) = self.bodies.get(&bone_id).and_then(|handle| physics.rigid_body_set.get_mut(*handle))
+            else {
+                continue;
+            };
+            body.set_translation(super::vec3_to_rapier(position), true);
+            body.set_rotation(super::quat_to_rapier(rotation), true);
+            body.set_linvel(vector![0.0, 0.0, 0.0], true);
+            body.set_angvel(vector![0.0, 0.0, 0.0], true);
+            body.reset_forces(true);
+            body.reset_torques(true);
+        }
+    }
+
+    /// Обмежує швидкості всіх кісток (не скінченні - в нуль)
+    ///
+    /// # Аргументи
+    /// * `max_linear` - m/s
+    /// * `max_angular` - rad/s
+    pub fn clamp_velocities(&self, physics: &mut PhysicsWorld, max_linear: f32, max_angular: f32) {
+        let clamp = |v: Vec3, max: f32| if v.is_finite() { v.clamp_length_max(max) } else { Vec3::ZERO };
+        for handle in self.bodies_in_order().collect::<Vec<_>>() {
+            let Some(body) = physics.rigid_body_set.get_mut(handle) else {
+                continue;
+            };
+            let linvel = clamp(super::rapier_to_vec3(body.linvel()), max_linear);
+            let angvel = clamp(super::rapier_to_vec3(body.angvel()), max_angular);
+            body.set_linvel(super::vec3_to_rapier(linvel), true);
+            body.set_angvel(super::vec3_to_rapier(angvel), true);
+        }
+    }
+
     /// Отримує позицію кістки
     pub fn get_bone_position(&self, physics: &PhysicsWorld, bone_id: BoneId) -> Option<Vec3> {
         self.bodies.get(&bone_id)
@@ -877,6 +1138,127 @@ impl Skeleton {
         }
     }
 
+    /// Тіла кісток у фіксованому порядку (all_bones, потім attachments)
+    fn bodies_in_order(&self) -> impl Iterator<Item = RigidBodyHandle> + '_ {
+        BoneId::all_bones()
+            .into_iter()
+            .chain(BoneId::attachments())
+            .filter_map(|bone_id| self.bodies.get(&bone_id).copied())
+    }
+
+    /// Загальна маса всіх кісток (кг)
+    pub fn total_mass(&self, physics: &PhysicsWorld) -> f32 {
+        self.bodies_in_order()
+            .filter_map(|handle| physics.rigid_body_set.get(handle))
+            .map(|body| body.mass())
+            .sum()
+    }
+
+    /// Центр мас скелета (world space)
+    pub fn center_of_mass(&self, physics: &PhysicsWorld) -> Option<Vec3> {
+        let mut weighted = Vec3::ZERO;
+        let mut total = 0.0;
+        for handle in self.bodies_in_order() {
+            if let Some(body) = physics.rigid_body_set.get(handle) {
+                weighted += super::rapier_to_vec3(&body.center_of_mass().coords) * body.mass();
+                total += body.mass();
+            }
+        }
+        (total > 0.0).then(|| weighted / total)
+    }
+
+    /// Швидкість центру мас скелета (зважена за масою швидкість кісток)
+    pub fn center_of_mass_velocity(&self, physics: &PhysicsWorld) -> Option<Vec3> {
+        let mut weighted = Vec3::ZERO;
+        let mut total = 0.0;
+        for handle in self.bodies_in_order() {
+            if let Some(body) = physics.rigid_body_set.get(handle) {
+                weighted += super::rapier_to_vec3(body.linvel()) * body.mass();
+                total += body.mass();
+            }
+        }
+        (total > 0.0).then(|| weighted / total)
+    }
+
+    /// Верхній кінець кістки (вздовж локальної +Y, world space) - для
+    /// стегна це кульшовий суглоб
+    pub fn bone_start_position(&self, physics: &PhysicsWorld, bone_id: BoneId) -> Option<Vec3> {
+        let position = self.get_bone_position(physics, bone_id)?;
+        let rotation = self.get_bone_rotation(physics, bone_id)?;
+        let half_length = self.bones.get(&bone_id)?.length / 2.0;
+        Some(position + rotation * Vec3::Y * half_length)
+    }
+
+    /// Нижній кінець кістки (вздовж локальної -Y, world space)
+    pub fn bone_end_position(&self, physics: &PhysicsWorld, bone_id: BoneId) -> Option<Vec3> {
+        let position = self.get_bone_position(physics, bone_id)?;
+        let rotation = self.get_bone_rotation(physics, bone_id)?;
+        let half_length = self.bones.get(&bone_id)?.length / 2.0;
+        Some(position - rotation * Vec3::Y * half_length)
+    }
+
+    /// Нижній кінець гомілки (anchor стопи, world space)
+    ///
+    /// `bone_id` - LeftLowerLeg / RightLowerLeg
+    pub fn foot_position(&self, physics: &PhysicsWorld, bone_id: BoneId) -> Option<Vec3> {
+        self.bone_end_position(physics, bone_id)
+    }
+
+    /// Середина між стопами (нижні кінці гомілок, world space)
+    pub fn feet_midpoint(&self, physics: &PhysicsWorld) -> Option<Vec3> {
+        let left = self.foot_position(physics, BoneId::LeftLowerLeg)?;
+        let right = self.foot_position(physics, BoneId::RightLowerLeg)?;
+        Some((left + right) * 0.5)
+    }
+
+    /// Довжина ноги (стегно + гомілка)
+    pub fn leg_length(&self) -> f32 {
+        let length = |bone_id: BoneId| self.bones.get(&bone_id).map_or(0.0, |bone| bone.length);
+        length(BoneId::LeftUpperLeg) + length(BoneId::LeftLowerLeg)
+    }
+
+    /// Висота центру pelvis над землею в стійці (ноги прямі)
+    pub fn standing_pelvis_height(&self) -> f32 {
+        let length = |bone_id: BoneId| self.bones.get(&bone_id).map_or(0.0, |bone| bone.length);
+        length(BoneId::LeftUpperLeg) + length(BoneId::LeftLowerLeg) + length(BoneId::Pelvis) / 2.0
+    }
+
+    /// Розрив суглоба кістки з батьком (world space, м) - відстань між
+    /// anchor'ами на обох тілах; 0 - суглоб зібраний
+    ///
+    /// # Повертає
+    /// None для кістки без суглоба (pelvis)
+    pub fn joint_separation(&self, physics: &PhysicsWorld, bone_id: BoneId) -> Option<f32> {
+        let joint = physics.impulse_joint_set.get(*self.joints.get(&bone_id)?)?;
+        let body1 = physics.rigid_body_set.get(joint.body1)?;
+        let body2 = physics.rigid_body_set.get(joint.body2)?;
+        let anchor1 = body1.position() * joint.data.local_anchor1();
+        let anchor2 = body2.position() * joint.data.local_anchor2();
+        Some(nalgebra::distance(&anchor1, &anchor2))
+    }
+
+    /// Знаходить кістку, якій належить collider
+    ///
+    /// # Повертає
+    /// None якщо collider не належить цьому скелету (земля, стіни, інший ragdoll)
+    pub fn bone_for_collider(&self, physics: &PhysicsWorld, collider: ColliderHandle) -> Option<BoneId> {
+        let body = physics.collider_set.get(collider)?.parent()?;
+        self.bodies
+            .iter()
+            .find(|(_, handle)| **handle == body)
+            .map(|(bone_id, _)| *bone_id)
+    }
+
+    /// Отримує linear velocity кістки
+    pub fn get_bone_linear_velocity(&self, physics: &PhysicsWorld, bone_id: BoneId) -> Option<Vec3> {
+        self.bodies.get(&bone_id)
+            .and_then(|handle| physics.rigid_body_set.get(*handle))
+            .map(|body| {
+                let lv = body.linvel();
+                Vec3::new(lv.x, lv.y, lv.z)
+            })
+    }
+
     /// Отримує angular velocity кістки
     pub fn get_bone_angular_velocity(&self, physics: &PhysicsWorld, bone_id: BoneId) -> Option<Vec3> {
         self.bodies.get(&bone_id)
@@ -887,3 +1269,69 @@ impl Skeleton {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::muscle::{MuscleSystem, TargetPose};
+
+    /// Допуск м'яких impulse joints (радіани)
+    const LIMIT_TOLERANCE: f32 = 0.1;
+
+    /// Найбільший кут swing X кістки відносно батька, поки м'язи тягнуть
+    /// її до `target` (невагомість, 4 секунди)
+    fn extreme_swing_x(bone_id: BoneId, target: f32) -> f32 {
+        let mut physics = PhysicsWorld::new();
+        physics.set_gravity(Vec3::ZERO);
+        let skeleton = Skeleton::create_humanoid(&mut physics, Vec3::new(0.0, 2.0, 0.0), RagdollStiffness::default());
+        let mut muscles = MuscleSystem::create_humanoid(RagdollStiffness::default());
+        let mut pose = TargetPose::standing();
+        pose.bone_rotations.insert(bone_id, Quat::from_rotation_x(target));
+        muscles.set_pose(&pose);
+
+        let parent = bone_id.parent().expect("кістка з батьком");
+        let mut extreme = 0.0f32;
+        for _ in 0..240 {
+            muscles.update(&mut physics, &skeleton);
+            physics.step(physics.fixed_timestep);
+            let relative = skeleton.get_bone_rotation(&physics, parent).expect("батько").inverse()
+                * skeleton.get_bone_rotation(&physics, bone_id).expect("кістка");
+            let (swing_x, _, _) = relative.to_euler(glam::EulerRot::XYZ);
+            if swing_x.abs() > extreme.abs() {
+                extreme = swing_x;
+            }
+        }
+        extreme
+    }
+
+    #[test]
+    fn extreme_pose_stays_within_limits() {
+        // Стегно назад, коліно назад, хребет вперед - далеко за межами
+        let hip = extreme_swing_x(BoneId::RightUpperLeg, 2.5);
+        assert!(hip <= AngleLimits::hip().swing_x_max + LIMIT_TOLERANCE, "стегно: {:.3}", hip);
+        let knee = extreme_swing_x(BoneId::RightLowerLeg, -1.5);
+        assert!(knee >= AngleLimits::knee().swing_x_min - LIMIT_TOLERANCE, "коліно: {:.3}", knee);
+        let spine = extreme_swing_x(BoneId::Spine, 1.5);
+        assert!(spine <= AngleLimits::spine().swing_x_max + LIMIT_TOLERANCE, "хребет: {:.3}", spine);
+    }
+
+    #[test]
+    fn left_side_limits_are_mirrored() {
+        let mut physics = PhysicsWorld::new();
+        let skeleton = Skeleton::create_humanoid(&mut physics, Vec3::new(0.0, 2.0, 0.0), RagdollStiffness::default());
+        let limits = |bone_id: BoneId, axis: JointAxis| {
+            let joint = &physics.impulse_joint_set.get(skeleton.joints[&bone_id]).expect("суглоб").data;
+            let limits = joint.limits(axis).expect("межі");
+            [limits.min, limits.max]
+        };
+
+        let hip = AngleLimits::hip();
+        assert_eq!(limits(BoneId::RightUpperLeg, JointAxis::AngX), [hip.swing_x_min, hip.swing_x_max]);
+        assert_eq!(limits(BoneId::RightUpperLeg, JointAxis::AngZ), [hip.swing_z_min, hip.swing_z_max]);
+        let mirrored = hip.mirrored();
+        assert_eq!(limits(BoneId::LeftUpperLeg, JointAxis::AngX), [hip.swing_x_min, hip.swing_x_max]);
+        assert_eq!(limits(BoneId::LeftUpperLeg, JointAxis::AngZ), [mirrored.swing_z_min, mirrored.swing_z_max]);
+        assert_eq!(limits(BoneId::LeftUpperLeg, JointAxis::AngY), [mirrored.twist_min, mirrored.twist_max]);
+        assert_eq!(mirrored.swing_z_min, -hip.swing_z_max);
+    }
+}
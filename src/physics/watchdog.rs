@@ -0,0 +1,225 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/physics/watchdog.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   RagdollWatchdog - запобіжник від "вибуху" ragdoll.
+
+   Поганий контакт або розрив суглоба інколи розганяє кістку до сотень m/s
+   або дає NaN, і за кадр ragdoll опиняється за межами арени. Watchdog
+   перевіряє кістки після кожного кроку фізики і повертає тіло до
+   останньої нормальної пози замість того, щоб NaN дійшов до рендеру.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Перевірка кісток після кроку: NaN / нескінченність, відстань від
+     центру арени > max_distance, швидкість > max_speed
+   - Порушення → лог (кістка, причина, крок) і обмеження швидкостей
+   - Порушення reset_steps кроків поспіль (або NaN позиції) → поза з
+     історії (найстаріша, ~HISTORY_SECONDS тому)
+   - Історія: кільцевий буфер поз кроків без порушень
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Імпортує:
+   - skeleton.rs - Skeleton::set_pose / clamp_velocities, BoneId
+   - settings.rs - секція [physics]
+
+   Експортує для:
+   - ragdoll.rs - ActiveRagdoll::check_watchdog (гравець і вороги)
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+   1. check() викликати ПІСЛЯ physics.step, ДО snapshot_transforms -
+      інакше NaN потрапить в інтерполяцію рендеру
+   2. Поза з історії - найстаріша: найновіша могла вже "розганятись"
+   3. Історії немає (перші кроки, щойно reset) - A-pose на root_position
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - watchdog вибуху ragdoll
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use std::collections::VecDeque;
+
+use glam::{Quat, Vec3};
+
+use super::{BoneId, PhysicsWorld, Skeleton};
+use crate::debug_log::log_debug;
+use crate::settings::Settings;
+
+/// Скільки секунд поз зберігається в історії
+const HISTORY_SECONDS: f32 = 1.0;
+
+/// Максимальна кутова швидкість кістки при обмеженні (rad/s)
+const MAX_ANGULAR_SPEED: f32 = 50.0;
+
+/// Результат перевірки кроку
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchdogOutcome {
+    /// Все в межах
+    Ok,
+    /// Порушення - швидкості обмежено
+    Clamped,
+    /// Ragdoll повернуто до пози з історії
+    Restored,
+}
+
+/// Причина порушення
+#[derive(Debug, Clone, Copy)]
+enum Violation {
+    /// Позиція або ротація - NaN / нескінченність
+    NonFinite,
+    /// Кістка далі max_distance від центру арени (метри)
+    OutOfBounds(f32),
+    /// Швидкість більша за max_speed (m/s)
+    TooFast(f32),
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Violation::NonFinite => write!(f, "NaN / нескінченність"),
+            Violation::OutOfBounds(distance) => write!(f, "{:.1} м від центру арени", distance),
+            Violation::TooFast(speed) => write!(f, "швидкість {:.1} m/s", speed),
+        }
+    }
+}
+
+/// Запобіжник від вибуху фізики ragdoll
+#[derive(Debug, Clone)]
+pub struct RagdollWatchdog {
+    /// Максимальна відстань кістки від центру арени (метри)
+    pub max_distance: f32,
+
+    /// Максимальна швидкість кістки (m/s)
+    pub max_speed: f32,
+
+    /// Скільки кроків поспіль порушення до повернення пози
+    pub reset_steps: u32,
+
+    /// Кроків поспіль з порушенням
+    violation_steps: u32,
+
+    /// Кроків, перевірених з початку (для логу)
+    step: u64,
+
+    /// Пози кроків без порушень (найстаріша - спереду)
+    history: VecDeque<Vec<(BoneId, Vec3, Quat)>>,
+}
+
+impl Default for RagdollWatchdog {
+    fn default() -> Self {
+        Self {
+            max_distance: 200.0,
+            max_speed: 100.0,
+            reset_steps: 10,
+            violation_steps: 0,
+            step: 0,
+            history: VecDeque::new(),
+        }
+    }
+}
+
+impl RagdollWatchdog {
+    /// Читає секцію [physics] з налаштувань
+    ///
+    /// Ключі: `watchdog_bound` (метри), `watchdog_max_speed` (m/s),
+    /// `watchdog_reset_steps`
+    pub fn from_settings(settings: &Settings) -> Self {
+        let default = Self::default();
+        Self {
+            max_distance: settings.get_f32("physics.watchdog_bound", default.max_distance).max(1.0),
+            max_speed: settings.get_f32("physics.watchdog_max_speed", default.max_speed).max(1.0),
+            reset_steps: settings.get_u32("physics.watchdog_reset_steps", default.reset_steps).max(1),
+            ..default
+        }
+    }
+
+    /// Перевіряє скелет після кроку фізики
+    ///
+    /// Викликати ПІСЛЯ physics.step, ДО snapshot_transforms
+    pub fn check(&mut self, physics: &mut PhysicsWorld, skeleton: &mut Skeleton) -> WatchdogOutcome {
+        self.step += 1;
+
+        let Some((bone_id, violation)) = self.find_violation(physics, skeleton) else {
+            self.violation_steps = 0;
+            self.record_pose(physics, skeleton);
+            return WatchdogOutcome::Ok;
+        };
+
+        self.violation_steps += 1;
+        log_debug(&format!(
+            "WATCHDOG: {:?} - {} (крок {}, {} поспіль)",
+            bone_id, violation, self.step, self.violation_steps
+        ));
+
+        // NaN / нескінченність - стан тіла вже зіпсований, лише повернення пози
+        if matches!(violation, Violation::NonFinite) || self.violation_steps >= self.reset_steps {
+            self.restore(physics, skeleton);
+            return WatchdogOutcome::Restored;
+        }
+
+        skeleton.clamp_velocities(physics, self.max_speed, MAX_ANGULAR_SPEED);
+        WatchdogOutcome::Clamped
+    }
+
+    /// Забуває історію (ragdoll переставлено - reset / рестарт)
+    pub fn clear(&mut self) {
+        self.history.clear();
+        self.violation_steps = 0;
+    }
+
+    /// Перше порушення серед кісток (і меча)
+    fn find_violation(&self, physics: &PhysicsWorld, skeleton: &Skeleton) -> Option<(BoneId, Violation)> {
+        BoneId::all_bones()
+            .into_iter()
+            .chain(BoneId::attachments())
+            .find_map(|bone_id| {
+                let position = skeleton.get_bone_position(physics, bone_id)?;
+                let rotation = skeleton.get_bone_rotation(physics, bone_id)?;
+                let velocity = skeleton.get_bone_linear_velocity(physics, bone_id)?;
+                let violation = if !position.is_finite() || !rotation.is_finite() || !velocity.is_finite() {
+                    Violation::NonFinite
+                } else if position.length() > self.max_distance {
+                    Violation::OutOfBounds(position.length())
+                } else if velocity.length() > self.max_speed {
+                    Violation::TooFast(velocity.length())
+                } else {
+                    return None;
+                };
+                Some((bone_id, violation))
+            })
+    }
+
+    /// Додає позу кроку без порушень в історію
+    fn record_pose(&mut self, physics: &PhysicsWorld, skeleton: &Skeleton) {
+        let capacity = (HISTORY_SECONDS / physics.fixed_timestep).ceil().max(1.0) as usize;
+        while self.history.len() >= capacity {
+            self.history.pop_front();
+        }
+        let pose = BoneId::all_bones()
+            .into_iter()
+            .chain(BoneId::attachments())
+            .filter_map(|bone_id| {
+                let position = skeleton.get_bone_position(physics, bone_id)?;
+                let rotation = skeleton.get_bone_rotation(physics, bone_id)?;
+                Some((bone_id, position, rotation))
+            })
+            .collect();
+        self.history.push_back(pose);
+    }
+
+    /// Повертає скелет до найстарішої пози з історії (немає - A-pose)
+    fn restore(&mut self, physics: &mut PhysicsWorld, skeleton: &mut Skeleton) {
+        match self.history.front() {
+            Some(pose) => skeleton.set_pose(physics, pose),
+            None => skeleton.reset_pose(physics, skeleton.root_position),
+        }
+        log_debug(&format!(
+            "WATCHDOG: поза відновлена (крок {}, історія {} кроків)",
+            self.step,
+            self.history.len()
+        ));
+        self.violation_steps = 0;
+    }
+}
@@ -0,0 +1,103 @@
+/*
+===============================================================================
+ ФАЙЛ: src/player/health.rs
+===============================================================================
+
+📋 ПРИЗНАЧЕННЯ:
+  Health - здоров'я з вікном невразливості після удару.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+  - current / max здоров'я
+  - Вікно невразливості (i-frames) після кожного отриманого удару
+  - Reset для рестарту
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+  - Hitbox ворога живе кілька кадрів і перекриває гравця весь цей час -
+    без невразливості шкода застосовувалась би кожен кадр
+  - update(delta) викликати раз на кадр (відлік невразливості)
+  - current = 0 → мертвий, подальша шкода ігнорується
+
+🕐 ІСТОРІЯ:
+  2026-10-16: Створено - здоров'я гравця з невразливістю після удару
+
+===============================================================================
+*/
+
+/// Тривалість невразливості після удару за замовчуванням (секунди)
+pub const DEFAULT_INVULNERABILITY: f32 = 0.5;
+
+/// Здоров'я
+#[derive(Debug, Clone, Copy)]
+pub struct Health {
+    /// Поточне здоров'я
+    pub current: f32,
+
+    /// Максимальне здоров'я
+    pub max: f32,
+
+    /// Тривалість невразливості після удару (секунди)
+    pub invulnerability_duration: f32,
+
+    /// Невразливість що залишилась (секунди)
+    invulnerability_timer: f32,
+}
+
+impl Health {
+    /// Створює повне здоров'я
+    pub fn new(max: f32) -> Self {
+        Self {
+            current: max,
+            max,
+            invulnerability_duration: DEFAULT_INVULNERABILITY,
+            invulnerability_timer: 0.0,
+        }
+    }
+
+    /// Чи живий
+    pub fn is_alive(&self) -> bool {
+        self.current > 0.0
+    }
+
+    /// Чи діє невразливість після удару
+    pub fn is_invulnerable(&self) -> bool {
+        self.invulnerability_timer > 0.0
+    }
+
+    /// Частка здоров'я (0..1)
+    pub fn fraction(&self) -> f32 {
+        if self.max <= 0.0 {
+            return 0.0;
+        }
+        (self.current / self.max).clamp(0.0, 1.0)
+    }
+
+    /// Завдає шкоди (якщо живий і не невразливий)
+    ///
+    /// # Повертає
+    /// `true` якщо шкоду застосовано
+    pub fn take_damage(&mut self, damage: f32) -> bool {
+        if !self.is_alive() || self.is_invulnerable() {
+            return false;
+        }
+        self.current = (self.current - damage).max(0.0);
+        self.invulnerability_timer = self.invulnerability_duration;
+        true
+    }
+
+    /// Оновлює таймер невразливості
+    pub fn update(&mut self, delta: f32) {
+        self.invulnerability_timer = (self.invulnerability_timer - delta).max(0.0);
+    }
+
+    /// Відновлює повне здоров'я (рестарт)
+    pub fn reset(&mut self) {
+        self.current = self.max;
+        self.invulnerability_timer = 0.0;
+    }
+}
+
+impl Default for Health {
+    fn default() -> Self {
+        Self::new(100.0)
+    }
+}
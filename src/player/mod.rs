@@ -10,6 +10,7 @@
   - Player struct (позиція, напрямок, швидкість)
   - Player movement logic
   - Player mesh (манекен з примітивів)
+  - Health (здоров'я з невразливістю після удару)
 
 🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
   Імпортує:
@@ -26,10 +27,14 @@
 
 🕐 ІСТОРІЯ:
   2025-12-14: Створено - базовий Player з позицією та рухом
+  2026-10-16: Health компонент (health.rs)
 
 ===============================================================================
 */
 
+#[allow(clippy::module_inception)]
 mod player;
+mod health;
 
-pub use player::Player;
+pub use player::{Player, camera_yaw_to_player_yaw};
+pub use health::Health;
@@ -11,21 +11,46 @@
   - Facing direction (yaw angle)
   - Movement logic (WASD input → position change)
   - Movement speed
+  - Health (шкода від атак ворогів, невразливість після удару)
 
 ⚠️  ВАЖЛИВІ ДЕТАЛІ:
   - Position: Vec3 в world space (Y-up)
   - Yaw: кут повороту навколо Y (0 = дивиться в -Z, як камера)
   - Movement speed: units/second (використовуйте delta time!)
   - Player рухається по XZ plane (Y = const для наземного руху)
+  - Crouch (Ctrl): crouch_blend плавно 0 → 1, опускає камеру та висоту атаки
 
 🕐 ІСТОРІЯ:
   2025-12-14: Створено - базовий Player з позицією та рухом
+  2026-10-16: Crouch - висота камери та атаки
+  2026-10-16: smooth_rotate через спільний transform::rotate_towards
+  2026-10-16: Health - вороги можуть завдавати шкоди
+  2026-10-16: Health компонент з невразливістю замість полів health/max_health
+  2026-10-16: eye_height - камера від першої особи
 
 ===============================================================================
 */
 
 use glam::Vec3;
 
+use super::Health;
+use crate::transform::{wrap_angle, rotate_towards};
+
+/// Висота точки погляду камери стоячи (груди гравця)
+pub const STANDING_CAMERA_HEIGHT: f32 = 1.2;
+
+/// Висота точки погляду камери присівши
+pub const CROUCHING_CAMERA_HEIGHT: f32 = 0.75;
+
+/// Очі вище точки погляду third person камери (центр голови mannequin)
+const EYE_HEIGHT_ABOVE_CAMERA: f32 = 0.35;
+
+/// Множник висоти атаки присівши (відносно висоти плеча стоячи)
+pub const CROUCH_ATTACK_HEIGHT_SCALE: f32 = 0.5;
+
+/// Швидкість переходу між стійками (1/секунду)
+const CROUCH_BLEND_SPEED: f32 = 8.0;
+
 /// Player - гравець з позицією та рухом
 ///
 /// Гравець має позицію в world space та facing direction (yaw).
@@ -49,6 +74,15 @@ pub struct Player {
 
     /// Чи персонаж зараз рухається
     pub is_moving: bool,
+
+    /// Чи гравець присів (утримує Ctrl)
+    pub is_crouching: bool,
+
+    /// Плавний перехід стійки (0.0 = стоїть, 1.0 = присів)
+    pub crouch_blend: f32,
+
+    /// Здоров'я (з невразливістю після удару)
+    pub health: Health,
 }
 
 impl Player {
@@ -64,9 +98,25 @@ impl Player {
             move_speed: 5.0,   // 5 units/second
             turn_speed: 10.0,  // швидке плавне обертання
             is_moving: false,
+            is_crouching: false,
+            crouch_blend: 0.0,
+            health: Health::new(100.0),
         }
     }
 
+    /// Чи гравець живий
+    pub fn is_alive(&self) -> bool {
+        self.health.is_alive()
+    }
+
+    /// Завдає шкоди гравцю (ігнорується під час невразливості)
+    ///
+    /// # Повертає
+    /// `true` якщо шкоду застосовано
+    pub fn take_damage(&mut self, damage: f32) -> bool {
+        self.health.take_damage(damage)
+    }
+
     /// Повертає forward vector (напрямок куди дивиться гравець)
     ///
     /// Forward = -Z при yaw=0, обертається навколо Y
@@ -158,46 +208,41 @@ impl Player {
     /// # Аргументи
     /// * `delta` - Delta time в секундах
     pub fn smooth_rotate(&mut self, delta: f32) {
-        // Обчислюємо найкоротшу різницю кутів
-        let mut diff = self.target_yaw - self.yaw;
+        // Найкоротшим шляхом, не швидше за turn_speed
+        self.yaw = rotate_towards(self.yaw, self.target_yaw, self.turn_speed * delta);
+    }
 
-        // Нормалізуємо до [-PI, PI] для найкоротшого шляху
-        while diff > std::f32::consts::PI {
-            diff -= std::f32::consts::TAU;
-        }
-        while diff < -std::f32::consts::PI {
-            diff += std::f32::consts::TAU;
-        }
+    /// Оновлює стійку (crouch) з плавним переходом
+    ///
+    /// # Аргументи
+    /// * `crouching` - Чи утримується клавіша crouch
+    /// * `delta` - Delta time в секундах
+    pub fn update_crouch(&mut self, crouching: bool, delta: f32) {
+        self.is_crouching = crouching;
+        let target = if crouching { 1.0 } else { 0.0 };
+        let step = CROUCH_BLEND_SPEED * delta;
+        self.crouch_blend += (target - self.crouch_blend).clamp(-step, step);
+    }
 
-        // Плавне обертання
-        let max_rotation = self.turn_speed * delta;
-        if diff.abs() <= max_rotation {
-            // Достатньо близько - завершуємо
-            self.yaw = self.target_yaw;
-        } else {
-            // Обертаємось у напрямку target
-            self.yaw += diff.signum() * max_rotation;
-        }
+    /// Висота точки погляду камери (з урахуванням crouch)
+    pub fn camera_height(&self) -> f32 {
+        STANDING_CAMERA_HEIGHT + (CROUCHING_CAMERA_HEIGHT - STANDING_CAMERA_HEIGHT) * self.crouch_blend
+    }
 
-        // Нормалізуємо yaw
-        self.normalize_yaw();
+    /// Висота очей для камери від першої особи (з урахуванням crouch)
+    pub fn eye_height(&self) -> f32 {
+        self.camera_height() + EYE_HEIGHT_ABOVE_CAMERA
     }
 
-    /// Нормалізує yaw до [-PI, PI]
-    fn normalize_yaw(&mut self) {
-        while self.yaw > std::f32::consts::PI {
-            self.yaw -= std::f32::consts::TAU;
-        }
-        while self.yaw < -std::f32::consts::PI {
-            self.yaw += std::f32::consts::TAU;
-        }
+    /// Множник висоти атаки (1.0 стоячи, CROUCH_ATTACK_HEIGHT_SCALE присівши)
+    pub fn attack_height_scale(&self) -> f32 {
+        1.0 + (CROUCH_ATTACK_HEIGHT_SCALE - 1.0) * self.crouch_blend
     }
 
     /// Встановлює yaw напряму (для синхронізації з камерою коли не рухаємось)
     pub fn set_yaw(&mut self, yaw: f32) {
-        self.yaw = yaw;
-        self.target_yaw = yaw;
-        self.normalize_yaw();
+        self.yaw = wrap_angle(yaw);
+        self.target_yaw = self.yaw;
     }
 }
 
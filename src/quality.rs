@@ -0,0 +1,241 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/quality.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   QualityController - адаптивна якість за часом кадру (FpsCounter).
+
+   Коли frame time стабільно вище цілі (більше ворогів / ragdolls) -
+   якість знижується на один рівень. Коли ціль стабільно тримається -
+   якість повертається вгору. Мета - стабільні 60 FPS без ручних налаштувань.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - QualityTier: Low / Medium / High та параметри кожного рівня
+   - Гістерезис: зниження швидке (1 с), підвищення повільне (10 с+)
+   - Backoff: після кожного зниження пауза до підвищення подвоюється
+     (проти коливань High ↔ Medium на межі)
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Імпортує:
+   - fps_counter.rs - усереднений frame time
+   - settings.rs - секція [quality]
+
+   Експортує для:
+   - main.rs - застосування рівня до renderer та ParticleSystem
+
+⚠️  ВАЖЛИВІ ОБМЕЖЕННЯ:
+   1. З VSync (PresentMode::Fifo) frame time не буває нижче 1/refresh -
+      "запас" не видно, тому підвищення = ціль тримається довго
+   2. Рівень керує тим, що вже є в renderer: сегменти капсул скелета
+      та ліміт частинок. Render scale та MSAA в renderer поки немає.
+   3. Після зміни рівня FpsCounter ще містить старі кадри - наступне
+      рішення не раніше ніж через DOWNGRADE_DELAY
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - адаптивна якість за frame time
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use crate::fps_counter::FpsCounter;
+use crate::settings::Settings;
+
+/// Скільки секунд frame time має бути вище цілі для зниження якості
+const DOWNGRADE_DELAY: f32 = 1.0;
+
+/// Базова пауза (секунди) стабільного frame time перед підвищенням якості
+const UPGRADE_DELAY: f32 = 10.0;
+
+/// Максимальна пауза перед підвищенням (після багатьох backoff)
+const MAX_UPGRADE_DELAY: f32 = 120.0;
+
+/// Допуск над ціллю (1.15 = на 15% повільніше - вже "не тримаємо")
+const OVER_BUDGET_RATIO: f32 = 1.15;
+
+/// Допуск "тримаємо ціль" (VSync дає трохи більше за 1/refresh)
+const ON_BUDGET_RATIO: f32 = 1.05;
+
+/// Рівень якості
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum QualityTier {
+    Low,
+    Medium,
+    High,
+}
+
+impl QualityTier {
+    /// Сегменти капсул скелета по колу
+    pub fn capsule_segments(self) -> u32 {
+        match self {
+            Self::Low => 6,
+            Self::Medium => 8,
+            Self::High => 12,
+        }
+    }
+
+    /// Ліміт живих частинок
+    pub fn max_particles(self) -> usize {
+        match self {
+            Self::Low => 64,
+            Self::Medium => 128,
+            Self::High => 256,
+        }
+    }
+
+    /// Рівень нижче (None якщо вже Low)
+    pub fn lower(self) -> Option<Self> {
+        match self {
+            Self::Low => None,
+            Self::Medium => Some(Self::Low),
+            Self::High => Some(Self::Medium),
+        }
+    }
+
+    /// Рівень вище (None якщо вже High)
+    pub fn higher(self) -> Option<Self> {
+        match self {
+            Self::Low => Some(Self::Medium),
+            Self::Medium => Some(Self::High),
+            Self::High => None,
+        }
+    }
+
+    /// Назва рівня (для заголовку вікна та логів)
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+        }
+    }
+
+    /// Парсить назву рівня ("low" / "medium" / "high")
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "low" => Some(Self::Low),
+            "medium" => Some(Self::Medium),
+            "high" => Some(Self::High),
+            _ => None,
+        }
+    }
+}
+
+/// Контролер адаптивної якості
+pub struct QualityController {
+    /// Поточний рівень
+    tier: QualityTier,
+
+    /// Автоматичне керування (false = рівень фіксований)
+    pub adaptive: bool,
+
+    /// Цільовий час кадру (мс)
+    pub target_frame_ms: f32,
+
+    /// Скільки часу поспіль frame time вище цілі
+    over_budget_time: f32,
+
+    /// Скільки часу поспіль ціль тримається
+    on_budget_time: f32,
+
+    /// Поточна пауза перед підвищенням (росте після кожного зниження)
+    upgrade_delay: f32,
+}
+
+impl QualityController {
+    /// Створює контролер
+    ///
+    /// # Аргументи
+    /// * `tier` - Початковий рівень
+    /// * `target_fps` - Цільовий FPS
+    pub fn new(tier: QualityTier, target_fps: f32) -> Self {
+        Self {
+            tier,
+            adaptive: true,
+            target_frame_ms: 1000.0 / target_fps.max(1.0),
+            over_budget_time: 0.0,
+            on_budget_time: 0.0,
+            upgrade_delay: UPGRADE_DELAY,
+        }
+    }
+
+    /// Читає секцію [quality] з налаштувань
+    ///
+    /// Ключі: `tier` (low / medium / high), `adaptive`, `target_fps`
+    pub fn from_settings(settings: &Settings) -> Self {
+        let tier = settings
+            .get_str("quality.tier")
+            .map(|name| {
+                QualityTier::from_name(name).unwrap_or_else(|| {
+                    log::warn!("quality.tier: невідомий рівень {:?}, використовуємо high", name);
+                    QualityTier::High
+                })
+            })
+            .unwrap_or(QualityTier::High);
+
+        let mut controller = Self::new(tier, settings.get_f32("quality.target_fps", 60.0));
+        controller.adaptive = settings.get_bool("quality.adaptive", true);
+        controller
+    }
+
+    /// Поточний рівень якості
+    pub fn tier(&self) -> QualityTier {
+        self.tier
+    }
+
+    /// Оновлює контролер (раз на кадр)
+    ///
+    /// # Аргументи
+    /// * `fps` - Лічильник FPS (усереднений frame time)
+    /// * `delta` - Час кадру (секунди)
+    ///
+    /// # Повертає
+    /// Новий рівень, якщо він змінився в цьому кадрі
+    pub fn update(&mut self, fps: &FpsCounter, delta: f32) -> Option<QualityTier> {
+        if !self.adaptive {
+            return None;
+        }
+
+        let frame_ms = fps.frame_time_ms();
+        if frame_ms > self.target_frame_ms * OVER_BUDGET_RATIO {
+            self.over_budget_time += delta;
+            self.on_budget_time = 0.0;
+        } else if frame_ms <= self.target_frame_ms * ON_BUDGET_RATIO {
+            self.on_budget_time += delta;
+            self.over_budget_time = 0.0;
+        } else {
+            // Між порогами - нічого не накопичуємо
+            self.over_budget_time = 0.0;
+            self.on_budget_time = 0.0;
+        }
+
+        if self.over_budget_time >= DOWNGRADE_DELAY {
+            let lower = self.tier.lower();
+            self.over_budget_time = 0.0;
+            if let Some(lower) = lower {
+                self.upgrade_delay = (self.upgrade_delay * 2.0).min(MAX_UPGRADE_DELAY);
+                return Some(self.set_tier(lower, frame_ms));
+            }
+        } else if self.on_budget_time >= self.upgrade_delay {
+            let higher = self.tier.higher();
+            self.on_budget_time = 0.0;
+            if let Some(higher) = higher {
+                return Some(self.set_tier(higher, frame_ms));
+            }
+        }
+
+        None
+    }
+
+    /// Встановлює рівень та скидає лічильники
+    fn set_tier(&mut self, tier: QualityTier, frame_ms: f32) -> QualityTier {
+        log::info!(
+            "Quality: {:?} → {:?} (frame time {:.1} ms, ціль {:.1} ms)",
+            self.tier, tier, frame_ms, self.target_frame_ms
+        );
+        self.tier = tier;
+        self.over_budget_time = 0.0;
+        self.on_budget_time = 0.0;
+        tier
+    }
+}
@@ -0,0 +1,562 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/rendering/debug_hud.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   DebugHud - текстовий оверлей поверх кадру (FPS, фізика, стан бою).
+
+   Рядки задаються за ключем (set_line) і малюються стовпчиком у лівому
+   верхньому куті на напівпрозорій панелі вбудованим bitmap шрифтом 5×7.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Атлас шрифту (ASCII 32..=126) генерується при старті, без файлів
+   - Вершини гліфів кешуються: перебудова лише коли змінився текст
+   - Власна ортографічна проєкція в пікселях вікна (set_screen_size)
+   - Окремий render pass поверх готового кадру (після resolve MSAA)
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Експортує для:
+   - renderer.rs - WgpuRenderer::debug_hud, render_overlay після render_scene
+   - main.rs - set_line щокадру, Action::DebugHud (F3) перемикає visible
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+   - Лише ASCII: інші символи малюються як '?'
+   - Pipeline без depth і без MSAA (sample_count 1) - малює одразу в
+     swapchain / screenshot texture
+   - Прихований HUD не перебудовує вершини (dirty чекає до показу)
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - debug HUD з bitmap шрифтом
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use wgpu::util::DeviceExt;
+
+/// Ширина / висота гліфа (пікселі шрифту)
+const GLYPH_WIDTH: u32 = 5;
+const GLYPH_HEIGHT: u32 = 7;
+
+/// Перший символ атласу (' ') та кількість гліфів (до '~' включно)
+const FIRST_CHAR: u32 = 32;
+const GLYPH_COUNT: u32 = 95;
+
+/// Атлас: 16 клітинок у рядку; клітинка GLYPH_COUNT - суцільна (фон панелі)
+const ATLAS_COLUMNS: u32 = 16;
+const ATLAS_ROWS: u32 = (GLYPH_COUNT + 1).div_ceil(ATLAS_COLUMNS);
+const SOLID_CELL: u32 = GLYPH_COUNT;
+
+/// Екранних пікселів на піксель шрифту
+const HUD_SCALE: f32 = 2.0;
+
+/// Крок символу та рядка (пікселі шрифту)
+const CHAR_ADVANCE: f32 = 6.0;
+const LINE_HEIGHT: f32 = 9.0;
+
+/// Відступ панелі від краю вікна (екранні пікселі) та тексту від краю панелі (пікселі шрифту)
+const HUD_MARGIN: f32 = 8.0;
+const PANEL_PADDING: f32 = 2.0;
+
+/// Колір тексту та фону панелі (лінійний RGBA)
+const TEXT_COLOR: [f32; 4] = [0.9, 0.95, 1.0, 1.0];
+const PANEL_COLOR: [f32; 4] = [0.0, 0.0, 0.0, 0.55];
+
+/// Вершина HUD (пікселі екрану)
+#[repr(C)]
+#[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+struct HudVertex {
+    position: [f32; 2],
+    uv: [f32; 2],
+    color: [f32; 4],
+}
+
+impl HudVertex {
+    const ATTRIBUTES: [wgpu::VertexAttribute; 3] =
+        wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x2, 2 => Float32x4];
+
+    fn desc() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<HudVertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &Self::ATTRIBUTES,
+        }
+    }
+}
+
+/// Uniform HUD (debug_hud.wgsl)
+#[repr(C)]
+#[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+struct HudUniform {
+    projection: [[f32; 4]; 4],
+}
+
+/// Текстовий debug оверлей
+pub struct DebugHud {
+    /// Чи малювати HUD (Action::DebugHud)
+    pub visible: bool,
+
+    /// (ключ, текст) у порядку першого set_line
+    lines: Vec<(String, String)>,
+
+    /// Текст змінився - вершини треба перебудувати
+    dirty: bool,
+
+    vertex_buffer: wgpu::Buffer,
+    /// Місткість vertex_buffer (вершини)
+    vertex_capacity: usize,
+    vertex_count: u32,
+
+    uniform_buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+    pipeline: wgpu::RenderPipeline,
+}
+
+impl DebugHud {
+    pub fn new(device: &wgpu::Device, queue: &wgpu::Queue, config: &wgpu::SurfaceConfiguration) -> Self {
+        let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Debug HUD Uniform Buffer"),
+            contents: bytemuck::cast_slice(&[HudUniform {
+                projection: screen_projection(config.width, config.height).to_cols_array_2d(),
+            }]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        // Атлас шрифту: R8 coverage, nearest - пікселі шрифту лишаються чіткими
+        let size = wgpu::Extent3d {
+            width: ATLAS_COLUMNS * GLYPH_WIDTH,
+            height: ATLAS_ROWS * GLYPH_HEIGHT,
+            depth_or_array_layers: 1,
+        };
+        let font_texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Debug HUD Font Atlas"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::R8Unorm,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture: &font_texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            &build_font_atlas(),
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(size.width),
+                rows_per_image: Some(size.height),
+            },
+            size,
+        );
+        let font_view = font_texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let font_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("debug_hud_font_sampler"),
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        multisampled: false,
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+            label: Some("debug_hud_bind_group_layout"),
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout: &bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: uniform_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&font_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Sampler(&font_sampler),
+                },
+            ],
+            label: Some("debug_hud_bind_group"),
+        });
+
+        let pipeline = Self::create_pipeline(device, config, &bind_group_layout);
+
+        // Місткість на старті - ~8 рядків по 32 символи; росте в prepare
+        let vertex_capacity = 6 * 256;
+        let vertex_buffer = Self::create_vertex_buffer(device, vertex_capacity);
+
+        Self {
+            visible: false,
+            lines: Vec::new(),
+            dirty: false,
+            vertex_buffer,
+            vertex_capacity,
+            vertex_count: 0,
+            uniform_buffer,
+            bind_group,
+            pipeline,
+        }
+    }
+
+    fn create_pipeline(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        bind_group_layout: &wgpu::BindGroupLayout,
+    ) -> wgpu::RenderPipeline {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Debug HUD Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../../assets/shaders/debug_hud.wgsl").into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Debug HUD Pipeline Layout"),
+            bind_group_layouts: &[bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Debug HUD Render Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[HudVertex::desc()],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: config.format,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            // Оверлей: без depth, поверх усього кадру
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        })
+    }
+
+    fn create_vertex_buffer(device: &wgpu::Device, capacity: usize) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Debug HUD Vertex Buffer"),
+            size: (capacity * std::mem::size_of::<HudVertex>()) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        })
+    }
+
+    /// Задає текст рядка `key`; новий ключ додається в кінець
+    ///
+    /// Вершини перебудовуються лише якщо текст справді змінився.
+    pub fn set_line(&mut self, key: &str, text: impl Into<String>) {
+        let text = text.into();
+        match self.lines.iter_mut().find(|(k, _)| k == key) {
+            Some((_, current)) if *current == text => {}
+            Some((_, current)) => {
+                *current = text;
+                self.dirty = true;
+            }
+            None => {
+                self.lines.push((key.to_string(), text));
+                self.dirty = true;
+            }
+        }
+    }
+
+    /// Прибирає рядок `key` (якщо є)
+    #[allow(dead_code)]
+    pub fn remove_line(&mut self, key: &str) {
+        let len = self.lines.len();
+        self.lines.retain(|(k, _)| k != key);
+        self.dirty |= self.lines.len() != len;
+    }
+
+    /// Оновлює проєкцію під новий розмір вікна
+    pub fn set_screen_size(&self, queue: &wgpu::Queue, width: u32, height: u32) {
+        let uniform = HudUniform {
+            projection: screen_projection(width, height).to_cols_array_2d(),
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::cast_slice(&[uniform]));
+    }
+
+    /// Перебудовує вершини, якщо текст змінився (раз на кадр, до render)
+    pub fn prepare(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        if !self.visible || !self.dirty {
+            return;
+        }
+        self.dirty = false;
+
+        let vertices = self.build_vertices();
+        self.vertex_count = vertices.len() as u32;
+        if vertices.is_empty() {
+            return;
+        }
+        if vertices.len() > self.vertex_capacity {
+            self.vertex_capacity = vertices.len().next_power_of_two();
+            self.vertex_buffer = Self::create_vertex_buffer(device, self.vertex_capacity);
+        }
+        queue.write_buffer(&self.vertex_buffer, 0, bytemuck::cast_slice(&vertices));
+    }
+
+    /// Панель + гліфи всіх рядків
+    fn build_vertices(&self) -> Vec<HudVertex> {
+        let mut vertices = Vec::new();
+        if self.lines.is_empty() {
+            return vertices;
+        }
+
+        let columns = self.lines.iter().map(|(_, text)| text.chars().count()).max().unwrap_or(0);
+        let panel_width = (columns as f32 * CHAR_ADVANCE - 1.0).max(0.0) + 2.0 * PANEL_PADDING;
+        let panel_height = self.lines.len() as f32 * LINE_HEIGHT - 2.0 + 2.0 * PANEL_PADDING;
+        push_quad(
+            &mut vertices,
+            [HUD_MARGIN, HUD_MARGIN],
+            [panel_width * HUD_SCALE, panel_height * HUD_SCALE],
+            cell_uv(SOLID_CELL),
+            PANEL_COLOR,
+        );
+
+        let origin = HUD_MARGIN + PANEL_PADDING * HUD_SCALE;
+        for (row, (_, text)) in self.lines.iter().enumerate() {
+            let y = origin + row as f32 * LINE_HEIGHT * HUD_SCALE;
+            for (column, ch) in text.chars().enumerate() {
+                if ch == ' ' {
+                    continue;
+                }
+                let code = ch as u32;
+                let glyph = if (FIRST_CHAR..FIRST_CHAR + GLYPH_COUNT).contains(&code) { code } else { '?' as u32 };
+                push_quad(
+                    &mut vertices,
+                    [origin + column as f32 * CHAR_ADVANCE * HUD_SCALE, y],
+                    [GLYPH_WIDTH as f32 * HUD_SCALE, GLYPH_HEIGHT as f32 * HUD_SCALE],
+                    cell_uv(glyph - FIRST_CHAR),
+                    TEXT_COLOR,
+                );
+            }
+        }
+        vertices
+    }
+
+    /// Малює HUD поверх `target_view` (окремий pass, вміст кадру зберігається)
+    pub fn render(&self, encoder: &mut wgpu::CommandEncoder, target_view: &wgpu::TextureView) {
+        if !self.visible || self.vertex_count == 0 {
+            return;
+        }
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Debug HUD Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: target_view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            occlusion_query_set: None,
+            timestamp_writes: None,
+        });
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(0, &self.bind_group, &[]);
+        render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        render_pass.draw(0..self.vertex_count, 0..1);
+    }
+}
+
+/// Ортографічна проєкція: (0, 0) - лівий верхній кут, (width, height) - правий нижній
+fn screen_projection(width: u32, height: u32) -> glam::Mat4 {
+    glam::Mat4::orthographic_rh(0.0, width.max(1) as f32, height.max(1) as f32, 0.0, -1.0, 1.0)
+}
+
+/// UV прямокутник клітинки атласу: [u0, v0, u1, v1]
+fn cell_uv(cell: u32) -> [f32; 4] {
+    let u = (cell % ATLAS_COLUMNS) as f32 / ATLAS_COLUMNS as f32;
+    let v = (cell / ATLAS_COLUMNS) as f32 / ATLAS_ROWS as f32;
+    [u, v, u + 1.0 / ATLAS_COLUMNS as f32, v + 1.0 / ATLAS_ROWS as f32]
+}
+
+/// Два трикутники прямокутника (position - лівий верхній кут)
+fn push_quad(vertices: &mut Vec<HudVertex>, position: [f32; 2], size: [f32; 2], uv: [f32; 4], color: [f32; 4]) {
+    let [x0, y0] = position;
+    let (x1, y1) = (x0 + size[0], y0 + size[1]);
+    let [u0, v0, u1, v1] = uv;
+    let corner = |x, y, u, v| HudVertex { position: [x, y], uv: [u, v], color };
+    vertices.extend_from_slice(&[
+        corner(x0, y0, u0, v0),
+        corner(x0, y1, u0, v1),
+        corner(x1, y1, u1, v1),
+        corner(x0, y0, u0, v0),
+        corner(x1, y1, u1, v1),
+        corner(x1, y0, u1, v0),
+    ]);
+}
+
+/// Пікселі атласу (R8, рядок за рядком): гліфи FONT_5X7 + суцільна клітинка
+fn build_font_atlas() -> Vec<u8> {
+    let width = ATLAS_COLUMNS * GLYPH_WIDTH;
+    let mut pixels = vec![0u8; (width * ATLAS_ROWS * GLYPH_HEIGHT) as usize];
+    let mut fill_cell = |cell: u32, lit: &dyn Fn(u32, u32) -> bool| {
+        let (cell_x, cell_y) = ((cell % ATLAS_COLUMNS) * GLYPH_WIDTH, (cell / ATLAS_COLUMNS) * GLYPH_HEIGHT);
+        for y in 0..GLYPH_HEIGHT {
+            for x in 0..GLYPH_WIDTH {
+                if lit(x, y) {
+                    pixels[((cell_y + y) * width + cell_x + x) as usize] = 255;
+                }
+            }
+        }
+    };
+
+    for (cell, rows) in FONT_5X7.iter().enumerate() {
+        // Біт 4 - крайній лівий піксель рядка
+        fill_cell(cell as u32, &|x, y| rows[y as usize] & (1 << (GLYPH_WIDTH - 1 - x)) != 0);
+    }
+    fill_cell(SOLID_CELL, &|_, _| true);
+    pixels
+}
+
+/// Bitmap шрифт 5×7 для ASCII 32..=126: 7 рядків зверху вниз, 5 молодших бітів
+#[rustfmt::skip]
+const FONT_5X7: [[u8; 7]; GLYPH_COUNT as usize] = [
+    [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000], // ' '
+    [0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00000, 0b00100], // '!'
+    [0b01010, 0b01010, 0b01010, 0b00000, 0b00000, 0b00000, 0b00000], // '"'
+    [0b01010, 0b01010, 0b11111, 0b01010, 0b11111, 0b01010, 0b01010], // '#'
+    [0b00100, 0b01111, 0b10100, 0b01110, 0b00101, 0b11110, 0b00100], // '$'
+    [0b11000, 0b11001, 0b00010, 0b00100, 0b01000, 0b10011, 0b00011], // '%'
+    [0b01100, 0b10010, 0b10100, 0b01000, 0b10101, 0b10010, 0b01101], // '&'
+    [0b00100, 0b00100, 0b01000, 0b00000, 0b00000, 0b00000, 0b00000], // '\''
+    [0b00010, 0b00100, 0b01000, 0b01000, 0b01000, 0b00100, 0b00010], // '('
+    [0b01000, 0b00100, 0b00010, 0b00010, 0b00010, 0b00100, 0b01000], // ')'
+    [0b00000, 0b00100, 0b10101, 0b01110, 0b10101, 0b00100, 0b00000], // '*'
+    [0b00000, 0b00100, 0b00100, 0b11111, 0b00100, 0b00100, 0b00000], // '+'
+    [0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b00100, 0b01000], // ','
+    [0b00000, 0b00000, 0b00000, 0b11111, 0b00000, 0b00000, 0b00000], // '-'
+    [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b01100], // '.'
+    [0b00000, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b00000], // '/'
+    [0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110], // '0'
+    [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110], // '1'
+    [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111], // '2'
+    [0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110], // '3'
+    [0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010], // '4'
+    [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110], // '5'
+    [0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110], // '6'
+    [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000], // '7'
+    [0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110], // '8'
+    [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100], // '9'
+    [0b00000, 0b01100, 0b01100, 0b00000, 0b01100, 0b01100, 0b00000], // ':'
+    [0b00000, 0b01100, 0b01100, 0b00000, 0b01100, 0b00100, 0b01000], // ';'
+    [0b00010, 0b00100, 0b01000, 0b10000, 0b01000, 0b00100, 0b00010], // '<'
+    [0b00000, 0b00000, 0b11111, 0b00000, 0b11111, 0b00000, 0b00000], // '='
+    [0b01000, 0b00100, 0b00010, 0b00001, 0b00010, 0b00100, 0b01000], // '>'
+    [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b00000, 0b00100], // '?'
+    [0b01110, 0b10001, 0b00001, 0b01101, 0b10101, 0b10101, 0b01110], // '@'
+    [0b01110, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001], // 'A'
+    [0b11110, 0b10001, 0b10001, 0b11110, 0b10001, 0b10001, 0b11110], // 'B'
+    [0b01110, 0b10001, 0b10000, 0b10000, 0b10000, 0b10001, 0b01110], // 'C'
+    [0b11100, 0b10010, 0b10001, 0b10001, 0b10001, 0b10010, 0b11100], // 'D'
+    [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111], // 'E'
+    [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000], // 'F'
+    [0b01110, 0b10001, 0b10000, 0b10111, 0b10001, 0b10001, 0b01111], // 'G'
+    [0b10001, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001], // 'H'
+    [0b01110, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110], // 'I'
+    [0b00111, 0b00010, 0b00010, 0b00010, 0b00010, 0b10010, 0b01100], // 'J'
+    [0b10001, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010, 0b10001], // 'K'
+    [0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b11111], // 'L'
+    [0b10001, 0b11011, 0b10101, 0b10101, 0b10001, 0b10001, 0b10001], // 'M'
+    [0b10001, 0b10001, 0b11001, 0b10101, 0b10011, 0b10001, 0b10001], // 'N'
+    [0b01110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110], // 'O'
+    [0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000], // 'P'
+    [0b01110, 0b10001, 0b10001, 0b10001, 0b10101, 0b10010, 0b01101], // 'Q'
+    [0b11110, 0b10001, 0b10001, 0b11110, 0b10100, 0b10010, 0b10001], // 'R'
+    [0b01111, 0b10000, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110], // 'S'
+    [0b11111, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100], // 'T'
+    [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110], // 'U'
+    [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100], // 'V'
+    [0b10001, 0b10001, 0b10001, 0b10101, 0b10101, 0b10101, 0b01010], // 'W'
+    [0b10001, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b10001], // 'X'
+    [0b10001, 0b10001, 0b10001, 0b01010, 0b00100, 0b00100, 0b00100], // 'Y'
+    [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b11111], // 'Z'
+    [0b01110, 0b01000, 0b01000, 0b01000, 0b01000, 0b01000, 0b01110], // '['
+    [0b00000, 0b10000, 0b01000, 0b00100, 0b00010, 0b00001, 0b00000], // '\\'
+    [0b01110, 0b00010, 0b00010, 0b00010, 0b00010, 0b00010, 0b01110], // ']'
+    [0b00100, 0b01010, 0b10001, 0b00000, 0b00000, 0b00000, 0b00000], // '^'
+    [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b11111], // '_'
+    [0b01000, 0b00100, 0b00010, 0b00000, 0b00000, 0b00000, 0b00000], // '`'
+    [0b00000, 0b00000, 0b01110, 0b00001, 0b01111, 0b10001, 0b01111], // 'a'
+    [0b10000, 0b10000, 0b10110, 0b11001, 0b10001, 0b10001, 0b11110], // 'b'
+    [0b00000, 0b00000, 0b01110, 0b10000, 0b10000, 0b10001, 0b01110], // 'c'
+    [0b00001, 0b00001, 0b01101, 0b10011, 0b10001, 0b10001, 0b01111], // 'd'
+    [0b00000, 0b00000, 0b01110, 0b10001, 0b11111, 0b10000, 0b01110], // 'e'
+    [0b00110, 0b01001, 0b01000, 0b11100, 0b01000, 0b01000, 0b01000], // 'f'
+    [0b00000, 0b01111, 0b10001, 0b10001, 0b01111, 0b00001, 0b01110], // 'g'
+    [0b10000, 0b10000, 0b10110, 0b11001, 0b10001, 0b10001, 0b10001], // 'h'
+    [0b00100, 0b00000, 0b01100, 0b00100, 0b00100, 0b00100, 0b01110], // 'i'
+    [0b00010, 0b00000, 0b00110, 0b00010, 0b00010, 0b10010, 0b01100], // 'j'
+    [0b10000, 0b10000, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010], // 'k'
+    [0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110], // 'l'
+    [0b00000, 0b00000, 0b11010, 0b10101, 0b10101, 0b10001, 0b10001], // 'm'
+    [0b00000, 0b00000, 0b10110, 0b11001, 0b10001, 0b10001, 0b10001], // 'n'
+    [0b00000, 0b00000, 0b01110, 0b10001, 0b10001, 0b10001, 0b01110], // 'o'
+    [0b00000, 0b00000, 0b11110, 0b10001, 0b11110, 0b10000, 0b10000], // 'p'
+    [0b00000, 0b00000, 0b01101, 0b10011, 0b01111, 0b00001, 0b00001], // 'q'
+    [0b00000, 0b00000, 0b10110, 0b11001, 0b10000, 0b10000, 0b10000], // 'r'
+    [0b00000, 0b00000, 0b01110, 0b10000, 0b01110, 0b00001, 0b11110], // 's'
+    [0b01000, 0b01000, 0b11100, 0b01000, 0b01000, 0b01001, 0b00110], // 't'
+    [0b00000, 0b00000, 0b10001, 0b10001, 0b10001, 0b10011, 0b01101], // 'u'
+    [0b00000, 0b00000, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100], // 'v'
+    [0b00000, 0b00000, 0b10001, 0b10001, 0b10101, 0b10101, 0b01010], // 'w'
+    [0b00000, 0b00000, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001], // 'x'
+    [0b00000, 0b00000, 0b10001, 0b10001, 0b01111, 0b00001, 0b01110], // 'y'
+    [0b00000, 0b00000, 0b11111, 0b00010, 0b00100, 0b01000, 0b11111], // 'z'
+    [0b00010, 0b00100, 0b00100, 0b01000, 0b00100, 0b00100, 0b00010], // '{'
+    [0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100], // '|'
+    [0b01000, 0b00100, 0b00100, 0b00010, 0b00100, 0b00100, 0b01000], // '}'
+    [0b00000, 0b00000, 0b01000, 0b10101, 0b00010, 0b00000, 0b00000], // '~'
+];
@@ -0,0 +1,268 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/rendering/debug_lines.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   DebugLineRenderer - довільні debug лінії в world space.
+
+   Кожен кадр лінії набираються заново (clear → line → upload → render).
+   Перше використання - візуалізація ragdoll: локальні осі кісток та
+   вектори лінійної/кутової швидкості. Це набагато наочніше за Euler-кути
+   з log_bone_positions при налаштуванні PD м'язів.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Накопичення ліній на CPU (start, end, color)
+   - Завантаження в dynamic vertex buffer (фіксована ємність)
+   - Рендеринг LineList поверх сцени (без depth test) або з depth test
+   - RagdollDebug - прапорці та масштаби для осей / швидкостей
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Імпортує:
+   - grid.rs - GridVertex (той самий layout: position + color)
+
+   Експортує для:
+   - renderer.rs - WgpuRenderer::update_ragdoll_debug, update_particles
+   - main.rs - перемикачі F2 (осі) / F4 (швидкості)
+
+⚠️  ВАЖЛИВІ ОБМЕЖЕННЯ:
+   1. Максимум MAX_DEBUG_LINES ліній за кадр - решта відкидається
+   2. Debug лінії малюються ОСТАННІМИ і без depth test (видно крізь капсули);
+      частинки - з depth test (ховаються за геометрією)
+   3. Кольори осей: X = червоний, Y = зелений, Z = синій
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - осі кісток та вектори швидкостей ragdoll
+   2026-10-16: Опційний depth test (лінії частинок пилу)
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use glam::{Vec3, Quat};
+use wgpu;
+
+use super::grid::GridVertex;
+use crate::settings::Settings;
+
+/// Максимальна кількість ліній за кадр
+pub const MAX_DEBUG_LINES: usize = 1024;
+
+/// Налаштування debug візуалізації ragdoll
+#[derive(Debug, Clone, Copy)]
+pub struct RagdollDebug {
+    /// Малювати локальні осі кожної кістки
+    pub show_bone_axes: bool,
+
+    /// Малювати вектори лінійної та кутової швидкості
+    pub show_velocities: bool,
+
+    /// Довжина ліній осей (world units)
+    pub axis_length: f32,
+
+    /// Масштаб векторів швидкості (units на 1 m/s або 1 rad/s)
+    pub velocity_scale: f32,
+}
+
+impl RagdollDebug {
+    /// Читає секцію [debug] з налаштувань
+    ///
+    /// Ключі: `bone_axes`, `velocities`, `bone_axis_length`, `velocity_scale`
+    pub fn from_settings(settings: &Settings) -> Self {
+        let default = Self::default();
+        Self {
+            show_bone_axes: settings.get_bool("debug.bone_axes", default.show_bone_axes),
+            show_velocities: settings.get_bool("debug.velocities", default.show_velocities),
+            axis_length: settings.get_f32("debug.bone_axis_length", default.axis_length),
+            velocity_scale: settings.get_f32("debug.velocity_scale", default.velocity_scale),
+        }
+    }
+
+    /// Чи увімкнено хоча б одну візуалізацію
+    pub fn is_enabled(&self) -> bool {
+        self.show_bone_axes || self.show_velocities
+    }
+}
+
+impl Default for RagdollDebug {
+    fn default() -> Self {
+        Self {
+            show_bone_axes: false,
+            show_velocities: false,
+            axis_length: 0.15,
+            velocity_scale: 0.2,
+        }
+    }
+}
+
+/// Renderer debug ліній
+pub struct DebugLineRenderer {
+    /// Вершини поточного кадру (по 2 на лінію)
+    vertices: Vec<GridVertex>,
+
+    /// Скільки вершин завантажено на GPU
+    num_vertices: u32,
+
+    vertex_buffer: wgpu::Buffer,
+    pipeline: wgpu::RenderPipeline,
+
+    /// Depth test ліній (для перестворення pipeline)
+    depth_test: bool,
+}
+
+impl DebugLineRenderer {
+    /// Створює новий DebugLineRenderer
+    ///
+    /// # Аргументи
+    /// * `device` - wgpu device
+    /// * `config` - surface configuration (для format)
+    /// * `camera_bind_group_layout` - layout для camera uniform buffer
+    /// * `depth_test` - false = видно крізь геометрію (debug), true = як звичайна геометрія
+    /// * `sample_count` - MSAA samples render target'а
+    pub fn new(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        depth_test: bool,
+        sample_count: u32,
+    ) -> Self {
+        let vertex_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Debug Lines Vertex Buffer"),
+            size: (MAX_DEBUG_LINES * 2 * std::mem::size_of::<GridVertex>()) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let pipeline = Self::create_pipeline(device, config, camera_bind_group_layout, depth_test, sample_count);
+
+        Self {
+            vertices: Vec::with_capacity(MAX_DEBUG_LINES * 2),
+            num_vertices: 0,
+            vertex_buffer,
+            pipeline,
+            depth_test,
+        }
+    }
+
+    /// Render pipeline ліній (з depth test або без)
+    fn create_pipeline(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        depth_test: bool,
+        sample_count: u32,
+    ) -> wgpu::RenderPipeline {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Debug Lines Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../../assets/shaders/debug_lines.wgsl").into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Debug Lines Pipeline Layout"),
+            bind_group_layouts: &[camera_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Debug Lines Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[GridVertex::desc()],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: config.format,
+                    blend: Some(wgpu::BlendState::REPLACE),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::LineList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float,
+                depth_write_enabled: false,
+                depth_compare: if depth_test {
+                    wgpu::CompareFunction::LessEqual
+                } else {
+                    wgpu::CompareFunction::Always // Видно крізь геометрію
+                },
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            multiview: None,
+            cache: None,
+        })
+    }
+
+    /// Перестворює pipeline під іншу кількість MSAA samples
+    pub fn set_sample_count(
+        &mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+    ) {
+        self.pipeline = Self::create_pipeline(device, config, camera_bind_group_layout, self.depth_test, sample_count);
+    }
+
+    /// Очищає лінії (викликати на початку кадру)
+    pub fn clear(&mut self) {
+        self.vertices.clear();
+    }
+
+    /// Додає лінію
+    pub fn line(&mut self, start: Vec3, end: Vec3, color: [f32; 3]) {
+        if self.vertices.len() >= MAX_DEBUG_LINES * 2 {
+            return;
+        }
+        self.vertices.push(GridVertex { position: start.to_array(), color });
+        self.vertices.push(GridVertex { position: end.to_array(), color });
+    }
+
+    /// Додає локальні осі X/Y/Z (червоний/зелений/синій)
+    pub fn axes(&mut self, position: Vec3, rotation: Quat, length: f32) {
+        self.line(position, position + rotation * Vec3::X * length, [1.0, 0.2, 0.2]);
+        self.line(position, position + rotation * Vec3::Y * length, [0.2, 1.0, 0.2]);
+        self.line(position, position + rotation * Vec3::Z * length, [0.3, 0.4, 1.0]);
+    }
+
+    /// Завантажує лінії на GPU
+    pub fn upload(&mut self, queue: &wgpu::Queue) {
+        if !self.vertices.is_empty() {
+            queue.write_buffer(&self.vertex_buffer, 0, bytemuck::cast_slice(&self.vertices));
+        }
+        self.num_vertices = self.vertices.len() as u32;
+    }
+
+    /// Рендерить лінії
+    pub fn render<'a>(
+        &'a self,
+        render_pass: &mut wgpu::RenderPass<'a>,
+        camera_bind_group: &'a wgpu::BindGroup,
+    ) {
+        if self.num_vertices == 0 {
+            return;
+        }
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(0, camera_bind_group, &[]);
+        render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        render_pass.draw(0..self.num_vertices, 0..1);
+    }
+}
@@ -27,9 +27,16 @@
    - bytemuck = "1.14"
 
 ⚠️  ВАЖЛИВІ ОБМЕЖЕННЯ:
-   1. Grid завжди на Y=0 (XZ plane)
+   1. Grid на Y=0 (XZ plane) + невеликий y_offset вгору
    2. Розмір grid: -size..+size по X та Z
    3. Інтервал між лініями: 1.0 unit
+   4. Анти z-fighting: лінії на тій самій висоті що й підлога (Y=0) мерехтять.
+      DepthBiasState для LineList в WebGPU заборонений (тільки для трикутників),
+      тому grid піднімається на y_offset (дефолт DEFAULT_GRID_Y_OFFSET).
+   5. Fade-out в shader починається з 15 units - для великих арен
+      далекі лінії зникають (розмір задається ArenaConfig::grid_size)
+   6. Grid НЕ пише depth і малюється після непрозорих об'єктів, але перед
+      напівпрозорими - лінії не перекривають те, що над підлогою
 
 🧪 ТЕСТУВАННЯ:
    Grid має бути видимий при camera.position = Vec3::new(0.0, 2.0, 5.0)
@@ -37,6 +44,8 @@
 
 🕐 ІСТОРІЯ:
    2025-12-14: Створено - генерація grid mesh та render pipeline
+   2026-10-16: y_offset проти z-fighting з підлогою, без depth write
+   2026-10-16: Розмір з ArenaConfig (WgpuRenderer::set_grid_size)
 
 ═══════════════════════════════════════════════════════════════════════════════
 */
@@ -45,6 +54,9 @@ use bytemuck::{Pod, Zeroable};
 use wgpu;
 use wgpu::util::DeviceExt;
 
+/// Висота grid над Y=0 за замовчуванням (проти z-fighting з підлогою)
+pub const DEFAULT_GRID_Y_OFFSET: f32 = 0.005;
+
 /// Вершина для grid (позиція + колір)
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Pod, Zeroable)]
@@ -79,6 +91,10 @@ impl GridVertex {
 
 /// Grid - координатна сітка на підлозі
 pub struct Grid {
+    /// Розмір grid (від -size до +size)
+    size: i32,
+    /// Висота ліній над Y=0
+    y_offset: f32,
     vertex_buffer: wgpu::Buffer,
     index_buffer: wgpu::Buffer,
     num_indices: u32,
@@ -93,6 +109,8 @@ impl Grid {
     /// * `config` - surface configuration (для format)
     /// * `camera_bind_group_layout` - layout для camera uniform buffer
     /// * `size` - розмір grid (від -size до +size по X та Z)
+    /// * `y_offset` - висота ліній над Y=0 (див. DEFAULT_GRID_Y_OFFSET)
+    /// * `sample_count` - MSAA samples render target'а
     ///
     /// # Повертає
     /// Новий Grid готовий до рендерінгу
@@ -101,15 +119,17 @@ impl Grid {
         config: &wgpu::SurfaceConfiguration,
         camera_bind_group_layout: &wgpu::BindGroupLayout,
         size: i32,
+        y_offset: f32,
+        sample_count: u32,
     ) -> Self {
         // Генеруємо вершини та індекси
-        let (vertices, indices) = Self::generate_grid_mesh(size);
+        let (vertices, indices) = Self::generate_grid_mesh(size, y_offset);
 
-        // Створюємо vertex buffer
+        // Створюємо vertex buffer (COPY_DST - для зміни y_offset)
         let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("Grid Vertex Buffer"),
             contents: bytemuck::cast_slice(&vertices),
-            usage: wgpu::BufferUsages::VERTEX,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
         });
 
         // Створюємо index buffer
@@ -121,6 +141,25 @@ impl Grid {
 
         let num_indices = indices.len() as u32;
 
+        let pipeline = Self::create_pipeline(device, config, camera_bind_group_layout, sample_count);
+
+        Self {
+            size,
+            y_offset,
+            vertex_buffer,
+            index_buffer,
+            num_indices,
+            pipeline,
+        }
+    }
+
+    /// Render pipeline ліній grid
+    fn create_pipeline(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+    ) -> wgpu::RenderPipeline {
         // Завантажуємо shader
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Grid Shader"),
@@ -134,7 +173,7 @@ impl Grid {
             push_constant_ranges: &[],
         });
 
-        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
             label: Some("Grid Render Pipeline"),
             layout: Some(&pipeline_layout),
             vertex: wgpu::VertexState {
@@ -164,38 +203,44 @@ impl Grid {
             },
             depth_stencil: Some(wgpu::DepthStencilState {
                 format: wgpu::TextureFormat::Depth32Float,
-                depth_write_enabled: true,
-                depth_compare: wgpu::CompareFunction::Less,
+                depth_write_enabled: false, // Лінії не перекривають об'єкти, намальовані пізніше
+                depth_compare: wgpu::CompareFunction::LessEqual,
                 stencil: wgpu::StencilState::default(),
+                // Depth bias для LineList не дозволений - використовуємо y_offset
                 bias: wgpu::DepthBiasState::default(),
-            }), // Depth buffer для правильного z-ordering
+            }), // Depth test для правильного z-ordering
             multisample: wgpu::MultisampleState {
-                count: 1,
+                count: sample_count,
                 mask: !0,
                 alpha_to_coverage_enabled: false,
             },
             multiview: None,
             cache: None,
-        });
+        })
+    }
 
-        Self {
-            vertex_buffer,
-            index_buffer,
-            num_indices,
-            pipeline,
-        }
+    /// Перестворює pipeline під іншу кількість MSAA samples
+    pub fn set_sample_count(
+        &mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+    ) {
+        self.pipeline = Self::create_pipeline(device, config, camera_bind_group_layout, sample_count);
     }
 
     /// Генерує вершини та індекси для grid mesh
     ///
-    /// Створює лінії паралельні до X та Z осей на площині Y=0
+    /// Створює лінії паралельні до X та Z осей на площині Y=y_offset
     ///
     /// # Аргументи
     /// * `size` - розмір grid (від -size до +size)
+    /// * `y_offset` - висота ліній над Y=0
     ///
     /// # Повертає
     /// (vertices, indices) для grid
-    fn generate_grid_mesh(size: i32) -> (Vec<GridVertex>, Vec<u16>) {
+    fn generate_grid_mesh(size: i32, y_offset: f32) -> (Vec<GridVertex>, Vec<u16>) {
         let mut vertices = Vec::new();
         let mut indices = Vec::new();
 
@@ -208,13 +253,13 @@ impl Grid {
 
             // Початок лінії
             vertices.push(GridVertex {
-                position: [-size as f32, 0.0, z_pos],
+                position: [-size as f32, y_offset, z_pos],
                 color: grid_color,
             });
 
             // Кінець лінії
             vertices.push(GridVertex {
-                position: [size as f32, 0.0, z_pos],
+                position: [size as f32, y_offset, z_pos],
                 color: grid_color,
             });
         }
@@ -225,13 +270,13 @@ impl Grid {
 
             // Початок лінії
             vertices.push(GridVertex {
-                position: [x_pos, 0.0, -size as f32],
+                position: [x_pos, y_offset, -size as f32],
                 color: grid_color,
             });
 
             // Кінець лінії
             vertices.push(GridVertex {
-                position: [x_pos, 0.0, size as f32],
+                position: [x_pos, y_offset, size as f32],
                 color: grid_color,
             });
         }
@@ -244,6 +289,21 @@ impl Grid {
         (vertices, indices)
     }
 
+    /// Висота ліній над Y=0
+    pub fn y_offset(&self) -> f32 {
+        self.y_offset
+    }
+
+    /// Змінює висоту ліній над Y=0 (перезаписує vertex buffer)
+    pub fn set_y_offset(&mut self, queue: &wgpu::Queue, y_offset: f32) {
+        if (self.y_offset - y_offset).abs() < f32::EPSILON {
+            return;
+        }
+        self.y_offset = y_offset;
+        let (vertices, _) = Self::generate_grid_mesh(self.size, y_offset);
+        queue.write_buffer(&self.vertex_buffer, 0, bytemuck::cast_slice(&vertices));
+    }
+
     /// Рендерить grid
     ///
     /// # Аргументи
@@ -0,0 +1,260 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/rendering/health_bar.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   HealthBarRenderer - смуги здоров'я над ворогами.
+
+   Кожна смуга - billboard quad (завжди лицем до камери): темний фон на
+   всю ширину та кольорове заповнення на health / max_health. Геометрії
+   немає - вершини будує shader з vertex_index, на смугу - один instance.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - HealthBarInstance: центр, заповнення, осі billboard'а, колір
+   - HealthBarInstance::billboard - осі з позиції камери (кожен кадр)
+   - Instance buffer, що росте під кількість смуг
+   - hide_when_full - не малювати смуги повного здоров'я
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Експортує для:
+   - renderer.rs - WgpuRenderer::update_enemies (смуги живих ворогів)
+   - main.rs - [rendering] health_bar_hide_when_full
+
+⚠️  ВАЖЛИВІ ОБМЕЖЕННЯ:
+   1. Depth test без запису - смугу ховає геометрія, але смуги одна одну ні
+   2. Колір заповнення - від червоного (мало) до зеленого (повне)
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - смуги здоров'я ворогів
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use glam::Vec3;
+use wgpu;
+
+/// Ширина смуги (world units)
+pub const HEALTH_BAR_WIDTH: f32 = 0.8;
+
+/// Висота смуги (world units)
+pub const HEALTH_BAR_HEIGHT: f32 = 0.08;
+
+/// Колір заповнення при повному здоров'ї
+const FULL_HEALTH_COLOR: Vec3 = Vec3::new(0.2, 0.85, 0.25);
+
+/// Колір заповнення при майже нульовому здоров'ї
+const LOW_HEALTH_COLOR: Vec3 = Vec3::new(0.9, 0.15, 0.1);
+
+/// Вершин на смугу: фон + заповнення, по 2 трикутники
+const VERTICES_PER_BAR: u32 = 12;
+
+/// Початкова місткість instance buffer (смуг)
+const INITIAL_CAPACITY: usize = 16;
+
+/// Instance data однієї смуги
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct HealthBarInstance {
+    /// Центр смуги (world space)
+    pub center: [f32; 3],
+    /// Частка заповнення (0..1)
+    pub fill: f32,
+    /// Вісь "вправо" billboard'а × половина ширини
+    pub right: [f32; 3],
+    /// Вісь "вгору" billboard'а × половина висоти
+    pub up: [f32; 3],
+    /// Колір заповнення (RGB)
+    pub color: [f32; 3],
+}
+
+impl HealthBarInstance {
+    /// Смуга лицем до камери
+    ///
+    /// # Аргументи
+    /// * `center` - центр смуги (world space)
+    /// * `fill` - частка здоров'я (0..1)
+    /// * `camera_position` - позиція камери цього кадру
+    pub fn billboard(center: Vec3, fill: f32, camera_position: Vec3) -> Self {
+        let forward = (center - camera_position).normalize_or(Vec3::NEG_Z);
+        // Камера прямо згори / знизу - будь-яка горизонтальна вісь
+        let right = forward.cross(Vec3::Y).try_normalize().unwrap_or(Vec3::X);
+        let up = right.cross(forward);
+        let fill = fill.clamp(0.0, 1.0);
+        Self {
+            center: center.to_array(),
+            fill,
+            right: (right * HEALTH_BAR_WIDTH * 0.5).to_array(),
+            up: (up * HEALTH_BAR_HEIGHT * 0.5).to_array(),
+            color: LOW_HEALTH_COLOR.lerp(FULL_HEALTH_COLOR, fill).to_array(),
+        }
+    }
+
+    fn instance_buffer_layout() -> wgpu::VertexBufferLayout<'static> {
+        const ATTRIBUTES: [wgpu::VertexAttribute; 5] = wgpu::vertex_attr_array![
+            0 => Float32x3, // center
+            1 => Float32,   // fill
+            2 => Float32x3, // right
+            3 => Float32x3, // up
+            4 => Float32x3, // color
+        ];
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<HealthBarInstance>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &ATTRIBUTES,
+        }
+    }
+}
+
+/// Renderer смуг здоров'я
+pub struct HealthBarRenderer {
+    /// Не малювати смуги повного здоров'я
+    pub hide_when_full: bool,
+
+    instance_buffer: wgpu::Buffer,
+
+    /// Місткість instance buffer (смуг)
+    capacity: usize,
+
+    /// Скільки смуг завантажено на GPU
+    instance_count: u32,
+
+    pipeline: wgpu::RenderPipeline,
+}
+
+impl HealthBarRenderer {
+    /// Створює новий HealthBarRenderer
+    ///
+    /// # Аргументи
+    /// * `device` - wgpu device
+    /// * `config` - surface configuration (для format)
+    /// * `camera_bind_group_layout` - layout для camera uniform buffer
+    /// * `sample_count` - MSAA samples render target'а
+    pub fn new(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+    ) -> Self {
+        let pipeline = Self::create_pipeline(device, config, camera_bind_group_layout, sample_count);
+
+        Self {
+            hide_when_full: false,
+            instance_buffer: Self::create_instance_buffer(device, INITIAL_CAPACITY),
+            capacity: INITIAL_CAPACITY,
+            instance_count: 0,
+            pipeline,
+        }
+    }
+
+    /// Render pipeline смуг (billboard quads з vertex_index)
+    fn create_pipeline(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+    ) -> wgpu::RenderPipeline {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Health Bar Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../../assets/shaders/health_bar.wgsl").into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Health Bar Pipeline Layout"),
+            bind_group_layouts: &[camera_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Health Bar Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[HealthBarInstance::instance_buffer_layout()],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: config.format,
+                    blend: Some(wgpu::BlendState::REPLACE),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None, // Billboard - обидві сторони
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float,
+                depth_write_enabled: false,
+                depth_compare: wgpu::CompareFunction::LessEqual,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            multiview: None,
+            cache: None,
+        })
+    }
+
+    /// Перестворює pipeline під іншу кількість MSAA samples
+    pub fn set_sample_count(
+        &mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+    ) {
+        self.pipeline = Self::create_pipeline(device, config, camera_bind_group_layout, sample_count);
+    }
+
+    /// Instance buffer на `capacity` смуг
+    fn create_instance_buffer(device: &wgpu::Device, capacity: usize) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Health Bar Instance Buffer"),
+            size: (std::mem::size_of::<HealthBarInstance>() * capacity) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        })
+    }
+
+    /// Завантажує смуги кадру на GPU (buffer росте, не зменшується)
+    pub fn update(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, bars: &[HealthBarInstance]) {
+        if bars.len() > self.capacity {
+            self.capacity = bars.len().next_power_of_two();
+            self.instance_buffer = Self::create_instance_buffer(device, self.capacity);
+        }
+        if !bars.is_empty() {
+            queue.write_buffer(&self.instance_buffer, 0, bytemuck::cast_slice(bars));
+        }
+        self.instance_count = bars.len() as u32;
+    }
+
+    /// Рендерить смуги
+    pub fn render<'a>(
+        &'a self,
+        render_pass: &mut wgpu::RenderPass<'a>,
+        camera_bind_group: &'a wgpu::BindGroup,
+    ) {
+        if self.instance_count == 0 {
+            return;
+        }
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(0, camera_bind_group, &[]);
+        render_pass.set_vertex_buffer(0, self.instance_buffer.slice(..));
+        render_pass.draw(0..VERTICES_PER_BAR, 0..self.instance_count);
+    }
+}
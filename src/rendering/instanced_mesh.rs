@@ -0,0 +1,367 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/rendering/instanced_mesh.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   InstancedMesh - одна геометрія, багато екземплярів за один draw_indexed.
+
+   Вершини та індекси зберігаються один раз; кожен екземпляр - MeshInstance
+   (model matrix + normal matrix + tint) в instance buffer, як BoneInstance
+   у SkeletonRenderer.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - MeshInstance + instance buffer layout (shader locations 3..=10)
+   - Сортування екземплярів: непрозорі першими, напівпрозорі - окремим
+     діапазоном (малюються після решти сцени), невидимі пропускаються
+   - Слот екземпляра за індексом (outline конкретного ворога)
+   - Pipeline з MSAA та wireframe, як у Mesh
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Імпортує:
+   - mesh.rs - MeshVertex
+   - transform - Transform, TransformUniform (model / normal matrix)
+
+   Експортує для:
+   - renderer.rs - enemy_mesh (всі манекени ворогів)
+   - shadow.rs / outline.rs - draw_instances зі своїм pipeline
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+   - Камера @ group(0), світло @ group(1) (як SkeletonRenderer)
+   - Instance buffer росте під кількість екземплярів і не зменшується
+   - Слоти змінюються при кожному update_instances - беріть instance_slot
+     того ж кадру
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - instancing манекенів ворогів
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use std::ops::Range;
+
+use wgpu::util::DeviceExt;
+
+use crate::transform::{Transform, TransformUniform};
+use super::mesh::MeshVertex;
+
+/// Дані одного екземпляра (той самий layout, що й TransformUniform)
+#[repr(C)]
+#[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct MeshInstance {
+    pub model_matrix: [[f32; 4]; 4],
+    /// Верхня ліва 3x3 inverse transpose model matrix (W - padding)
+    pub normal_matrix: [[f32; 4]; 3],
+    /// RGBA множник кольору; alpha < 1.0 - напівпрозорий, 0.0 - не малюється
+    pub tint: [f32; 4],
+}
+
+impl MeshInstance {
+    const ATTRIBUTES: [wgpu::VertexAttribute; 8] = wgpu::vertex_attr_array![
+        // model_matrix - 4 slots
+        3 => Float32x4, 4 => Float32x4, 5 => Float32x4, 6 => Float32x4,
+        // normal_matrix - 3 slots
+        7 => Float32x4, 8 => Float32x4, 9 => Float32x4,
+        // tint
+        10 => Float32x4,
+    ];
+
+    /// Екземпляр з transform (scale враховується і в normal matrix)
+    pub fn new(transform: &Transform, tint: [f32; 4]) -> Self {
+        let mut uniform = TransformUniform::new();
+        uniform.update(transform);
+        Self {
+            model_matrix: uniform.model,
+            normal_matrix: uniform.normal_matrix,
+            tint,
+        }
+    }
+
+    pub fn instance_buffer_layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<MeshInstance>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &Self::ATTRIBUTES,
+        }
+    }
+
+    fn is_visible(&self) -> bool {
+        self.tint[3] > 0.0
+    }
+
+    fn is_transparent(&self) -> bool {
+        self.tint[3] < 1.0
+    }
+}
+
+/// Геометрія з instance buffer
+pub struct InstancedMesh {
+    vertex_buffer: wgpu::Buffer,
+    index_buffer: wgpu::Buffer,
+    num_indices: u32,
+    render_pipeline: wgpu::RenderPipeline,
+
+    /// Fill або Line (wireframe, потребує Features::POLYGON_MODE_LINE)
+    polygon_mode: wgpu::PolygonMode,
+
+    instance_buffer: wgpu::Buffer,
+    /// Місткість instance_buffer (екземпляри)
+    instance_capacity: usize,
+
+    /// Видимі екземпляри в буфері: непрозорі - [0, opaque_count), напівпрозорі - до instance_count
+    opaque_count: u32,
+    instance_count: u32,
+
+    /// Слот у instance buffer для кожного індексу з update_instances (None - невидимий)
+    slots: Vec<Option<u32>>,
+}
+
+impl InstancedMesh {
+    /// Створює InstancedMesh з вершин та індексів (без екземплярів)
+    ///
+    /// # Аргументи
+    /// * `vertices` / `indices` - спільна геометрія всіх екземплярів
+    /// * `camera_bind_group_layout` / `light_bind_group_layout` - group(0) / group(1)
+    /// * `sample_count` - MSAA samples render target'а
+    pub fn new(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        vertices: &[MeshVertex],
+        indices: &[u32],
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        light_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+    ) -> Self {
+        let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Instanced Mesh Vertex Buffer"),
+            contents: bytemuck::cast_slice(vertices),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+        let index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Instanced Mesh Index Buffer"),
+            contents: bytemuck::cast_slice(indices),
+            usage: wgpu::BufferUsages::INDEX,
+        });
+
+        let render_pipeline = Self::create_pipeline(
+            device,
+            config,
+            camera_bind_group_layout,
+            light_bind_group_layout,
+            sample_count,
+            wgpu::PolygonMode::Fill,
+        );
+
+        // Початкова місткість; росте в update_instances
+        let instance_capacity = 16;
+
+        Self {
+            vertex_buffer,
+            index_buffer,
+            num_indices: indices.len() as u32,
+            render_pipeline,
+            polygon_mode: wgpu::PolygonMode::Fill,
+            instance_buffer: Self::create_instance_buffer(device, instance_capacity),
+            instance_capacity,
+            opaque_count: 0,
+            instance_count: 0,
+            slots: Vec::new(),
+        }
+    }
+
+    fn create_pipeline(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        light_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+        polygon_mode: wgpu::PolygonMode,
+    ) -> wgpu::RenderPipeline {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Instanced Mesh Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../../assets/shaders/instanced_mesh.wgsl").into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Instanced Mesh Pipeline Layout"),
+            bind_group_layouts: &[camera_bind_group_layout, light_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Instanced Mesh Render Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[MeshVertex::vertex_buffer_layout(), MeshInstance::instance_buffer_layout()],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: config.format,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING), // tint.a для fade-out
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: Some(wgpu::Face::Back),
+                polygon_mode,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float,
+                depth_write_enabled: true,
+                depth_compare: wgpu::CompareFunction::Less,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            multiview: None,
+            cache: None,
+        })
+    }
+
+    fn create_instance_buffer(device: &wgpu::Device, capacity: usize) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Instanced Mesh Instance Buffer"),
+            size: (std::mem::size_of::<MeshInstance>() * capacity) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        })
+    }
+
+    /// Перестворює pipeline під іншу кількість MSAA samples
+    pub fn set_sample_count(
+        &mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        light_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+    ) {
+        self.render_pipeline = Self::create_pipeline(
+            device,
+            config,
+            camera_bind_group_layout,
+            light_bind_group_layout,
+            sample_count,
+            self.polygon_mode,
+        );
+    }
+
+    /// Перемикає заливку / wireframe (перестворює pipeline)
+    pub fn set_polygon_mode(
+        &mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        light_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+        polygon_mode: wgpu::PolygonMode,
+    ) {
+        self.polygon_mode = polygon_mode;
+        self.set_sample_count(device, config, camera_bind_group_layout, light_bind_group_layout, sample_count);
+    }
+
+    /// Завантажує екземпляри в instance buffer
+    ///
+    /// Невидимі (tint.a = 0) пропускаються; непрозорі йдуть першими,
+    /// напівпрозорі - після них (render_transparent).
+    ///
+    /// # Аргументи
+    /// * `instances` - екземпляр з індексом i (індекс для instance_slot)
+    pub fn update_instances(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, instances: &[MeshInstance]) {
+        let mut order: Vec<usize> = (0..instances.len()).filter(|&i| instances[i].is_visible()).collect();
+        // Стабільне сортування - порядок однакових зберігається
+        order.sort_by_key(|&i| instances[i].is_transparent());
+
+        self.slots = vec![None; instances.len()];
+        for (slot, &index) in order.iter().enumerate() {
+            self.slots[index] = Some(slot as u32);
+        }
+        self.opaque_count = order.iter().filter(|&&i| !instances[i].is_transparent()).count() as u32;
+        self.instance_count = order.len() as u32;
+
+        if order.is_empty() {
+            return;
+        }
+        if order.len() > self.instance_capacity {
+            self.instance_capacity = order.len().next_power_of_two();
+            self.instance_buffer = Self::create_instance_buffer(device, self.instance_capacity);
+        }
+        let data: Vec<MeshInstance> = order.iter().map(|&i| instances[i]).collect();
+        queue.write_buffer(&self.instance_buffer, 0, bytemuck::cast_slice(&data));
+    }
+
+    /// Слот екземпляра `index` в instance buffer (None - невидимий / немає)
+    pub fn instance_slot(&self, index: usize) -> Option<u32> {
+        self.slots.get(index).copied().flatten()
+    }
+
+    /// Всі видимі екземпляри (shadow pre-pass)
+    pub fn visible_instances(&self) -> Range<u32> {
+        0..self.instance_count
+    }
+
+    /// Малює непрозорі екземпляри
+    pub fn render_opaque<'a>(
+        &'a self,
+        render_pass: &mut wgpu::RenderPass<'a>,
+        camera_bind_group: &'a wgpu::BindGroup,
+        light_bind_group: &'a wgpu::BindGroup,
+    ) {
+        self.render_range(render_pass, camera_bind_group, light_bind_group, 0..self.opaque_count);
+    }
+
+    /// Малює напівпрозорі екземпляри (після всіх непрозорих об'єктів сцени)
+    pub fn render_transparent<'a>(
+        &'a self,
+        render_pass: &mut wgpu::RenderPass<'a>,
+        camera_bind_group: &'a wgpu::BindGroup,
+        light_bind_group: &'a wgpu::BindGroup,
+    ) {
+        self.render_range(render_pass, camera_bind_group, light_bind_group, self.opaque_count..self.instance_count);
+    }
+
+    fn render_range<'a>(
+        &'a self,
+        render_pass: &mut wgpu::RenderPass<'a>,
+        camera_bind_group: &'a wgpu::BindGroup,
+        light_bind_group: &'a wgpu::BindGroup,
+        instances: Range<u32>,
+    ) {
+        if instances.is_empty() {
+            return;
+        }
+        render_pass.set_pipeline(&self.render_pipeline);
+        render_pass.set_bind_group(0, camera_bind_group, &[]);
+        render_pass.set_bind_group(1, light_bind_group, &[]);
+        self.draw_instances(render_pass, instances);
+    }
+
+    /// Малює екземпляри з вже встановленим pipeline (shadow, outline)
+    ///
+    /// # Аргументи
+    /// * `instances` - діапазон слотів (visible_instances або один instance_slot)
+    pub fn draw_instances<'a>(&'a self, render_pass: &mut wgpu::RenderPass<'a>, instances: Range<u32>) {
+        if instances.is_empty() {
+            return;
+        }
+        render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        render_pass.set_vertex_buffer(1, self.instance_buffer.slice(..));
+        render_pass.set_index_buffer(self.index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+        render_pass.draw_indexed(0..self.num_indices, 0, instances);
+    }
+}
@@ -0,0 +1,151 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/rendering/light.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   Directional light сцени - одне "сонце" + ambient.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Light: напрямок, колір, ambient (поле WgpuRenderer::light)
+   - Обертання сонця навколо вертикалі (sun_rotation) - для візуальної
+     перевірки освітлення та тіней
+   - LightUniform: дані для GPU (mesh.wgsl group(2), skeleton.wgsl group(1))
+   - create_light_bind_group_layout - спільний layout для обох pipeline:
+     uniform + shadow map + comparison sampler (shadow.rs)
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Експортує для:
+   - renderer.rs - light buffer / bind group, оновлення кожен кадр
+   - mesh.rs, skeleton_renderer.rs - layout у pipeline
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+   - direction - куди світить світло (від джерела до сцени), не нормалізований
+   - Освітлення: ambient + (1 - ambient) × color × max(N·L, 0) - при білому
+     світлі не більше 1.0 (формула однакова в mesh.wgsl та skeleton.wgsl)
+   - Значення за замовчуванням - колишнє захардкоджене світло шейдерів
+   - Тінь множить лише diffuse - в тіні лишається ambient
+   - Обертання змінює лише азимут - висота сонця над горизонтом та сама
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - directional light як uniform замість констант шейдерів
+   2026-10-16: Shadow map у bind group світла (view_proj, texel, texture, sampler)
+   2026-10-16: sun_rotation / update - повільне обертання сонця ([rendering])
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use glam::{Mat4, Quat, Vec3};
+
+/// Швидкість обертання сонця за замовчуванням (градуси / секунду)
+pub const DEFAULT_SUN_ROTATION_SPEED_DEG: f32 = 10.0;
+
+/// Directional light
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Light {
+    /// Напрямок світла (від джерела до сцени)
+    pub direction: Vec3,
+
+    /// Колір (інтенсивність) світла
+    pub color: Vec3,
+
+    /// Ambient - мінімальна освітленість (тіні не чорні)
+    pub ambient: f32,
+
+    /// Чи обертається сонце навколо вертикалі (update)
+    pub sun_rotation: bool,
+
+    /// Швидкість обертання сонця (радіани / секунду)
+    pub sun_rotation_speed: f32,
+}
+
+impl Default for Light {
+    /// Світло зверху-спереду-справа, біле, ambient 0.3
+    fn default() -> Self {
+        Self {
+            direction: Vec3::new(-0.5, -1.0, -0.3),
+            color: Vec3::ONE,
+            ambient: 0.3,
+            sun_rotation: false,
+            sun_rotation_speed: DEFAULT_SUN_ROTATION_SPEED_DEG.to_radians(),
+        }
+    }
+}
+
+impl Light {
+    /// Раз на кадр: обертає сонце (якщо sun_rotation)
+    pub fn update(&mut self, delta: f32) {
+        if self.sun_rotation {
+            self.direction = Quat::from_rotation_y(self.sun_rotation_speed * delta) * self.direction;
+        }
+    }
+}
+
+/// Light uniform для GPU (std140: mat4, далі vec3 + f32 на рядок)
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct LightUniform {
+    /// View-projection світла (world → shadow map)
+    pub view_proj: [[f32; 4]; 4],
+    /// Напрямок ДО світла (нормалізований) - готовий для N·L
+    pub to_light: [f32; 3],
+    pub ambient: f32,
+    pub color: [f32; 3],
+    /// Розмір texel'а shadow map в UV (1 / resolution) - крок PCF
+    pub shadow_texel: f32,
+}
+
+impl LightUniform {
+    /// Uniform зі світла та shadow map кадру
+    ///
+    /// # Аргументи
+    /// * `light` - directional light
+    /// * `shadow_view_proj` - ShadowMap::view_proj
+    /// * `shadow_resolution` - ShadowMap::resolution
+    pub fn new(light: &Light, shadow_view_proj: Mat4, shadow_resolution: u32) -> Self {
+        Self {
+            view_proj: shadow_view_proj.to_cols_array_2d(),
+            to_light: (-light.direction).normalize_or(Vec3::Y).to_array(),
+            ambient: light.ambient.clamp(0.0, 1.0),
+            color: light.color.to_array(),
+            shadow_texel: 1.0 / shadow_resolution.max(1) as f32,
+        }
+    }
+}
+
+/// Layout bind group'и зі світлом (fragment shader)
+///
+/// binding 0 - LightUniform, 1 - shadow map (depth), 2 - comparison sampler
+pub fn create_light_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        entries: &[
+            wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            },
+            wgpu::BindGroupLayoutEntry {
+                binding: 1,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Depth,
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            },
+            wgpu::BindGroupLayoutEntry {
+                binding: 2,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Comparison),
+                count: None,
+            },
+        ],
+        label: Some("light_bind_group_layout"),
+    })
+}
@@ -8,9 +8,11 @@
 
 🎯 ВІДПОВІДАЛЬНІСТЬ:
    - MeshVertex struct (position + normal + color)
-   - Генерація простих примітивів (cube, sphere, plane)
+   - Генерація простих примітивів (cube, sphere, plane, cone, torus)
+   - Mesh з .obj файлу (Mesh::from_obj, парсер - obj.rs)
+   - create_transform_bind_group_layout (спільний з TexturedMesh)
    - Mesh struct з vertex/index buffers
-   - Render pipeline для 3D об'єктів
+   - Render pipeline для 3D об'єктів (MeshShader: mesh.wgsl або ground.wgsl)
    - Transform support (Model matrix)
 
 🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
@@ -26,12 +28,24 @@
    - Coordinate system: Y-up, right-handed
    - Normals: outward facing for lighting
    - Winding order: counter-clockwise (CCW) for front faces
-   - Index format: u16 (max 65535 vertices per mesh)
+   - Index format: u32 (IndexFormat::Uint32) - моделі та примітиви з будь-якою
+     кількістю вершин (капсули скелета та grid лишаються на u16)
    - Transform: Model matrix в group(1) binding(0)
+   - Світло: LightUniform в group(2) binding(0) (спільне для всіх mesh)
+   - MeshShader::Ground - той самий vertex layout та bind groups, лише
+     fragment shader інший (шахівниця у world space)
 
 🕐 ІСТОРІЯ:
    2025-12-14: Створено - базовий mesh rendering з cube primitive
    2025-12-14: Додано Transform support (Model matrix)
+   2026-10-16: Runtime tint + alpha blending (fade-out мертвих ворогів)
+   2026-10-16: Directional light з uniform (group(2)) замість констант шейдера
+   2026-10-16: MSAA - sample_count у pipeline (set_sample_count)
+   2026-10-16: Mesh::from_obj - моделі з Wavefront .obj
+   2026-10-16: Індекси u32 замість u16 (великі моделі, high-poly сфери)
+   2026-10-16: Wireframe (set_polygon_mode) для debug геометрії
+   2026-10-16: generate_cone (наконечники стріл / списів), generate_torus (кільця, щити)
+   2026-10-16: MeshShader / set_shader - ground.wgsl для підлоги арени
 
 ═══════════════════════════════════════════════════════════════════════════════
 */
@@ -39,6 +53,7 @@
 use wgpu::util::DeviceExt;
 use crate::transform::{Transform, TransformUniform};
 use crate::debug_log::log_debug;
+use super::obj::{load_obj, DEFAULT_OBJ_COLOR};
 
 /// Vertex структура для 3D mesh
 ///
@@ -84,6 +99,11 @@ impl MeshVertex {
     }
 }
 
+/// Індекс наступної вершини (база для індексів частини, що додається)
+fn next_index(vertices: &[MeshVertex]) -> u32 {
+    u32::try_from(vertices.len()).expect("mesh має більше u32::MAX вершин")
+}
+
 /// Генерує циліндр вздовж Y-осі
 ///
 /// # Аргументи
@@ -94,7 +114,7 @@ impl MeshVertex {
 ///
 /// # Повертає
 /// (vertices, indices) - вершини та індекси для rendering
-pub fn generate_cylinder(radius: f32, height: f32, segments: u32, color: [f32; 3]) -> (Vec<MeshVertex>, Vec<u16>) {
+pub fn generate_cylinder(radius: f32, height: f32, segments: u32, color: [f32; 3]) -> (Vec<MeshVertex>, Vec<u32>) {
     let mut vertices = Vec::new();
     let mut indices = Vec::new();
 
@@ -127,17 +147,17 @@ pub fn generate_cylinder(radius: f32, height: f32, segments: u32, color: [f32; 3
     for i in 0..segments {
         let base = i * 2;
         // Two triangles per quad
-        indices.push(base as u16);
-        indices.push((base + 1) as u16);
-        indices.push((base + 2) as u16);
+        indices.push(base);
+        indices.push(base + 1);
+        indices.push(base + 2);
 
-        indices.push((base + 2) as u16);
-        indices.push((base + 1) as u16);
-        indices.push((base + 3) as u16);
+        indices.push(base + 2);
+        indices.push(base + 1);
+        indices.push(base + 3);
     }
 
     // Top cap
-    let top_center_idx = vertices.len() as u16;
+    let top_center_idx = next_index(&vertices);
     vertices.push(MeshVertex {
         position: [0.0, half_height, 0.0],
         normal: [0.0, 1.0, 0.0],
@@ -158,14 +178,14 @@ pub fn generate_cylinder(radius: f32, height: f32, segments: u32, color: [f32; 3
 
     // Top cap indices
     for i in 0..segments {
-        let base = top_center_idx + 1 + i as u16;
+        let base = top_center_idx + 1 + i;
         indices.push(top_center_idx);
         indices.push(base + 1);
         indices.push(base);
     }
 
     // Bottom cap
-    let bottom_center_idx = vertices.len() as u16;
+    let bottom_center_idx = next_index(&vertices);
     vertices.push(MeshVertex {
         position: [0.0, -half_height, 0.0],
         normal: [0.0, -1.0, 0.0],
@@ -186,7 +206,7 @@ pub fn generate_cylinder(radius: f32, height: f32, segments: u32, color: [f32; 3
 
     // Bottom cap indices (reversed winding)
     for i in 0..segments {
-        let base = bottom_center_idx + 1 + i as u16;
+        let base = bottom_center_idx + 1 + i;
         indices.push(bottom_center_idx);
         indices.push(base);
         indices.push(base + 1);
@@ -195,6 +215,149 @@ pub fn generate_cylinder(radius: f32, height: f32, segments: u32, color: [f32; 3
     (vertices, indices)
 }
 
+/// Генерує конус вздовж Y-осі: основа на -height/2, вершина на +height/2
+///
+/// Вершина дублюється для кожного сегмента (нормаль по середині сегмента),
+/// тож бокова поверхня гладка і без виродженої нормалі на вістрі.
+///
+/// # Аргументи
+/// * `radius` - радіус основи
+/// * `height` - висота конуса
+/// * `segments` - кількість сегментів по колу (більше = гладкіший)
+/// * `color` - колір всіх вершин
+///
+/// # Повертає
+/// (vertices, indices) - вершини та індекси для rendering
+#[allow(dead_code)]
+pub fn generate_cone(radius: f32, height: f32, segments: u32, color: [f32; 3]) -> (Vec<MeshVertex>, Vec<u32>) {
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+
+    let half_height = height / 2.0;
+
+    // Нормаль бокової поверхні під кутом angle: перпендикуляр до твірної,
+    // (cos * height, radius, sin * height) / довжина
+    let slant = (height * height + radius * radius).sqrt().max(f32::EPSILON);
+    let side_normal = |angle: f32| -> [f32; 3] {
+        [angle.cos() * height / slant, radius / slant, angle.sin() * height / slant]
+    };
+
+    // Бокова поверхня: кільце основи (segments + 1, шов) + вершина на кожен сегмент
+    for i in 0..=segments {
+        let angle = (i as f32 / segments as f32) * std::f32::consts::TAU;
+        vertices.push(MeshVertex {
+            position: [angle.cos() * radius, -half_height, angle.sin() * radius],
+            normal: side_normal(angle),
+            color,
+        });
+    }
+    let apex_start = next_index(&vertices);
+    for i in 0..segments {
+        let angle = ((i as f32 + 0.5) / segments as f32) * std::f32::consts::TAU;
+        vertices.push(MeshVertex {
+            position: [0.0, half_height, 0.0],
+            normal: side_normal(angle),
+            color,
+        });
+    }
+
+    // Індекси бокової поверхні (winding як у generate_cylinder)
+    for i in 0..segments {
+        indices.push(i);
+        indices.push(apex_start + i);
+        indices.push(i + 1);
+    }
+
+    // Base cap (нормаль вниз)
+    let base_center_idx = next_index(&vertices);
+    vertices.push(MeshVertex {
+        position: [0.0, -half_height, 0.0],
+        normal: [0.0, -1.0, 0.0],
+        color,
+    });
+
+    for i in 0..=segments {
+        let angle = (i as f32 / segments as f32) * std::f32::consts::TAU;
+        vertices.push(MeshVertex {
+            position: [angle.cos() * radius, -half_height, angle.sin() * radius],
+            normal: [0.0, -1.0, 0.0],
+            color,
+        });
+    }
+
+    // Base cap indices (reversed winding, як bottom cap циліндра)
+    for i in 0..segments {
+        let base = base_center_idx + 1 + i;
+        indices.push(base_center_idx);
+        indices.push(base);
+        indices.push(base + 1);
+    }
+
+    (vertices, indices)
+}
+
+/// Генерує тор (бублик) з центром в (0, 0, 0), кільце лежить в площині XZ
+///
+/// # Аргументи
+/// * `major_radius` - відстань від центру тора до центру трубки
+/// * `minor_radius` - радіус трубки
+/// * `major_segments` - сегменти вздовж кільця
+/// * `minor_segments` - сегменти по колу трубки
+/// * `color` - колір всіх вершин
+///
+/// # Повертає
+/// (vertices, indices) - вершини та індекси для rendering
+#[allow(dead_code)]
+pub fn generate_torus(
+    major_radius: f32,
+    minor_radius: f32,
+    major_segments: u32,
+    minor_segments: u32,
+    color: [f32; 3],
+) -> (Vec<MeshVertex>, Vec<u32>) {
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+
+    // Generate vertices (шви по обох кутах мають окремі вершини, як у сфері)
+    for i in 0..=major_segments {
+        let major_angle = (i as f32 / major_segments as f32) * std::f32::consts::TAU;
+        let (major_sin, major_cos) = major_angle.sin_cos();
+
+        for j in 0..=minor_segments {
+            let minor_angle = (j as f32 / minor_segments as f32) * std::f32::consts::TAU;
+            let (minor_sin, minor_cos) = minor_angle.sin_cos();
+
+            // Нормаль - від осі трубки назовні
+            let normal = [minor_cos * major_cos, minor_sin, minor_cos * major_sin];
+            let ring = major_radius + minor_radius * minor_cos;
+            vertices.push(MeshVertex {
+                position: [ring * major_cos, minor_radius * minor_sin, ring * major_sin],
+                normal,
+                color,
+            });
+        }
+    }
+
+    // Generate indices
+    for i in 0..major_segments {
+        for j in 0..minor_segments {
+            let current = i * (minor_segments + 1) + j;
+            let next = current + minor_segments + 1;
+
+            // Two triangles per quad
+            indices.push(current);
+            indices.push(current + 1);
+            indices.push(next);
+
+            indices.push(current + 1);
+            indices.push(next + 1);
+            indices.push(next);
+        }
+    }
+
+    (vertices, indices)
+}
+
 /// Генерує сферу з центром в (0, 0, 0)
 ///
 /// # Аргументи
@@ -205,7 +368,7 @@ pub fn generate_cylinder(radius: f32, height: f32, segments: u32, color: [f32; 3
 ///
 /// # Повертає
 /// (vertices, indices) - вершини та індекси для rendering
-pub fn generate_sphere(radius: f32, h_segments: u32, v_segments: u32, color: [f32; 3]) -> (Vec<MeshVertex>, Vec<u16>) {
+pub fn generate_sphere(radius: f32, h_segments: u32, v_segments: u32, color: [f32; 3]) -> (Vec<MeshVertex>, Vec<u32>) {
     let mut vertices = Vec::new();
     let mut indices = Vec::new();
 
@@ -235,13 +398,13 @@ pub fn generate_sphere(radius: f32, h_segments: u32, v_segments: u32, color: [f3
             let next = current + h_segments + 1;
 
             // Two triangles per quad
-            indices.push(current as u16);
-            indices.push(next as u16);
-            indices.push((current + 1) as u16);
+            indices.push(current);
+            indices.push(next);
+            indices.push(current + 1);
 
-            indices.push((current + 1) as u16);
-            indices.push(next as u16);
-            indices.push((next + 1) as u16);
+            indices.push(current + 1);
+            indices.push(next);
+            indices.push(next + 1);
         }
     }
 
@@ -269,7 +432,7 @@ pub fn generate_player_mannequin(
     head_radius: f32,
     body_color: [f32; 3],
     head_color: [f32; 3],
-) -> (Vec<MeshVertex>, Vec<u16>) {
+) -> (Vec<MeshVertex>, Vec<u32>) {
     let segments = 12; // Достатньо для гладкого вигляду
 
     // Генеруємо тіло (циліндр)
@@ -282,7 +445,7 @@ pub fn generate_player_mannequin(
     let head_y_offset = body_height / 2.0 + head_radius * 0.8; // Трохи втоплена в тіло
 
     // Додаємо голову з offset
-    let vertex_offset = vertices.len() as u16;
+    let vertex_offset = next_index(&vertices);
     for mut v in head_vertices {
         v.position[1] += head_y_offset;
         vertices.push(v);
@@ -308,7 +471,7 @@ pub fn generate_player_mannequin(
 pub fn generate_player_body(
     body_color: [f32; 3],
     head_color: [f32; 3],
-) -> (Vec<MeshVertex>, Vec<u16>) {
+) -> (Vec<MeshVertex>, Vec<u32>) {
     let segments = 12;
 
     // Body parameters
@@ -330,7 +493,7 @@ pub fn generate_player_body(
     let (arrow_verts, arrow_idx) = generate_box(0.1, 0.1, 1.5, arrow_color); // Довга коробка
     let arrow_z = -0.75 - body_radius; // Центр стрілки попереду тіла
     let arrow_y = 0.3;
-    let vertex_offset = vertices.len() as u16;
+    let vertex_offset = next_index(&vertices);
     for mut v in arrow_verts {
         v.position[1] += arrow_y;
         v.position[2] += arrow_z;
@@ -343,7 +506,7 @@ pub fn generate_player_body(
     // === HEAD ===
     let (head_verts, head_idx) = generate_sphere(head_radius, segments, segments / 2, head_color);
     let head_y_offset = body_height / 2.0 + head_radius * 0.8;
-    let vertex_offset = vertices.len() as u16;
+    let vertex_offset = next_index(&vertices);
     for mut v in head_verts {
         v.position[1] += head_y_offset;
         vertices.push(v);
@@ -361,7 +524,7 @@ pub fn generate_player_body(
 
     // Простий "ніс" - маленький box
     let (nose_verts, nose_idx) = generate_box(nose_size, nose_size * 0.8, nose_size, face_color);
-    let vertex_offset = vertices.len() as u16;
+    let vertex_offset = next_index(&vertices);
     for mut v in nose_verts {
         v.position[1] += nose_y;
         v.position[2] += nose_z;
@@ -377,18 +540,18 @@ pub fn generate_player_body(
 /// Генерує руку з мечем (для анімації)
 ///
 /// Pivot point (центр обертання) - на плечі (0, 0, 0).
-/// Рука йде вправо (+X), меч направлений вперед (-Z)
+/// Рука йде вправо (+X), меч довжиною `weapon_length` направлений вперед (-Z)
 pub fn generate_weapon_arm(
     arm_color: [f32; 3],
     weapon_color: [f32; 3],
-) -> (Vec<MeshVertex>, Vec<u16>) {
+    weapon_length: f32,
+) -> (Vec<MeshVertex>, Vec<u32>) {
     // Arm parameters
     let arm_radius = 0.08;
     let arm_length = 0.6;
 
     // Weapon parameters
     let weapon_width = 0.08;
-    let weapon_length = 1.0;
 
     let mut vertices = Vec::new();
     let mut indices = Vec::new();
@@ -402,9 +565,7 @@ pub fn generate_weapon_arm(
         v.position[1] = v.position[0];
         v.position[0] = old_y + arm_length / 2.0;  // Зсув щоб початок був на pivot
 
-        let old_ny = v.normal[1];
-        v.normal[1] = v.normal[0];
-        v.normal[0] = old_ny;
+        v.normal.swap(0, 1);
 
         vertices.push(v);
     }
@@ -416,7 +577,7 @@ pub fn generate_weapon_arm(
     let weapon_z = -weapon_length / 2.0; // Центр меча попереду
 
     let (weapon_verts, weapon_idx) = generate_box(weapon_width, weapon_width, weapon_length, weapon_color);
-    let vertex_offset = vertices.len() as u16;
+    let vertex_offset = next_index(&vertices);
     for mut v in weapon_verts {
         v.position[0] += weapon_x;
         v.position[2] += weapon_z;
@@ -436,7 +597,7 @@ pub fn generate_weapon_arm(
 /// * `height` - розмір по Y
 /// * `depth` - розмір по Z
 /// * `color` - колір
-pub fn generate_box(width: f32, height: f32, depth: f32, color: [f32; 3]) -> (Vec<MeshVertex>, Vec<u16>) {
+pub fn generate_box(width: f32, height: f32, depth: f32, color: [f32; 3]) -> (Vec<MeshVertex>, Vec<u32>) {
     let hx = width / 2.0;
     let hy = height / 2.0;
     let hz = depth / 2.0;
@@ -474,7 +635,7 @@ pub fn generate_box(width: f32, height: f32, depth: f32, color: [f32; 3]) -> (Ve
         MeshVertex { position: [-hx,  hy, -hz], normal: [-1.0, 0.0, 0.0], color },
     ];
 
-    let indices: Vec<u16> = vec![
+    let indices: Vec<u32> = vec![
         0, 1, 2,  2, 3, 0,     // Front
         4, 5, 6,  6, 7, 4,     // Back
         8, 9, 10,  10, 11, 8,  // Top
@@ -500,7 +661,8 @@ pub fn generate_box(width: f32, height: f32, depth: f32, color: [f32; 3]) -> (Ve
 /// - 36 індексів (6 граней × 2 трикутники × 3 вершини)
 /// - Нормалі направлені назовні
 /// - CCW winding order
-pub fn generate_cube(size: f32, color: [f32; 3]) -> (Vec<MeshVertex>, Vec<u16>) {
+#[allow(dead_code)]
+pub fn generate_cube(size: f32, color: [f32; 3]) -> (Vec<MeshVertex>, Vec<u32>) {
     let half = size / 2.0;
 
     // 6 граней куба, кожна з 4 вершинами (різні нормалі для кожної грані)
@@ -543,7 +705,7 @@ pub fn generate_cube(size: f32, color: [f32; 3]) -> (Vec<MeshVertex>, Vec<u16>)
     ];
 
     // Індекси для 6 граней (2 трикутники на грань, CCW winding)
-    let indices: Vec<u16> = vec![
+    let indices: Vec<u32> = vec![
         // Front
         0, 1, 2,  2, 3, 0,
         // Back
@@ -561,6 +723,44 @@ pub fn generate_cube(size: f32, color: [f32; 3]) -> (Vec<MeshVertex>, Vec<u16>)
     (vertices, indices)
 }
 
+/// Layout bind group'и з TransformUniform (спільний для Mesh та TexturedMesh)
+pub(super) fn create_transform_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        entries: &[wgpu::BindGroupLayoutEntry {
+            binding: 0,
+            // VERTEX - model matrix, FRAGMENT - tint
+            visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        }],
+        label: Some("transform_bind_group_layout"),
+    })
+}
+
+/// Shader mesh (vertex layout та bind groups однакові)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MeshShader {
+    /// mesh.wgsl - колір вершин з освітленням
+    #[default]
+    Standard,
+    /// ground.wgsl - процедурна шахівниця підлоги (world space XZ)
+    Ground,
+}
+
+impl MeshShader {
+    /// (label, WGSL код)
+    fn source(self) -> (&'static str, &'static str) {
+        match self {
+            MeshShader::Standard => ("Mesh Shader", include_str!("../../assets/shaders/mesh.wgsl")),
+            MeshShader::Ground => ("Ground Shader", include_str!("../../assets/shaders/ground.wgsl")),
+        }
+    }
+}
+
 /// Mesh struct для рендерингу 3D об'єктів
 pub struct Mesh {
     vertex_buffer: wgpu::Buffer,
@@ -568,13 +768,23 @@ pub struct Mesh {
     num_indices: u32,
     render_pipeline: wgpu::RenderPipeline,
 
+    /// Fill або Line (wireframe, потребує Features::POLYGON_MODE_LINE)
+    polygon_mode: wgpu::PolygonMode,
+
+    /// Fragment shader (set_shader)
+    shader: MeshShader,
+
     /// Transform для позиціонування mesh
     pub transform: Transform,
 
+    /// Tint (RGBA множник кольору). alpha < 1.0 = напівпрозорий mesh
+    pub tint: [f32; 4],
+
     /// Transform uniform buffer
     transform_uniform: TransformUniform,
     transform_buffer: wgpu::Buffer,
     transform_bind_group: wgpu::BindGroup,
+    transform_bind_group_layout: wgpu::BindGroupLayout,
 }
 
 impl Mesh {
@@ -586,14 +796,19 @@ impl Mesh {
     /// * `vertices` - Вершини mesh
     /// * `indices` - Індекси для indexed drawing
     /// * `camera_bind_group_layout` - Layout для camera uniform
+    /// * `light_bind_group_layout` - Layout для light uniform
     /// * `transform` - Початковий transform для mesh
+    /// * `sample_count` - MSAA samples render target'а
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         device: &wgpu::Device,
         config: &wgpu::SurfaceConfiguration,
         vertices: &[MeshVertex],
-        indices: &[u16],
+        indices: &[u32],
         camera_bind_group_layout: &wgpu::BindGroupLayout,
+        light_bind_group_layout: &wgpu::BindGroupLayout,
         transform: Transform,
+        sample_count: u32,
     ) -> Self {
         // Vertex buffer
         let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
@@ -620,20 +835,7 @@ impl Mesh {
         });
 
         // Transform bind group layout
-        let transform_bind_group_layout =
-            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
-                entries: &[wgpu::BindGroupLayoutEntry {
-                    binding: 0,
-                    visibility: wgpu::ShaderStages::VERTEX,
-                    ty: wgpu::BindingType::Buffer {
-                        ty: wgpu::BufferBindingType::Uniform,
-                        has_dynamic_offset: false,
-                        min_binding_size: None,
-                    },
-                    count: None,
-                }],
-                label: Some("transform_bind_group_layout"),
-            });
+        let transform_bind_group_layout = create_transform_bind_group_layout(device);
 
         // Transform bind group
         let transform_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
@@ -645,21 +847,92 @@ impl Mesh {
             label: Some("transform_bind_group"),
         });
 
+        let render_pipeline = Self::create_pipeline(
+            device,
+            config,
+            camera_bind_group_layout,
+            &transform_bind_group_layout,
+            light_bind_group_layout,
+            sample_count,
+            wgpu::PolygonMode::Fill,
+            MeshShader::Standard,
+        );
+
+        Self {
+            vertex_buffer,
+            index_buffer,
+            num_indices: indices.len() as u32,
+            render_pipeline,
+            polygon_mode: wgpu::PolygonMode::Fill,
+            shader: MeshShader::Standard,
+            transform,
+            tint: [1.0; 4],
+            transform_uniform,
+            transform_buffer,
+            transform_bind_group,
+            transform_bind_group_layout,
+        }
+    }
+
+    /// Створює Mesh з Wavefront .obj (колір DEFAULT_OBJ_COLOR, змінюється через tint)
+    ///
+    /// # Аргументи
+    /// * `path` - шлях до .obj (v / vn / f; полігони тріангулюються)
+    /// * решта - як у Mesh::new
+    ///
+    /// # Повертає
+    /// Err з описом, якщо файл не читається або некоректний
+    #[allow(dead_code)]
+    pub fn from_obj(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        path: impl AsRef<std::path::Path>,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        light_bind_group_layout: &wgpu::BindGroupLayout,
+        transform: Transform,
+        sample_count: u32,
+    ) -> Result<Self, String> {
+        let (vertices, indices) = load_obj(path, DEFAULT_OBJ_COLOR)?;
+        Ok(Self::new(
+            device,
+            config,
+            &vertices,
+            &indices,
+            camera_bind_group_layout,
+            light_bind_group_layout,
+            transform,
+            sample_count,
+        ))
+    }
+
+    /// Render pipeline mesh (camera @ group(0), transform @ group(1), light @ group(2))
+    #[allow(clippy::too_many_arguments)]
+    fn create_pipeline(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        transform_bind_group_layout: &wgpu::BindGroupLayout,
+        light_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+        polygon_mode: wgpu::PolygonMode,
+        shader: MeshShader,
+    ) -> wgpu::RenderPipeline {
         // Shader
+        let (label, source) = shader.source();
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
-            label: Some("Mesh Shader"),
-            source: wgpu::ShaderSource::Wgsl(include_str!("../../assets/shaders/mesh.wgsl").into()),
+            label: Some(label),
+            source: wgpu::ShaderSource::Wgsl(source.into()),
         });
 
-        // Pipeline layout (camera @ group(0), transform @ group(1))
+        // Pipeline layout (camera @ group(0), transform @ group(1), light @ group(2))
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("Mesh Pipeline Layout"),
-            bind_group_layouts: &[camera_bind_group_layout, &transform_bind_group_layout],
+            bind_group_layouts: &[camera_bind_group_layout, transform_bind_group_layout, light_bind_group_layout],
             push_constant_ranges: &[],
         });
 
         // Render pipeline
-        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
             label: Some("Mesh Render Pipeline"),
             layout: Some(&pipeline_layout),
             vertex: wgpu::VertexState {
@@ -673,7 +946,7 @@ impl Mesh {
                 entry_point: Some("fs_main"),
                 targets: &[Some(wgpu::ColorTargetState {
                     format: config.format,
-                    blend: Some(wgpu::BlendState::REPLACE),
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING), // tint.a для fade-out
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
                 compilation_options: wgpu::PipelineCompilationOptions::default(),
@@ -683,7 +956,7 @@ impl Mesh {
                 strip_index_format: None,
                 front_face: wgpu::FrontFace::Ccw,
                 cull_mode: Some(wgpu::Face::Back), // Back-face culling
-                polygon_mode: wgpu::PolygonMode::Fill,
+                polygon_mode,
                 unclipped_depth: false,
                 conservative: false,
             },
@@ -695,42 +968,88 @@ impl Mesh {
                 bias: wgpu::DepthBiasState::default(),
             }),
             multisample: wgpu::MultisampleState {
-                count: 1,
+                count: sample_count,
                 mask: !0,
                 alpha_to_coverage_enabled: false,
             },
             multiview: None,
             cache: None,
-        });
+        })
+    }
 
-        Self {
-            vertex_buffer,
-            index_buffer,
-            num_indices: indices.len() as u32,
-            render_pipeline,
-            transform,
-            transform_uniform,
-            transform_buffer,
-            transform_bind_group,
-        }
+    /// Перестворює pipeline під іншу кількість MSAA samples
+    pub fn set_sample_count(
+        &mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        light_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+    ) {
+        self.render_pipeline = Self::create_pipeline(
+            device,
+            config,
+            camera_bind_group_layout,
+            &self.transform_bind_group_layout,
+            light_bind_group_layout,
+            sample_count,
+            self.polygon_mode,
+            self.shader,
+        );
+    }
+
+    /// Змінює fragment shader (перестворює pipeline)
+    pub fn set_shader(
+        &mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        light_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+        shader: MeshShader,
+    ) {
+        self.shader = shader;
+        self.set_sample_count(device, config, camera_bind_group_layout, light_bind_group_layout, sample_count);
+    }
+
+    /// Перемикає заливку / wireframe (перестворює pipeline)
+    ///
+    /// PolygonMode::Line - лише з Features::POLYGON_MODE_LINE на device.
+    pub fn set_polygon_mode(
+        &mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        light_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+        polygon_mode: wgpu::PolygonMode,
+    ) {
+        self.polygon_mode = polygon_mode;
+        self.set_sample_count(device, config, camera_bind_group_layout, light_bind_group_layout, sample_count);
+    }
+
+    /// Чи mesh видно (alpha = 0 - не малюється взагалі)
+    pub fn is_visible(&self) -> bool {
+        self.tint[3] > 0.0
     }
 
     /// Оновлює transform buffer на GPU
     ///
-    /// Викликайте після зміни self.transform
+    /// Викликайте після зміни self.transform або self.tint
     pub fn update_transform(&mut self, queue: &wgpu::Queue) {
         // DEBUG: log model matrix before upload
         let model = self.transform.model_matrix();
         static mut COUNTER: u32 = 0;
         unsafe {
             COUNTER += 1;
-            if COUNTER % 120 == 0 {
+            if COUNTER.is_multiple_of(120) {
                 log_debug(&format!("GPU upload model[0]: [{:.3}, {:.3}, {:.3}, {:.3}]",
                     model.x_axis.x, model.x_axis.y, model.x_axis.z, model.x_axis.w));
             }
         }
 
         self.transform_uniform.update(&self.transform);
+        self.transform_uniform.tint = self.tint;
         queue.write_buffer(
             &self.transform_buffer,
             0,
@@ -743,12 +1062,106 @@ impl Mesh {
     /// # Аргументи
     /// * `render_pass` - Активний render pass
     /// * `camera_bind_group` - Bind group з camera uniform
-    pub fn render<'a>(&'a self, render_pass: &mut wgpu::RenderPass<'a>, camera_bind_group: &'a wgpu::BindGroup) {
+    /// * `light_bind_group` - Bind group з light uniform
+    pub fn render<'a>(
+        &'a self,
+        render_pass: &mut wgpu::RenderPass<'a>,
+        camera_bind_group: &'a wgpu::BindGroup,
+        light_bind_group: &'a wgpu::BindGroup,
+    ) {
         render_pass.set_pipeline(&self.render_pipeline);
         render_pass.set_bind_group(0, camera_bind_group, &[]);
-        render_pass.set_bind_group(1, &self.transform_bind_group, &[]);
+        render_pass.set_bind_group(2, light_bind_group, &[]);
+        self.draw_geometry(render_pass, 1);
+    }
+
+    /// Малює геометрію mesh з вже встановленим pipeline
+    ///
+    /// Використовується іншими проходами (outline) з тією самою геометрією.
+    ///
+    /// # Аргументи
+    /// * `render_pass` - Активний render pass з встановленим pipeline
+    /// * `transform_group` - Індекс bind group для transform uniform
+    pub fn draw_geometry<'a>(&'a self, render_pass: &mut wgpu::RenderPass<'a>, transform_group: u32) {
+        render_pass.set_bind_group(transform_group, &self.transform_bind_group, &[]);
         render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
-        render_pass.set_index_buffer(self.index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+        render_pass.set_index_buffer(self.index_buffer.slice(..), wgpu::IndexFormat::Uint32);
         render_pass.draw_indexed(0..self.num_indices, 0, 0..1);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLOR: [f32; 3] = [1.0, 1.0, 1.0];
+
+    /// Усі індекси в межах вершин, трикутники цілі
+    fn assert_valid(vertices: &[MeshVertex], indices: &[u32]) {
+        assert_eq!(indices.len() % 3, 0);
+        assert!(indices.iter().all(|&i| (i as usize) < vertices.len()), "індекс поза вершинами");
+    }
+
+    #[test]
+    fn high_poly_sphere_exceeds_u16() {
+        let (vertices, indices) = generate_sphere(1.0, 512, 256, COLOR);
+        assert_eq!(vertices.len(), 513 * 257);
+        assert_eq!(indices.len(), 512 * 256 * 6);
+        assert_valid(&vertices, &indices);
+        // Останні вершини адресуються - індекси не обрізаються до u16
+        let max_index = *indices.iter().max().unwrap();
+        assert!(max_index > u16::MAX as u32);
+        assert_eq!(max_index as usize, vertices.len() - 1);
+    }
+
+    #[test]
+    fn primitives_have_valid_indices() {
+        let meshes = [
+            generate_box(1.0, 2.0, 3.0, COLOR),
+            generate_cylinder(0.5, 1.0, 16, COLOR),
+            generate_cone(0.5, 1.0, 16, COLOR),
+            generate_torus(1.0, 0.2, 24, 12, COLOR),
+            generate_sphere(1.0, 16, 8, COLOR),
+        ];
+        for (vertices, indices) in &meshes {
+            assert!(!indices.is_empty());
+            assert_valid(vertices, indices);
+        }
+    }
+
+    /// Нормалі одиничні, трикутники CCW назовні (геометрична нормаль ↔ нормаль вершин)
+    fn assert_normals_and_winding(vertices: &[MeshVertex], indices: &[u32]) {
+        for vertex in vertices {
+            let length = glam::Vec3::from(vertex.normal).length();
+            assert!((length - 1.0).abs() < 1e-5, "нормаль довжини {}", length);
+        }
+        for triangle in indices.chunks(3) {
+            let [a, b, c] = [0, 1, 2].map(|k| vertices[triangle[k] as usize]);
+            let face = (glam::Vec3::from(b.position) - glam::Vec3::from(a.position))
+                .cross(glam::Vec3::from(c.position) - glam::Vec3::from(a.position));
+            let normal = glam::Vec3::from(a.normal) + glam::Vec3::from(b.normal) + glam::Vec3::from(c.normal);
+            assert!(face.dot(normal) > 0.0, "трикутник {:?} не CCW назовні", triangle);
+        }
+    }
+
+    #[test]
+    fn cone_counts_and_normals() {
+        let segments = 12;
+        let (vertices, indices) = generate_cone(0.5, 1.0, segments, COLOR);
+        // Бічна поверхня + основа: по трикутнику на сегмент
+        assert_eq!(indices.len(), (segments * 3 * 2) as usize);
+        assert_eq!(vertices.len(), (segments + 1 + segments + 1 + segments + 1) as usize);
+        assert_valid(&vertices, &indices);
+        assert_normals_and_winding(&vertices, &indices);
+    }
+
+    #[test]
+    fn torus_counts_and_normals() {
+        let (major, minor) = (24, 12);
+        let (vertices, indices) = generate_torus(1.0, 0.25, major, minor, COLOR);
+        assert_eq!(indices.len(), (major * minor * 6) as usize);
+        assert_eq!(vertices.len(), ((major + 1) * (minor + 1)) as usize);
+        assert_valid(&vertices, &indices);
+        assert_normals_and_winding(&vertices, &indices);
+    }
+}
@@ -34,11 +34,31 @@
 pub mod renderer;
 pub mod grid;
 pub mod mesh;
+pub mod instanced_mesh;
 pub mod skeleton_renderer;
 pub mod screenshot;
+pub mod outline;
+pub mod debug_lines;
+pub mod health_bar;
+pub mod light;
+pub mod shadow;
+pub mod obj;
+pub mod skybox;
+pub mod debug_hud;
+// Текстуровані mesh - API для моделей з текстурами, в сцені ще не використовується
+#[allow(dead_code)]
+pub mod texture;
+#[allow(dead_code)]
+pub mod textured_mesh;
 
 // Реєкспортуємо для зручності
 pub use renderer::WgpuRenderer;
+pub use grid::DEFAULT_GRID_Y_OFFSET;
+#[allow(unused_imports)]
 pub use grid::Grid;
+#[allow(unused_imports)]
 pub use mesh::{Mesh, MeshVertex, generate_cube};
+#[allow(unused_imports)]
 pub use skeleton_renderer::SkeletonRenderer;
+pub use outline::OutlineTarget;
+pub use debug_lines::RagdollDebug;
@@ -0,0 +1,252 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/rendering/obj.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   Завантаження Wavefront .obj у MeshVertex / u32 індекси.
+
+   Замість процедурних примітивів (generate_box, generate_cylinder) -
+   моделі props арени та зброї з файлів (Mesh::from_obj).
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Парсинг v / vn / f (інші рядки - vt, o, g, s, usemtl - ігноруються)
+   - Тріангуляція полігонів (quads та опуклі n-кутники - віялом)
+   - Flat нормалі для граней без vn
+   - Спільні вершини для однакових пар (позиція, нормаль)
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Експортує для:
+   - mesh.rs - Mesh::from_obj
+
+⚠️  ВАЖЛИВІ ОБМЕЖЕННЯ:
+   1. Колір один на всю модель (матеріали .mtl не читаються)
+   2. Winding - CCW (як в OBJ та в решті mesh)
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - .obj loader для Mesh
+   2026-10-16: Індекси u32 (без обмеження 65536 вершин)
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use glam::Vec3;
+
+use super::mesh::MeshVertex;
+
+/// Колір вершин моделі з .obj (перефарбовується через Mesh::tint)
+pub const DEFAULT_OBJ_COLOR: [f32; 3] = [0.7, 0.7, 0.7];
+
+/// Завантажує .obj файл
+///
+/// # Повертає
+/// (вершини, індекси трикутників) або опис помилки з номером рядка
+pub fn load_obj(path: impl AsRef<Path>, color: [f32; 3]) -> Result<(Vec<MeshVertex>, Vec<u32>), String> {
+    let path = path.as_ref();
+    let text = std::fs::read_to_string(path).map_err(|e| format!("{:?}: {}", path, e))?;
+    parse_obj(&text, color).map_err(|e| format!("{:?}: {}", path, e))
+}
+
+/// Парсить текст .obj
+pub fn parse_obj(text: &str, color: [f32; 3]) -> Result<(Vec<MeshVertex>, Vec<u32>), String> {
+    let mut positions: Vec<Vec3> = Vec::new();
+    let mut normals: Vec<Vec3> = Vec::new();
+    let mut vertices: Vec<MeshVertex> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+
+    // (позиція, нормаль) → індекс вершини; грані без vn вершини не діляться
+    let mut shared: HashMap<(usize, usize), u32> = HashMap::new();
+
+    for (line_no, raw_line) in text.lines().enumerate() {
+        let line_no = line_no + 1;
+        let line = raw_line.split('#').next().unwrap_or("").trim();
+        let mut parts = line.split_whitespace();
+        let Some(keyword) = parts.next() else {
+            continue;
+        };
+
+        match keyword {
+            "v" => positions.push(parse_vec3(parts, line_no)?),
+            "vn" => normals.push(parse_vec3(parts, line_no)?.normalize_or_zero()),
+            "f" => {
+                let corners = parts
+                    .map(|corner| parse_corner(corner, positions.len(), normals.len(), line_no))
+                    .collect::<Result<Vec<_>, _>>()?;
+                if corners.len() < 3 {
+                    return Err(format!("рядок {}: грань з {} вершинами", line_no, corners.len()));
+                }
+
+                // Без нормалей - flat нормаль першого трикутника грані
+                let face_normal = if corners.iter().all(|(_, normal)| normal.is_some()) {
+                    None
+                } else {
+                    let (a, b, c) = (positions[corners[0].0], positions[corners[1].0], positions[corners[2].0]);
+                    Some((b - a).cross(c - a).normalize_or(Vec3::Y))
+                };
+
+                let mut face_indices = Vec::with_capacity(corners.len());
+                for &(position, normal) in &corners {
+                    let index = match (normal, face_normal) {
+                        (Some(normal), None) => match shared.get(&(position, normal)) {
+                            Some(&index) => index,
+                            None => {
+                                let index = push_vertex(&mut vertices, positions[position], normals[normal], color, line_no)?;
+                                shared.insert((position, normal), index);
+                                index
+                            }
+                        },
+                        _ => {
+                            let normal = face_normal.unwrap_or(Vec3::Y);
+                            push_vertex(&mut vertices, positions[position], normal, color, line_no)?
+                        }
+                    };
+                    face_indices.push(index);
+                }
+
+                // Віяло: (0, i, i + 1)
+                for i in 1..face_indices.len() - 1 {
+                    indices.extend_from_slice(&[face_indices[0], face_indices[i], face_indices[i + 1]]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if indices.is_empty() {
+        return Err("немає граней (f)".to_string());
+    }
+    Ok((vertices, indices))
+}
+
+/// Додає вершину; більше u32::MAX + 1 вершин - помилка
+fn push_vertex(
+    vertices: &mut Vec<MeshVertex>,
+    position: Vec3,
+    normal: Vec3,
+    color: [f32; 3],
+    line_no: usize,
+) -> Result<u32, String> {
+    let index = u32::try_from(vertices.len())
+        .map_err(|_| format!("рядок {}: модель має більше {} вершин", line_no, u32::MAX as u64 + 1))?;
+    vertices.push(MeshVertex {
+        position: position.to_array(),
+        normal: normal.to_array(),
+        color,
+    });
+    Ok(index)
+}
+
+/// "x y z" → Vec3
+fn parse_vec3<'a>(mut parts: impl Iterator<Item = &'a str>, line_no: usize) -> Result<Vec3, String> {
+    let mut component = || -> Result<f32, String> {
+        parts
+            .next()
+            .and_then(|value| value.parse().ok())
+            .ok_or_else(|| format!("рядок {}: очікується 3 числа", line_no))
+    };
+    Ok(Vec3::new(component()?, component()?, component()?))
+}
+
+/// Вершина грані "v", "v/vt", "v//vn", "v/vt/vn" → (індекс позиції, індекс нормалі)
+///
+/// Індекси OBJ з 1, від'ємні - з кінця списку.
+fn parse_corner(
+    corner: &str,
+    position_count: usize,
+    normal_count: usize,
+    line_no: usize,
+) -> Result<(usize, Option<usize>), String> {
+    let mut fields = corner.split('/');
+    let position = resolve_index(fields.next().unwrap_or(""), position_count, line_no)?;
+    let _texcoord = fields.next();
+    let normal = match fields.next() {
+        Some(field) if !field.is_empty() => Some(resolve_index(field, normal_count, line_no)?),
+        _ => None,
+    };
+    Ok((position, normal))
+}
+
+/// Індекс OBJ (1-based або від'ємний) → індекс у списку
+fn resolve_index(field: &str, count: usize, line_no: usize) -> Result<usize, String> {
+    let raw: i64 = field
+        .parse()
+        .map_err(|_| format!("рядок {}: некоректний індекс {:?}", line_no, field))?;
+    let index = if raw < 0 { count as i64 + raw } else { raw - 1 };
+    if index < 0 || index >= count as i64 {
+        return Err(format!("рядок {}: індекс {} поза межами (є {})", line_no, raw, count));
+    }
+    Ok(index as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Куб 1×1×1 з quad-гранями без нормалей (CCW назовні)
+    const CUBE_OBJ: &str = "\
+# cube
+o cube
+v -0.5 -0.5 -0.5
+v  0.5 -0.5 -0.5
+v  0.5  0.5 -0.5
+v -0.5  0.5 -0.5
+v -0.5 -0.5  0.5
+v  0.5 -0.5  0.5
+v  0.5  0.5  0.5
+v -0.5  0.5  0.5
+f 1 4 3 2
+f 5 6 7 8
+f 1 5 8 4
+f 2 3 7 6
+f 1 2 6 5
+f 4 8 7 3
+";
+
+    #[test]
+    fn cube_obj_vertex_and_index_counts() {
+        let path = std::env::temp_dir().join(format!("arena_combat_cube_{}.obj", std::process::id()));
+        std::fs::write(&path, CUBE_OBJ).unwrap();
+        let loaded = load_obj(&path, DEFAULT_OBJ_COLOR);
+        std::fs::remove_file(&path).ok();
+
+        let (vertices, indices) = loaded.expect("cube.obj не завантажився");
+        // 6 quad-граней без vn - по 4 власні вершини, по 2 трикутники
+        assert_eq!(vertices.len(), 24);
+        assert_eq!(indices.len(), 36);
+        assert!(indices.iter().all(|&i| (i as usize) < vertices.len()));
+    }
+
+    #[test]
+    fn missing_normals_are_flat_and_outward() {
+        let (vertices, _) = parse_obj(CUBE_OBJ, DEFAULT_OBJ_COLOR).unwrap();
+        for vertex in &vertices {
+            let normal = Vec3::from(vertex.normal);
+            let position = Vec3::from(vertex.position);
+            // Нормаль грані - одна з осей, назовні від центру
+            assert!((normal.length() - 1.0).abs() < 1e-5);
+            assert!((normal.abs().max_element() - 1.0).abs() < 1e-5);
+            assert!(normal.dot(position) > 0.0, "нормаль всередину: {:?} в {:?}", normal, position);
+        }
+    }
+
+    #[test]
+    fn shared_normals_reuse_vertices() {
+        let text = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nvn 0 0 1\nf 1//1 2//1 3//1\nf 1//1 3//1 4//1\n";
+        let (vertices, indices) = parse_obj(text, DEFAULT_OBJ_COLOR).unwrap();
+        assert_eq!(vertices.len(), 4);
+        assert_eq!(indices, vec![0, 1, 2, 0, 2, 3]);
+    }
+
+    #[test]
+    fn malformed_obj_reports_line() {
+        let err = parse_obj("v 0 0 0\nv 1 0\n", DEFAULT_OBJ_COLOR).unwrap_err();
+        assert!(err.contains("рядок 2"), "{}", err);
+        let err = parse_obj("v 0 0 0\nv 1 0 0\nf 1 2 5\n", DEFAULT_OBJ_COLOR).unwrap_err();
+        assert!(err.contains("рядок 3"), "{}", err);
+        assert!(parse_obj("v 0 0 0\n", DEFAULT_OBJ_COLOR).is_err(), "файл без граней");
+        assert!(load_obj("does/not/exist.obj", DEFAULT_OBJ_COLOR).is_err());
+    }
+}
@@ -0,0 +1,333 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/rendering/outline.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   OutlineRenderer - контур (силует) навколо гравця та цільового ворога.
+
+   ПІДХІД: Inverted hull
+   - Та сама геометрія роздувається вздовж нормалей (shader)
+   - Малюються тільки back-faces (cull Front) суцільним кольором
+   - Основний mesh перекриває середину → видно тільки контур
+   - Не потребує stencil чи post-process проходу
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Pipeline для Mesh (player mannequin) та InstancedMesh (enemies)
+   - Pipeline для капсул скелета (instanced)
+   - Uniform з кольором та товщиною контуру
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Імпортує:
+   - mesh.rs - MeshVertex layout, Mesh::draw_geometry
+   - instanced_mesh.rs - MeshInstance layout, InstancedMesh::draw_instances
+   - skeleton_renderer.rs - CapsuleVertex/BoneInstance layouts
+
+   Експортує для:
+   - renderer.rs - WgpuRenderer::set_outline_targets
+
+⚠️  ВАЖЛИВІ ОБМЕЖЕННЯ:
+   1. Малювати ПІСЛЯ основних meshes (depth вже заповнений)
+   2. Товщина в world units, не в пікселях (далекі об'єкти - тонший контур)
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - inverted hull outline
+   2026-10-16: MSAA - sample_count у pipelines (set_sample_count)
+   2026-10-16: render_instance - контур одного екземпляра InstancedMesh
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use wgpu::util::DeviceExt;
+
+use super::mesh::{Mesh, MeshVertex};
+use super::instanced_mesh::{InstancedMesh, MeshInstance};
+use super::skeleton_renderer::{CapsuleVertex, BoneInstance, SkeletonRenderer};
+
+/// Що саме обводити контуром
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutlineTarget {
+    /// Гравець (ragdoll скелет або mannequin mesh)
+    Player,
+    /// Ворог за індексом в `enemies`
+    Enemy(usize),
+}
+
+/// Uniform з параметрами контуру
+#[repr(C)]
+#[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+struct OutlineUniform {
+    color: [f32; 4],
+    /// x = товщина (world units)
+    params: [f32; 4],
+}
+
+/// Renderer контурів
+pub struct OutlineRenderer {
+    /// Колір контуру (RGBA)
+    pub color: [f32; 4],
+
+    /// Товщина контуру в world units
+    pub width: f32,
+
+    uniform_buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+    mesh_pipeline: wgpu::RenderPipeline,
+    instanced_pipeline: wgpu::RenderPipeline,
+    skeleton_pipeline: wgpu::RenderPipeline,
+}
+
+impl OutlineRenderer {
+    pub fn new(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+    ) -> Self {
+        let color = [1.0, 0.85, 0.2, 1.0]; // Жовтий
+        let width = 0.025;
+
+        let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Outline Uniform Buffer"),
+            contents: bytemuck::cast_slice(&[OutlineUniform {
+                color,
+                params: [width, 0.0, 0.0, 0.0],
+            }]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let uniform_layout = Self::uniform_layout(device, "outline_bind_group_layout", wgpu::ShaderStages::VERTEX_FRAGMENT);
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout: &uniform_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: uniform_buffer.as_entire_binding(),
+            }],
+            label: Some("outline_bind_group"),
+        });
+
+        let (mesh_pipeline, instanced_pipeline, skeleton_pipeline) =
+            Self::create_pipelines(device, config, camera_bind_group_layout, sample_count);
+
+        Self {
+            color,
+            width,
+            uniform_buffer,
+            bind_group,
+            mesh_pipeline,
+            instanced_pipeline,
+            skeleton_pipeline,
+        }
+    }
+
+    /// Перестворює pipelines під іншу кількість MSAA samples
+    pub fn set_sample_count(
+        &mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+    ) {
+        (self.mesh_pipeline, self.instanced_pipeline, self.skeleton_pipeline) =
+            Self::create_pipelines(device, config, camera_bind_group_layout, sample_count);
+    }
+
+    /// Pipelines контуру: (mesh, instanced mesh, капсули скелета)
+    fn create_pipelines(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+    ) -> (wgpu::RenderPipeline, wgpu::RenderPipeline, wgpu::RenderPipeline) {
+        // Ті самі layouts що в new та Mesh (wgpu дедуплікує ідентичні layouts)
+        let uniform_layout = Self::uniform_layout(device, "outline_bind_group_layout", wgpu::ShaderStages::VERTEX_FRAGMENT);
+        let transform_layout = Self::uniform_layout(device, "transform_bind_group_layout", wgpu::ShaderStages::VERTEX_FRAGMENT);
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Outline Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../../assets/shaders/outline.wgsl").into()),
+        });
+
+        let mesh_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Outline Mesh Pipeline Layout"),
+            bind_group_layouts: &[camera_bind_group_layout, &uniform_layout, &transform_layout],
+            push_constant_ranges: &[],
+        });
+
+        let skeleton_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Outline Skeleton Pipeline Layout"),
+            bind_group_layouts: &[camera_bind_group_layout, &uniform_layout],
+            push_constant_ranges: &[],
+        });
+
+        let mesh_pipeline = Self::create_pipeline(
+            device,
+            config,
+            &shader,
+            &mesh_layout,
+            "vs_mesh",
+            &[MeshVertex::vertex_buffer_layout()],
+            "Outline Mesh Pipeline",
+            sample_count,
+        );
+
+        let instanced_pipeline = Self::create_pipeline(
+            device,
+            config,
+            &shader,
+            &skeleton_layout,
+            "vs_mesh_instanced",
+            &[
+                MeshVertex::vertex_buffer_layout(),
+                MeshInstance::instance_buffer_layout(),
+            ],
+            "Outline Instanced Mesh Pipeline",
+            sample_count,
+        );
+
+        let skeleton_pipeline = Self::create_pipeline(
+            device,
+            config,
+            &shader,
+            &skeleton_layout,
+            "vs_skeleton",
+            &[
+                CapsuleVertex::vertex_buffer_layout(),
+                BoneInstance::instance_buffer_layout(),
+            ],
+            "Outline Skeleton Pipeline",
+            sample_count,
+        );
+
+        (mesh_pipeline, instanced_pipeline, skeleton_pipeline)
+    }
+
+    fn uniform_layout(
+        device: &wgpu::Device,
+        label: &str,
+        visibility: wgpu::ShaderStages,
+    ) -> wgpu::BindGroupLayout {
+        device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+            label: Some(label),
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn create_pipeline(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        shader: &wgpu::ShaderModule,
+        layout: &wgpu::PipelineLayout,
+        vs_entry: &str,
+        buffers: &[wgpu::VertexBufferLayout],
+        label: &str,
+        sample_count: u32,
+    ) -> wgpu::RenderPipeline {
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some(label),
+            layout: Some(layout),
+            vertex: wgpu::VertexState {
+                module: shader,
+                entry_point: Some(vs_entry),
+                buffers,
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: config.format,
+                    blend: Some(wgpu::BlendState::REPLACE),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: Some(wgpu::Face::Front), // Inverted hull - тільки back-faces
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float,
+                depth_write_enabled: true,
+                depth_compare: wgpu::CompareFunction::LessEqual,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            multiview: None,
+            cache: None,
+        })
+    }
+
+    /// Завантажує колір та товщину в uniform (викликати перед render pass)
+    pub fn update_uniform(&self, queue: &wgpu::Queue) {
+        let uniform = OutlineUniform {
+            color: self.color,
+            params: [self.width, 0.0, 0.0, 0.0],
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::cast_slice(&[uniform]));
+    }
+
+    /// Малює контур навколо Mesh
+    pub fn render_mesh<'a>(
+        &'a self,
+        render_pass: &mut wgpu::RenderPass<'a>,
+        camera_bind_group: &'a wgpu::BindGroup,
+        mesh: &'a Mesh,
+    ) {
+        render_pass.set_pipeline(&self.mesh_pipeline);
+        render_pass.set_bind_group(0, camera_bind_group, &[]);
+        render_pass.set_bind_group(1, &self.bind_group, &[]);
+        mesh.draw_geometry(render_pass, 2);
+    }
+
+    /// Малює контур навколо одного екземпляра InstancedMesh
+    ///
+    /// # Аргументи
+    /// * `slot` - InstancedMesh::instance_slot екземпляра
+    pub fn render_instance<'a>(
+        &'a self,
+        render_pass: &mut wgpu::RenderPass<'a>,
+        camera_bind_group: &'a wgpu::BindGroup,
+        mesh: &'a InstancedMesh,
+        slot: u32,
+    ) {
+        render_pass.set_pipeline(&self.instanced_pipeline);
+        render_pass.set_bind_group(0, camera_bind_group, &[]);
+        render_pass.set_bind_group(1, &self.bind_group, &[]);
+        mesh.draw_instances(render_pass, slot..slot + 1);
+    }
+
+    /// Малює контур навколо всього скелета
+    pub fn render_skeleton<'a>(
+        &'a self,
+        render_pass: &mut wgpu::RenderPass<'a>,
+        camera_bind_group: &'a wgpu::BindGroup,
+        skeleton: &'a SkeletonRenderer,
+    ) {
+        render_pass.set_pipeline(&self.skeleton_pipeline);
+        render_pass.set_bind_group(0, camera_bind_group, &[]);
+        render_pass.set_bind_group(1, &self.bind_group, &[]);
+        skeleton.draw_instances(render_pass);
+    }
+}
@@ -56,6 +56,27 @@
 
 🕐 ІСТОРІЯ:
    2025-12-14: Створено - базова ініціалізація wgpu + clear color
+   2026-10-16: Скелети ворогів з ragdoll (enemy_skeleton_renderer) замість манекенів
+   2026-10-16: Mesh меча перебудовується під довжину леза зброї (Weapon::reach)
+   2026-10-16: Замах ворога - tint до ENEMY_WINDUP_TINT (телеграф удару)
+   2026-10-16: Спалах влученого ворога (HIT_FLASH_TINT)
+   2026-10-16: Смуги здоров'я над живими ворогами (HealthBarRenderer)
+   2026-10-16: Directional light (pub light) - uniform для mesh та скелетів
+   2026-10-16: Shadow map pre-pass (ShadowMap) + підлога арени, що приймає тіні
+   2026-10-16: MSAA (set_sample_count) - multisampled color/depth + resolve
+   2026-10-16: Маркер lock-on над захопленим ворогом (set_lock_on_marker)
+   2026-10-16: Градієнтне небо (pub skybox) першим у кадрі замість clear color
+   2026-10-16: Wireframe mesh та скелетів (set_wireframe, POLYGON_MODE_LINE)
+   2026-10-16: Debug HUD (pub debug_hud) - текстовий оверлей після сцени
+   2026-10-16: Вороги - один InstancedMesh (enemy_mesh) замість Mesh на ворога
+   2026-10-16: Скелети з NaN / нескінченними transforms не оновлюються
+   2026-10-16: set_light; обертання сонця в update_animations
+   2026-10-16: Підлога - ground.wgsl (шахівниця), mesh стін арени (set_arena),
+               grid - debug оверлей (show_grid)
+   2026-10-16: Present mode (set_present_mode / cycle_present_mode) - surface
+               переконфігурується без перестворення device
+   2026-10-16: Screenshot на вимогу (request_screenshot) - debug/screenshot_*.png
+   2026-10-17: Стіни арени з поворотом (WallBox) - сегменти круглої арени
 
 ═══════════════════════════════════════════════════════════════════════════════
 */
@@ -68,16 +89,102 @@ use winit::window::Window;
 use crate::camera::{Camera, CameraUniform};
 use crate::transform::Transform;
 use crate::player::Player;
-use crate::combat::Combat;
+use crate::combat::{Combat, Weapon};
 use crate::enemy::Enemy;
 use crate::debug_log::log_debug;
 use crate::physics::BoneId;
-use super::grid::Grid;
-use super::mesh::{Mesh, generate_player_mannequin, generate_player_body, generate_weapon_arm};
-use super::skeleton_renderer::SkeletonRenderer;
-use super::screenshot::FirstFrameCapture;
+use crate::particles::ParticleSystem;
+use crate::quality::QualityTier;
+use crate::arena::ArenaConfig;
+use super::grid::{Grid, DEFAULT_GRID_Y_OFFSET};
+use super::skybox::Skybox;
+use super::debug_hud::DebugHud;
+use super::instanced_mesh::{InstancedMesh, MeshInstance};
+use super::mesh::{Mesh, MeshShader, generate_box, generate_player_mannequin, generate_player_body, generate_sphere, generate_weapon_arm};
+use super::skeleton_renderer::{BoneTransform, SkeletonRenderer};
+use super::screenshot::{FirstFrameCapture, ScreenshotCapture};
+use super::outline::{OutlineRenderer, OutlineTarget};
+use super::debug_lines::{DebugLineRenderer, RagdollDebug};
+use super::health_bar::{HealthBarInstance, HealthBarRenderer};
+use super::light::{create_light_bind_group_layout, Light, LightUniform};
+use super::shadow::{ShadowMap, DEFAULT_SHADOW_RESOLUTION};
 use glam::{Vec3, Quat};
 
+/// Tint скелета живого ворога (червонуватий - відрізнити від гравця)
+const ENEMY_SKELETON_TINT: [f32; 3] = [1.0, 0.55, 0.55];
+
+/// Tint скелета мертвого ворога (темніший)
+const DEAD_ENEMY_SKELETON_TINT: [f32; 3] = [0.45, 0.3, 0.3];
+
+/// Висота центру смуги здоров'я над позицією ворога (над головою)
+const HEALTH_BAR_OFFSET_Y: f32 = 2.1;
+
+/// Половина розміру арени до set_arena (як grid за замовчуванням)
+const DEFAULT_ARENA_HALF_EXTENT: f32 = 20.0;
+
+/// Колір підлоги: після освітлення зверху (~0.9) ≈ колір неба на горизонті
+const FLOOR_COLOR: [f32; 3] = [0.11, 0.22, 0.33];
+
+/// Товщина підлоги (верх - на Y=0)
+const FLOOR_THICKNESS: f32 = 0.02;
+
+/// Колір стін арени
+const WALL_COLOR: [f32; 3] = [0.32, 0.3, 0.28];
+
+/// MSAA samples за замовчуванням (якщо адаптер підтримує)
+pub const DEFAULT_SAMPLE_COUNT: u32 = 4;
+
+/// Директорія screenshot'ів на вимогу
+const SCREENSHOT_DIR: &str = "debug";
+
+/// Present modes, між якими можна перемикатись (порядок cycle_present_mode)
+const PRESENT_MODES: [wgpu::PresentMode; 3] =
+    [wgpu::PresentMode::Fifo, wgpu::PresentMode::Mailbox, wgpu::PresentMode::Immediate];
+
+/// Назва present mode ([rendering] present_mode, заголовок вікна)
+pub fn present_mode_name(mode: wgpu::PresentMode) -> &'static str {
+    match mode {
+        wgpu::PresentMode::Fifo => "fifo",
+        wgpu::PresentMode::Mailbox => "mailbox",
+        wgpu::PresentMode::Immediate => "immediate",
+        wgpu::PresentMode::FifoRelaxed => "fifo_relaxed",
+        wgpu::PresentMode::AutoVsync => "auto_vsync",
+        wgpu::PresentMode::AutoNoVsync => "auto_no_vsync",
+    }
+}
+
+/// Present mode з назви (None - невідома назва)
+pub fn present_mode_from_name(name: &str) -> Option<wgpu::PresentMode> {
+    PRESENT_MODES
+        .into_iter()
+        .find(|&mode| present_mode_name(mode).eq_ignore_ascii_case(name.trim()))
+}
+
+/// Маркер lock-on: колір та висота над ногами ворога (над смугою здоров'я)
+const LOCK_ON_MARKER_COLOR: [f32; 3] = [1.0, 0.85, 0.1];
+const LOCK_ON_MARKER_OFFSET_Y: f32 = 2.45;
+
+/// Tint ворога в кінці замаху (Enemy::windup_progress = 1) - телеграф удару
+const ENEMY_WINDUP_TINT: [f32; 3] = [1.0, 0.1, 0.05];
+
+/// Tint влученого ворога (Enemy::hit_flash = 1) - більше 1, колір до білого
+const HIT_FLASH_TINT: [f32; 3] = [3.0, 2.4, 2.2];
+
+/// Tint → ENEMY_WINDUP_TINT за прогресом замаху
+fn windup_tint(base: [f32; 3], windup: f32) -> [f32; 3] {
+    Vec3::from(base).lerp(Vec3::from(ENEMY_WINDUP_TINT), windup).to_array()
+}
+
+/// Чи всі transforms скінченні (NaN на GPU - зламаний кадр)
+fn transforms_finite(bone_transforms: &[BoneTransform]) -> bool {
+    bone_transforms.iter().all(|(_, position, rotation)| position.is_finite() && rotation.is_finite())
+}
+
+/// Tint → HIT_FLASH_TINT за силою спалаху від удару
+fn hit_flash_tint(base: [f32; 3], flash: f32) -> [f32; 3] {
+    Vec3::from(base).lerp(Vec3::from(HIT_FLASH_TINT), flash).to_array()
+}
+
 /// Основний renderer на базі wgpu
 ///
 /// Структура містить всі необхідні wgpu об'єкти для рендерінгу.
@@ -113,13 +220,58 @@ pub struct WgpuRenderer {
     /// Bind group для camera
     camera_bind_group: wgpu::BindGroup,
 
+    /// Directional light сцени (завантажується на GPU кожен кадр)
+    pub light: Light,
+
+    /// Light uniform buffer на GPU
+    light_buffer: wgpu::Buffer,
+
+    /// Bind group для light
+    light_bind_group: wgpu::BindGroup,
+
+    /// Light bind group layout (для створення нових mesh)
+    light_bind_group_layout: wgpu::BindGroupLayout,
+
+    /// Shadow map directional light (pre-pass кожен кадр)
+    shadow_map: ShadowMap,
+
+    /// Підлога арени - приймає тіні (сама тінь не кидає)
+    floor: Mesh,
+
+    /// Стіни арени (ArenaConfig::wall_boxes, ті самі, що й colliders)
+    walls: Vec<Mesh>,
+
     /// Grid (координатна сітка)
     grid: Grid,
 
-    /// Depth texture для правильного z-ordering
+    /// Чи показувати grid (debug оверлей поверх підлоги)
+    pub show_grid: bool,
+
+    /// Градієнтне небо (кольори - top_color / bottom_color)
+    pub skybox: Skybox,
+
+    /// Текстовий debug оверлей (FPS, фізика, бій) - поверх готового кадру
+    pub debug_hud: DebugHud,
+
+    /// Depth texture для правильного z-ordering (sample_count samples)
     depth_texture: wgpu::Texture,
     depth_view: wgpu::TextureView,
 
+    /// MSAA samples сцени (1 = без MSAA); однаковий у всіх pipeline сцени
+    sample_count: u32,
+
+    /// Кількості samples, які підтримує адаптер (для surface format та depth)
+    supported_sample_counts: Vec<u32>,
+
+    /// Present modes, які підтримує surface (Fifo - завжди)
+    supported_present_modes: Vec<wgpu::PresentMode>,
+
+    /// Wireframe mesh та капсул скелета (debug, set_wireframe)
+    wireframe: bool,
+
+    /// Multisampled color target - resolve в swapchain (None при sample_count = 1)
+    msaa_view: Option<wgpu::TextureView>,
+
     /// Cubes (тестові об'єкти)
     cubes: Vec<Mesh>,
 
@@ -129,8 +281,14 @@ pub struct WgpuRenderer {
     /// Player weapon mesh (рука + меч) - окремий для анімації
     weapon_mesh: Mesh,
 
-    /// Enemy meshes (вороги)
-    enemy_meshes: Vec<Mesh>,
+    /// Довжина леза, з якою збудовано weapon_mesh
+    weapon_length: f32,
+
+    /// Манекени ворогів - одна геометрія, екземпляр на ворога (індекс = індекс в enemies)
+    enemy_mesh: InstancedMesh,
+
+    /// Маркер над захопленим ворогом (lock-on); alpha 0 - схований
+    lock_on_marker: Mesh,
 
     /// Camera bind group layout (зберігаємо для створення нових mesh)
     camera_bind_group_layout: wgpu::BindGroupLayout,
@@ -138,15 +296,45 @@ pub struct WgpuRenderer {
     /// Skeleton renderer для фізичного ragdoll
     skeleton_renderer: SkeletonRenderer,
 
+    /// Skeleton renderer для ragdoll ворогів (малюється завжди)
+    enemy_skeleton_renderer: SkeletonRenderer,
+
     /// Чи показувати скелет (для debug)
     pub show_skeleton: bool,
 
+    /// Outline (контур) для гравця та цільового ворога
+    pub outline: OutlineRenderer,
+
+    /// Об'єкти, які обводяться контуром
+    outline_targets: Vec<OutlineTarget>,
+
+    /// Debug лінії (осі кісток, швидкості)
+    debug_lines: DebugLineRenderer,
+
+    /// Частинки (пил від ударів) - лінії з depth test
+    particle_lines: DebugLineRenderer,
+
+    /// Смуги здоров'я над ворогами
+    pub health_bars: HealthBarRenderer,
+
+    /// Що з ragdoll показувати debug лініями
+    pub ragdoll_debug: RagdollDebug,
+
     /// Offscreen render texture (for screenshot support)
     render_texture: wgpu::Texture,
     render_texture_view: wgpu::TextureView,
 
     /// Screenshot capture for first frame (for AI analysis)
     first_frame_capture: FirstFrameCapture,
+
+    /// Screenshot на вимогу: запит до наступного render()
+    screenshot_requested: bool,
+
+    /// Буфер screenshot'а на вимогу (перестворюється при зміні розміру)
+    screenshot_capture: Option<ScreenshotCapture>,
+
+    /// Screenshot'ів на вимогу за сесію (унікальна назва файлу)
+    screenshot_count: u32,
 }
 
 impl WgpuRenderer {
@@ -201,11 +389,14 @@ impl WgpuRenderer {
         );
 
         // 4. Запитати Device і Queue
+        // (MSAA x2 / x8 - лише з adapter-specific format features)
         let (device, queue) = adapter
             .request_device(
                 &wgpu::DeviceDescriptor {
                     label: Some("Main Device"),
-                    required_features: wgpu::Features::empty(),
+                    // POLYGON_MODE_LINE - лише для debug wireframe, якщо є
+                    required_features: adapter.features()
+                        & (wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES | wgpu::Features::POLYGON_MODE_LINE),
                     required_limits: wgpu::Limits::default(),
                     memory_hints: Default::default(),
                 },
@@ -232,6 +423,7 @@ impl WgpuRenderer {
             .unwrap_or(surface_caps.formats[0]);
 
         log::debug!("Surface format: {:?}", surface_format);
+        log::debug!("Present modes: {:?}", surface_caps.present_modes);
 
         let config = wgpu::SurfaceConfiguration {
             usage: wgpu::TextureUsages::RENDER_ATTACHMENT, // Тільки RENDER_ATTACHMENT для swapchain
@@ -246,6 +438,11 @@ impl WgpuRenderer {
 
         surface.configure(&device, &config);
 
+        // 5b. MSAA: DEFAULT_SAMPLE_COUNT, якщо підтримується
+        let supported_sample_counts = Self::supported_sample_counts(&adapter, surface_format);
+        let sample_count = if supported_sample_counts.contains(&DEFAULT_SAMPLE_COUNT) { DEFAULT_SAMPLE_COUNT } else { 1 };
+        log::info!("MSAA: x{} (підтримується: {:?})", sample_count, supported_sample_counts);
+
         // 6. Створити Camera
         use glam::Vec3;
         let camera = Camera::new(
@@ -290,11 +487,38 @@ impl WgpuRenderer {
             label: Some("camera_bind_group"),
         });
 
+        // 9b. Shadow map + light uniform buffer + bind group
+        let light = Light::default();
+        let shadow_map = ShadowMap::new(
+            &device,
+            &camera_bind_group_layout,
+            DEFAULT_SHADOW_RESOLUTION,
+            DEFAULT_ARENA_HALF_EXTENT,
+        );
+        let light_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Light Buffer"),
+            contents: bytemuck::cast_slice(&[LightUniform::new(&light, shadow_map.view_proj(), shadow_map.resolution())]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+        let light_bind_group_layout = create_light_bind_group_layout(&device);
+        let light_bind_group = Self::create_light_bind_group(&device, &light_bind_group_layout, &light_buffer, &shadow_map);
+        let floor = Self::create_floor_mesh(
+            &device,
+            &config,
+            &camera_bind_group_layout,
+            &light_bind_group_layout,
+            DEFAULT_ARENA_HALF_EXTENT,
+            sample_count,
+        );
+
         // 10. Створити Grid
-        let grid = Grid::new(&device, &config, &camera_bind_group_layout, 20);
+        let grid = Grid::new(&device, &config, &camera_bind_group_layout, 20, DEFAULT_GRID_Y_OFFSET, sample_count);
+        let skybox = Skybox::new(&device, &config, sample_count);
+        let debug_hud = DebugHud::new(&device, &queue, &config);
 
-        // 11. Створити Depth Texture
-        let (depth_texture, depth_view) = Self::create_depth_texture(&device, &config);
+        // 11. Створити Depth Texture та MSAA color target
+        let (depth_texture, depth_view) = Self::create_depth_texture(&device, &config, sample_count);
+        let msaa_view = Self::create_msaa_view(&device, &config, sample_count);
 
         // 12. Cubes (вимкнено для тестування ragdoll)
         let cubes = Vec::new();
@@ -310,32 +534,69 @@ impl WgpuRenderer {
             &body_vertices,
             &body_indices,
             &camera_bind_group_layout,
+            &light_bind_group_layout,
             Transform::new(Vec3::new(0.0, 0.75, 0.0)),
+            sample_count,
         );
 
         // 14. Створити Weapon/Arm mesh (окремо для анімації)
-        let (weapon_vertices, weapon_indices) = generate_weapon_arm(
-            [0.2, 0.5, 0.8],          // arm_color
-            [0.7, 0.7, 0.75],         // weapon_color (світлий метал)
+        let weapon_length = Weapon::default().reach;
+        let weapon_mesh = Self::create_weapon_mesh(
+            &device,
+            &config,
+            &camera_bind_group_layout,
+            &light_bind_group_layout,
+            weapon_length,
+            sample_count,
         );
-        // Початкова позиція - на плечі (body_radius + offset, shoulder_height, 0)
-        let shoulder_offset = Vec3::new(0.3, 0.75 + 0.45, 0.0);  // body_radius=0.3, shoulder at 0.45 above center
-        let weapon_mesh = Mesh::new(
+
+        // Enemy mesh - червоне тіло з темно-червоною головою; екземпляри
+        // з'являться в spawn_enemies / update_enemies
+        let (enemy_vertices, enemy_indices) = generate_player_mannequin(
+            0.3,              // body_radius
+            1.2,              // body_height
+            0.25,             // head_radius
+            [0.8, 0.2, 0.2],  // body_color (червоний)
+            [0.6, 0.1, 0.1],  // head_color (темно-червоний)
+        );
+        let enemy_mesh = InstancedMesh::new(
             &device,
             &config,
-            &weapon_vertices,
-            &weapon_indices,
+            &enemy_vertices,
+            &enemy_indices,
             &camera_bind_group_layout,
-            Transform::new(shoulder_offset),
+            &light_bind_group_layout,
+            sample_count,
         );
 
-        // Enemy meshes (порожній вектор, заповниться через spawn_enemies)
-        let enemy_meshes = Vec::new();
+        // Маркер lock-on - ромб (сфера 4×2 сегменти), схований до захоплення
+        let (marker_vertices, marker_indices) = generate_sphere(0.12, 4, 2, LOCK_ON_MARKER_COLOR);
+        let mut lock_on_marker = Mesh::new(
+            &device,
+            &config,
+            &marker_vertices,
+            &marker_indices,
+            &camera_bind_group_layout,
+            &light_bind_group_layout,
+            Transform::new(Vec3::ZERO),
+            sample_count,
+        );
+        lock_on_marker.tint[3] = 0.0;
+        lock_on_marker.update_transform(&queue);
 
         // 15. Створити Skeleton Renderer для фізичного ragdoll
-        let skeleton_renderer = SkeletonRenderer::new(&device, &config, &camera_bind_group_layout);
-
-        // 16. Створити render texture для screenshot support
+        let skeleton_renderer =
+            SkeletonRenderer::new(&device, &config, &camera_bind_group_layout, &light_bind_group_layout, sample_count);
+        let enemy_skeleton_renderer =
+            SkeletonRenderer::new(&device, &config, &camera_bind_group_layout, &light_bind_group_layout, sample_count);
+
+        // 16. Outline renderer (контур гравця / цілі)
+        let outline = OutlineRenderer::new(&device, &config, &camera_bind_group_layout, sample_count);
+        let debug_lines = DebugLineRenderer::new(&device, &config, &camera_bind_group_layout, false, sample_count);
+        let particle_lines = DebugLineRenderer::new(&device, &config, &camera_bind_group_layout, true, sample_count);
+        let health_bars = HealthBarRenderer::new(&device, &config, &camera_bind_group_layout, sample_count);
+
+        // 17. Створити render texture для screenshot support
         let (render_texture, render_texture_view) = Self::create_render_texture(&device, &config);
 
         log::info!("wgpu renderer готовий до роботи!");
@@ -352,26 +613,103 @@ impl WgpuRenderer {
             camera_uniform,
             camera_buffer,
             camera_bind_group,
+            light,
+            light_buffer,
+            light_bind_group,
+            light_bind_group_layout,
+            shadow_map,
+            floor,
+            walls: Vec::new(),
             grid,
+            show_grid: false,
+            skybox,
+            debug_hud,
             depth_texture,
             depth_view,
+            sample_count,
+            supported_sample_counts,
+            supported_present_modes: surface_caps.present_modes.clone(),
+            wireframe: false,
+            msaa_view,
             cubes,
             player_mesh,
             weapon_mesh,
-            enemy_meshes,
+            weapon_length,
+            enemy_mesh,
+            lock_on_marker,
             camera_bind_group_layout,
             skeleton_renderer,
+            enemy_skeleton_renderer,
             show_skeleton: false,
+            outline,
+            outline_targets: Vec::new(),
+            debug_lines,
+            particle_lines,
+            health_bars,
+            ragdoll_debug: RagdollDebug::default(),
             render_texture,
             render_texture_view,
             first_frame_capture: FirstFrameCapture::new(),
+            screenshot_requested: false,
+            screenshot_capture: None,
+            screenshot_count: 0,
+        }
+    }
+
+    /// Кількості MSAA samples (з 1, 2, 4, 8), які адаптер підтримує і для
+    /// surface format (з resolve), і для depth
+    ///
+    /// Без TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES device дозволяє лише 1 та 4.
+    fn supported_sample_counts(adapter: &wgpu::Adapter, format: wgpu::TextureFormat) -> Vec<u32> {
+        let adapter_specific = adapter.features().contains(wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES);
+        let color = adapter.get_texture_format_features(format).flags;
+        let depth = adapter.get_texture_format_features(wgpu::TextureFormat::Depth32Float).flags;
+        let can_resolve = color.contains(wgpu::TextureFormatFeatureFlags::MULTISAMPLE_RESOLVE);
+        [1, 2, 4, 8]
+            .into_iter()
+            .filter(|&count| {
+                count == 1
+                    || (can_resolve
+                        && (adapter_specific || count == 4)
+                        && color.sample_count_supported(count)
+                        && depth.sample_count_supported(count))
+            })
+            .collect()
+    }
+
+    /// Створює multisampled color texture (None при sample_count = 1)
+    fn create_msaa_view(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        sample_count: u32,
+    ) -> Option<wgpu::TextureView> {
+        if sample_count <= 1 {
+            return None;
         }
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("MSAA Color Texture"),
+            size: wgpu::Extent3d {
+                width: config.width,
+                height: config.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count,
+            dimension: wgpu::TextureDimension::D2,
+            format: config.format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+
+        Some(texture.create_view(&wgpu::TextureViewDescriptor::default()))
     }
 
     /// Створює depth texture для z-ordering
     fn create_depth_texture(
         device: &wgpu::Device,
         config: &wgpu::SurfaceConfiguration,
+        sample_count: u32,
     ) -> (wgpu::Texture, wgpu::TextureView) {
         let size = wgpu::Extent3d {
             width: config.width,
@@ -383,7 +721,7 @@ impl WgpuRenderer {
             label: Some("Depth Texture"),
             size,
             mip_level_count: 1,
-            sample_count: 1,
+            sample_count,
             dimension: wgpu::TextureDimension::D2,
             format: wgpu::TextureFormat::Depth32Float,
             usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
@@ -438,16 +776,184 @@ impl WgpuRenderer {
 
             // Оновлюємо aspect ratio камери
             self.camera.update_aspect(new_size.width, new_size.height);
+            self.debug_hud.set_screen_size(&self.queue, new_size.width, new_size.height);
 
             // Пересоздаємо render texture з новим розміром
             let (render_texture, render_texture_view) = Self::create_render_texture(&self.device, &self.config);
             self.render_texture = render_texture;
             self.render_texture_view = render_texture_view;
 
-            // Пересоздаємо depth texture з новим розміром
-            let (depth_texture, depth_view) = Self::create_depth_texture(&self.device, &self.config);
+            // Пересоздаємо depth texture та MSAA target з новим розміром
+            let (depth_texture, depth_view) = Self::create_depth_texture(&self.device, &self.config, self.sample_count);
             self.depth_texture = depth_texture;
             self.depth_view = depth_view;
+            self.msaa_view = Self::create_msaa_view(&self.device, &self.config, self.sample_count);
+        }
+    }
+
+    /// Кількість MSAA samples сцени (2 / 4 / 8; 1 = вимкнено)
+    ///
+    /// Перестворює depth / MSAA textures та всі pipeline сцени (shadow map
+    /// не змінюється). Непідтримувана адаптером кількість → 1.
+    ///
+    /// # Повертає
+    /// Фактично встановлену кількість samples
+    pub fn set_sample_count(&mut self, sample_count: u32) -> u32 {
+        let sample_count = if self.supported_sample_counts.contains(&sample_count) {
+            sample_count
+        } else {
+            log::warn!(
+                "MSAA x{} не підтримується (доступно: {:?}) - MSAA вимкнено",
+                sample_count,
+                self.supported_sample_counts
+            );
+            1
+        };
+        if sample_count == self.sample_count {
+            return sample_count;
+        }
+        self.sample_count = sample_count;
+        log::info!("MSAA: x{}", sample_count);
+
+        let (depth_texture, depth_view) = Self::create_depth_texture(&self.device, &self.config, sample_count);
+        self.depth_texture = depth_texture;
+        self.depth_view = depth_view;
+        self.msaa_view = Self::create_msaa_view(&self.device, &self.config, sample_count);
+
+        let device = &self.device;
+        let config = &self.config;
+        let camera_layout = &self.camera_bind_group_layout;
+        let light_layout = &self.light_bind_group_layout;
+        for mesh in std::iter::once(&mut self.floor)
+            .chain(self.walls.iter_mut())
+            .chain(std::iter::once(&mut self.player_mesh))
+            .chain(std::iter::once(&mut self.weapon_mesh))
+            .chain(std::iter::once(&mut self.lock_on_marker))
+            .chain(self.cubes.iter_mut())
+        {
+            mesh.set_sample_count(device, config, camera_layout, light_layout, sample_count);
+        }
+        self.enemy_mesh.set_sample_count(device, config, camera_layout, light_layout, sample_count);
+        self.skeleton_renderer.set_sample_count(device, config, camera_layout, light_layout, sample_count);
+        self.enemy_skeleton_renderer.set_sample_count(device, config, camera_layout, light_layout, sample_count);
+        self.grid.set_sample_count(device, config, camera_layout, sample_count);
+        self.skybox.set_sample_count(device, config, sample_count);
+        self.outline.set_sample_count(device, config, camera_layout, sample_count);
+        self.debug_lines.set_sample_count(device, config, camera_layout, sample_count);
+        self.particle_lines.set_sample_count(device, config, camera_layout, sample_count);
+        self.health_bars.set_sample_count(device, config, camera_layout, sample_count);
+
+        sample_count
+    }
+
+    /// Wireframe для mesh та капсул скелета (debug геометрії)
+    ///
+    /// Без Features::POLYGON_MODE_LINE на адаптері - warning, режим не змінюється.
+    ///
+    /// # Повертає
+    /// Фактичний стан wireframe
+    pub fn set_wireframe(&mut self, wireframe: bool) -> bool {
+        if wireframe && !self.device.features().contains(wgpu::Features::POLYGON_MODE_LINE) {
+            log::warn!("Wireframe не підтримується адаптером (немає POLYGON_MODE_LINE)");
+            return self.wireframe;
+        }
+        if wireframe == self.wireframe {
+            return wireframe;
+        }
+        self.wireframe = wireframe;
+
+        let polygon_mode = self.polygon_mode();
+        let device = &self.device;
+        let config = &self.config;
+        let camera_layout = &self.camera_bind_group_layout;
+        let light_layout = &self.light_bind_group_layout;
+        let sample_count = self.sample_count;
+        for mesh in std::iter::once(&mut self.floor)
+            .chain(self.walls.iter_mut())
+            .chain(std::iter::once(&mut self.player_mesh))
+            .chain(std::iter::once(&mut self.weapon_mesh))
+            .chain(std::iter::once(&mut self.lock_on_marker))
+            .chain(self.cubes.iter_mut())
+        {
+            mesh.set_polygon_mode(device, config, camera_layout, light_layout, sample_count, polygon_mode);
+        }
+        self.enemy_mesh.set_polygon_mode(device, config, camera_layout, light_layout, sample_count, polygon_mode);
+        for skeleton in [&mut self.skeleton_renderer, &mut self.enemy_skeleton_renderer] {
+            skeleton.set_polygon_mode(device, config, camera_layout, light_layout, sample_count, polygon_mode);
+        }
+
+        wireframe
+    }
+
+    /// Чи ввімкнено wireframe
+    pub fn is_wireframe(&self) -> bool {
+        self.wireframe
+    }
+
+    /// Present mode surface (Fifo = VSync, Mailbox, Immediate)
+    ///
+    /// Лише переконфігурує surface - device, textures та pipelines ті самі.
+    /// Непідтримуваний surface режим → Fifo.
+    ///
+    /// # Повертає
+    /// Фактично встановлений режим
+    pub fn set_present_mode(&mut self, present_mode: wgpu::PresentMode) -> wgpu::PresentMode {
+        let present_mode = if self.supported_present_modes.contains(&present_mode) {
+            present_mode
+        } else {
+            log::warn!(
+                "Present mode {:?} не підтримується (доступно: {:?}) - Fifo",
+                present_mode,
+                self.supported_present_modes
+            );
+            wgpu::PresentMode::Fifo
+        };
+        if present_mode != self.config.present_mode {
+            self.config.present_mode = present_mode;
+            self.surface.configure(&self.device, &self.config);
+            log::info!("Present mode: {:?}", present_mode);
+        }
+        present_mode
+    }
+
+    /// Наступний підтримуваний present mode з PRESENT_MODES
+    ///
+    /// # Повертає
+    /// Встановлений режим
+    pub fn cycle_present_mode(&mut self) -> wgpu::PresentMode {
+        let current = PRESENT_MODES.iter().position(|&mode| mode == self.config.present_mode).unwrap_or(0);
+        let next = (1..=PRESENT_MODES.len())
+            .map(|offset| PRESENT_MODES[(current + offset) % PRESENT_MODES.len()])
+            .find(|mode| self.supported_present_modes.contains(mode))
+            .unwrap_or(wgpu::PresentMode::Fifo);
+        self.set_present_mode(next)
+    }
+
+    /// Поточний present mode surface
+    pub fn present_mode(&self) -> wgpu::PresentMode {
+        self.config.present_mode
+    }
+
+    /// PolygonMode для mesh та скелетів за поточним wireframe
+    fn polygon_mode(&self) -> wgpu::PolygonMode {
+        if self.wireframe {
+            wgpu::PolygonMode::Line
+        } else {
+            wgpu::PolygonMode::Fill
+        }
+    }
+
+    /// Переводить новостворений mesh у поточний режим (wireframe)
+    fn apply_polygon_mode(&self, mesh: &mut Mesh) {
+        if self.wireframe {
+            mesh.set_polygon_mode(
+                &self.device,
+                &self.config,
+                &self.camera_bind_group_layout,
+                &self.light_bind_group_layout,
+                self.sample_count,
+                self.polygon_mode(),
+            );
         }
     }
 
@@ -465,10 +971,18 @@ impl WgpuRenderer {
     /// - `SurfaceError::OutOfMemory` - не вистачає пам'яті
     pub fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
         // Check if we need screenshot this frame
-        let need_screenshot = self.first_frame_capture.should_capture();
-        if need_screenshot {
+        let first_frame = self.first_frame_capture.should_capture();
+        if first_frame {
             self.first_frame_capture.init(&self.device, self.config.width, self.config.height);
         }
+        let on_demand = std::mem::take(&mut self.screenshot_requested);
+        if on_demand {
+            let size = (self.config.width, self.config.height);
+            if self.screenshot_capture.as_ref().map(ScreenshotCapture::size) != Some(size) {
+                self.screenshot_capture = Some(ScreenshotCapture::new(&self.device, size.0, size.1));
+            }
+        }
+        let need_screenshot = first_frame || on_demand;
 
         // 1. Оновити camera uniform buffer
         self.camera_uniform.update_view_proj(&self.camera);
@@ -477,6 +991,14 @@ impl WgpuRenderer {
             0,
             bytemuck::cast_slice(&[self.camera_uniform]),
         );
+        self.skybox.update(&self.queue, &self.camera);
+        self.debug_hud.prepare(&self.device, &self.queue);
+        self.shadow_map.update(&self.queue, &self.light);
+        let light_uniform = LightUniform::new(&self.light, self.shadow_map.view_proj(), self.shadow_map.resolution());
+        self.queue.write_buffer(&self.light_buffer, 0, bytemuck::cast_slice(&[light_uniform]));
+        if !self.outline_targets.is_empty() {
+            self.outline.update_uniform(&self.queue);
+        }
 
         // 2. Отримати поточний frame з surface
         let output = self.surface.get_current_texture()?;
@@ -489,22 +1011,31 @@ impl WgpuRenderer {
                 label: Some("Render Encoder"),
             });
 
-        // 4. Рендеримо напряму на swapchain
+        // 4. Shadow map, потім сцена напряму на swapchain, HUD - поверх
+        self.render_shadows(&mut encoder);
         self.render_scene(&mut encoder, &output_view);
+        self.debug_hud.render(&mut encoder, &output_view);
 
         // 5. Якщо потрібен screenshot - рендеримо ще раз в offscreen texture
         if need_screenshot {
             self.render_scene(&mut encoder, &self.render_texture_view);
+            self.debug_hud.render(&mut encoder, &self.render_texture_view);
             self.first_frame_capture.copy_if_needed(&mut encoder, &self.render_texture);
+            if let Some(capture) = self.screenshot_capture.as_ref().filter(|_| on_demand) {
+                capture.copy_texture_to_buffer(&mut encoder, &self.render_texture);
+            }
         }
 
         // 6. Відправити команди в queue
         self.queue.submit(std::iter::once(encoder.finish()));
 
         // 7. Save screenshot after submit
-        if need_screenshot {
+        if first_frame {
             self.first_frame_capture.save_if_needed(&self.device);
         }
+        if on_demand {
+            self.save_screenshot();
+        }
 
         // 8. Презентувати frame
         output.present();
@@ -512,21 +1043,73 @@ impl WgpuRenderer {
         Ok(())
     }
 
+    /// Screenshot наступного кадру в debug/screenshot_<час>_<номер>.png
+    ///
+    /// Кожен запит - новий файл; розмір - поточний розмір вікна.
+    pub fn request_screenshot(&mut self) {
+        self.screenshot_requested = true;
+    }
+
+    /// Зберігає screenshot на вимогу (після submit)
+    fn save_screenshot(&mut self) {
+        let Some(capture) = &self.screenshot_capture else {
+            return;
+        };
+        self.screenshot_count += 1;
+        let secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let path = std::path::Path::new(SCREENSHOT_DIR)
+            .join(format!("screenshot_{}_{:03}.png", secs, self.screenshot_count));
+        let result = std::fs::create_dir_all(SCREENSHOT_DIR)
+            .map_err(|e| format!("{}: {}", SCREENSHOT_DIR, e))
+            .and_then(|()| capture.save_to_file(&self.device, &path));
+        match result {
+            Ok(()) => log::info!("Screenshot: {:?} ({}x{})", path, capture.size().0, capture.size().1),
+            Err(e) => log::error!("Не вдалося зберегти screenshot: {}", e),
+        }
+    }
+
+    /// Shadow pre-pass: те, що малюється цього кадру (крім підлоги)
+    fn render_shadows(&self, encoder: &mut wgpu::CommandEncoder) {
+        let mut meshes: Vec<&Mesh> = self.walls.iter().chain(&self.cubes).collect();
+        if !self.show_skeleton {
+            meshes.push(&self.player_mesh);
+            meshes.push(&self.weapon_mesh);
+        }
+
+        let mut skeletons = vec![&self.enemy_skeleton_renderer];
+        if self.show_skeleton {
+            skeletons.push(&self.skeleton_renderer);
+        }
+
+        self.shadow_map.render(encoder, &meshes, &[&self.enemy_mesh], &skeletons);
+    }
+
     /// Внутрішній метод для рендерингу сцени в конкретний view
+    ///
+    /// З MSAA сцена малюється в msaa_view і resolve'иться в `target_view`.
     fn render_scene(&self, encoder: &mut wgpu::CommandEncoder, target_view: &wgpu::TextureView) {
+        let (view, resolve_target, store) = match &self.msaa_view {
+            // Multisampled вміст після resolve не потрібен
+            Some(msaa_view) => (msaa_view, Some(target_view), wgpu::StoreOp::Discard),
+            None => (target_view, None, wgpu::StoreOp::Store),
+        };
         let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
             label: Some("Render Pass"),
             color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                view: target_view,
-                resolve_target: None,
+                view,
+                resolve_target,
                 ops: wgpu::Operations {
+                    // Фон повністю перекриває skybox; clear - лише початковий стан
                     load: wgpu::LoadOp::Clear(wgpu::Color {
                         r: 0.1, // Темно-синій колір для арени
                         g: 0.2,
                         b: 0.3,
                         a: 1.0,
                     }),
-                    store: wgpu::StoreOp::Store,
+                    store,
                 },
             })],
             depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
@@ -541,36 +1124,244 @@ impl WgpuRenderer {
             timestamp_writes: None,
         });
 
+        // Небо - першим (без depth), потім підлога (приймає тіні)
+        self.skybox.render(&mut render_pass);
+        self.floor.render(&mut render_pass, &self.camera_bind_group, &self.light_bind_group);
+        for wall in &self.walls {
+            wall.render(&mut render_pass, &self.camera_bind_group, &self.light_bind_group);
+        }
+
         // Малюємо 3D об'єкти (cubes)
         for cube in &self.cubes {
-            cube.render(&mut render_pass, &self.camera_bind_group);
+            cube.render(&mut render_pass, &self.camera_bind_group, &self.light_bind_group);
         }
 
         // Малюємо старий player mesh ТІЛЬКИ якщо скелет вимкнено
         if !self.show_skeleton {
             // Малюємо player body
-            self.player_mesh.render(&mut render_pass, &self.camera_bind_group);
+            self.player_mesh.render(&mut render_pass, &self.camera_bind_group, &self.light_bind_group);
 
             // Малюємо player weapon/arm
-            self.weapon_mesh.render(&mut render_pass, &self.camera_bind_group);
+            self.weapon_mesh.render(&mut render_pass, &self.camera_bind_group, &self.light_bind_group);
         }
 
-        // Малюємо enemies
-        for enemy_mesh in &self.enemy_meshes {
-            enemy_mesh.render(&mut render_pass, &self.camera_bind_group);
+        // Малюємо enemies (непрозорі; ті що зникають - після скелета)
+        self.enemy_mesh.render_opaque(&mut render_pass, &self.camera_bind_group, &self.light_bind_group);
+
+        // Маркер lock-on (тіні не кидає)
+        if self.lock_on_marker.is_visible() {
+            self.lock_on_marker.render(&mut render_pass, &self.camera_bind_group, &self.light_bind_group);
         }
 
+        // Вороги з ragdoll - скелетами
+        self.enemy_skeleton_renderer.render(&mut render_pass, &self.camera_bind_group, &self.light_bind_group);
+
         // Малюємо skeleton (якщо увімкнено)
         if self.show_skeleton {
-            self.skeleton_renderer.render(&mut render_pass, &self.camera_bind_group);
+            self.skeleton_renderer.render(&mut render_pass, &self.camera_bind_group, &self.light_bind_group);
+        }
+
+        // Малюємо grid (debug) - після непрозорих об'єктів (depth заповнений,
+        // лінії ховаються під ними), але ДО напівпрозорих (grid не пише depth,
+        // тож alpha blending напівпрозорих об'єктів накладається поверх ліній)
+        if self.show_grid {
+            self.grid.render(&mut render_pass, &self.camera_bind_group);
         }
 
-        // Малюємо grid (після mesh щоб правильно відображався поверх через alpha)
-        self.grid.render(&mut render_pass, &self.camera_bind_group);
+        // Частинки (пил) - з depth test, як звичайна геометрія
+        self.particle_lines.render(&mut render_pass, &self.camera_bind_group);
+
+        // Смуги здоров'я ворогів - з depth test, без запису depth
+        self.health_bars.render(&mut render_pass, &self.camera_bind_group);
+
+        // Напівпрозорі enemies (fade-out) - після всіх непрозорих об'єктів
+        self.enemy_mesh.render_transparent(&mut render_pass, &self.camera_bind_group, &self.light_bind_group);
+
+        // Малюємо контури (після основних mesh - depth вже заповнений)
+        for target in &self.outline_targets {
+            match *target {
+                OutlineTarget::Player => {
+                    if self.show_skeleton {
+                        self.outline.render_skeleton(&mut render_pass, &self.camera_bind_group, &self.skeleton_renderer);
+                    } else {
+                        self.outline.render_mesh(&mut render_pass, &self.camera_bind_group, &self.player_mesh);
+                        self.outline.render_mesh(&mut render_pass, &self.camera_bind_group, &self.weapon_mesh);
+                    }
+                }
+                OutlineTarget::Enemy(index) => {
+                    // Ворог з ragdoll (mesh схований) - без контуру
+                    if let Some(slot) = self.enemy_mesh.instance_slot(index) {
+                        self.outline.render_instance(&mut render_pass, &self.camera_bind_group, &self.enemy_mesh, slot);
+                    }
+                }
+            }
+        }
+        // Debug лінії - останніми (без depth test, видно крізь геометрію)
+        self.debug_lines.render(&mut render_pass, &self.camera_bind_group);
         // render_pass автоматично завершується при drop
     }
 
+    /// Directional light сцени (завантажується на GPU в наступному render)
+    ///
+    /// # Аргументи
+    /// * `direction` - куди світить світло (нульовий - лишається попередній)
+    /// * `color` - колір / інтенсивність (компоненти >= 0)
+    /// * `ambient` - мінімальна освітленість 0..1
+    pub fn set_light(&mut self, direction: Vec3, color: Vec3, ambient: f32) {
+        if direction.length_squared() > 0.0 {
+            self.light.direction = direction;
+        }
+        self.light.color = color.max(Vec3::ZERO);
+        self.light.ambient = ambient.clamp(0.0, 1.0);
+    }
+
+    /// Висота grid над підлогою (проти z-fighting)
+    ///
+    /// # Аргументи
+    /// * `y_offset` - зсув ліній по Y (world units)
+    pub fn set_grid_y_offset(&mut self, y_offset: f32) {
+        self.grid.set_y_offset(&self.queue, y_offset);
+    }
+
+    /// Розмір grid (перестворює grid, бо змінюється кількість ліній)
+    ///
+    /// # Аргументи
+    /// * `size` - від -size до +size по X та Z (див. ArenaConfig::grid_size)
+    pub fn set_grid_size(&mut self, size: i32) {
+        self.grid = Grid::new(
+            &self.device,
+            &self.config,
+            &self.camera_bind_group_layout,
+            size,
+            self.grid.y_offset(),
+            self.sample_count,
+        );
+    }
+
+    /// Арена: підлога, стіни та frustum світла для тіней
+    pub fn set_arena(&mut self, arena: &ArenaConfig) {
+        self.shadow_map.half_extent = arena.half_extent;
+        let mut floor = Self::create_floor_mesh(
+            &self.device,
+            &self.config,
+            &self.camera_bind_group_layout,
+            &self.light_bind_group_layout,
+            arena.half_extent,
+            self.sample_count,
+        );
+        self.apply_polygon_mode(&mut floor);
+        self.floor = floor;
+
+        self.walls = arena
+            .wall_boxes()
+            .into_iter()
+            .map(|wall_box| {
+                let size = wall_box.half_extents * 2.0;
+                let (vertices, indices) = generate_box(size.x, size.y, size.z, WALL_COLOR);
+                let mut wall = Mesh::new(
+                    &self.device,
+                    &self.config,
+                    &vertices,
+                    &indices,
+                    &self.camera_bind_group_layout,
+                    &self.light_bind_group_layout,
+                    Transform::from_position_rotation_scale(wall_box.center, wall_box.rotation(), Vec3::ONE),
+                    self.sample_count,
+                );
+                self.apply_polygon_mode(&mut wall);
+                wall
+            })
+            .collect();
+    }
+
+    /// Розмір shadow map (texels по стороні)
+    pub fn set_shadow_resolution(&mut self, resolution: u32) {
+        if self.shadow_map.set_resolution(&self.device, resolution) {
+            self.light_bind_group = Self::create_light_bind_group(
+                &self.device,
+                &self.light_bind_group_layout,
+                &self.light_buffer,
+                &self.shadow_map,
+            );
+        }
+    }
+
+    /// Bind group світла: uniform + shadow map + comparison sampler
+    fn create_light_bind_group(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        light_buffer: &wgpu::Buffer,
+        shadow_map: &ShadowMap,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: light_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(shadow_map.texture_view()),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Sampler(shadow_map.sampler()),
+                },
+            ],
+            label: Some("light_bind_group"),
+        })
+    }
+
+    /// Підлога арени: плоский box з верхом на Y=0, шахівниця ground.wgsl
+    fn create_floor_mesh(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        light_bind_group_layout: &wgpu::BindGroupLayout,
+        half_extent: f32,
+        sample_count: u32,
+    ) -> Mesh {
+        let size = half_extent * 2.0;
+        let (vertices, indices) = generate_box(size, FLOOR_THICKNESS, size, FLOOR_COLOR);
+        let mut floor = Mesh::new(
+            device,
+            config,
+            &vertices,
+            &indices,
+            camera_bind_group_layout,
+            light_bind_group_layout,
+            Transform::new(Vec3::new(0.0, -FLOOR_THICKNESS / 2.0, 0.0)),
+            sample_count,
+        );
+        floor.set_shader(
+            device,
+            config,
+            camera_bind_group_layout,
+            light_bind_group_layout,
+            sample_count,
+            MeshShader::Ground,
+        );
+        floor
+    }
+
+    /// Застосовує рівень якості (сегменти капсул скелета)
+    pub fn set_quality(&mut self, tier: QualityTier) {
+        self.skeleton_renderer.set_segments(&self.device, tier.capsule_segments());
+        self.enemy_skeleton_renderer.set_segments(&self.device, tier.capsule_segments());
+    }
+
+    /// Встановлює, які об'єкти обводити контуром
+    ///
+    /// # Аргументи
+    /// * `targets` - Гравець та/або вороги (за індексом в enemies)
+    pub fn set_outline_targets(&mut self, targets: &[OutlineTarget]) {
+        self.outline_targets.clear();
+        self.outline_targets.extend_from_slice(targets);
+    }
+
     /// Повертає поточний розмір вікна
+    #[allow(dead_code)]
     pub fn size(&self) -> winit::dpi::PhysicalSize<u32> {
         self.size
     }
@@ -616,6 +1407,21 @@ impl WgpuRenderer {
         self.player_mesh.update_transform(&self.queue);
 
         // === WEAPON/ARM ===
+        // Інша зброя - перебудовуємо mesh під довжину її леза
+        if (combat.weapon.reach - self.weapon_length).abs() > f32::EPSILON {
+            self.weapon_length = combat.weapon.reach;
+            let mut weapon_mesh = Self::create_weapon_mesh(
+                &self.device,
+                &self.config,
+                &self.camera_bind_group_layout,
+                &self.light_bind_group_layout,
+                self.weapon_length,
+                self.sample_count,
+            );
+            self.apply_polygon_mode(&mut weapon_mesh);
+            self.weapon_mesh = weapon_mesh;
+        }
+
         // Позиція плеча в world space (праворуч від гравця)
         let body_radius = 0.3_f32;
         let shoulder_height = 0.45_f32;
@@ -625,20 +1431,49 @@ impl WgpuRenderer {
             + Vec3::new(0.0, 0.75 + shoulder_height, 0.0)
             + right_dir * body_radius;
 
-        self.weapon_mesh.transform.position = shoulder_world;
-
-        // Rotation: base yaw + swing angle
-        let base_rotation = Quat::from_rotation_y(yaw);
-        let swing_rotation = Quat::from_rotation_y(combat.weapon_swing_angle);
-        self.weapon_mesh.transform.rotation = base_rotation * swing_rotation;
+        // Rotation: base yaw + поза меча (yaw / pitch), укол - зсув вздовж меча
+        let weapon_rotation = combat.weapon_pose.rotation(yaw);
+        self.weapon_mesh.transform.position =
+            shoulder_world + weapon_rotation * Vec3::new(0.0, 0.0, -combat.weapon_pose.thrust);
+        self.weapon_mesh.transform.rotation = weapon_rotation;
         self.weapon_mesh.update_transform(&self.queue);
     }
 
+    /// Створює mesh руки з мечем довжиною `weapon_length`
+    fn create_weapon_mesh(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        light_bind_group_layout: &wgpu::BindGroupLayout,
+        weapon_length: f32,
+        sample_count: u32,
+    ) -> Mesh {
+        let (weapon_vertices, weapon_indices) = generate_weapon_arm(
+            [0.2, 0.5, 0.8],          // arm_color
+            [0.7, 0.7, 0.75],         // weapon_color (світлий метал)
+            weapon_length,
+        );
+        // Початкова позиція - на плечі (body_radius + offset, shoulder_height, 0)
+        let shoulder_offset = Vec3::new(0.3, 0.75 + 0.45, 0.0);  // body_radius=0.3, shoulder at 0.45 above center
+        Mesh::new(
+            device,
+            config,
+            &weapon_vertices,
+            &weapon_indices,
+            camera_bind_group_layout,
+            light_bind_group_layout,
+            Transform::new(shoulder_offset),
+            sample_count,
+        )
+    }
+
     /// Оновлює анімації об'єктів
     ///
     /// # Аргументи
     /// * `delta` - Delta time в секундах
     pub fn update_animations(&mut self, delta: f32) {
+        self.light.update(delta);
+
         // Швидкості обертання для кожного куба (радіани/секунда)
         let rotation_speeds = [
             1.0_f32,   // Червоний куб - 1 рад/с (~57°/с)
@@ -662,75 +1497,172 @@ impl WgpuRenderer {
         }
     }
 
-    /// Створює meshes для ворогів
+    /// Створює екземпляри манекенів ворогів
     ///
     /// # Аргументи
     /// * `enemies` - Список ворогів для spawning
     pub fn spawn_enemies(&mut self, enemies: &[Enemy]) {
-        self.enemy_meshes.clear();
-
-        // Enemy колір - червоний (тіло) з темно-червоною головою
-        let enemy_body_color = [0.8, 0.2, 0.2];  // Червоний
-        let enemy_head_color = [0.6, 0.1, 0.1];  // Темно-червоний
-
-        let (enemy_vertices, enemy_indices) = generate_player_mannequin(
-            0.3,              // body_radius
-            1.2,              // body_height
-            0.25,             // head_radius
-            enemy_body_color,
-            enemy_head_color,
-        );
-
-        for enemy in enemies {
-            let mut transform = Transform::new(enemy.position + Vec3::new(0.0, 0.75, 0.0));
-            transform.rotation = Quat::from_rotation_y(enemy.yaw);
-
-            let mesh = Mesh::new(
-                &self.device,
-                &self.config,
-                &enemy_vertices,
-                &enemy_indices,
-                &self.camera_bind_group_layout,
-                transform,
-            );
+        self.update_enemy_instances(enemies);
+        log::info!("Spawned {} enemy instances", enemies.len());
+    }
 
-            self.enemy_meshes.push(mesh);
+    /// Показує маркер lock-on над ворогом (None - ховає)
+    ///
+    /// # Аргументи
+    /// * `enemy_position` - Позиція захопленого ворога (ноги)
+    pub fn set_lock_on_marker(&mut self, enemy_position: Option<Vec3>) {
+        match enemy_position {
+            Some(position) => {
+                self.lock_on_marker.transform.position = position + Vec3::Y * LOCK_ON_MARKER_OFFSET_Y;
+                self.lock_on_marker.tint[3] = 1.0;
+            }
+            None => self.lock_on_marker.tint[3] = 0.0,
         }
+        self.lock_on_marker.update_transform(&self.queue);
+    }
 
-        log::info!("Spawned {} enemy meshes", self.enemy_meshes.len());
+    /// Ворога видалено з `enemies` (екземпляри перебудує update_enemies)
+    ///
+    /// Індекси outline targets після видаленого зсуваються на 1.
+    pub fn remove_enemy_mesh(&mut self, index: usize) {
+        self.outline_targets.retain(|t| *t != OutlineTarget::Enemy(index));
+        for target in &mut self.outline_targets {
+            if let OutlineTarget::Enemy(i) = target {
+                if *i > index {
+                    *i -= 1;
+                }
+            }
+        }
     }
 
     /// Оновлює bone transforms для skeleton renderer
     ///
     /// # Аргументи
     /// * `bone_transforms` - Список кісток з позиціями та ротаціями
+    ///
+    /// NaN / нескінченні transforms - кадр пропускається (лишається попередня поза)
     pub fn update_skeleton(&mut self, bone_transforms: &[(BoneId, Vec3, Quat)]) {
+        if !transforms_finite(bone_transforms) {
+            log_debug("update_skeleton: не скінченні transforms - кадр пропущено");
+            return;
+        }
         self.skeleton_renderer.update_bones(&self.queue, bone_transforms);
     }
 
-    /// Оновлює позиції enemy meshes
+    /// Оновлює скелети ворогів з ragdoll
     ///
     /// # Аргументи
-    /// * `enemies` - Список ворогів з оновленими позиціями
-    pub fn update_enemies(&mut self, enemies: &[Enemy]) {
-        for (i, enemy) in enemies.iter().enumerate() {
-            if i < self.enemy_meshes.len() {
-                // Оновлюємо позицію
-                self.enemy_meshes[i].transform.position = enemy.position + Vec3::new(0.0, 0.75, 0.0);
-
-                // Оновлюємо rotation
-                self.enemy_meshes[i].transform.rotation = Quat::from_rotation_y(enemy.yaw);
-
-                // Якщо ворог мертвий - зменшуємо scale (або можна приховати)
-                if !enemy.is_alive() {
-                    self.enemy_meshes[i].transform.scale = Vec3::new(1.0, 0.1, 1.0); // Сплющений
-                } else {
-                    self.enemy_meshes[i].transform.scale = Vec3::ONE;
-                }
+    /// * `skeletons` - (bone transforms, чи ворог живий, прогрес замаху,
+    ///   спалах від удару) кожного ворога з ragdoll
+    ///
+    /// NaN / нескінченні transforms хоч одного ворога - кадр пропускається
+    pub fn update_enemy_skeletons(&mut self, skeletons: &[(Vec<BoneTransform>, bool, f32, f32)]) {
+        if !skeletons.iter().all(|(bones, ..)| transforms_finite(bones)) {
+            log_debug("update_enemy_skeletons: не скінченні transforms - кадр пропущено");
+            return;
+        }
+        let tinted: Vec<_> = skeletons
+            .iter()
+            .map(|(bones, alive, windup, flash)| {
+                let tint = if *alive { windup_tint(ENEMY_SKELETON_TINT, *windup) } else { DEAD_ENEMY_SKELETON_TINT };
+                (bones.as_slice(), hit_flash_tint(tint, *flash))
+            })
+            .collect();
+        self.enemy_skeleton_renderer.update_skeletons(&self.device, &self.queue, &tinted);
+    }
 
-                // Оновлюємо GPU buffer
-                self.enemy_meshes[i].update_transform(&self.queue);
+    /// Будує debug лінії для ragdoll (осі кісток та вектори швидкостей)
+    ///
+    /// # Аргументи
+    /// * `bone_transforms` - (bone, position, rotation) в world space
+    /// * `bone_velocities` - (bone, linear, angular) в world space
+    pub fn update_ragdoll_debug(
+        &mut self,
+        bone_transforms: &[(BoneId, Vec3, Quat)],
+        bone_velocities: &[(BoneId, Vec3, Vec3)],
+    ) {
+        let debug = self.ragdoll_debug;
+        self.debug_lines.clear();
+
+        if !debug.is_enabled() {
+            self.debug_lines.upload(&self.queue);
+            return;
+        }
+
+        if debug.show_bone_axes {
+            for (_, position, rotation) in bone_transforms {
+                self.debug_lines.axes(*position, *rotation, debug.axis_length);
             }
         }
+
+        if debug.show_velocities {
+            for (bone_id, linvel, angvel) in bone_velocities {
+                let Some((_, position, _)) = bone_transforms.iter().find(|(id, _, _)| id == bone_id) else {
+                    continue;
+                };
+                // Лінійна - жовта, кутова (вісь обертання) - пурпурна
+                self.debug_lines.line(*position, *position + *linvel * debug.velocity_scale, [1.0, 0.9, 0.1]);
+                self.debug_lines.line(*position, *position + *angvel * debug.velocity_scale, [0.9, 0.2, 0.9]);
+            }
+        }
+
+        self.debug_lines.upload(&self.queue);
+    }
+
+    /// Будує лінії частинок (кожна частинка - короткий штрих вздовж швидкості)
+    pub fn update_particles(&mut self, particles: &ParticleSystem) {
+        self.particle_lines.clear();
+        for particle in particles.particles() {
+            // Пил темнішає та коротшає до кінця життя
+            let fade = 1.0 - particle.life_fraction();
+            let shade = 0.35 + 0.35 * fade;
+            let tail = particle.position - particle.velocity * 0.05 * fade;
+            self.particle_lines.line(tail, particle.position, [shade, shade * 0.95, shade * 0.85]);
+        }
+        self.particle_lines.upload(&self.queue);
+    }
+
+    /// Оновлює екземпляри манекенів ворогів та смуги здоров'я
+    ///
+    /// Смуги - тільки живих ворогів, повернуті до поточної позиції камери.
+    ///
+    /// # Аргументи
+    /// * `enemies` - Список ворогів з оновленими позиціями
+    pub fn update_enemies(&mut self, enemies: &[Enemy]) {
+        let camera_position = self.camera.position;
+        let bars: Vec<_> = enemies
+            .iter()
+            .filter(|e| e.is_alive() && e.max_health > 0.0)
+            .filter(|e| !(self.health_bars.hide_when_full && e.health >= e.max_health))
+            .map(|e| {
+                HealthBarInstance::billboard(
+                    e.position + Vec3::Y * HEALTH_BAR_OFFSET_Y,
+                    e.health / e.max_health,
+                    camera_position,
+                )
+            })
+            .collect();
+        self.health_bars.update(&self.device, &self.queue, &bars);
+
+        self.update_enemy_instances(enemies);
+    }
+
+    /// Екземпляр манекена на кожного ворога (одним записом в instance buffer)
+    fn update_enemy_instances(&mut self, enemies: &[Enemy]) {
+        let instances: Vec<MeshInstance> = enemies
+            .iter()
+            .map(|enemy| {
+                let mut transform = Transform::new(enemy.position + Vec3::new(0.0, 0.75, 0.0));
+                transform.rotation = Quat::from_rotation_y(enemy.yaw);
+
+                // Замах - червоніє до удару, влучання - спалах; мертвий ворог
+                // поступово зникає (alpha fade-out); ворог з ragdoll малюється
+                // скелетом - схований
+                let [r, g, b] = hit_flash_tint(windup_tint([1.0; 3], enemy.windup_progress()), enemy.hit_flash());
+                let alpha = if enemy.ragdoll.is_some() { 0.0 } else { enemy.fade_alpha() };
+                MeshInstance::new(&transform, [r, g, b, alpha])
+            })
+            .collect();
+        self.enemy_mesh.update_instances(&self.device, &self.queue, &instances);
     }
 }
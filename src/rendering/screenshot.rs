@@ -27,7 +27,7 @@ impl ScreenshotCapture {
         let unpadded_bytes_per_row = width * bytes_per_pixel;
         // wgpu requires rows aligned to 256 bytes
         let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
-        let padded_bytes_per_row = (unpadded_bytes_per_row + align - 1) / align * align;
+        let padded_bytes_per_row = unpadded_bytes_per_row.div_ceil(align) * align;
 
         let buffer_size = (padded_bytes_per_row * height) as u64;
 
@@ -47,6 +47,11 @@ impl ScreenshotCapture {
         }
     }
 
+    /// Capture dimensions (width, height)
+    pub fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
     /// Copy texture to buffer (call after render, before present)
     pub fn copy_texture_to_buffer(
         &self,
@@ -188,6 +193,7 @@ impl FirstFrameCapture {
         }
     }
 
+    #[allow(dead_code)]
     pub fn is_captured(&self) -> bool {
         self.captured
     }
@@ -0,0 +1,384 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/rendering/shadow.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   ShadowMap - тіні від directional light (Light).
+
+   ПІДХІД: Shadow mapping
+   - Pre-pass: глибина mesh та капсул скелетів з точки зору світла
+     (ортографічна проекція на всю арену) в depth texture
+   - Основний прохід: mesh.wgsl / skeleton.wgsl переводять фрагмент у
+     простір світла і порівнюють глибину (sampler_comparison, PCF 3×3)
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Depth texture (resolution × resolution) + comparison sampler
+   - View-projection світла (light_view_proj) з напрямку та розміру арени
+   - Depth-only pipelines для Mesh, InstancedMesh та SkeletonRenderer
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Імпортує:
+   - light.rs - Light (напрямок)
+   - mesh.rs - MeshVertex layout, Mesh::draw_geometry
+   - instanced_mesh.rs - MeshInstance layout, InstancedMesh::draw_instances
+   - skeleton_renderer.rs - CapsuleVertex/BoneInstance layouts, draw_instances
+
+   Експортує для:
+   - renderer.rs - pre-pass у render, texture / sampler у light bind group
+
+⚠️  ВАЖЛИВІ ОБМЕЖЕННЯ:
+   1. Frustum світла фіксований - покриває арену (half_extent), не камеру
+   2. Texture перестворюється при зміні resolution - light bind group теж
+   3. Shadow acne гаситься depth bias pipeline (SHADOW_DEPTH_BIAS_*)
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - shadow map з PCF
+   2026-10-16: Pipeline для InstancedMesh (вороги)
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use glam::{Mat4, Vec3};
+use wgpu::util::DeviceExt;
+
+use crate::camera::CameraUniform;
+use super::light::Light;
+use super::mesh::{Mesh, MeshVertex};
+use super::instanced_mesh::{InstancedMesh, MeshInstance};
+use super::skeleton_renderer::{BoneInstance, CapsuleVertex, SkeletonRenderer};
+
+/// Розмір shadow map за замовчуванням (texels по стороні)
+pub const DEFAULT_SHADOW_RESOLUTION: u32 = 2048;
+
+/// Межі розміру shadow map
+const MIN_SHADOW_RESOLUTION: u32 = 256;
+const MAX_SHADOW_RESOLUTION: u32 = 8192;
+
+/// Формат shadow map
+const SHADOW_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+
+/// Запас frustum'а світла понад діагональ арени (персонажі біля стін)
+const SHADOW_EXTENT_MARGIN: f32 = 2.0;
+
+/// На скільки світло "стоїть" за ареною - все, що вище землі, в frustum'і
+const SHADOW_HEIGHT_MARGIN: f32 = 10.0;
+
+/// Depth bias проти shadow acne (у одиницях depth та на нахил)
+const SHADOW_DEPTH_BIAS_CONSTANT: i32 = 2;
+const SHADOW_DEPTH_BIAS_SLOPE: f32 = 2.0;
+
+/// View-projection ортографічного світла, що покриває арену
+///
+/// # Аргументи
+/// * `light` - напрямок світла
+/// * `half_extent` - арена від -half_extent до +half_extent по X та Z
+pub fn light_view_proj(light: &Light, half_extent: f32) -> Mat4 {
+    let direction = light.direction.normalize_or(Vec3::NEG_Y);
+    // Квадрат арени під будь-яким кутом вміщається в коло з радіусом діагоналі
+    let radius = half_extent * std::f32::consts::SQRT_2 + SHADOW_EXTENT_MARGIN;
+    let distance = radius + SHADOW_HEIGHT_MARGIN;
+
+    // Світло прямо згори - "вгору" view не може бути Y
+    let up = if direction.cross(Vec3::Y).length_squared() < 1e-6 { Vec3::Z } else { Vec3::Y };
+    let view = Mat4::look_at_rh(-direction * distance, Vec3::ZERO, up);
+    let proj = Mat4::orthographic_rh(-radius, radius, -radius, radius, 0.1, distance * 2.0);
+    proj * view
+}
+
+/// Shadow map directional light
+pub struct ShadowMap {
+    /// Розмір texture (texels по стороні)
+    resolution: u32,
+
+    /// Половина розміру арени, яку покриває frustum світла
+    pub half_extent: f32,
+
+    view: wgpu::TextureView,
+    sampler: wgpu::Sampler,
+
+    /// View-projection світла останнього update
+    view_proj: Mat4,
+
+    /// View-projection світла як CameraUniform (group(0) pre-pass)
+    camera_buffer: wgpu::Buffer,
+    camera_bind_group: wgpu::BindGroup,
+
+    mesh_pipeline: wgpu::RenderPipeline,
+    instanced_pipeline: wgpu::RenderPipeline,
+    skeleton_pipeline: wgpu::RenderPipeline,
+}
+
+impl ShadowMap {
+    /// Створює shadow map
+    ///
+    /// # Аргументи
+    /// * `device` - wgpu device
+    /// * `camera_bind_group_layout` - layout CameraUniform (view-projection світла)
+    /// * `resolution` - розмір texture (обмежується MIN/MAX_SHADOW_RESOLUTION)
+    /// * `half_extent` - половина розміру арени
+    pub fn new(
+        device: &wgpu::Device,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        resolution: u32,
+        half_extent: f32,
+    ) -> Self {
+        let resolution = resolution.clamp(MIN_SHADOW_RESOLUTION, MAX_SHADOW_RESOLUTION);
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Shadow Sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            compare: Some(wgpu::CompareFunction::LessEqual),
+            ..Default::default()
+        });
+
+        let camera_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Shadow Camera Buffer"),
+            contents: bytemuck::cast_slice(&[CameraUniform::new()]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+        let camera_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout: camera_bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: camera_buffer.as_entire_binding(),
+            }],
+            label: Some("shadow_camera_bind_group"),
+        });
+
+        // Такий самий layout як в Mesh (wgpu дедуплікує ідентичні layouts)
+        let transform_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+            label: Some("transform_bind_group_layout"),
+        });
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Shadow Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../../assets/shaders/shadow.wgsl").into()),
+        });
+
+        let mesh_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Shadow Mesh Pipeline Layout"),
+            bind_group_layouts: &[camera_bind_group_layout, &transform_layout],
+            push_constant_ranges: &[],
+        });
+        // Instanced mesh та скелети - лише камера світла (transform - в instance buffer)
+        let skeleton_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Shadow Skeleton Pipeline Layout"),
+            bind_group_layouts: &[camera_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let mesh_pipeline = Self::create_pipeline(
+            device,
+            &shader,
+            &mesh_layout,
+            "vs_mesh",
+            &[MeshVertex::vertex_buffer_layout()],
+            "Shadow Mesh Pipeline",
+        );
+        let instanced_pipeline = Self::create_pipeline(
+            device,
+            &shader,
+            &skeleton_layout,
+            "vs_mesh_instanced",
+            &[
+                MeshVertex::vertex_buffer_layout(),
+                MeshInstance::instance_buffer_layout(),
+            ],
+            "Shadow Instanced Mesh Pipeline",
+        );
+        let skeleton_pipeline = Self::create_pipeline(
+            device,
+            &shader,
+            &skeleton_layout,
+            "vs_skeleton",
+            &[
+                CapsuleVertex::vertex_buffer_layout(),
+                BoneInstance::instance_buffer_layout(),
+            ],
+            "Shadow Skeleton Pipeline",
+        );
+
+        Self {
+            resolution,
+            half_extent,
+            view: Self::create_view(device, resolution),
+            sampler,
+            view_proj: Mat4::IDENTITY,
+            camera_buffer,
+            camera_bind_group,
+            mesh_pipeline,
+            instanced_pipeline,
+            skeleton_pipeline,
+        }
+    }
+
+    /// Depth texture resolution × resolution (render target + sampling)
+    fn create_view(device: &wgpu::Device, resolution: u32) -> wgpu::TextureView {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Shadow Map"),
+            size: wgpu::Extent3d {
+                width: resolution,
+                height: resolution,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: SHADOW_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        texture.create_view(&wgpu::TextureViewDescriptor::default())
+    }
+
+    fn create_pipeline(
+        device: &wgpu::Device,
+        shader: &wgpu::ShaderModule,
+        layout: &wgpu::PipelineLayout,
+        vs_entry: &str,
+        buffers: &[wgpu::VertexBufferLayout],
+        label: &str,
+    ) -> wgpu::RenderPipeline {
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some(label),
+            layout: Some(layout),
+            vertex: wgpu::VertexState {
+                module: shader,
+                entry_point: Some(vs_entry),
+                buffers,
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: None, // Тільки depth
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: Some(wgpu::Face::Back),
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: SHADOW_FORMAT,
+                depth_write_enabled: true,
+                depth_compare: wgpu::CompareFunction::Less,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState {
+                    constant: SHADOW_DEPTH_BIAS_CONSTANT,
+                    slope_scale: SHADOW_DEPTH_BIAS_SLOPE,
+                    clamp: 0.0,
+                },
+            }),
+            multisample: wgpu::MultisampleState {
+                count: 1,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            multiview: None,
+            cache: None,
+        })
+    }
+
+    /// Розмір texture (texels по стороні)
+    pub fn resolution(&self) -> u32 {
+        self.resolution
+    }
+
+    /// Змінює розмір texture (обмежується MIN/MAX_SHADOW_RESOLUTION)
+    ///
+    /// # Повертає
+    /// `true` якщо texture перестворено - треба оновити bind group зі світлом
+    pub fn set_resolution(&mut self, device: &wgpu::Device, resolution: u32) -> bool {
+        let resolution = resolution.clamp(MIN_SHADOW_RESOLUTION, MAX_SHADOW_RESOLUTION);
+        if resolution == self.resolution {
+            return false;
+        }
+        self.resolution = resolution;
+        self.view = Self::create_view(device, resolution);
+        true
+    }
+
+    /// View-projection світла (для LightUniform)
+    pub fn view_proj(&self) -> Mat4 {
+        self.view_proj
+    }
+
+    /// Shadow map для light bind group
+    pub fn texture_view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+
+    /// Comparison sampler для light bind group
+    pub fn sampler(&self) -> &wgpu::Sampler {
+        &self.sampler
+    }
+
+    /// Перераховує view-projection світла та завантажує для pre-pass
+    pub fn update(&mut self, queue: &wgpu::Queue, light: &Light) {
+        self.view_proj = light_view_proj(light, self.half_extent);
+        let uniform = CameraUniform { view_proj: self.view_proj.to_cols_array_2d() };
+        queue.write_buffer(&self.camera_buffer, 0, bytemuck::cast_slice(&[uniform]));
+    }
+
+    /// Pre-pass: глибина тих, хто кидає тінь
+    ///
+    /// # Аргументи
+    /// * `meshes` - видимі mesh (без підлоги - вона лише приймає тінь)
+    /// * `instanced` - instanced mesh (всі їхні видимі екземпляри)
+    /// * `skeletons` - скелети, що малюються цього кадру
+    pub fn render(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        meshes: &[&Mesh],
+        instanced: &[&InstancedMesh],
+        skeletons: &[&SkeletonRenderer],
+    ) {
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Shadow Pass"),
+            color_attachments: &[],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: &self.view,
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(1.0),
+                    store: wgpu::StoreOp::Store,
+                }),
+                stencil_ops: None,
+            }),
+            occlusion_query_set: None,
+            timestamp_writes: None,
+        });
+
+        render_pass.set_bind_group(0, &self.camera_bind_group, &[]);
+
+        render_pass.set_pipeline(&self.mesh_pipeline);
+        for mesh in meshes {
+            mesh.draw_geometry(&mut render_pass, 1);
+        }
+
+        render_pass.set_pipeline(&self.instanced_pipeline);
+        for mesh in instanced {
+            mesh.draw_instances(&mut render_pass, mesh.visible_instances());
+        }
+
+        render_pass.set_pipeline(&self.skeleton_pipeline);
+        for skeleton in skeletons {
+            skeleton.draw_instances(&mut render_pass);
+        }
+    }
+}
@@ -11,6 +11,11 @@
    - Однакові кістки (ліва/права рука) використовують той самий mesh
    - Shader НЕ масштабує геометрію, тільки застосовує position/rotation
    - Це гарантує правильні пропорції без спотворення caps
+   - Кількість сегментів змінюється на льоту (set_segments, адаптивна якість)
+   - Кілька скелетів (вороги) - update_skeletons з tint кожного скелета;
+     instance buffers ростуть під кількість скелетів
+   - Освітлення - той самий LightUniform, що й у mesh (group(1))
+   - Wireframe (set_polygon_mode) - трикутники капсул для debug
 
 ═══════════════════════════════════════════════════════════════════════════════
 */
@@ -22,6 +27,12 @@ use std::collections::HashMap;
 use crate::physics::BoneId;
 use crate::debug_log::log_debug;
 
+/// Transform кістки в world space: (кістка, позиція, ротація)
+pub type BoneTransform = (BoneId, Vec3, Quat);
+
+/// Максимум instances одного типу кістки в скелеті (ліва/права пара × 2)
+const INSTANCES_PER_SKELETON: usize = 4;
+
 /// Кольори для різних частин тіла
 pub fn get_bone_color(bone_id: BoneId) -> [f32; 3] {
     match bone_id {
@@ -43,6 +54,9 @@ pub fn get_bone_color(bone_id: BoneId) -> [f32; 3] {
 
         // Права нога - помаранчевий
         BoneId::RightUpperLeg | BoneId::RightLowerLeg => [0.9, 0.5, 0.2],
+
+        // Меч - світлий метал
+        BoneId::Weapon => [0.7, 0.7, 0.75],
     }
 }
 
@@ -53,6 +67,7 @@ pub fn get_bone_color(bone_id: BoneId) -> [f32; 3] {
 /// - Гомілка: товще біля коліна (~0.055м), тонше біля щиколотки (~0.035м)
 /// - Плече: товще біля плеча (~0.055м), тонше біля ліктя (~0.04м)
 /// - Передпліччя: товще біля ліктя (~0.04м), тонше біля зап'ястя (~0.025м)
+#[allow(dead_code)]
 pub fn get_bone_dimensions(bone_id: BoneId) -> (f32, f32, f32) {
     // Повертає (length, radius_top, radius_bottom)
     // top = ближче до центру тіла (+Y в локальних координатах кістки)
@@ -70,6 +85,9 @@ pub fn get_bone_dimensions(bone_id: BoneId) -> (f32, f32, f32) {
         // === НОГИ (tapered - товще біля тіла) ===
         BoneId::LeftUpperLeg | BoneId::RightUpperLeg => (0.45, 0.10, 0.065),
         BoneId::LeftLowerLeg | BoneId::RightLowerLeg => (0.40, 0.058, 0.038),
+
+        // === МЕЧ (руків'я зверху, вістря знизу) ===
+        BoneId::Weapon => (1.0, 0.03, 0.015),
     }
 }
 
@@ -83,6 +101,7 @@ pub enum BoneType {
     LowerArm,
     UpperLeg,
     LowerLeg,
+    Weapon,
 }
 
 impl BoneType {
@@ -95,6 +114,7 @@ impl BoneType {
             BoneId::LeftLowerArm | BoneId::RightLowerArm => BoneType::LowerArm,
             BoneId::LeftUpperLeg | BoneId::RightUpperLeg => BoneType::UpperLeg,
             BoneId::LeftLowerLeg | BoneId::RightLowerLeg => BoneType::LowerLeg,
+            BoneId::Weapon => BoneType::Weapon,
         }
     }
 
@@ -108,6 +128,7 @@ impl BoneType {
             BoneType::LowerArm => (0.29, 0.042, 0.028),
             BoneType::UpperLeg => (0.45, 0.10, 0.065),
             BoneType::LowerLeg => (0.40, 0.058, 0.038),
+            BoneType::Weapon => (1.0, 0.03, 0.015),
         }
     }
 }
@@ -346,6 +367,12 @@ impl BoneInstance {
     }
 }
 
+/// Кількість сегментів капсули по колу за замовчуванням
+pub const DEFAULT_CAPSULE_SEGMENTS: u32 = 12;
+
+/// Мінімальна кількість сегментів (менше - капсула стає плоскою)
+const MIN_CAPSULE_SEGMENTS: u32 = 4;
+
 /// Mesh data для одного типу кістки
 struct BoneMesh {
     vertex_buffer: wgpu::Buffer,
@@ -353,6 +380,37 @@ struct BoneMesh {
     index_count: u32,
 }
 
+impl BoneMesh {
+    /// Генерує капсулу для типу кістки і завантажує на GPU
+    fn new(device: &wgpu::Device, bone_type: BoneType, segments: u32) -> Self {
+        let (length, radius_top, radius_bottom) = bone_type.dimensions();
+        let (vertices, indices) = generate_tapered_capsule_real(length, radius_top, radius_bottom, segments);
+
+        log_debug(&format!(
+            "Generated mesh for {:?}: len={:.3}, r_top={:.3}, r_bot={:.3}, verts={}, indices={}",
+            bone_type, length, radius_top, radius_bottom, vertices.len(), indices.len()
+        ));
+
+        let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some(&format!("{:?} Vertex Buffer", bone_type)),
+            contents: bytemuck::cast_slice(&vertices),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+
+        let index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some(&format!("{:?} Index Buffer", bone_type)),
+            contents: bytemuck::cast_slice(&indices),
+            usage: wgpu::BufferUsages::INDEX,
+        });
+
+        Self {
+            vertex_buffer,
+            index_buffer,
+            index_count: indices.len() as u32,
+        }
+    }
+}
+
 /// Renderer для скелета
 pub struct SkeletonRenderer {
     /// Pre-generated meshes для кожного типу кістки
@@ -362,7 +420,16 @@ pub struct SkeletonRenderer {
     instance_buffers: HashMap<BoneType, wgpu::Buffer>,
     instance_counts: HashMap<BoneType, u32>,
 
+    /// Місткість кожного instance buffer (instances)
+    instance_capacity: usize,
+
+    /// Поточна кількість сегментів капсул
+    segments: u32,
+
     render_pipeline: wgpu::RenderPipeline,
+
+    /// Fill або Line (wireframe, потребує Features::POLYGON_MODE_LINE)
+    polygon_mode: wgpu::PolygonMode,
 }
 
 impl SkeletonRenderer {
@@ -370,6 +437,8 @@ impl SkeletonRenderer {
         device: &wgpu::Device,
         config: &wgpu::SurfaceConfiguration,
         camera_bind_group_layout: &wgpu::BindGroupLayout,
+        light_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
     ) -> Self {
         // === GENERATE MESHES FOR EACH BONE TYPE ===
         let mut bone_meshes = HashMap::new();
@@ -384,43 +453,43 @@ impl SkeletonRenderer {
             BoneType::LowerArm,
             BoneType::UpperLeg,
             BoneType::LowerLeg,
+            BoneType::Weapon,
         ] {
-            let (length, radius_top, radius_bottom) = bone_type.dimensions();
-            let (vertices, indices) = generate_tapered_capsule_real(length, radius_top, radius_bottom, 12);
-
-            log_debug(&format!(
-                "Generated mesh for {:?}: len={:.3}, r_top={:.3}, r_bot={:.3}, verts={}, indices={}",
-                bone_type, length, radius_top, radius_bottom, vertices.len(), indices.len()
-            ));
-
-            let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                label: Some(&format!("{:?} Vertex Buffer", bone_type)),
-                contents: bytemuck::cast_slice(&vertices),
-                usage: wgpu::BufferUsages::VERTEX,
-            });
+            bone_meshes.insert(bone_type, BoneMesh::new(device, bone_type, DEFAULT_CAPSULE_SEGMENTS));
 
-            let index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                label: Some(&format!("{:?} Index Buffer", bone_type)),
-                contents: bytemuck::cast_slice(&indices),
-                usage: wgpu::BufferUsages::INDEX,
-            });
+            // Instance buffer (max 4 instances per type - left/right pairs)
+            instance_buffers.insert(bone_type, Self::create_instance_buffer(device, bone_type, INSTANCES_PER_SKELETON));
+        }
 
-            bone_meshes.insert(bone_type, BoneMesh {
-                vertex_buffer,
-                index_buffer,
-                index_count: indices.len() as u32,
-            });
+        let render_pipeline = Self::create_pipeline(
+            device,
+            config,
+            camera_bind_group_layout,
+            light_bind_group_layout,
+            sample_count,
+            wgpu::PolygonMode::Fill,
+        );
 
-            // Instance buffer (max 4 instances per type - left/right pairs)
-            let instance_buffer = device.create_buffer(&wgpu::BufferDescriptor {
-                label: Some(&format!("{:?} Instance Buffer", bone_type)),
-                size: (std::mem::size_of::<BoneInstance>() * 4) as u64,
-                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
-                mapped_at_creation: false,
-            });
-            instance_buffers.insert(bone_type, instance_buffer);
+        Self {
+            bone_meshes,
+            instance_buffers,
+            instance_counts,
+            instance_capacity: INSTANCES_PER_SKELETON,
+            segments: DEFAULT_CAPSULE_SEGMENTS,
+            render_pipeline,
+            polygon_mode: wgpu::PolygonMode::Fill,
         }
+    }
 
+    /// Render pipeline капсул (camera @ group(0), light @ group(1))
+    fn create_pipeline(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        light_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+        polygon_mode: wgpu::PolygonMode,
+    ) -> wgpu::RenderPipeline {
         // === SHADER ===
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Skeleton Shader"),
@@ -429,11 +498,11 @@ impl SkeletonRenderer {
 
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("Skeleton Pipeline Layout"),
-            bind_group_layouts: &[camera_bind_group_layout],
+            bind_group_layouts: &[camera_bind_group_layout, light_bind_group_layout],
             push_constant_ranges: &[],
         });
 
-        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
             label: Some("Skeleton Render Pipeline"),
             layout: Some(&pipeline_layout),
             vertex: wgpu::VertexState {
@@ -460,7 +529,7 @@ impl SkeletonRenderer {
                 strip_index_format: None,
                 front_face: wgpu::FrontFace::Ccw,
                 cull_mode: Some(wgpu::Face::Back),
-                polygon_mode: wgpu::PolygonMode::Fill,
+                polygon_mode,
                 unclipped_depth: false,
                 conservative: false,
             },
@@ -472,20 +541,72 @@ impl SkeletonRenderer {
                 bias: wgpu::DepthBiasState::default(),
             }),
             multisample: wgpu::MultisampleState {
-                count: 1,
+                count: sample_count,
                 mask: !0,
                 alpha_to_coverage_enabled: false,
             },
             multiview: None,
             cache: None,
-        });
+        })
+    }
 
-        Self {
-            bone_meshes,
-            instance_buffers,
-            instance_counts,
-            render_pipeline,
+    /// Перестворює pipeline під іншу кількість MSAA samples
+    pub fn set_sample_count(
+        &mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        light_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+    ) {
+        self.render_pipeline = Self::create_pipeline(
+            device,
+            config,
+            camera_bind_group_layout,
+            light_bind_group_layout,
+            sample_count,
+            self.polygon_mode,
+        );
+    }
+
+    /// Перемикає заливку / wireframe (перестворює pipeline)
+    ///
+    /// PolygonMode::Line - лише з Features::POLYGON_MODE_LINE на device.
+    pub fn set_polygon_mode(
+        &mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        light_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+        polygon_mode: wgpu::PolygonMode,
+    ) {
+        self.polygon_mode = polygon_mode;
+        self.set_sample_count(device, config, camera_bind_group_layout, light_bind_group_layout, sample_count);
+    }
+
+    /// Instance buffer типу кістки на `capacity` instances
+    fn create_instance_buffer(device: &wgpu::Device, bone_type: BoneType, capacity: usize) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(&format!("{:?} Instance Buffer", bone_type)),
+            size: (std::mem::size_of::<BoneInstance>() * capacity) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        })
+    }
+
+    /// Перегенеровує meshes капсул з новою кількістю сегментів (якість)
+    ///
+    /// Instance buffers не змінюються - позиції кісток зберігаються.
+    pub fn set_segments(&mut self, device: &wgpu::Device, segments: u32) {
+        let segments = segments.max(MIN_CAPSULE_SEGMENTS);
+        if segments == self.segments {
+            return;
+        }
+        for (bone_type, mesh) in self.bone_meshes.iter_mut() {
+            *mesh = BoneMesh::new(device, *bone_type, segments);
         }
+        self.segments = segments;
     }
 
     /// Оновлює instances на основі позицій кісток
@@ -525,27 +646,78 @@ impl SkeletonRenderer {
 
             instances_by_type
                 .entry(bone_type)
-                .or_insert_with(Vec::new)
+                .or_default()
                 .push(BoneInstance {
                     model_matrix: model_matrix.to_cols_array_2d(),
                     color: [color[0], color[1], color[2], 1.0],
                 });
         }
 
-        // Update instance buffers
+        self.write_instances(queue, instances_by_type);
+    }
+
+    /// Оновлює instances кількох скелетів (вороги)
+    ///
+    /// # Аргументи
+    /// * `skeletons` - (bone transforms, tint - множник кольору кісток)
+    pub fn update_skeletons(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        skeletons: &[(&[BoneTransform], [f32; 3])],
+    ) {
+        // Ростемо під кількість скелетів (буфери не зменшуються)
+        let capacity = skeletons.len() * INSTANCES_PER_SKELETON;
+        if capacity > self.instance_capacity {
+            for (bone_type, buffer) in self.instance_buffers.iter_mut() {
+                *buffer = Self::create_instance_buffer(device, *bone_type, capacity);
+            }
+            self.instance_capacity = capacity;
+        }
+
+        let mut instances_by_type: HashMap<BoneType, Vec<BoneInstance>> = HashMap::new();
+        for (bone_transforms, tint) in skeletons {
+            for (bone_id, position, rotation) in bone_transforms.iter() {
+                let color = get_bone_color(*bone_id);
+                instances_by_type
+                    .entry(BoneType::from_bone_id(*bone_id))
+                    .or_default()
+                    .push(BoneInstance {
+                        model_matrix: Mat4::from_rotation_translation(*rotation, *position).to_cols_array_2d(),
+                        color: [color[0] * tint[0], color[1] * tint[1], color[2] * tint[2], 1.0],
+                    });
+            }
+        }
+
+        self.write_instances(queue, instances_by_type);
+    }
+
+    /// Записує instances в buffers (понад місткість - відкидаються)
+    fn write_instances(&mut self, queue: &wgpu::Queue, mut instances_by_type: HashMap<BoneType, Vec<BoneInstance>>) {
         self.instance_counts.clear();
-        for (bone_type, instances) in instances_by_type {
-            if let Some(buffer) = self.instance_buffers.get(&bone_type) {
-                self.instance_counts.insert(bone_type, instances.len() as u32);
-                queue.write_buffer(buffer, 0, bytemuck::cast_slice(&instances));
+        for (bone_type, instances) in instances_by_type.iter_mut() {
+            instances.truncate(self.instance_capacity);
+            if let Some(buffer) = self.instance_buffers.get(bone_type) {
+                self.instance_counts.insert(*bone_type, instances.len() as u32);
+                queue.write_buffer(buffer, 0, bytemuck::cast_slice(instances));
             }
         }
     }
 
-    pub fn render<'a>(&'a self, render_pass: &mut wgpu::RenderPass<'a>, camera_bind_group: &'a wgpu::BindGroup) {
+    pub fn render<'a>(
+        &'a self,
+        render_pass: &mut wgpu::RenderPass<'a>,
+        camera_bind_group: &'a wgpu::BindGroup,
+        light_bind_group: &'a wgpu::BindGroup,
+    ) {
         render_pass.set_pipeline(&self.render_pipeline);
         render_pass.set_bind_group(0, camera_bind_group, &[]);
+        render_pass.set_bind_group(1, light_bind_group, &[]);
+        self.draw_instances(render_pass);
+    }
 
+    /// Малює всі кістки з вже встановленим pipeline (також для outline)
+    pub fn draw_instances<'a>(&'a self, render_pass: &mut wgpu::RenderPass<'a>) {
         // Render each bone type
         for (bone_type, mesh) in &self.bone_meshes {
             let instance_count = self.instance_counts.get(bone_type).copied().unwrap_or(0);
@@ -0,0 +1,211 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/rendering/skybox.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   Skybox - градієнтне небо замість однотонного clear color.
+
+   Fullscreen трикутник малюється першим у кадрі; колір пікселя - з
+   напрямку променя камери (skybox.wgsl), тож горизонт лишається на місці
+   при повороті та нахилі камери.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Власний pipeline без vertex buffers
+   - Uniform: inverse view-projection + кольори зеніту та горизонту
+   - Кольори з settings.ini ([rendering] sky_top_color / sky_bottom_color)
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Імпортує:
+   - camera.rs - Camera::build_view_projection_matrix
+   - settings.rs - Settings::get_rgb
+
+   Експортує для:
+   - renderer.rs - WgpuRenderer::skybox, малюється першим у render_scene
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+   - Depth не пише і не перевіряє (Always) - все інше малюється поверх
+   - Кольори лінійні (surface sRGB), як FLOOR_COLOR та clear color
+   - bottom_color за замовчуванням = колишній clear color: підлога на
+     горизонті зливається з небом
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - градієнтне небо
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use glam::Vec3;
+use wgpu::util::DeviceExt;
+
+use crate::camera::Camera;
+use crate::settings::Settings;
+
+/// Колір неба в зеніті за замовчуванням
+pub const DEFAULT_SKY_TOP_COLOR: [f32; 3] = [0.02, 0.05, 0.12];
+
+/// Колір неба на горизонті та нижче за замовчуванням (колишній clear color)
+pub const DEFAULT_SKY_BOTTOM_COLOR: [f32; 3] = [0.1, 0.2, 0.3];
+
+/// Uniform неба (skybox.wgsl)
+#[repr(C)]
+#[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+struct SkyboxUniform {
+    inv_view_proj: [[f32; 4]; 4],
+    /// rgb + padding
+    top_color: [f32; 4],
+    bottom_color: [f32; 4],
+}
+
+/// Градієнтне небо
+pub struct Skybox {
+    /// Колір у зеніті (лінійний RGB)
+    pub top_color: Vec3,
+
+    /// Колір на горизонті та нижче (лінійний RGB)
+    pub bottom_color: Vec3,
+
+    uniform_buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+    bind_group_layout: wgpu::BindGroupLayout,
+    pipeline: wgpu::RenderPipeline,
+}
+
+impl Skybox {
+    pub fn new(device: &wgpu::Device, config: &wgpu::SurfaceConfiguration, sample_count: u32) -> Self {
+        let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Skybox Uniform Buffer"),
+            contents: bytemuck::cast_slice(&[SkyboxUniform {
+                inv_view_proj: glam::Mat4::IDENTITY.to_cols_array_2d(),
+                top_color: [0.0; 4],
+                bottom_color: [0.0; 4],
+            }]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+            label: Some("skybox_bind_group_layout"),
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout: &bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: uniform_buffer.as_entire_binding(),
+            }],
+            label: Some("skybox_bind_group"),
+        });
+
+        let pipeline = Self::create_pipeline(device, config, &bind_group_layout, sample_count);
+
+        Self {
+            top_color: Vec3::from(DEFAULT_SKY_TOP_COLOR),
+            bottom_color: Vec3::from(DEFAULT_SKY_BOTTOM_COLOR),
+            uniform_buffer,
+            bind_group,
+            bind_group_layout,
+            pipeline,
+        }
+    }
+
+    /// Кольори з секції [rendering] (`sky_top_color`, `sky_bottom_color` = "r, g, b")
+    pub fn apply_settings(&mut self, settings: &Settings) {
+        self.top_color = Vec3::from(settings.get_rgb("rendering.sky_top_color", DEFAULT_SKY_TOP_COLOR));
+        self.bottom_color = Vec3::from(settings.get_rgb("rendering.sky_bottom_color", DEFAULT_SKY_BOTTOM_COLOR));
+    }
+
+    fn create_pipeline(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+    ) -> wgpu::RenderPipeline {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Skybox Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../../assets/shaders/skybox.wgsl").into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Skybox Pipeline Layout"),
+            bind_group_layouts: &[bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Skybox Render Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[], // Вершини з vertex_index
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: config.format,
+                    blend: Some(wgpu::BlendState::REPLACE),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            // Фон: depth не пишеться, все інше малюється поверх
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float,
+                depth_write_enabled: false,
+                depth_compare: wgpu::CompareFunction::Always,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            multiview: None,
+            cache: None,
+        })
+    }
+
+    /// Перестворює pipeline під іншу кількість MSAA samples
+    pub fn set_sample_count(&mut self, device: &wgpu::Device, config: &wgpu::SurfaceConfiguration, sample_count: u32) {
+        self.pipeline = Self::create_pipeline(device, config, &self.bind_group_layout, sample_count);
+    }
+
+    /// Оновлює uniform (раз на кадр, разом з camera uniform)
+    pub fn update(&self, queue: &wgpu::Queue, camera: &Camera) {
+        let uniform = SkyboxUniform {
+            inv_view_proj: camera.build_view_projection_matrix().inverse().to_cols_array_2d(),
+            top_color: self.top_color.extend(1.0).to_array(),
+            bottom_color: self.bottom_color.extend(1.0).to_array(),
+        };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::cast_slice(&[uniform]));
+    }
+
+    /// Малює небо (першим у render pass)
+    pub fn render<'a>(&'a self, render_pass: &mut wgpu::RenderPass<'a>) {
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(0, &self.bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+    }
+}
@@ -0,0 +1,240 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/rendering/texture.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   Texture - зображення на GPU (texture + view + sampler) для TexturedMesh.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Texture::from_path - PNG (та інші формати image crate) → GPU
+   - Texture::from_rgba - сирі RGBA8 пікселі → GPU
+   - create_texture_bind_group_layout - layout texture + sampler
+     (textured_mesh.wgsl group(3))
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Експортує для:
+   - textured_mesh.rs - bind group текстури та layout у pipeline
+
+📦 ЗАЛЕЖНОСТІ:
+   - image = "0.25" - декодування файлів (та сама залежність що в screenshot.rs)
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+   - Формат Rgba8UnormSrgb - кольори PNG в sRGB, surface теж sRGB
+   - Sampler: Repeat (тайлінг UV > 1 для підлоги), linear фільтр
+   - Без mipmaps - один mip-рівень
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - текстури для TexturedMesh
+   2026-10-17: TEXTURE_LAYOUT_ENTRIES - тест звіряє їх з textured_mesh.wgsl
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use std::path::Path;
+
+/// Текстура на GPU
+pub struct Texture {
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    sampler: wgpu::Sampler,
+
+    /// Розмір у пікселях
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Texture {
+    /// Завантажує зображення з файлу (PNG, JPEG, ... - все що декодує image)
+    ///
+    /// # Повертає
+    /// Err з описом, якщо файл не читається або не декодується
+    pub fn from_path(device: &wgpu::Device, queue: &wgpu::Queue, path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let image = image::open(path)
+            .map_err(|e| format!("Failed to load texture {:?}: {}", path, e))?
+            .into_rgba8();
+        let (width, height) = image.dimensions();
+        let label = path.to_string_lossy();
+        Self::from_rgba(device, queue, width, height, image.as_raw(), &label)
+    }
+
+    /// Створює текстуру з RGBA8 пікселів (рядки зверху вниз, без padding)
+    ///
+    /// # Повертає
+    /// Err якщо розмір 0 або довжина pixels не width × height × 4
+    pub fn from_rgba(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        width: u32,
+        height: u32,
+        pixels: &[u8],
+        label: &str,
+    ) -> Result<Self, String> {
+        if width == 0 || height == 0 {
+            return Err(format!("Texture {}: розмір {}x{}", label, width, height));
+        }
+        let expected = width as usize * height as usize * 4;
+        if pixels.len() != expected {
+            return Err(format!(
+                "Texture {}: {} байт замість {} ({}x{} RGBA8)",
+                label,
+                pixels.len(),
+                expected,
+                width,
+                height
+            ));
+        }
+
+        let size = wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        };
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some(label),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+
+        queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            pixels,
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(width * 4),
+                rows_per_image: Some(height),
+            },
+            size,
+        );
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("texture_sampler"),
+            address_mode_u: wgpu::AddressMode::Repeat,
+            address_mode_v: wgpu::AddressMode::Repeat,
+            address_mode_w: wgpu::AddressMode::Repeat,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        Ok(Self {
+            texture,
+            view,
+            sampler,
+            width,
+            height,
+        })
+    }
+
+    /// Bind group текстури (layout - create_texture_bind_group_layout)
+    pub fn create_bind_group(&self, device: &wgpu::Device, layout: &wgpu::BindGroupLayout) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&self.view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&self.sampler),
+                },
+            ],
+            label: Some("texture_bind_group"),
+        })
+    }
+}
+
+/// Entries layout'у текстури: binding 0 - texture_2d<f32> (filterable),
+/// 1 - filtering sampler (fragment shader)
+pub const TEXTURE_LAYOUT_ENTRIES: [wgpu::BindGroupLayoutEntry; 2] = [
+    wgpu::BindGroupLayoutEntry {
+        binding: 0,
+        visibility: wgpu::ShaderStages::FRAGMENT,
+        ty: wgpu::BindingType::Texture {
+            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+            view_dimension: wgpu::TextureViewDimension::D2,
+            multisampled: false,
+        },
+        count: None,
+    },
+    wgpu::BindGroupLayoutEntry {
+        binding: 1,
+        visibility: wgpu::ShaderStages::FRAGMENT,
+        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+        count: None,
+    },
+];
+
+/// Layout bind group'и з текстурою (TEXTURE_LAYOUT_ENTRIES)
+pub fn create_texture_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        entries: &TEXTURE_LAYOUT_ENTRIES,
+        label: Some("texture_bind_group_layout"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use naga::{ImageClass, ImageDimension, ScalarKind, TypeInner};
+
+    /// Група текстури в textured_mesh.wgsl (TexturedMesh::render)
+    const TEXTURE_GROUP: u32 = 3;
+
+    #[test]
+    fn texture_layout_matches_shader() {
+        let source = include_str!("../../assets/shaders/textured_mesh.wgsl");
+        let module = naga::front::wgsl::parse_str(source).expect("textured_mesh.wgsl не парситься");
+        naga::valid::Validator::new(naga::valid::ValidationFlags::all(), naga::valid::Capabilities::empty())
+            .validate(&module)
+            .expect("textured_mesh.wgsl не проходить валідацію");
+
+        let bindings: Vec<_> = module
+            .global_variables
+            .iter()
+            .filter_map(|(_, var)| var.binding.as_ref().map(|binding| (binding.clone(), var.ty)))
+            .filter(|(binding, _)| binding.group == TEXTURE_GROUP)
+            .collect();
+        assert_eq!(bindings.len(), TEXTURE_LAYOUT_ENTRIES.len(), "різна кількість bindings у group(3)");
+
+        for entry in &TEXTURE_LAYOUT_ENTRIES {
+            let (_, ty) = bindings
+                .iter()
+                .find(|(binding, _)| binding.binding == entry.binding)
+                .unwrap_or_else(|| panic!("binding {} немає в шейдері", entry.binding));
+            match (&entry.ty, &module.types[*ty].inner) {
+                (
+                    wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { .. },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled,
+                    },
+                    TypeInner::Image {
+                        dim: ImageDimension::D2,
+                        arrayed: false,
+                        class: ImageClass::Sampled { kind: ScalarKind::Float, multi },
+                    },
+                ) => assert_eq!(multisampled, multi),
+                (
+                    wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    TypeInner::Sampler { comparison: false },
+                ) => {}
+                (layout, shader) => panic!("binding {}: layout {:?}, шейдер {:?}", entry.binding, layout, shader),
+            }
+        }
+    }
+}
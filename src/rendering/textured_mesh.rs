@@ -0,0 +1,399 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/rendering/textured_mesh.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   TexturedMesh - варіант Mesh з UV координатами та текстурою поверхні
+   (підлога арени, персонажі з картинкою замість кольору вершин).
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - TexturedVertex struct (position + normal + uv)
+   - Генерація примітивів з UV (UV-сфера, box)
+   - TexturedMesh: vertex/index buffers, transform, bind group текстури
+   - Render pipeline (textured_mesh.wgsl)
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Імпортує:
+   - texture.rs - Texture, create_texture_bind_group_layout
+   - mesh.rs - create_transform_bind_group_layout (той самий TransformUniform)
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+   - Bind groups: camera @ 0, transform @ 1, light @ 2, texture @ 3
+   - UV: (0, 0) - верхній лівий кут зображення, V вниз (як у wgpu)
+   - Index format: u32 (як у Mesh)
+   - У shadow map поки не малюється (shadow.rs знає лише MeshVertex)
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - textured mesh pipeline з UV
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use wgpu::util::DeviceExt;
+use crate::transform::{Transform, TransformUniform};
+use super::mesh::create_transform_bind_group_layout;
+use super::texture::Texture;
+
+/// Vertex структура для mesh з текстурою
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct TexturedVertex {
+    pub position: [f32; 3],
+    pub normal: [f32; 3],
+    pub uv: [f32; 2],
+}
+
+impl TexturedVertex {
+    /// Vertex buffer layout для wgpu pipeline
+    pub fn vertex_buffer_layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<TexturedVertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &[
+                // position: location 0
+                wgpu::VertexAttribute {
+                    offset: 0,
+                    shader_location: 0,
+                    format: wgpu::VertexFormat::Float32x3,
+                },
+                // normal: location 1
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 3]>() as wgpu::BufferAddress,
+                    shader_location: 1,
+                    format: wgpu::VertexFormat::Float32x3,
+                },
+                // uv: location 2
+                wgpu::VertexAttribute {
+                    offset: std::mem::size_of::<[f32; 6]>() as wgpu::BufferAddress,
+                    shader_location: 2,
+                    format: wgpu::VertexFormat::Float32x2,
+                },
+            ],
+        }
+    }
+}
+
+/// Генерує UV-сферу з центром в (0, 0, 0)
+///
+/// U - по довготі (0..1 навколо Y), V - від північного полюса (0) до південного (1).
+/// Шов по U = 0 / 1 має окремі вершини, тож текстура не розмазується.
+///
+/// # Аргументи
+/// * `radius` - радіус сфери
+/// * `h_segments` - горизонтальні сегменти (longitude)
+/// * `v_segments` - вертикальні сегменти (latitude)
+pub fn generate_textured_sphere(radius: f32, h_segments: u32, v_segments: u32) -> (Vec<TexturedVertex>, Vec<u32>) {
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+
+    for v in 0..=v_segments {
+        let v_t = v as f32 / v_segments as f32;
+        let v_angle = v_t * std::f32::consts::PI;
+        let y = v_angle.cos();
+        let ring_radius = v_angle.sin();
+
+        for h in 0..=h_segments {
+            let h_t = h as f32 / h_segments as f32;
+            let h_angle = h_t * std::f32::consts::TAU;
+            let x = ring_radius * h_angle.cos();
+            let z = ring_radius * h_angle.sin();
+
+            vertices.push(TexturedVertex {
+                position: [x * radius, y * radius, z * radius],
+                normal: [x, y, z],
+                uv: [h_t, v_t],
+            });
+        }
+    }
+
+    // Winding як у generate_sphere
+    for v in 0..v_segments {
+        for h in 0..h_segments {
+            let current = v * (h_segments + 1) + h;
+            let next = current + h_segments + 1;
+
+            indices.extend_from_slice(&[current, next, current + 1]);
+            indices.extend_from_slice(&[current + 1, next, next + 1]);
+        }
+    }
+
+    (vertices, indices)
+}
+
+/// Генерує box з центром в (0, 0, 0), кожна грань - повна текстура (UV 0..1)
+///
+/// # Аргументи
+/// * `width` - розмір по X
+/// * `height` - розмір по Y
+/// * `depth` - розмір по Z
+pub fn generate_textured_box(width: f32, height: f32, depth: f32) -> (Vec<TexturedVertex>, Vec<u32>) {
+    let hx = width / 2.0;
+    let hy = height / 2.0;
+    let hz = depth / 2.0;
+
+    // (нормаль, 4 кути CCW починаючи з нижнього лівого - як у generate_box)
+    let faces: [([f32; 3], [[f32; 3]; 4]); 6] = [
+        // Front (Z+)
+        ([0.0, 0.0, 1.0], [[-hx, -hy, hz], [hx, -hy, hz], [hx, hy, hz], [-hx, hy, hz]]),
+        // Back (Z-)
+        ([0.0, 0.0, -1.0], [[hx, -hy, -hz], [-hx, -hy, -hz], [-hx, hy, -hz], [hx, hy, -hz]]),
+        // Top (Y+)
+        ([0.0, 1.0, 0.0], [[-hx, hy, hz], [hx, hy, hz], [hx, hy, -hz], [-hx, hy, -hz]]),
+        // Bottom (Y-)
+        ([0.0, -1.0, 0.0], [[-hx, -hy, -hz], [hx, -hy, -hz], [hx, -hy, hz], [-hx, -hy, hz]]),
+        // Right (X+)
+        ([1.0, 0.0, 0.0], [[hx, -hy, hz], [hx, -hy, -hz], [hx, hy, -hz], [hx, hy, hz]]),
+        // Left (X-)
+        ([-1.0, 0.0, 0.0], [[-hx, -hy, -hz], [-hx, -hy, hz], [-hx, hy, hz], [-hx, hy, -hz]]),
+    ];
+    // UV кутів: нижній лівий, нижній правий, верхній правий, верхній лівий
+    let corner_uvs = [[0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]];
+
+    let mut vertices = Vec::with_capacity(24);
+    let mut indices = Vec::with_capacity(36);
+    for (face, (normal, corners)) in (0u32..).zip(faces) {
+        for (position, uv) in corners.into_iter().zip(corner_uvs) {
+            vertices.push(TexturedVertex { position, normal, uv });
+        }
+        let base = face * 4;
+        indices.extend_from_slice(&[base, base + 1, base + 2, base + 2, base + 3, base]);
+    }
+
+    (vertices, indices)
+}
+
+/// Mesh з текстурою
+pub struct TexturedMesh {
+    vertex_buffer: wgpu::Buffer,
+    index_buffer: wgpu::Buffer,
+    num_indices: u32,
+    render_pipeline: wgpu::RenderPipeline,
+
+    /// Transform для позиціонування mesh
+    pub transform: Transform,
+
+    /// Tint (RGBA множник кольору текстури)
+    pub tint: [f32; 4],
+
+    /// Transform uniform buffer
+    transform_uniform: TransformUniform,
+    transform_buffer: wgpu::Buffer,
+    transform_bind_group: wgpu::BindGroup,
+    transform_bind_group_layout: wgpu::BindGroupLayout,
+
+    /// Текстура поверхні
+    texture_bind_group: wgpu::BindGroup,
+    texture_bind_group_layout: wgpu::BindGroupLayout,
+}
+
+impl TexturedMesh {
+    /// Створює TexturedMesh з вершин, індексів та текстури
+    ///
+    /// # Аргументи
+    /// * `texture` - текстура поверхні (Texture::from_path)
+    /// * `texture_bind_group_layout` - create_texture_bind_group_layout
+    /// * решта - як у Mesh::new
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        vertices: &[TexturedVertex],
+        indices: &[u32],
+        texture: &Texture,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        light_bind_group_layout: &wgpu::BindGroupLayout,
+        texture_bind_group_layout: &wgpu::BindGroupLayout,
+        transform: Transform,
+        sample_count: u32,
+    ) -> Self {
+        let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Textured Mesh Vertex Buffer"),
+            contents: bytemuck::cast_slice(vertices),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+
+        let index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Textured Mesh Index Buffer"),
+            contents: bytemuck::cast_slice(indices),
+            usage: wgpu::BufferUsages::INDEX,
+        });
+
+        let mut transform_uniform = TransformUniform::new();
+        transform_uniform.update(&transform);
+
+        let transform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Textured Mesh Transform Buffer"),
+            contents: bytemuck::cast_slice(&[transform_uniform]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let transform_bind_group_layout = create_transform_bind_group_layout(device);
+        let transform_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout: &transform_bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: transform_buffer.as_entire_binding(),
+            }],
+            label: Some("textured_mesh_transform_bind_group"),
+        });
+
+        let texture_bind_group = texture.create_bind_group(device, texture_bind_group_layout);
+
+        let render_pipeline = Self::create_pipeline(
+            device,
+            config,
+            camera_bind_group_layout,
+            &transform_bind_group_layout,
+            light_bind_group_layout,
+            texture_bind_group_layout,
+            sample_count,
+        );
+
+        Self {
+            vertex_buffer,
+            index_buffer,
+            num_indices: indices.len() as u32,
+            render_pipeline,
+            transform,
+            tint: [1.0; 4],
+            transform_uniform,
+            transform_buffer,
+            transform_bind_group,
+            transform_bind_group_layout,
+            texture_bind_group,
+            texture_bind_group_layout: texture_bind_group_layout.clone(),
+        }
+    }
+
+    /// Render pipeline (camera @ 0, transform @ 1, light @ 2, texture @ 3)
+    fn create_pipeline(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        transform_bind_group_layout: &wgpu::BindGroupLayout,
+        light_bind_group_layout: &wgpu::BindGroupLayout,
+        texture_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+    ) -> wgpu::RenderPipeline {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Textured Mesh Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../../assets/shaders/textured_mesh.wgsl").into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Textured Mesh Pipeline Layout"),
+            bind_group_layouts: &[
+                camera_bind_group_layout,
+                transform_bind_group_layout,
+                light_bind_group_layout,
+                texture_bind_group_layout,
+            ],
+            push_constant_ranges: &[],
+        });
+
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Textured Mesh Render Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[TexturedVertex::vertex_buffer_layout()],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: config.format,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: Some(wgpu::Face::Back),
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float,
+                depth_write_enabled: true,
+                depth_compare: wgpu::CompareFunction::Less,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            multiview: None,
+            cache: None,
+        })
+    }
+
+    /// Перестворює pipeline під іншу кількість MSAA samples
+    pub fn set_sample_count(
+        &mut self,
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+        light_bind_group_layout: &wgpu::BindGroupLayout,
+        sample_count: u32,
+    ) {
+        self.render_pipeline = Self::create_pipeline(
+            device,
+            config,
+            camera_bind_group_layout,
+            &self.transform_bind_group_layout,
+            light_bind_group_layout,
+            &self.texture_bind_group_layout,
+            sample_count,
+        );
+    }
+
+    /// Замінює текстуру поверхні
+    pub fn set_texture(&mut self, device: &wgpu::Device, texture: &Texture) {
+        self.texture_bind_group = texture.create_bind_group(device, &self.texture_bind_group_layout);
+    }
+
+    /// Оновлює transform buffer на GPU
+    ///
+    /// Викликайте після зміни self.transform або self.tint
+    pub fn update_transform(&mut self, queue: &wgpu::Queue) {
+        self.transform_uniform.update(&self.transform);
+        self.transform_uniform.tint = self.tint;
+        queue.write_buffer(
+            &self.transform_buffer,
+            0,
+            bytemuck::cast_slice(&[self.transform_uniform]),
+        );
+    }
+
+    /// Рендерить mesh
+    ///
+    /// # Аргументи
+    /// * `render_pass` - Активний render pass
+    /// * `camera_bind_group` - Bind group з camera uniform
+    /// * `light_bind_group` - Bind group з light uniform
+    pub fn render<'a>(
+        &'a self,
+        render_pass: &mut wgpu::RenderPass<'a>,
+        camera_bind_group: &'a wgpu::BindGroup,
+        light_bind_group: &'a wgpu::BindGroup,
+    ) {
+        render_pass.set_pipeline(&self.render_pipeline);
+        render_pass.set_bind_group(0, camera_bind_group, &[]);
+        render_pass.set_bind_group(1, &self.transform_bind_group, &[]);
+        render_pass.set_bind_group(2, light_bind_group, &[]);
+        render_pass.set_bind_group(3, &self.texture_bind_group, &[]);
+        render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        render_pass.set_index_buffer(self.index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+        render_pass.draw_indexed(0..self.num_indices, 0, 0..1);
+    }
+}
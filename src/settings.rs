@@ -0,0 +1,386 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/settings.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   Settings - налаштування гри з файлу settings.ini (формат INI).
+
+   Замість hard-coded констант в main.rs параметри читаються з простого
+   текстового файлу. Якщо файлу немає або значення некоректне -
+   використовуються дефолтні значення (з попередженням в лог).
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Парсинг INI файлу: [section], key = value, коментарі # та ;
+   - Типізований доступ до значень (str, u32, f32, bool, будь-який FromStr)
+   - Кольори "r, g, b" (get_rgb) та вектори "x, y, z" (get_vec3)
+   - Зміни під час гри (set) та їх збереження у файл (save)
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Експортує для:
+   - main.rs - завантаження налаштувань при старті
+   - window_info.rs - формат заголовку вікна
+   - main.rs - [rendering] (висота grid)
+   - rendering/debug_lines.rs - [debug] (візуалізація ragdoll)
+   - rendering/skybox.rs - [rendering] кольори неба
+   - main.rs - [rendering] світло (напрямок, колір, ambient)
+
+📦 ЗАЛЕЖНОСТІ:
+   - Немає зовнішніх (тільки std)
+
+⚠️  ВАЖЛИВІ ОБМЕЖЕННЯ:
+   1. Ключі зберігаються як "section.key" (без section - просто "key")
+   2. Відсутній файл - НЕ помилка, гра стартує з дефолтами
+   3. Значення в лапках "..." - лапки знімаються (для рядків з пробілами)
+   4. save() переписує лише рядки змінених ключів - коментарі та решта
+      файлу лишаються як є; нового ключа немає у файлі - додається в
+      кінець своєї секції (або нової секції в кінці файлу)
+
+📝 ПРИКЛАД ФАЙЛУ:
+   ```ini
+   [window]
+   title = "Arena Combat - {fps} FPS"
+   title_update_frames = 30
+   ```
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - INI парсер для налаштувань вікна
+   2026-10-16: get_rgb - кольори у вигляді "r, g, b"
+   2026-10-16: get_vec3 - вектори "x, y, z" (без обмеження 0..1)
+   2026-10-16: set / save - зміни під час гри зберігаються у файл
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Шлях до файлу налаштувань за замовчуванням
+pub const DEFAULT_SETTINGS_PATH: &str = "settings.ini";
+
+/// Налаштування гри (key → value)
+#[derive(Debug, Clone, Default)]
+pub struct Settings {
+    /// Значення за ключем "section.key"
+    values: HashMap<String, String>,
+
+    /// Ключі, змінені через set (в порядку зміни) - їх пише save
+    changed: Vec<String>,
+}
+
+impl Settings {
+    /// Завантажує налаштування з файлу
+    ///
+    /// Якщо файл не існує або не читається - повертає порожні налаштування
+    /// (всі getters повернуть дефолтні значення).
+    pub fn load(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        match std::fs::read_to_string(path) {
+            Ok(text) => {
+                log::info!("Налаштування завантажено з {:?}", path);
+                Self::parse(&text)
+            }
+            Err(e) => {
+                log::warn!("Не вдалося прочитати {:?} ({}), використовуємо дефолти", path, e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Парсить текст у форматі INI
+    pub fn parse(text: &str) -> Self {
+        let mut values = HashMap::new();
+        let mut section = String::new();
+
+        for (line_no, raw_line) in text.lines().enumerate() {
+            let line = raw_line.trim();
+
+            // Порожні рядки та коментарі
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+
+            // [section]
+            if line.starts_with('[') && line.ends_with(']') {
+                section = line[1..line.len() - 1].trim().to_lowercase();
+                continue;
+            }
+
+            // key = value
+            let Some((key, value)) = line.split_once('=') else {
+                log::warn!("settings: рядок {} ігнорується: {:?}", line_no + 1, raw_line);
+                continue;
+            };
+
+            let key = key.trim().to_lowercase();
+            let mut value = value.trim();
+            if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+                value = &value[1..value.len() - 1];
+            }
+
+            let full_key = if section.is_empty() {
+                key
+            } else {
+                format!("{}.{}", section, key)
+            };
+            values.insert(full_key, value.to_string());
+        }
+
+        Self { values, changed: Vec::new() }
+    }
+
+    /// Повертає сирий рядок за ключем "section.key"
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    /// Повертає значення, розпарсене в тип T (None якщо немає або некоректне)
+    pub fn get<T: FromStr>(&self, key: &str) -> Option<T> {
+        let raw = self.get_str(key)?;
+        match raw.parse::<T>() {
+            Ok(value) => Some(value),
+            Err(_) => {
+                log::warn!("settings: некоректне значення {} = {:?}", key, raw);
+                None
+            }
+        }
+    }
+
+    /// Повертає u32 або дефолт
+    pub fn get_u32(&self, key: &str, default: u32) -> u32 {
+        self.get(key).unwrap_or(default)
+    }
+
+    /// Повертає f32 або дефолт
+    pub fn get_f32(&self, key: &str, default: f32) -> f32 {
+        self.get(key).unwrap_or(default)
+    }
+
+    /// Три числа "a, b, c" (None - немає ключа, Err - сирий некоректний рядок)
+    fn get_triple(&self, key: &str) -> Option<Result<[f32; 3], &str>> {
+        let raw = self.get_str(key)?;
+        let components: Vec<f32> = raw.split(',').filter_map(|c| c.trim().parse().ok()).collect();
+        Some(match components[..] {
+            [a, b, c] => Ok([a, b, c]),
+            _ => Err(raw),
+        })
+    }
+
+    /// Повертає колір "r, g, b" (компоненти 0..1) або дефолт
+    pub fn get_rgb(&self, key: &str, default: [f32; 3]) -> [f32; 3] {
+        match self.get_triple(key) {
+            None => default,
+            Some(Ok(rgb)) => rgb.map(|c| c.clamp(0.0, 1.0)),
+            Some(Err(raw)) => {
+                log::warn!("settings: некоректний колір {} = {:?} (очікується r, g, b)", key, raw);
+                default
+            }
+        }
+    }
+
+    /// Повертає вектор "x, y, z" або дефолт
+    pub fn get_vec3(&self, key: &str, default: [f32; 3]) -> [f32; 3] {
+        match self.get_triple(key) {
+            None => default,
+            Some(Ok(xyz)) => xyz,
+            Some(Err(raw)) => {
+                log::warn!("settings: некоректний вектор {} = {:?} (очікується x, y, z)", key, raw);
+                default
+            }
+        }
+    }
+
+    /// Змінює значення за ключем "section.key" (у файл - при save)
+    pub fn set(&mut self, key: &str, value: impl ToString) {
+        let value = value.to_string();
+        if self.values.get(key) == Some(&value) {
+            return;
+        }
+        self.values.insert(key.to_string(), value);
+        if !self.changed.iter().any(|k| k == key) {
+            self.changed.push(key.to_string());
+        }
+    }
+
+    /// Зберігає змінені через set значення у файл
+    ///
+    /// Нічого не змінено - файл не чіпається. Файлу немає - створюється
+    /// лише зі зміненими ключами (решта - дефолти, як і без файлу).
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        if self.changed.is_empty() {
+            return Ok(());
+        }
+        let path = path.as_ref();
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(format!("{:?}: {}", path, e)),
+        };
+        std::fs::write(path, self.apply_changes(&text)).map_err(|e| format!("{:?}: {}", path, e))?;
+        log::info!("Налаштування збережено в {:?} ({})", path, self.changed.join(", "));
+        Ok(())
+    }
+
+    /// Текст файлу зі зміненими значеннями (рядки ключів - на місці)
+    fn apply_changes(&self, text: &str) -> String {
+        let section_of = |key: &str| key.split_once('.').map_or("", |(section, _)| section).to_string();
+        let line_of = |key: &str| {
+            let name = key.split_once('.').map_or(key, |(_, name)| name);
+            format!("{} = {}", name, self.values[key])
+        };
+
+        // Прохід 1: рядки ключів та останній рядок кожної секції
+        let lines: Vec<&str> = text.lines().collect();
+        let mut line_keys = vec![None; lines.len()];
+        let mut section_ends: HashMap<String, usize> = HashMap::new();
+        let mut section = String::new();
+        for (index, raw_line) in lines.iter().enumerate() {
+            let line = raw_line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+            if line.starts_with('[') && line.ends_with(']') {
+                section = line[1..line.len() - 1].trim().to_lowercase();
+            } else if let Some((key, _)) = line.split_once('=') {
+                let key = key.trim().to_lowercase();
+                line_keys[index] = Some(if section.is_empty() { key } else { format!("{}.{}", section, key) });
+            }
+            section_ends.insert(section.clone(), index);
+        }
+
+        // Нові ключі - в кінець своєї секції (або нової секції)
+        let existing: Vec<&String> = line_keys.iter().flatten().collect();
+        let mut appended: HashMap<String, Vec<String>> = HashMap::new();
+        let mut new_sections: Vec<String> = Vec::new();
+        for key in self.changed.iter().filter(|key| !existing.contains(key)) {
+            let section = section_of(key);
+            if !section_ends.contains_key(&section) && !new_sections.contains(&section) {
+                new_sections.push(section.clone());
+            }
+            appended.entry(section).or_default().push(line_of(key));
+        }
+
+        // Прохід 2: змінені рядки на місці, нові ключі після кінця секції
+        let mut out = String::with_capacity(text.len());
+        for (index, raw_line) in lines.iter().enumerate() {
+            match &line_keys[index] {
+                Some(key) if self.changed.contains(key) => out.push_str(&line_of(key)),
+                _ => out.push_str(raw_line),
+            }
+            out.push('\n');
+            for (section, end) in &section_ends {
+                if *end == index {
+                    for line in appended.get(section).into_iter().flatten() {
+                        out.push_str(line);
+                        out.push('\n');
+                    }
+                }
+            }
+        }
+        for section in new_sections {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            if !section.is_empty() {
+                out.push_str(&format!("[{}]\n", section));
+            }
+            for line in &appended[&section] {
+                out.push_str(line);
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    /// Повертає bool або дефолт (true/false, yes/no, on/off, 1/0)
+    pub fn get_bool(&self, key: &str, default: bool) -> bool {
+        match self.get_str(key).map(|v| v.to_lowercase()) {
+            None => default,
+            Some(v) => match v.as_str() {
+                "true" | "yes" | "on" | "1" => true,
+                "false" | "no" | "off" | "0" => false,
+                _ => {
+                    log::warn!("settings: некоректне значення {} = {:?}", key, v);
+                    default
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INI: &str = "\
+# коментар
+; теж коментар
+top = 1
+
+[Camera]
+FOV = 60
+name = \"third person\"
+sensitivity=0.5
+
+рядок без знака рівності
+[physics]
+gravity = 0, -9.81, 0
+substeps = багато
+collision = off
+";
+
+    #[test]
+    fn parses_sections_keys_and_quotes() {
+        let settings = Settings::parse(INI);
+        assert_eq!(settings.get_u32("top", 0), 1);
+        // Секції та ключі - без регістру, значення - як є, без лапок
+        assert_eq!(settings.get_u32("camera.fov", 0), 60);
+        assert_eq!(settings.get_str("camera.name"), Some("third person"));
+        assert_eq!(settings.get_f32("camera.sensitivity", 0.0), 0.5);
+        assert_eq!(settings.get_vec3("physics.gravity", [0.0; 3]), [0.0, -9.81, 0.0]);
+        assert!(!settings.get_bool("physics.collision", true));
+    }
+
+    #[test]
+    fn missing_keys_fall_back_to_defaults() {
+        let settings = Settings::parse(INI);
+        assert_eq!(settings.get_str("camera.missing"), None);
+        assert_eq!(settings.get_u32("camera.missing", 7), 7);
+        assert_eq!(settings.get_f32("missing.key", 1.5), 1.5);
+        assert!(settings.get_bool("missing.key", true));
+        // Ключ з іншої секції не підхоплюється
+        assert_eq!(settings.get_u32("physics.fov", 45), 45);
+        assert_eq!(Settings::parse("").get_u32("camera.fov", 45), 45);
+        assert_eq!(Settings::load("does/not/exist.ini").get_u32("camera.fov", 45), 45);
+    }
+
+    #[test]
+    fn malformed_values_fall_back_to_defaults() {
+        let settings = Settings::parse(INI);
+        assert_eq!(settings.get_u32("physics.substeps", 4), 4);
+        assert_eq!(settings.get_f32("camera.name", 2.0), 2.0);
+        assert!(settings.get_bool("camera.name", true));
+        assert_eq!(settings.get_rgb("physics.gravity", [1.0; 3]), [0.0, 0.0, 0.0], "компоненти кольору обрізаються до 0..1");
+        assert_eq!(settings.get_vec3("camera.fov", [1.0, 2.0, 3.0]), [1.0, 2.0, 3.0]);
+        // Рядок без "=" пропущено, розбір продовжився
+        assert_eq!(settings.get_str("рядок без знака рівності"), None);
+    }
+
+    #[test]
+    fn set_rewrites_line_in_place_and_appends_new_keys() {
+        let mut settings = Settings::parse(INI);
+        settings.set("camera.fov", 75);
+        settings.set("camera.distance", 4.5);
+        settings.set("audio.volume", 0.8);
+        let text = settings.apply_changes(INI);
+
+        let reparsed = Settings::parse(&text);
+        assert_eq!(reparsed.get_u32("camera.fov", 0), 75);
+        assert_eq!(reparsed.get_f32("camera.distance", 0.0), 4.5);
+        assert_eq!(reparsed.get_f32("audio.volume", 0.0), 0.8);
+        assert_eq!(reparsed.get_str("physics.gravity"), Some("0, -9.81, 0"));
+        assert!(text.contains("# коментар"), "коментарі загубились");
+        assert!(!text.contains("FOV = 60"));
+    }
+}
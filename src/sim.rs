@@ -0,0 +1,414 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/sim.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   Simulation - фізика гри без вікна та GPU: фізичний світ, ragdoll гравця,
+   вороги з їхніми ragdoll та AI, hitbox'и, fixed timestep.
+
+   main.rs веде її кадрами (кроки з world_delta → advance) і синхронізує
+   renderer. Headless режим (`--headless <ticks>`) веде її тим самим
+   advance і перевіряє інваріанти кожен крок - для CI та для перевірки
+   змін у physics/ без запуску гри.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Створення світу з settings.ini: земля, стіни, ragdoll гравця, вороги
+   - SimInput: рух, спринт, стрибок гравця
+   - Крок фізики: м'язи ragdoll → step → watchdog → snapshot → удари кінцівок
+   - AI ворогів: переслідування, атаки → hitbox'и, розштовхування, стіни,
+     видалення зниклих
+   - Час життя hitbox'ів
+   - Рестарт: ragdoll гравця на місце, вороги заново
+   - Інваріанти: pelvis у стійці над землею, суглоби не розходяться
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Імпортує:
+   - physics/ - PhysicsWorld, ActiveRagdoll, ImpactDetector
+   - enemy/ - Enemy, EnemyConfig, ragdoll ворогів, AI
+   - combat/hitbox.rs - HitboxManager (атаки ворогів і гравця)
+   - arena.rs, settings.rs - розмір арени та параметри ragdoll
+
+   Експортує для:
+   - main.rs - App::sim, `--headless`
+   - tests/sim.rs - integration тести (N кроків, інваріанти)
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+   - Бій гравця (Combat, влучання hitbox'ів у гравця та ворогів, блок,
+     шкода Player) лишається в main.rs - йому потрібні InputState, aim
+     assist, кінематичний Player, hitstop та тряска камери
+   - advance - рівно `ticks` кроків; main.rs рахує їх FixedStepper'ом
+     (step_count для replay), headless - передає напряму
+   - Headless вороги переслідують ragdoll гравця та б'ють, але їхні
+     hitbox'и нікого не ранять (влучання в гравця - в main.rs)
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - фізика з main.rs без вікна, headless режим
+   2026-10-16: RagdollWatchdog після кожного кроку (гравець і вороги)
+   2026-10-17: FixedStepper замість accumulator у PhysicsWorld
+   2026-10-17: В lib.rs - integration тести в tests/sim.rs
+   2026-10-17: Кругла арена - PhysicsWorld::create_arena
+   2026-10-17: AI ворогів та hitbox'и з main.rs - advance замість step
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use glam::Vec3;
+
+use crate::arena::{ArenaConfig, ArenaShape};
+use crate::combat::HitboxManager;
+use crate::enemy::{self, Enemy, EnemyConfig};
+use crate::physics::ragdoll::{RagdollMode, DEFAULT_MOVE_SPEED};
+use crate::physics::{
+    ActiveRagdoll, BoneId, ImpactDetector, ImpactEvent, PhysicsWorld, RagdollStiffness, RagdollWatchdog,
+};
+use crate::settings::Settings;
+use crate::time::FixedStepper;
+
+/// Висота pelvis при спавні / reset ragdoll (метри)
+pub const RAGDOLL_SPAWN_HEIGHT: f32 = 2.0;
+
+/// Мінімальна висота pelvis у стійці (RagdollMode::Active), метри
+const MIN_STANDING_PELVIS_HEIGHT: f32 = 0.5;
+
+/// Допустимий розрив суглоба - частка довжини кістки
+const MAX_JOINT_SEPARATION: f32 = 0.1;
+
+/// Ввід гравця для Simulation
+#[derive(Debug, Clone, Copy)]
+pub struct SimInput {
+    /// Напрямок руху (world space, XZ; нуль - стоїть)
+    pub move_dir: Vec3,
+
+    /// Множник швидкості руху (спринт)
+    pub speed_scale: f32,
+
+    /// Спринт (довші кроки ragdoll)
+    pub sprinting: bool,
+
+    /// Стрибок (раз на apply_input)
+    pub jump: bool,
+
+    /// Ціль AI ворогів: кінематичний гравець (None - ragdoll гравця)
+    pub player_position: Option<Vec3>,
+}
+
+impl Default for SimInput {
+    /// Стоїть на місці
+    fn default() -> Self {
+        Self {
+            move_dir: Vec3::ZERO,
+            speed_scale: 1.0,
+            sprinting: false,
+            jump: false,
+            player_position: None,
+        }
+    }
+}
+
+/// Події кроків advance для main.rs (частинки, меші ворогів, lock-on)
+#[derive(Debug, Default)]
+pub struct SimEvents {
+    /// Удари кінцівок ragdoll гравця
+    pub impacts: Vec<ImpactEvent>,
+
+    /// Індекси видалених ворогів у порядку видалення
+    pub removed_enemies: Vec<usize>,
+}
+
+/// Фізика гри без вікна та GPU
+pub struct Simulation {
+    pub physics_world: PhysicsWorld,
+
+    /// Accumulator кадрів → fixed steps (main.rs, replay)
+    pub stepper: FixedStepper,
+
+    /// Ragdoll гравця
+    pub ragdoll: ActiveRagdoll,
+
+    pub enemies: Vec<Enemy>,
+    pub enemy_config: EnemyConfig,
+
+    /// Hitbox'и атак ворогів та гравця
+    pub hitbox_manager: HitboxManager,
+
+    /// Стіни арени для AI ворогів (рух повз фізику)
+    pub arena: ArenaConfig,
+
+    /// Удари кінцівок ragdoll гравця → пил та звук
+    pub impact_detector: ImpactDetector,
+
+    /// Профіль жорсткості ragdoll ворогів (рестарт)
+    stiffness: RagdollStiffness,
+}
+
+impl Simulation {
+    /// Створює світ: арена, ragdoll гравця та вороги по колу
+    ///
+    /// Ключі: [physics] (ragdoll гравця), [enemies] (вороги), [combat]
+    /// (ImpactDetector)
+    pub fn from_settings(settings: &Settings, arena: &ArenaConfig) -> Self {
+        // Вороги по колу навколо центру ([enemies] count = 0 - без ворогів, тестування ragdoll)
+        let enemy_config = EnemyConfig::from_settings(settings);
+        let mut enemies = spawn_enemies(&enemy_config);
+
+        // Фізичний світ та ragdoll
+        let mut physics_world = PhysicsWorld::new();
+        match arena.shape {
+            ArenaShape::Circle => physics_world.create_arena(arena.half_extent, arena.wall_height),
+            ArenaShape::Square => {
+                physics_world.create_ground(0.0, arena.half_extent); // Земля на Y=0
+                physics_world.create_walls(&arena.wall_boxes());
+            }
+        }
+        physics_world.collect_collision_events = true;
+
+        // Ragdoll на висоті RAGDOLL_SPAWN_HEIGHT
+        // Профіль жорсткості з settings.ini ([physics] ragdoll_stiffness)
+        let stiffness = RagdollStiffness::from_settings(settings);
+        let mut ragdoll = ActiveRagdoll::new(
+            &mut physics_world,
+            Vec3::new(0.0, RAGDOLL_SPAWN_HEIGHT, 0.0),
+            stiffness,
+        );
+        // Падіння: поріг нахилу pelvis та автоматичний підйом ([physics])
+        ragdoll.fall_threshold = settings
+            .get_f32("physics.fall_threshold", ragdoll.fall_threshold.to_degrees())
+            .to_radians();
+        ragdoll.balance_recovery_enabled = settings.get_bool("physics.balance_recovery", false);
+        ragdoll.balance_kp = settings.get_f32("physics.balance_kp", ragdoll.balance_kp).max(0.0);
+        ragdoll.balance_kd = settings.get_f32("physics.balance_kd", ragdoll.balance_kd).max(0.0);
+        // Нокдаун від жорсткого приземлення та шкода від падіння
+        ragdoll.knockdown_speed = settings.get_f32("physics.knockdown_speed", ragdoll.knockdown_speed).max(0.0);
+        ragdoll.knockdown_duration = settings
+            .get_f32("physics.knockdown_duration", ragdoll.knockdown_duration)
+            .max(0.0);
+        ragdoll.fall_damage_per_speed = settings
+            .get_f32("physics.fall_damage_per_speed", ragdoll.fall_damage_per_speed)
+            .max(0.0);
+        ragdoll.jump_speed = settings.get_f32("physics.jump_speed", ragdoll.jump_speed).max(0.0);
+        // Ходьба кроками та старт з увімкненими м'язами
+        ragdoll.stepping_enabled = settings.get_bool("physics.stepping", ragdoll.stepping_enabled);
+        ragdoll.step_assist = settings.get_f32("physics.step_assist", ragdoll.step_assist).clamp(0.0, 1.0);
+        ragdoll.start_active = settings.get_bool("physics.start_active", true);
+        if ragdoll.start_active {
+            ragdoll.activate();
+        }
+        ragdoll.walk_cycle.foot_ik_enabled = settings.get_bool("physics.foot_ik", true);
+        // Запобіжник від вибуху фізики ([physics] watchdog_*)
+        ragdoll.watchdog = RagdollWatchdog::from_settings(settings);
+        // Фізичний меч у правій руці
+        ragdoll.skeleton.attach_weapon(&mut physics_world);
+        log::info!("Physics ragdoll created");
+
+        // Ragdoll ворогів ([enemies] ragdoll = false - манекени)
+        if enemy_config.ragdoll {
+            enemy::attach_ragdolls(&mut enemies, &mut physics_world, stiffness);
+        }
+
+        Self {
+            stepper: FixedStepper::new(physics_world.fixed_timestep),
+            physics_world,
+            ragdoll,
+            enemies,
+            enemy_config,
+            hitbox_manager: HitboxManager::new(),
+            arena: *arena,
+            impact_detector: ImpactDetector::from_settings(settings),
+            stiffness,
+        }
+    }
+
+    /// Ввід гравця для наступних кроків
+    pub fn apply_input(&mut self, input: &SimInput) {
+        self.ragdoll.move_speed = DEFAULT_MOVE_SPEED * input.speed_scale;
+        self.ragdoll.set_sprinting(input.sprinting);
+        self.ragdoll.set_move_direction(input.move_dir);
+        if input.jump {
+            self.ragdoll.jump(&mut self.physics_world);
+        }
+    }
+
+    /// Ввід та рівно `ticks` фіксованих кроків: AI ворогів, hitbox'и, фізика
+    ///
+    /// main.rs - кроки, що накопичились за кадр (FixedStepper), headless -
+    /// скільки попросили
+    ///
+    /// # Повертає
+    /// Удари кінцівок ragdoll гравця та видалених ворогів за всі кроки
+    pub fn advance(&mut self, ticks: u32, input: &SimInput) -> SimEvents {
+        self.apply_input(input);
+
+        let dt = self.physics_world.fixed_timestep;
+        let mut events = SimEvents::default();
+        for _ in 0..ticks {
+            // Ragdoll ворогів йдуть за AI (позиції з минулого кроку)
+            for enemy in &mut self.enemies {
+                enemy.sync_ragdoll(dt);
+            }
+            self.fixed_step(&mut events.impacts);
+
+            let target = input
+                .player_position
+                .unwrap_or_else(|| self.ragdoll.get_position(&self.physics_world));
+            self.update_enemies(target, dt, &mut events.removed_enemies);
+            self.hitbox_manager.update(dt);
+        }
+        events
+    }
+
+    /// Один fixed timestep: м'язи → крок фізики → watchdog → snapshot для інтерполяції
+    fn fixed_step(&mut self, impacts: &mut Vec<ImpactEvent>) {
+        let physics = &mut self.physics_world;
+        let dt = physics.fixed_timestep;
+
+        // Оновлюємо ragdoll (м'язи + цільова поза)
+        self.ragdoll.update(physics, dt);
+        for enemy_ragdoll in self.enemies.iter_mut().filter_map(|e| e.ragdoll.as_mut()) {
+            enemy_ragdoll.update(physics, dt);
+        }
+
+        // Крок фізики (швидкості до кроку - для сили удару)
+        self.impact_detector.record_velocities(physics, &self.ragdoll.skeleton);
+        physics.step(dt);
+        self.ragdoll.check_watchdog(physics);
+        self.ragdoll.snapshot_transforms(physics);
+        for enemy_ragdoll in self.enemies.iter_mut().filter_map(|e| e.ragdoll.as_mut()) {
+            enemy_ragdoll.check_watchdog(physics);
+            enemy_ragdoll.snapshot_transforms(physics);
+        }
+        impacts.extend(self.impact_detector.collect(physics, &self.ragdoll.skeleton, dt));
+    }
+
+    /// AI ворогів за крок: переслідування та атаки (hitbox б'є гравця в
+    /// main.rs), розштовхування, стіни арени, видалення зниклих
+    fn update_enemies(&mut self, player_pos: Vec3, dt: f32, removed: &mut Vec<usize>) {
+        for (i, enemy) in self.enemies.iter_mut().enumerate() {
+            if let Some(attack) = enemy.update(player_pos, dt) {
+                self.hitbox_manager.spawn(attack.hitbox(i));
+            }
+        }
+        enemy::apply_separation(&mut self.enemies, self.enemy_config.radius, dt);
+        // AI та knockback рухають ворогів повз фізику - стіни арени тут
+        for enemy in &mut self.enemies {
+            enemy.position = self.arena.clamp_inside(enemy.position, self.enemy_config.radius);
+        }
+
+        // Видаляємо ворогів, які повністю зникли (з кінця, щоб індекси не зсувались)
+        for i in (0..self.enemies.len()).rev() {
+            if self.enemies[i].should_remove() {
+                self.enemies[i].detach_ragdoll(&mut self.physics_world);
+                self.enemies.remove(i);
+                self.hitbox_manager.on_enemy_removed(i);
+                removed.push(i);
+                log::info!("Enemy {} removed after fade-out", i);
+            }
+        }
+    }
+
+    /// Рестарт: ragdoll гравця над центром, вороги заново по колу
+    pub fn reset(&mut self) {
+        self.ragdoll.reset(&mut self.physics_world, Vec3::new(0.0, RAGDOLL_SPAWN_HEIGHT, 0.0));
+
+        // Старі ragdoll ворогів - з фізичного світу
+        for enemy in &mut self.enemies {
+            enemy.detach_ragdoll(&mut self.physics_world);
+        }
+        self.enemies = spawn_enemies(&self.enemy_config);
+        self.hitbox_manager.clear();
+        if self.enemy_config.ragdoll {
+            enemy::attach_ragdolls(&mut self.enemies, &mut self.physics_world, self.stiffness);
+        }
+    }
+
+    /// Перевіряє інваріанти ragdoll гравця
+    ///
+    /// - У стійці (RagdollMode::Active) pelvis не нижче MIN_STANDING_PELVIS_HEIGHT
+    /// - Розрив кожного суглоба - не більше MAX_JOINT_SEPARATION довжини кістки
+    ///
+    /// # Повертає
+    /// Err з описом першого порушення
+    pub fn check_invariants(&self) -> Result<(), String> {
+        let physics = &self.physics_world;
+        let skeleton = &self.ragdoll.skeleton;
+
+        if self.ragdoll.mode == RagdollMode::Active {
+            let pelvis = skeleton
+                .get_bone_position(physics, BoneId::Pelvis)
+                .ok_or("немає тіла pelvis")?;
+            if !pelvis.is_finite() || pelvis.y < MIN_STANDING_PELVIS_HEIGHT {
+                return Err(format!("pelvis у стійці на висоті {:.3} м", pelvis.y));
+            }
+        }
+
+        for bone_id in BoneId::all_bones() {
+            let (Some(bone), Some(separation)) =
+                (skeleton.bones.get(&bone_id), skeleton.joint_separation(physics, bone_id))
+            else {
+                continue;
+            };
+            if !separation.is_finite() || separation > bone.length * MAX_JOINT_SEPARATION {
+                return Err(format!(
+                    "суглоб {:?} розійшовся на {:.3} м (кістка {:.2} м)",
+                    bone_id, separation, bone.length
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Вороги по колу навколо центру з параметрами EnemyConfig
+fn spawn_enemies(config: &EnemyConfig) -> Vec<Enemy> {
+    let mut enemies = enemy::spawn_enemies_circle(Vec3::ZERO, config.spawn_radius, config.count);
+    for enemy in &mut enemies {
+        config.apply(enemy);
+    }
+    enemies
+}
+
+/// Кількість кроків headless режиму (`--headless <ticks>` або `--headless=<ticks>`)
+pub fn headless_ticks_from_args(args: impl IntoIterator<Item = String>) -> Option<u32> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let value = if let Some(v) = arg.strip_prefix("--headless=") {
+            Some(v.to_string())
+        } else if arg == "--headless" {
+            args.next()
+        } else {
+            continue;
+        };
+        return match value.as_deref().map(str::parse::<u32>) {
+            Some(Ok(ticks)) => Some(ticks),
+            _ => {
+                log::warn!("--headless: некоректна кількість кроків {:?}", value);
+                None
+            }
+        };
+    }
+    None
+}
+
+/// Headless прогін: `ticks` кроків без вводу, інваріанти після кожного
+///
+/// # Повертає
+/// Err з номером кроку та першим порушенням
+pub fn run_headless(settings: &Settings, arena: &ArenaConfig, ticks: u32) -> Result<(), String> {
+    let mut sim = Simulation::from_settings(settings, arena);
+    let input = SimInput::default();
+    for tick in 1..=ticks {
+        sim.advance(1, &input);
+        sim.check_invariants().map_err(|e| format!("крок {}: {}", tick, e))?;
+    }
+
+    let pelvis = sim.ragdoll.get_position(&sim.physics_world);
+    log::info!(
+        "Headless: {} кроків, pelvis ({:.3}, {:.3}, {:.3}), режим {:?}",
+        ticks,
+        pelvis.x,
+        pelvis.y,
+        pelvis.z,
+        sim.ragdoll.mode
+    );
+    Ok(())
+}
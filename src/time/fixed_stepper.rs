@@ -0,0 +1,159 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/time/fixed_stepper.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   FixedStepper - accumulator fixed timestep: час кадру → ціла кількість
+   кроків фізики по `dt`, залишок переходить у наступний кадр.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Накопичення часу кадру, не більше MAX_FIXED_STEPS_PER_FRAME кроків
+   - Видача кроків (consume_step, steps_for), лічильник кроків з початку
+   - interpolation_alpha - частка кроку в залишку (інтерполяція рендеру)
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Експортує для:
+   - main.rs - кроки кадру для Simulation::advance (steps_for), step_count
+     (replay), interpolation_alpha (скелети)
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+   - Час понад MAX_FIXED_STEPS_PER_FRAME кроків відкидається - після
+     довгого кадру (hitch) світ на мить сповільнюється замість "spiral of death"
+
+📝 ПРИКЛАД:
+   ```rust
+   stepper.accumulate(frame_delta);
+   while stepper.consume_step() {
+       physics.step(stepper.dt());
+   }
+   ```
+
+🕐 ІСТОРІЯ:
+   2026-10-17: Створено - accumulator з PhysicsWorld
+   2026-10-17: steps_for - кроки кадру одним числом (Simulation::advance)
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+/// Максимум fixed steps за кадр
+pub const MAX_FIXED_STEPS_PER_FRAME: u32 = 8;
+
+/// Accumulator fixed timestep
+#[derive(Debug, Clone)]
+pub struct FixedStepper {
+    /// Фіксований крок (секунди)
+    dt: f32,
+
+    /// Накопичений час, ще не виданий кроками (< dt після кроків)
+    accumulator: f32,
+
+    /// Виданих кроків з початку (consume_step)
+    step_count: u64,
+}
+
+impl FixedStepper {
+    /// Створює stepper з кроком `dt` секунд
+    pub fn new(dt: f32) -> Self {
+        Self {
+            dt,
+            accumulator: 0.0,
+            step_count: 0,
+        }
+    }
+
+    /// Фіксований крок (секунди)
+    pub fn dt(&self) -> f32 {
+        self.dt
+    }
+
+    /// Додає час кадру (обмежено MAX_FIXED_STEPS_PER_FRAME кроками)
+    pub fn accumulate(&mut self, delta: f32) {
+        let max_accumulated = self.dt * MAX_FIXED_STEPS_PER_FRAME as f32;
+        self.accumulator = (self.accumulator + delta).min(max_accumulated);
+    }
+
+    /// Забирає один крок з accumulator
+    ///
+    /// # Повертає
+    /// `true` якщо накопичено достатньо часу для ще одного кроку
+    pub fn consume_step(&mut self) -> bool {
+        if self.accumulator >= self.dt {
+            self.accumulator -= self.dt;
+            self.step_count += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Кадр: додає `delta` та забирає всі кроки, що накопичились
+    ///
+    /// # Повертає
+    /// Кількість кроків (не більше MAX_FIXED_STEPS_PER_FRAME)
+    pub fn steps_for(&mut self, delta: f32) -> u32 {
+        self.accumulate(delta);
+        let mut steps = 0;
+        while self.consume_step() {
+            steps += 1;
+        }
+        steps
+    }
+
+    /// Кількість кроків з початку (replay порівнює її з записом)
+    pub fn step_count(&self) -> u64 {
+        self.step_count
+    }
+
+    /// Частка кроку, що залишилась в accumulator (0..1)
+    ///
+    /// Використовується для інтерполяції між попереднім та поточним станом.
+    pub fn interpolation_alpha(&self) -> f32 {
+        (self.accumulator / self.dt).clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DT: f32 = 1.0 / 60.0;
+
+    #[test]
+    fn frame_of_whole_steps() {
+        let mut stepper = FixedStepper::new(DT);
+        assert_eq!(stepper.steps_for(DT * 3.0 + 1e-4), 3);
+        assert_eq!(stepper.step_count(), 3);
+    }
+
+    #[test]
+    fn remainder_carries_over() {
+        let mut stepper = FixedStepper::new(DT);
+        assert_eq!(stepper.steps_for(DT * 0.6), 0);
+        assert_eq!(stepper.steps_for(DT * 0.6), 1);
+        assert!((stepper.interpolation_alpha() - 0.2).abs() < 1e-3);
+    }
+
+    #[test]
+    fn long_frame_is_clamped() {
+        // 0.5 с = 30 кроків, але видається не більше MAX_FIXED_STEPS_PER_FRAME
+        let mut stepper = FixedStepper::new(DT);
+        assert_eq!(stepper.steps_for(0.5), MAX_FIXED_STEPS_PER_FRAME);
+        // Відкинутий час не надолужується наступним кадром
+        assert_eq!(stepper.steps_for(0.0), 0);
+        assert_eq!(stepper.step_count(), MAX_FIXED_STEPS_PER_FRAME as u64);
+    }
+
+    #[test]
+    fn interpolation_alpha_in_unit_range() {
+        let mut stepper = FixedStepper::new(DT);
+        assert_eq!(stepper.interpolation_alpha(), 0.0);
+        stepper.accumulate(DT * 0.25);
+        assert!((stepper.interpolation_alpha() - 0.25).abs() < 1e-5);
+        stepper.accumulate(DT * 0.5);
+        assert!((stepper.interpolation_alpha() - 0.75).abs() < 1e-5);
+        // Кроки ще не забрані - alpha не більша за 1
+        stepper.accumulate(DT * 2.0);
+        assert_eq!(stepper.interpolation_alpha(), 1.0);
+    }
+}
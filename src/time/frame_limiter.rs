@@ -0,0 +1,128 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/time/frame_limiter.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   FrameLimiter - обмеження частоти кадрів (max_fps).
+
+   З ControlFlow::Poll і Mailbox / Immediate кадри не чекають VSync, і гра
+   займає ціле ядро. Limiter чекає залишок бюджету кадру перед наступним
+   request_redraw.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Бюджет кадру з max_fps (0 = без обмеження)
+   - Очікування: thread::sleep до SPIN_MARGIN перед дедлайном, далі spin
+     (sleep ОС неточний на 1-2 мс, spin добирає решту)
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Імпортує:
+   - settings.rs - [rendering] max_fps
+
+   Експортує для:
+   - main.rs - App::about_to_wait
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+   - Дедлайни йдуть рівним кроком від попереднього, а не від "зараз" -
+     середня частота точно max_fps
+   - Кадр довший за бюджет не надолужується пачкою коротких: дедлайн
+     відстав більше ніж на кадр → відлік заново від "зараз"
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - max_fps, sleep + spin
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use std::time::{Duration, Instant};
+
+use crate::settings::Settings;
+
+/// Скільки перед дедлайном перестати спати і крутитись (sleep ОС неточний)
+const SPIN_MARGIN: Duration = Duration::from_millis(2);
+
+/// Обмеження частоти кадрів
+#[derive(Debug, Clone)]
+pub struct FrameLimiter {
+    /// Бюджет кадру (None - без обмеження)
+    frame_budget: Option<Duration>,
+
+    /// Дедлайн наступного кадру
+    next_frame: Instant,
+}
+
+impl FrameLimiter {
+    /// Створює limiter (`max_fps` = 0 - без обмеження)
+    pub fn new(max_fps: u32) -> Self {
+        Self {
+            frame_budget: (max_fps > 0).then(|| Duration::from_secs_f64(1.0 / max_fps as f64)),
+            next_frame: Instant::now(),
+        }
+    }
+
+    /// Читає `[rendering] max_fps` (0 або відсутній - без обмеження)
+    pub fn from_settings(settings: &Settings) -> Self {
+        let max_fps = settings.get_u32("rendering.max_fps", 0);
+        if max_fps > 0 {
+            log::info!("Обмеження кадрів: {} FPS", max_fps);
+        }
+        Self::new(max_fps)
+    }
+
+    /// Чекає до дедлайну кадру (без обмеження - одразу повертається)
+    pub fn wait(&mut self) {
+        let Some(budget) = self.frame_budget else {
+            return;
+        };
+
+        let now = Instant::now();
+        if let Some(remaining) = self.next_frame.checked_duration_since(now) {
+            if remaining > SPIN_MARGIN {
+                std::thread::sleep(remaining - SPIN_MARGIN);
+            }
+            while Instant::now() < self.next_frame {
+                std::hint::spin_loop();
+            }
+        }
+
+        // Рівний крок; відстали більше ніж на кадр - відлік від "зараз"
+        self.next_frame += budget;
+        let now = Instant::now();
+        if self.next_frame < now {
+            self.next_frame = now + budget;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limiter_spaces_frames_by_budget() {
+        let mut limiter = FrameLimiter::new(200);
+        let start = Instant::now();
+        // Перший кадр - одразу, далі 10 дедлайнів по 5 мс
+        for _ in 0..11 {
+            limiter.wait();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(50), "кадри частіше за max_fps: {:?}", start.elapsed());
+    }
+
+    #[test]
+    fn unlimited_does_not_wait() {
+        let mut limiter = FrameLimiter::new(0);
+        let start = Instant::now();
+        for _ in 0..1000 {
+            limiter.wait();
+        }
+        assert!(start.elapsed() < Duration::from_millis(50));
+    }
+
+    #[test]
+    fn max_fps_from_settings() {
+        let limiter = FrameLimiter::from_settings(&Settings::parse("[rendering]\nmax_fps = 50\n"));
+        assert_eq!(limiter.frame_budget, Some(Duration::from_millis(20)));
+        assert_eq!(FrameLimiter::from_settings(&Settings::parse("")).frame_budget, None);
+    }
+}
@@ -10,20 +10,58 @@
    - Delta time (час між кадрами)
    - Total elapsed time
    - Frame counting
+   - Time scale (slow-mo): set_time_scale, плавний ramp_time_scale
+   - Пауза: pause() / resume() - delta() = 0, real_delta() йде далі
+   - Обмеження delta кадру (max_delta, [physics] max_frame_delta)
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Імпортує:
+   - settings.rs - [physics] max_frame_delta
 
 ⚠️  ВАЖЛИВІ ДЕТАЛІ:
    - Delta time в секундах (f32)
-   - Clamped до max 0.1s для уникнення physics explosions
+   - Clamped до max_delta (дефолт 0.1s) для уникнення physics explosions:
+     перетягування вікна чи ривок ОС не телепортує ragdoll і не
+     "прострочує" таймери бою за один кадр
    - Використовує std::time::Instant для точності
+   - delta() - масштабований (фізика, бій, вороги, анімації),
+     real_delta() - реальний (камера, миша, UI); total() - ігровий час
+   - time scale в межах MIN_TIME_SCALE..=MAX_TIME_SCALE; ramp рахується
+     в реальному часі (не сповільнюється сам собою), на паузі стоїть
 
 🕐 ІСТОРІЯ:
    2025-12-14: Створено - базовий delta time tracking
+   2026-10-16: resume() - без стрибка delta після паузи
+   2026-10-16: Time scale (slow-mo) - delta() масштабований, real_delta()
+   2026-10-16: update_with_delta - delta кадру ззовні (відтворення replay)
+   2026-10-16: pause() - ігровий час стоїть до resume()
+   2026-10-16: max_delta з settings.ini ([physics] max_frame_delta)
 
 ═══════════════════════════════════════════════════════════════════════════════
 */
 
 use std::time::Instant;
 
+use crate::settings::Settings;
+
+/// Найбільша delta кадру за замовчуванням (секунди)
+pub const DEFAULT_MAX_DELTA: f32 = 0.1;
+
+/// Найменший time scale (0 - це вже пауза, див. GameState::Paused)
+pub const MIN_TIME_SCALE: f32 = 0.05;
+
+/// Найбільший time scale (далі fixed timestep не встигає за кадром)
+pub const MAX_TIME_SCALE: f32 = 2.0;
+
+/// Плавна зміна time scale (ramp_time_scale)
+#[derive(Debug, Clone, Copy)]
+struct TimeScaleRamp {
+    from: f32,
+    to: f32,
+    elapsed: f32,
+    duration: f32,
+}
+
 /// GameTime - управління часом в грі
 ///
 /// Відстежує delta time (час між кадрами) та загальний час.
@@ -32,9 +70,24 @@ pub struct GameTime {
     /// Час останнього кадру
     last_frame: Instant,
 
-    /// Delta time в секундах (час з останнього кадру)
+    /// Delta time в секундах (час з останнього кадру × time_scale)
     delta_time: f32,
 
+    /// Реальний delta time в секундах (без time_scale)
+    real_delta_time: f32,
+
+    /// Множник швидкості ігрового часу (1.0 - нормальна)
+    time_scale: f32,
+
+    /// Плавна зміна time_scale, що триває
+    ramp: Option<TimeScaleRamp>,
+
+    /// Пауза: delta() = 0, total() не росте (pause / resume)
+    paused: bool,
+
+    /// Найбільша реальна delta кадру в секундах (довший кадр обрізається)
+    max_delta: f32,
+
     /// Загальний час з початку гри в секундах
     total_time: f32,
 
@@ -48,44 +101,139 @@ impl GameTime {
         Self {
             last_frame: Instant::now(),
             delta_time: 0.0,
+            real_delta_time: 0.0,
+            time_scale: 1.0,
+            ramp: None,
+            paused: false,
+            max_delta: DEFAULT_MAX_DELTA,
             total_time: 0.0,
             frame_count: 0,
         }
     }
 
+    /// GameTime з `[physics] max_frame_delta` (секунди, дефолт 0.1)
+    pub fn from_settings(settings: &Settings) -> Self {
+        let mut game_time = Self::new();
+        game_time.set_max_delta(settings.get_f32("physics.max_frame_delta", DEFAULT_MAX_DELTA));
+        game_time
+    }
+
+    /// Встановлює найбільшу delta кадру (секунди; не менше 1 мс)
+    pub fn set_max_delta(&mut self, max_delta: f32) {
+        self.max_delta = if max_delta.is_finite() { max_delta.max(0.001) } else { DEFAULT_MAX_DELTA };
+    }
+
     /// Оновлює час (викликати на початку кожного кадру)
     ///
     /// Обчислює delta time та оновлює total time.
-    /// Delta time обмежений максимумом 0.1s (100ms) щоб уникнути
+    /// Delta time обмежений max_delta (дефолт 100ms) щоб уникнути
     /// physics explosions при великих лагах.
     pub fn update(&mut self) {
         let now = Instant::now();
         let elapsed = now.duration_since(self.last_frame);
 
+        // Зберігаємо час цього кадру
+        self.last_frame = now;
+
         // Конвертуємо в секунди
-        let raw_delta = elapsed.as_secs_f32();
+        self.update_with_delta(elapsed.as_secs_f32());
+    }
 
-        // Clamp delta time до максимуму 100ms
+    /// Оновлює час з заданою реальною delta замість годинника
+    ///
+    /// Для replay: кадри відтворюються з записаними delta (ті самі кроки фізики).
+    pub fn update_with_delta(&mut self, raw_delta: f32) {
+        // Clamp delta time до max_delta
         // Це важливо для уникнення physics explosions при лагах
-        self.delta_time = raw_delta.min(0.1);
+        self.real_delta_time = raw_delta.min(self.max_delta);
+
+        // Кадри рахуються і на паузі (FPS)
+        self.frame_count += 1;
+        if self.paused {
+            self.delta_time = 0.0;
+            return;
+        }
+
+        // Ramp - в реальному часі, потім масштабуємо delta
+        if let Some(ramp) = &mut self.ramp {
+            ramp.elapsed += self.real_delta_time;
+            let t = (ramp.elapsed / ramp.duration).min(1.0);
+            self.time_scale = ramp.from + (ramp.to - ramp.from) * t;
+            if t >= 1.0 {
+                self.ramp = None;
+            }
+        }
+        self.delta_time = self.real_delta_time * self.time_scale;
 
         // Оновлюємо total time
         self.total_time += self.delta_time;
+    }
 
-        // Оновлюємо frame count
-        self.frame_count += 1;
+    /// Ставить ігровий час на паузу (до resume)
+    ///
+    /// delta() = 0, real_delta() та frame_count() - як завжди (UI, FPS).
+    pub fn pause(&mut self) {
+        self.paused = true;
+        self.delta_time = 0.0;
+    }
 
-        // Зберігаємо час цього кадру
-        self.last_frame = now;
+    /// Знімає паузу і скидає годинник кадру
+    ///
+    /// Наступний update() рахує delta від цього моменту, а не від
+    /// останнього кадру до паузи (інакше - стрибок delta і ragdoll "летить").
+    pub fn resume(&mut self) {
+        self.paused = false;
+        self.last_frame = Instant::now();
+        self.delta_time = 0.0;
+        self.real_delta_time = 0.0;
+    }
+
+    /// Множник швидкості ігрового часу
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale
     }
 
-    /// Повертає delta time в секундах
+    /// Time scale, до якого йде ramp (без ramp - поточний)
+    pub fn target_time_scale(&self) -> f32 {
+        self.ramp.map_or(self.time_scale, |ramp| ramp.to)
+    }
+
+    /// Встановлює time scale одразу (скасовує ramp)
+    ///
+    /// Обмежується до MIN_TIME_SCALE..=MAX_TIME_SCALE
+    pub fn set_time_scale(&mut self, scale: f32) {
+        self.time_scale = scale.clamp(MIN_TIME_SCALE, MAX_TIME_SCALE);
+        self.ramp = None;
+    }
+
+    /// Плавно змінює time scale до `target` за `duration` секунд реального часу
+    ///
+    /// Рахується в update(); duration <= 0 - як set_time_scale
+    pub fn ramp_time_scale(&mut self, target: f32, duration: f32) {
+        let target = target.clamp(MIN_TIME_SCALE, MAX_TIME_SCALE);
+        if duration <= 0.0 {
+            self.set_time_scale(target);
+            return;
+        }
+        self.ramp = Some(TimeScaleRamp {
+            from: self.time_scale,
+            to: target,
+            elapsed: 0.0,
+            duration,
+        });
+    }
+
+    /// Повертає delta time в секундах (з урахуванням time scale)
     ///
     /// Це час з попереднього кадру. Використовуйте для
     /// frame-rate independent руху та анімацій.
     ///
     /// # Приклад
     /// ```
+    /// # use arena_combat::time::GameTime;
+    /// # use glam::Vec3;
+    /// # let game_time = GameTime::new();
+    /// # let (mut position, velocity) = (Vec3::ZERO, Vec3::X);
     /// // Рух зі швидкістю 5 units/second
     /// let speed = 5.0;
     /// position += velocity * speed * game_time.delta();
@@ -95,7 +243,15 @@ impl GameTime {
         self.delta_time
     }
 
-    /// Повертає загальний час з початку гри в секундах
+    /// Повертає реальний delta time в секундах (без time scale)
+    ///
+    /// Для камери, миші та UI - керування не сповільнюється в slow-mo.
+    #[inline]
+    pub fn real_delta(&self) -> f32 {
+        self.real_delta_time
+    }
+
+    /// Повертає загальний ігровий час з початку гри в секундах
     ///
     /// Корисно для shader effects, анімацій тощо.
     #[inline]
@@ -121,3 +277,99 @@ impl Default for GameTime {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Кадр 60 FPS
+    const FRAME: f32 = 1.0 / 60.0;
+
+    #[test]
+    fn paused_delta_is_zero_but_real_time_runs() {
+        let mut time = GameTime::new();
+        time.update_with_delta(FRAME);
+        let total = time.total();
+
+        time.pause();
+        for _ in 0..10 {
+            time.update_with_delta(FRAME);
+            assert_eq!(time.delta(), 0.0);
+            assert_eq!(time.real_delta(), FRAME);
+        }
+        assert_eq!(time.total(), total, "ігровий час йде на паузі");
+        assert_eq!(time.frame_count(), 11, "кадри рахуються і на паузі");
+
+        time.resume();
+        assert_eq!(time.delta(), 0.0);
+        time.update_with_delta(FRAME);
+        assert_eq!(time.delta(), FRAME);
+    }
+
+    #[test]
+    fn half_time_scale_halves_delta() {
+        let mut time = GameTime::new();
+        time.set_time_scale(0.5);
+        for _ in 0..60 {
+            time.update_with_delta(FRAME);
+            assert!((time.delta() - FRAME * 0.5).abs() < 1e-7);
+            assert_eq!(time.real_delta(), FRAME);
+        }
+        assert!((time.total() - 0.5).abs() < 1e-4, "за секунду реального часу - пів секунди ігрового");
+    }
+
+    #[test]
+    fn time_scale_is_clamped() {
+        let mut time = GameTime::new();
+        time.set_time_scale(0.0);
+        assert_eq!(time.time_scale(), MIN_TIME_SCALE);
+        time.set_time_scale(10.0);
+        assert_eq!(time.time_scale(), MAX_TIME_SCALE);
+    }
+
+    #[test]
+    fn ramp_reaches_target_in_real_time() {
+        let mut time = GameTime::new();
+        time.ramp_time_scale(0.25, 0.5);
+        assert_eq!(time.target_time_scale(), 0.25);
+        for _ in 0..5 {
+            time.update_with_delta(0.05);
+        }
+        assert!((time.time_scale() - 0.625).abs() < 1e-4, "половина ramp: {}", time.time_scale());
+        for _ in 0..5 {
+            time.update_with_delta(0.05);
+        }
+        assert_eq!(time.time_scale(), 0.25);
+    }
+
+    #[test]
+    fn long_frame_is_clamped_to_max_delta() {
+        let mut time = GameTime::new();
+        time.update_with_delta(2.0);
+        assert_eq!(time.real_delta(), DEFAULT_MAX_DELTA);
+        assert_eq!(time.delta(), DEFAULT_MAX_DELTA);
+
+        time.set_max_delta(0.05);
+        time.update_with_delta(0.2);
+        assert_eq!(time.delta(), 0.05);
+        // Некоректні значення не обнуляють delta
+        time.set_max_delta(0.0);
+        time.update_with_delta(0.2);
+        assert_eq!(time.delta(), 0.001);
+        time.set_max_delta(f32::NAN);
+        time.update_with_delta(0.2);
+        assert_eq!(time.delta(), DEFAULT_MAX_DELTA);
+    }
+
+    #[test]
+    fn max_delta_from_settings() {
+        let settings = Settings::parse("[physics]\nmax_frame_delta = 0.25\n");
+        let mut time = GameTime::from_settings(&settings);
+        time.update_with_delta(1.0);
+        assert_eq!(time.delta(), 0.25);
+
+        let mut time = GameTime::from_settings(&Settings::parse(""));
+        time.update_with_delta(1.0);
+        assert_eq!(time.delta(), DEFAULT_MAX_DELTA);
+    }
+}
@@ -8,8 +8,9 @@
 
 🎯 ВІДПОВІДАЛЬНІСТЬ:
    - GameTime struct для tracking часу
+   - FrameLimiter - обмеження частоти кадрів (max_fps)
+   - FixedStepper - fixed timestep для physics (accumulator)
    - Delta time для frame-rate independence
-   - (Майбутнє) Fixed timestep для physics
 
 🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
    Експортує для:
@@ -21,10 +22,18 @@
 
 🕐 ІСТОРІЯ:
    2025-12-14: Створено - базовий GameTime з delta time
+   2026-10-16: Time scale (slow-mo), real_delta
+   2026-10-16: FrameLimiter
+   2026-10-16: GameTime::from_settings (max_frame_delta)
+   2026-10-17: FixedStepper (accumulator з PhysicsWorld)
 
 ═══════════════════════════════════════════════════════════════════════════════
 */
 
+mod fixed_stepper;
+mod frame_limiter;
 mod game_time;
 
+pub use fixed_stepper::{FixedStepper, MAX_FIXED_STEPS_PER_FRAME};
+pub use frame_limiter::FrameLimiter;
 pub use game_time::GameTime;
@@ -0,0 +1,47 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/transform/angle.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   Допоміжні функції для кутів (yaw) - нормалізація та плавний поворот.
+
+   Раніше логіка "найкоротший шлях + обмеження швидкості повороту" жила
+   тільки в Player::smooth_rotate. Тепер її використовують і Player, і Enemy.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - wrap_angle: кут → [-PI, PI]
+   - rotate_towards: крок повороту до цілі найкоротшим шляхом
+
+⚠️  ВАЖЛИВІ ДЕТАЛІ:
+   - Всі кути в радіанах
+   - max_step - максимальна зміна за виклик (turn_speed * delta)
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - спільний поворот для Player та Enemy
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use std::f32::consts::{PI, TAU};
+
+/// Нормалізує кут до [-PI, PI]
+pub fn wrap_angle(angle: f32) -> f32 {
+    if !angle.is_finite() {
+        return 0.0;
+    }
+    (angle + PI).rem_euclid(TAU) - PI
+}
+
+/// Повертає `current` в бік `target` найкоротшим шляхом, не більше ніж на `max_step`
+///
+/// # Повертає
+/// Новий кут в [-PI, PI] (точно `target`, якщо він ближче за max_step)
+pub fn rotate_towards(current: f32, target: f32, max_step: f32) -> f32 {
+    let diff = wrap_angle(target - current);
+    if diff.abs() <= max_step {
+        wrap_angle(target)
+    } else {
+        wrap_angle(current + diff.signum() * max_step)
+    }
+}
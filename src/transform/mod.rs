@@ -10,6 +10,7 @@
    - Transform struct (position, rotation, scale)
    - Model matrix обчислення
    - Uniform buffer для shader
+   - Кути: нормалізація та плавний поворот (angle.rs)
 
 🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
    Імпортує:
@@ -17,6 +18,7 @@
 
    Експортує для:
    - rendering/mesh.rs - transform для mesh об'єктів
+   - player, enemy - rotate_towards для плавного повороту
    - main.rs (в майбутньому) - керування об'єктами
 
 ⚠️  ВАЖЛИВІ ДЕТАЛІ:
@@ -26,10 +28,14 @@
 
 🕐 ІСТОРІЯ:
    2025-12-14: Створено - Transform system для 3D об'єктів
+   2026-10-16: Додано angle.rs (wrap_angle, rotate_towards)
 
 ═══════════════════════════════════════════════════════════════════════════════
 */
 
+#[allow(clippy::module_inception)]
 mod transform;
+mod angle;
 
 pub use transform::{Transform, TransformUniform};
+pub use angle::{wrap_angle, rotate_towards};
@@ -21,6 +21,7 @@
 
 🕐 ІСТОРІЯ:
    2025-12-14: Створено - базовий Transform з Model matrix
+   2026-10-16: _padding → tint (RGBA) для fade-out мертвих ворогів
 
 ═══════════════════════════════════════════════════════════════════════════════
 */
@@ -162,8 +163,9 @@ pub struct TransformUniform {
     /// Padding до 16 bytes alignment
     pub normal_matrix: [[f32; 4]; 3],
 
-    /// Padding для вирівнювання (16 bytes alignment)
-    pub _padding: [f32; 4],
+    /// Tint (RGBA множник кольору), alpha < 1.0 = прозорість
+    /// Також забезпечує 16 bytes alignment
+    pub tint: [f32; 4],
 }
 
 impl TransformUniform {
@@ -172,7 +174,7 @@ impl TransformUniform {
         Self {
             model: Mat4::IDENTITY.to_cols_array_2d(),
             normal_matrix: [[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0]],
-            _padding: [0.0; 4],
+            tint: [1.0; 4],
         }
     }
 
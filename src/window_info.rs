@@ -0,0 +1,172 @@
+/*
+═══════════════════════════════════════════════════════════════════════════════
+ ФАЙЛ: src/window_info.rs
+═══════════════════════════════════════════════════════════════════════════════
+
+📋 ПРИЗНАЧЕННЯ:
+   WindowInfo - формат заголовку вікна та частота його оновлення.
+
+   Раніше заголовок "Arena Combat Prototype - {fps} FPS" та інтервал
+   "кожні 30 кадрів" були hard-coded в main.rs. Тепер це шаблон з
+   settings.ini, в якому можна показувати FPS, health, wave, score.
+
+🎯 ВІДПОВІДАЛЬНІСТЬ:
+   - Шаблон заголовку з placeholders ({fps}, {frame_ms}, {health}, ...)
+   - Інтервал оновлення (в кадрах або секундах)
+   - Рішення "чи оновлювати заголовок цього кадру"
+
+🔗 ЗВ'ЯЗКИ З ІНШИМИ ФАЙЛАМИ:
+   Імпортує:
+   - settings.rs - секція [window]
+
+   Експортує для:
+   - main.rs - оновлення заголовку в RedrawRequested
+
+⚠️  ВАЖЛИВІ ОБМЕЖЕННЯ:
+   1. window.set_title() не безкоштовний - не оновлювати кожен кадр
+   2. Невідомі placeholders залишаються в тексті як є
+   3. Відсутні значення (напр. health без гравця) показуються як "-"
+
+🕐 ІСТОРІЯ:
+   2026-10-16: Створено - шаблон заголовку та інтервал з settings.ini
+   2026-10-16: Placeholder {quality} - поточний рівень адаптивної якості
+   2026-10-16: Placeholder {stamina} - витривалість гравця у відсотках
+   2026-10-16: TitleStats::tag - префікс режиму ("[FREECAM] ...") перед шаблоном,
+               request_update - оновити заголовок вже наступного кадру
+   2026-10-16: Placeholder {present} - present mode surface
+
+═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use crate::settings::Settings;
+
+/// Дефолтний шаблон заголовку (як було раніше в main.rs)
+pub const DEFAULT_TITLE_TEMPLATE: &str = "Arena Combat Prototype - {fps} FPS ({frame_ms}ms)";
+
+/// Як часто оновлювати заголовок
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TitleUpdateInterval {
+    /// Кожні N кадрів
+    Frames(u32),
+    /// Кожні N секунд
+    Seconds(f32),
+}
+
+/// Значення для підстановки в шаблон заголовку
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TitleStats {
+    pub fps: f32,
+    pub frame_time_ms: f32,
+    pub health: Option<f32>,
+    pub wave: Option<u32>,
+    pub score: Option<u32>,
+    pub enemies_alive: Option<usize>,
+    pub quality: Option<&'static str>,
+    pub stamina: Option<f32>,
+    /// Present mode surface ("fifo", "mailbox", "immediate")
+    pub present_mode: Option<&'static str>,
+    /// Режим, що показується на початку заголовку в дужках (напр. "FREECAM")
+    pub tag: Option<&'static str>,
+}
+
+/// Конфігурація заголовку вікна
+#[derive(Debug, Clone)]
+pub struct WindowInfo {
+    /// Шаблон заголовку з placeholders
+    pub title_template: String,
+
+    /// Інтервал оновлення
+    pub update_interval: TitleUpdateInterval,
+
+    /// Кадрів з останнього оновлення
+    frames_since_update: u32,
+
+    /// Секунд з останнього оновлення
+    time_since_update: f32,
+}
+
+impl WindowInfo {
+    /// Створює WindowInfo з шаблоном та інтервалом
+    pub fn new(title_template: impl Into<String>, update_interval: TitleUpdateInterval) -> Self {
+        Self {
+            title_template: title_template.into(),
+            update_interval,
+            frames_since_update: 0,
+            time_since_update: 0.0,
+        }
+    }
+
+    /// Читає секцію [window] з налаштувань
+    ///
+    /// Ключі:
+    /// - `title` - шаблон заголовку
+    /// - `title_update_seconds` - інтервал в секундах (має пріоритет)
+    /// - `title_update_frames` - інтервал в кадрах (дефолт 30)
+    pub fn from_settings(settings: &Settings) -> Self {
+        let template = settings
+            .get_str("window.title")
+            .unwrap_or(DEFAULT_TITLE_TEMPLATE);
+
+        let interval = match settings.get::<f32>("window.title_update_seconds") {
+            Some(seconds) if seconds > 0.0 => TitleUpdateInterval::Seconds(seconds),
+            _ => TitleUpdateInterval::Frames(settings.get_u32("window.title_update_frames", 30).max(1)),
+        };
+
+        Self::new(template, interval)
+    }
+
+    /// Викликається раз на кадр
+    ///
+    /// # Повертає
+    /// `true` якщо цього кадру треба оновити заголовок
+    pub fn tick(&mut self, delta: f32) -> bool {
+        self.frames_since_update += 1;
+        self.time_since_update += delta;
+
+        let due = match self.update_interval {
+            TitleUpdateInterval::Frames(frames) => self.frames_since_update >= frames,
+            TitleUpdateInterval::Seconds(seconds) => self.time_since_update >= seconds,
+        };
+
+        if due {
+            self.frames_since_update = 0;
+            self.time_since_update = 0.0;
+        }
+        due
+    }
+
+    /// Наступний tick поверне `true` (заголовок змінився не за розкладом)
+    pub fn request_update(&mut self) {
+        self.frames_since_update = u32::MAX - 1;
+        self.time_since_update = f32::INFINITY;
+    }
+
+    /// Підставляє значення в шаблон
+    pub fn format_title(&self, stats: &TitleStats) -> String {
+        fn or_dash<T: ToString>(value: Option<T>) -> String {
+            value.map(|v| v.to_string()).unwrap_or_else(|| "-".to_string())
+        }
+
+        let title = self.title_template
+            .replace("{fps}", &format!("{:.1}", stats.fps))
+            .replace("{frame_ms}", &format!("{:.2}", stats.frame_time_ms))
+            .replace("{health}", &or_dash(stats.health.map(|h| h.round() as i32)))
+            .replace("{wave}", &or_dash(stats.wave))
+            .replace("{score}", &or_dash(stats.score))
+            .replace("{enemies}", &or_dash(stats.enemies_alive))
+            .replace("{quality}", &or_dash(stats.quality))
+            .replace("{stamina}", &or_dash(stats.stamina.map(|s| (s * 100.0).round() as i32)))
+            .replace("{present}", &or_dash(stats.present_mode));
+
+        match stats.tag {
+            Some(tag) => format!("[{}] {}", tag, title),
+            None => title,
+        }
+    }
+}
+
+impl Default for WindowInfo {
+    fn default() -> Self {
+        Self::new(DEFAULT_TITLE_TEMPLATE, TitleUpdateInterval::Frames(30))
+    }
+}
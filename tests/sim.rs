@@ -0,0 +1,172 @@
+//! Integration тести Simulation: фізика без вікна, фіксовані кроки
+//!
+//! `cargo test --test sim`
+
+use arena_combat::arena::{ArenaConfig, ArenaShape, RING_WALL_THICKNESS};
+use arena_combat::physics::ragdoll::RagdollMode;
+use arena_combat::physics::BoneId;
+use arena_combat::settings::Settings;
+use arena_combat::sim::{SimInput, Simulation};
+use glam::Vec3;
+
+/// Кроки прогону (10 секунд при 60 Гц)
+const TICKS: u32 = 600;
+
+/// Розгін ходьби перед виміром швидкості (5 секунд)
+const WALK_WARMUP_TICKS: u32 = 300;
+
+/// Вікно виміру швидкості ходьби (3 секунди)
+const WALK_MEASURE_TICKS: u32 = 180;
+
+/// Simulation з дефолтними налаштуваннями, без ворогів
+fn simulation() -> Simulation {
+    let settings = Settings::parse("[enemies]\ncount = 0\n");
+    Simulation::from_settings(&settings, &ArenaConfig::default())
+}
+
+#[test]
+fn standing_ragdoll_keeps_invariants() {
+    let mut sim = simulation();
+    let input = SimInput::default();
+    for tick in 1..=TICKS {
+        sim.advance(1, &input);
+        if let Err(e) = sim.check_invariants() {
+            panic!("крок {}: {}", tick, e);
+        }
+    }
+    assert_eq!(sim.ragdoll.mode, RagdollMode::Active);
+}
+
+#[test]
+fn standing_pelvis_stays_above_half_meter() {
+    let mut sim = simulation();
+    let input = SimInput::default();
+    for _ in 0..TICKS / 10 {
+        sim.advance(10, &input);
+        let pelvis = sim
+            .ragdoll
+            .skeleton
+            .get_bone_position(&sim.physics_world, BoneId::Pelvis)
+            .expect("pelvis");
+        assert!(pelvis.y > 0.5, "pelvis на висоті {}", pelvis.y);
+    }
+}
+
+#[test]
+fn joints_stay_within_tenth_of_bone_length() {
+    let mut sim = simulation();
+    sim.advance(TICKS, &SimInput::default());
+    for bone_id in BoneId::all_bones() {
+        let (Some(bone), Some(separation)) = (
+            sim.ragdoll.skeleton.bones.get(&bone_id),
+            sim.ragdoll.skeleton.joint_separation(&sim.physics_world, bone_id),
+        ) else {
+            continue;
+        };
+        assert!(
+            separation <= bone.length * 0.1,
+            "{:?}: розрив {:.3} м при кістці {:.2} м",
+            bone_id,
+            separation,
+            bone.length
+        );
+    }
+}
+
+#[test]
+fn walking_reaches_walk_speed() {
+    let mut sim = simulation();
+    let input = SimInput {
+        move_dir: Vec3::Z,
+        ..SimInput::default()
+    };
+
+    // Розгін: м'яка допомога кроків набирає швидкість кілька секунд
+    sim.advance(WALK_WARMUP_TICKS, &input);
+    let start = sim.ragdoll.get_position(&sim.physics_world);
+    sim.advance(WALK_MEASURE_TICKS, &input);
+    let end = sim.ragdoll.get_position(&sim.physics_world);
+
+    let seconds = WALK_MEASURE_TICKS as f32 * sim.physics_world.fixed_timestep;
+    let speed = (end.z - start.z) / seconds;
+    assert!(speed >= 1.5, "середня швидкість після розгону {:.2} м/с", speed);
+    assert!((end.x - start.x).abs() < 0.5, "ragdoll звернув: x {:.3} → {:.3}", start.x, end.x);
+    assert!(sim.check_invariants().is_ok());
+}
+
+#[test]
+fn circle_wall_keeps_ragdoll_inside() {
+    let radius = 2.0;
+    let arena = ArenaConfig {
+        half_extent: radius,
+        shape: ArenaShape::Circle,
+        ..ArenaConfig::default()
+    };
+    let mut sim = Simulation::from_settings(&Settings::parse("[enemies]\ncount = 0\n"), &arena);
+    let input = SimInput {
+        move_dir: Vec3::Z,
+        ..SimInput::default()
+    };
+    for _ in 0..TICKS / 10 {
+        sim.advance(10, &input);
+        for bone_id in BoneId::all_bones() {
+            let Some(position) = sim.ragdoll.skeleton.get_bone_position(&sim.physics_world, bone_id) else {
+                continue;
+            };
+            let distance = Vec3::new(position.x, 0.0, position.z).length();
+            assert!(distance < radius + RING_WALL_THICKNESS, "{:?} за стіною: {:.3} м", bone_id, distance);
+        }
+    }
+    // Дійшов до стіни, а не зупинився раніше
+    let pelvis = sim.ragdoll.get_position(&sim.physics_world);
+    assert!(pelvis.z > radius - 0.5, "ragdoll не дійшов до стіни: z = {:.3}", pelvis.z);
+}
+
+#[test]
+fn enemies_ragdolls_stay_finite() {
+    let settings = Settings::parse("[enemies]\ncount = 3\n");
+    let mut sim = Simulation::from_settings(&settings, &ArenaConfig::default());
+    assert_eq!(sim.enemies.len(), 3);
+    sim.advance(TICKS / 5, &SimInput::default());
+    for enemy in &sim.enemies {
+        if let Some(ragdoll) = &enemy.ragdoll {
+            assert!(ragdoll.get_position(&sim.physics_world).is_finite());
+        }
+    }
+    assert!(sim.check_invariants().is_ok());
+}
+
+#[test]
+fn enemy_chases_and_attacks_under_advance() {
+    let settings = Settings::parse("[enemies]\ncount = 1\n");
+    let mut sim = Simulation::from_settings(&settings, &ArenaConfig::default());
+    let distance = |sim: &Simulation| {
+        let to_player = sim.ragdoll.get_position(&sim.physics_world) - sim.enemies[0].position;
+        Vec3::new(to_player.x, 0.0, to_player.z).length()
+    };
+    let start = distance(&sim);
+
+    // Удар ворога - hitbox у HitboxManager (влучання в гравця - в main.rs)
+    let mut attacked = false;
+    for _ in 0..TICKS / 10 {
+        sim.advance(10, &SimInput::default());
+        attacked |= sim.hitbox_manager.active_count() > 0;
+    }
+    let end = distance(&sim);
+    assert!(end < start - 1.0, "ворог не наблизився: {:.2} → {:.2} м", start, end);
+    assert!(end <= sim.enemy_config.attack_range + 0.5, "ворог зупинився на {:.2} м", end);
+    assert!(attacked, "ворог не атакував");
+}
+
+#[test]
+fn reset_returns_player_to_spawn() {
+    let mut sim = simulation();
+    let input = SimInput {
+        move_dir: Vec3::X,
+        ..SimInput::default()
+    };
+    sim.advance(120, &input);
+    sim.reset();
+    let position = sim.ragdoll.get_position(&sim.physics_world);
+    assert!(position.x.abs() < 0.5 && position.z.abs() < 0.5, "після reset: {:?}", position);
+}